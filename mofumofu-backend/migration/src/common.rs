@@ -114,3 +114,15 @@ pub enum ReportStatus {
     #[sea_orm(iden = "dismissed")]
     Dismissed,
 }
+
+#[derive(DeriveIden, EnumIter)]
+pub enum OutboxStatus {
+    #[sea_orm(iden = "outbox_status")]
+    Table,
+    #[sea_orm(iden = "pending")]
+    Pending,
+    #[sea_orm(iden = "delivered")]
+    Delivered,
+    #[sea_orm(iden = "dead")]
+    Dead,
+}
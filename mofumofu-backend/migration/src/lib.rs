@@ -19,6 +19,18 @@ mod m20250815_103031_create_likes_table;
 mod m20250820_061016_create_report_target_type_enum;
 mod m20250820_061038_create_report_status_enum;
 mod m20250820_061054_create_reports_table;
+mod m20261019_000001_add_post_content_stats;
+mod m20261019_000002_create_reactions_table;
+mod m20261019_000003_create_mentions_table;
+mod m20261019_000004_add_comment_moderation;
+mod m20261019_000005_create_comment_edits_table;
+mod m20261019_000006_create_hashtag_follows_table;
+mod m20261019_000007_create_hashtag_aliases_table;
+mod m20261019_000008_create_autocomplete_indexes;
+mod m20261019_000009_add_post_trending_score;
+mod m20261019_000010_create_post_analytics_tables;
+mod m20261019_000011_create_outbox_table;
+mod m20261019_000012_drop_likes_table;
 
 pub struct Migrator;
 
@@ -45,6 +57,18 @@ impl MigratorTrait for Migrator {
             Box::new(m20250820_061016_create_report_target_type_enum::Migration),
             Box::new(m20250820_061038_create_report_status_enum::Migration),
             Box::new(m20250820_061054_create_reports_table::Migration),
+            Box::new(m20261019_000001_add_post_content_stats::Migration),
+            Box::new(m20261019_000002_create_reactions_table::Migration),
+            Box::new(m20261019_000003_create_mentions_table::Migration),
+            Box::new(m20261019_000004_add_comment_moderation::Migration),
+            Box::new(m20261019_000005_create_comment_edits_table::Migration),
+            Box::new(m20261019_000006_create_hashtag_follows_table::Migration),
+            Box::new(m20261019_000007_create_hashtag_aliases_table::Migration),
+            Box::new(m20261019_000008_create_autocomplete_indexes::Migration),
+            Box::new(m20261019_000009_add_post_trending_score::Migration),
+            Box::new(m20261019_000010_create_post_analytics_tables::Migration),
+            Box::new(m20261019_000011_create_outbox_table::Migration),
+            Box::new(m20261019_000012_drop_likes_table::Migration),
         ]
     }
 }
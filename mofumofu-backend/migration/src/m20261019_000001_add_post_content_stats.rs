@@ -0,0 +1,73 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 기존 포스트의 값은 언어 감지가 필요해 SQL로 계산할 수 없으므로
+        // 서버 시작 시 backfill 작업(service::post::backfill_content_stats)이 채운다.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Posts::Table)
+                    .add_column(
+                        ColumnDef::new(Posts::WordCount)
+                            .integer()
+                            .not_null()
+                            .default(0), // 단어 수 (CJK 문자는 글자 단위)
+                    )
+                    .add_column(
+                        ColumnDef::new(Posts::ReadingTime)
+                            .integer()
+                            .not_null()
+                            .default(0), // 예상 읽기 시간 (분)
+                    )
+                    .add_column(ColumnDef::new(Posts::Language).string_len(8).null()) // 감지된 언어 (ISO 639-3)
+                    .to_owned(),
+            )
+            .await?;
+
+        // 언어별 필터링
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_posts_language")
+                    .table(Posts::Table)
+                    .col(Posts::Language)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_posts_language")
+                    .table(Posts::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Posts::Table)
+                    .drop_column(Posts::WordCount)
+                    .drop_column(Posts::ReadingTime)
+                    .drop_column(Posts::Language)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Posts {
+    Table,
+    WordCount,
+    ReadingTime,
+    Language,
+}
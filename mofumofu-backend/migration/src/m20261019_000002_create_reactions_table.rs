@@ -0,0 +1,158 @@
+use crate::common::LikeTargetType;
+use sea_orm_migration::prelude::*;
+use strum::IntoEnumIterator;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Reactions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Reactions::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(ColumnDef::new(Reactions::UserId).uuid().not_null())
+                    .col(ColumnDef::new(Reactions::PostId).uuid().null())
+                    .col(ColumnDef::new(Reactions::CommentId).uuid().null())
+                    .col(
+                        ColumnDef::new(Reactions::TargetType)
+                            .enumeration(LikeTargetType::Table, LikeTargetType::iter().skip(1))
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(Reactions::Emoji).string_len(32).not_null())
+                    .col(
+                        ColumnDef::new(Reactions::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::cust("CURRENT_TIMESTAMP")),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Reactions::Table, Reactions::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Reactions::Table, Reactions::PostId)
+                            .to(Posts::Table, Posts::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Reactions::Table, Reactions::CommentId)
+                            .to(Comments::Table, Comments::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // 사용자는 대상마다 같은 이모지를 한 번만 남길 수 있음
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_unique_user_post_reaction")
+                    .table(Reactions::Table)
+                    .col(Reactions::UserId)
+                    .col(Reactions::PostId)
+                    .col(Reactions::Emoji)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_unique_user_comment_reaction")
+                    .table(Reactions::Table)
+                    .col(Reactions::UserId)
+                    .col(Reactions::CommentId)
+                    .col(Reactions::Emoji)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        // 대상별 이모지 집계용 인덱스
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_reactions_post_id_emoji")
+                    .table(Reactions::Table)
+                    .col(Reactions::PostId)
+                    .col(Reactions::Emoji)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_reactions_comment_id_emoji")
+                    .table(Reactions::Table)
+                    .col(Reactions::CommentId)
+                    .col(Reactions::Emoji)
+                    .to_owned(),
+            )
+            .await?;
+
+        // 기존 좋아요는 ❤️ 반응으로 이전
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "INSERT INTO reactions (user_id, post_id, comment_id, target_type, emoji, created_at) \
+                 SELECT user_id, post_id, comment_id, target_type, '❤️', created_at FROM likes \
+                 ON CONFLICT DO NOTHING;",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Reactions::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Reactions {
+    Table,
+    Id,
+    UserId,
+    PostId,
+    CommentId,
+    TargetType,
+    Emoji,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Posts {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Comments {
+    Table,
+    Id,
+}
@@ -0,0 +1,133 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 멘션 이벤트 타입 추가
+        manager
+            .get_connection()
+            .execute_unprepared("ALTER TYPE action_type ADD VALUE IF NOT EXISTS 'mention_created';")
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(Mentions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Mentions::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(ColumnDef::new(Mentions::MentionedUserId).uuid().not_null()) // 멘션된 사용자
+                    .col(ColumnDef::new(Mentions::MentionerId).uuid().not_null()) // 멘션한 사용자
+                    .col(ColumnDef::new(Mentions::PostId).uuid().not_null())
+                    .col(ColumnDef::new(Mentions::CommentId).uuid().null()) // null이면 포스트 본문의 멘션
+                    .col(
+                        ColumnDef::new(Mentions::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::cust("CURRENT_TIMESTAMP")),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Mentions::Table, Mentions::MentionedUserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Mentions::Table, Mentions::MentionerId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Mentions::Table, Mentions::PostId)
+                            .to(Posts::Table, Posts::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Mentions::Table, Mentions::CommentId)
+                            .to(Comments::Table, Comments::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // 멘션함 조회 (최신순)
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_mentions_mentioned_user_id_created_at")
+                    .table(Mentions::Table)
+                    .col(Mentions::MentionedUserId)
+                    .col(Mentions::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_mentions_post_id")
+                    .table(Mentions::Table)
+                    .col(Mentions::PostId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_mentions_comment_id")
+                    .table(Mentions::Table)
+                    .col(Mentions::CommentId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // PostgreSQL은 enum 값 삭제를 지원하지 않으므로 mention_created는 남겨둠
+        manager
+            .drop_table(Table::drop().table(Mentions::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Mentions {
+    Table,
+    Id,
+    MentionedUserId,
+    MentionerId,
+    PostId,
+    CommentId,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Posts {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Comments {
+    Table,
+    Id,
+}
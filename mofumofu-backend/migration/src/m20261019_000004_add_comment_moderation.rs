@@ -0,0 +1,115 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 포스트 작성자가 댓글 기능을 끌 수 있음
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Posts::Table)
+                    .add_column(
+                        ColumnDef::new(Posts::CommentsDisabled)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Comments::Table)
+                    .add_column(
+                        ColumnDef::new(Comments::IsPinned)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .add_column(
+                        ColumnDef::new(Comments::PinnedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .add_column(
+                        ColumnDef::new(Comments::IsHidden)
+                            .boolean()
+                            .not_null()
+                            .default(false), // 댓글 작성자에게만 보임
+                    )
+                    .add_column(
+                        ColumnDef::new(Comments::IsLocked)
+                            .boolean()
+                            .not_null()
+                            .default(false), // 새 답글 금지
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // 포스트별 고정 댓글 조회
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_comments_post_id_is_pinned")
+                    .table(Comments::Table)
+                    .col(Comments::PostId)
+                    .col(Comments::IsPinned)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_comments_post_id_is_pinned")
+                    .table(Comments::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Comments::Table)
+                    .drop_column(Comments::IsPinned)
+                    .drop_column(Comments::PinnedAt)
+                    .drop_column(Comments::IsHidden)
+                    .drop_column(Comments::IsLocked)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Posts::Table)
+                    .drop_column(Posts::CommentsDisabled)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Posts {
+    Table,
+    CommentsDisabled,
+}
+
+#[derive(DeriveIden)]
+enum Comments {
+    Table,
+    PostId,
+    IsPinned,
+    PinnedAt,
+    IsHidden,
+    IsLocked,
+}
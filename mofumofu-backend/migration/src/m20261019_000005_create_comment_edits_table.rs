@@ -0,0 +1,106 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 댓글 렌더링 결과와 수정 횟수
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Comments::Table)
+                    .add_column(ColumnDef::new(Comments::Render).text().null())
+                    .add_column(
+                        ColumnDef::new(Comments::EditCount)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(CommentEdits::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(CommentEdits::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(ColumnDef::new(CommentEdits::CommentId).uuid().not_null())
+                    .col(ColumnDef::new(CommentEdits::Content).text().not_null()) // 수정 전 내용
+                    .col(
+                        ColumnDef::new(CommentEdits::WrittenAt)
+                            .timestamp_with_time_zone()
+                            .not_null(), // 수정 전 내용이 작성된 시각
+                    )
+                    .col(
+                        ColumnDef::new(CommentEdits::EditedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::cust("CURRENT_TIMESTAMP")),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(CommentEdits::Table, CommentEdits::CommentId)
+                            .to(Comments::Table, Comments::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // 댓글별 수정 기록 조회 (시간순)
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_comment_edits_comment_id_edited_at")
+                    .table(CommentEdits::Table)
+                    .col(CommentEdits::CommentId)
+                    .col(CommentEdits::EditedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CommentEdits::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Comments::Table)
+                    .drop_column(Comments::Render)
+                    .drop_column(Comments::EditCount)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum CommentEdits {
+    Table,
+    Id,
+    CommentId,
+    Content,
+    WrittenAt,
+    EditedAt,
+}
+
+#[derive(DeriveIden)]
+enum Comments {
+    Table,
+    Id,
+    Render,
+    EditCount,
+}
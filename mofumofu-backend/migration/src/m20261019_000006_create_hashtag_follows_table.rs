@@ -0,0 +1,119 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(HashTags::Table)
+                    .add_column(
+                        ColumnDef::new(HashTags::FollowerCount)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(HashtagFollows::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(HashtagFollows::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(ColumnDef::new(HashtagFollows::UserId).uuid().not_null())
+                    .col(ColumnDef::new(HashtagFollows::HashTagId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(HashtagFollows::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::cust("CURRENT_TIMESTAMP")),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(HashtagFollows::Table, HashtagFollows::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(HashtagFollows::Table, HashtagFollows::HashTagId)
+                            .to(HashTags::Table, HashTags::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // 사용자당 해시태그 하나는 한 번만 팔로우
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_hashtag_follows_user_id_hash_tag_id")
+                    .table(HashtagFollows::Table)
+                    .col(HashtagFollows::UserId)
+                    .col(HashtagFollows::HashTagId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_hashtag_follows_hash_tag_id")
+                    .table(HashtagFollows::Table)
+                    .col(HashtagFollows::HashTagId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(HashtagFollows::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(HashTags::Table)
+                    .drop_column(HashTags::FollowerCount)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum HashtagFollows {
+    Table,
+    Id,
+    UserId,
+    HashTagId,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum HashTags {
+    Table,
+    Id,
+    FollowerCount,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
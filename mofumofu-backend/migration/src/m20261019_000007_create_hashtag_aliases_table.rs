@@ -0,0 +1,243 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::{ConnectionTrait, Statement};
+
+// 백엔드와 같은 규칙으로 정규화하도록 함수를 그대로 가져옴
+// (PostgreSQL의 NORMALIZE/LOWER는 유니코드 버전과 collation에 따라 결과가 다를 수 있음)
+#[path = "../../src/utils/hashtag_normalizer.rs"]
+mod hashtag_normalizer;
+
+use hashtag_normalizer::normalize_hashtag;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+const CREATE_NORMALIZED_NAMES: &str = r#"
+CREATE TEMP TABLE hashtag_normalized (
+    id UUID PRIMARY KEY,
+    normalized_name TEXT NOT NULL
+) ON COMMIT DROP
+"#;
+
+/// hashtag_normalized에 들어간 정규화 이름이 같은 해시태그를 하나로 합친다.
+/// (사용량이 가장 많은 해시태그를 남기고 포스트/팔로우 연결을 옮김)
+const MERGE_NORMALIZED_HASHTAGS: &str = r#"
+CREATE TEMP TABLE hashtag_canonical ON COMMIT DROP AS
+SELECT
+    h.id,
+    n.normalized_name,
+    FIRST_VALUE(h.id) OVER (
+        PARTITION BY n.normalized_name
+        ORDER BY h.usage_count DESC, h.created_at ASC
+    ) AS canonical_id
+FROM hash_tags h
+JOIN hashtag_normalized n ON n.id = h.id;
+
+DELETE FROM post_hash_tags p
+USING hashtag_canonical c
+WHERE p.hash_tag_id = c.id
+  AND c.id <> c.canonical_id
+  AND EXISTS (
+      SELECT 1
+      FROM post_hash_tags q
+      JOIN hashtag_canonical qc ON qc.id = q.hash_tag_id
+      WHERE q.post_id = p.post_id
+        AND qc.canonical_id = c.canonical_id
+        AND (qc.id = qc.canonical_id OR q.id < p.id)
+  );
+
+UPDATE post_hash_tags p
+SET hash_tag_id = c.canonical_id
+FROM hashtag_canonical c
+WHERE p.hash_tag_id = c.id AND c.id <> c.canonical_id;
+
+DELETE FROM hashtag_follows f
+USING hashtag_canonical c
+WHERE f.hash_tag_id = c.id
+  AND c.id <> c.canonical_id
+  AND EXISTS (
+      SELECT 1
+      FROM hashtag_follows g
+      JOIN hashtag_canonical gc ON gc.id = g.hash_tag_id
+      WHERE g.user_id = f.user_id
+        AND gc.canonical_id = c.canonical_id
+        AND (gc.id = gc.canonical_id OR g.id < f.id)
+  );
+
+UPDATE hashtag_follows f
+SET hash_tag_id = c.canonical_id
+FROM hashtag_canonical c
+WHERE f.hash_tag_id = c.id AND c.id <> c.canonical_id;
+
+DELETE FROM hash_tags h
+USING hashtag_canonical c
+WHERE h.id = c.id AND c.id <> c.canonical_id;
+
+UPDATE hash_tags h
+SET name = c.normalized_name
+FROM hashtag_canonical c
+WHERE h.id = c.id AND h.name <> c.normalized_name;
+
+UPDATE hash_tags h
+SET usage_count = (SELECT COUNT(*) FROM post_hash_tags p WHERE p.hash_tag_id = h.id),
+    follower_count = (SELECT COUNT(*) FROM hashtag_follows f WHERE f.hash_tag_id = h.id)
+WHERE h.id IN (
+    SELECT canonical_id FROM hashtag_canonical WHERE id <> canonical_id
+);
+"#;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(HashTags::Table)
+                    .add_column(ColumnDef::new(HashTags::Description).text().null())
+                    .add_column(ColumnDef::new(HashTags::CoverImage).text().null())
+                    .add_column(
+                        ColumnDef::new(HashTags::IsBanned)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(HashtagAliases::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(HashtagAliases::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(
+                        ColumnDef::new(HashtagAliases::Alias)
+                            .text()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(ColumnDef::new(HashtagAliases::HashTagId).uuid().not_null())
+                    .col(ColumnDef::new(HashtagAliases::CreatedBy).uuid().null())
+                    .col(
+                        ColumnDef::new(HashtagAliases::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::cust("CURRENT_TIMESTAMP")),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(HashtagAliases::Table, HashtagAliases::HashTagId)
+                            .to(HashTags::Table, HashTags::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(HashtagAliases::Table, HashtagAliases::CreatedBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_hashtag_aliases_hash_tag_id")
+                    .table(HashtagAliases::Table)
+                    .col(HashtagAliases::HashTagId)
+                    .to_owned(),
+            )
+            .await?;
+
+        normalize_existing_hashtags(manager).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 이름 정규화와 병합은 되돌리지 않음
+        manager
+            .drop_table(Table::drop().table(HashtagAliases::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(HashTags::Table)
+                    .drop_column(HashTags::Description)
+                    .drop_column(HashTags::CoverImage)
+                    .drop_column(HashTags::IsBanned)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+/// 기존 해시태그 이름을 Rust에서 정규화한 뒤, 같은 이름이 되는 해시태그를 하나로 합친다.
+async fn normalize_existing_hashtags(manager: &SchemaManager<'_>) -> Result<(), DbErr> {
+    let conn = manager.get_connection();
+    let backend = manager.get_database_backend();
+
+    conn.execute_unprepared(CREATE_NORMALIZED_NAMES).await?;
+
+    let rows = conn
+        .query_all(Statement::from_string(
+            backend,
+            "SELECT id::text AS id, name FROM hash_tags",
+        ))
+        .await?;
+
+    for row in rows {
+        let id: String = row.try_get("", "id")?;
+        let name: String = row.try_get("", "name")?;
+
+        // 정규화 후 빈 이름이 되는 해시태그는 그대로 둠
+        let normalized_name = match normalize_hashtag(&name) {
+            normalized if normalized.is_empty() => name,
+            normalized => normalized,
+        };
+
+        conn.execute(Statement::from_sql_and_values(
+            backend,
+            "INSERT INTO hashtag_normalized (id, normalized_name) VALUES ($1::uuid, $2)",
+            [id.into(), normalized_name.into()],
+        ))
+        .await?;
+    }
+
+    conn.execute_unprepared(MERGE_NORMALIZED_HASHTAGS).await?;
+
+    Ok(())
+}
+
+#[derive(DeriveIden)]
+enum HashtagAliases {
+    Table,
+    Id,
+    Alias,
+    HashTagId,
+    CreatedBy,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum HashTags {
+    Table,
+    Id,
+    Description,
+    CoverImage,
+    IsBanned,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
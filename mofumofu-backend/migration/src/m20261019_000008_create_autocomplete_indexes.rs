@@ -0,0 +1,52 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// 자동완성용 인덱스 (trigram은 접두사/부분 일치 LIKE 검색에 사용)
+const CREATE_TRIGRAM_INDEXES: &str = r#"
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+CREATE INDEX IF NOT EXISTS idx_hash_tags_name_trgm
+    ON hash_tags USING gin (name gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS idx_hashtag_aliases_alias_trgm
+    ON hashtag_aliases USING gin (alias gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS idx_users_handle_trgm
+    ON users USING gin (LOWER(handle) gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS idx_users_name_trgm
+    ON users USING gin (LOWER(name) gin_trgm_ops);
+
+-- 일치하는 사용자가 많을 때 팔로워 순으로 후보를 빠르게 자르기 위함
+CREATE INDEX IF NOT EXISTS idx_users_follower_count
+    ON users (follower_count);
+"#;
+
+// pg_trgm 확장은 다른 곳에서 쓰일 수 있으므로 남겨둠
+const DROP_TRIGRAM_INDEXES: &str = r#"
+DROP INDEX IF EXISTS idx_users_follower_count;
+DROP INDEX IF EXISTS idx_users_name_trgm;
+DROP INDEX IF EXISTS idx_users_handle_trgm;
+DROP INDEX IF EXISTS idx_hashtag_aliases_alias_trgm;
+DROP INDEX IF EXISTS idx_hash_tags_name_trgm;
+"#;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(CREATE_TRIGRAM_INDEXES)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(DROP_TRIGRAM_INDEXES)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,149 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// 가중치는 하나의 행만 두고 모더레이터가 수정
+const INSERT_DEFAULT_TRENDING_SETTINGS: &str = r#"
+INSERT INTO trending_settings (id) VALUES (1) ON CONFLICT (id) DO NOTHING;
+"#;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Posts::Table)
+                    .add_column(
+                        ColumnDef::new(Posts::TrendingScore)
+                            .double()
+                            .not_null()
+                            .default(0.0), // 주기적으로 갱신되는 트렌딩 점수
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // 트렌딩 정렬
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_posts_trending_score")
+                    .table(Posts::Table)
+                    .col(Posts::TrendingScore)
+                    .col(Posts::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(TrendingSettings::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TrendingSettings::Id)
+                            .small_integer()
+                            .not_null()
+                            .primary_key()
+                            .check(Expr::col(TrendingSettings::Id).eq(1)),
+                    )
+                    .col(
+                        ColumnDef::new(TrendingSettings::LikeWeight)
+                            .double()
+                            .not_null()
+                            .default(1.0),
+                    )
+                    .col(
+                        ColumnDef::new(TrendingSettings::CommentWeight)
+                            .double()
+                            .not_null()
+                            .default(2.0),
+                    )
+                    .col(
+                        ColumnDef::new(TrendingSettings::ViewWeight)
+                            .double()
+                            .not_null()
+                            .default(0.05),
+                    )
+                    .col(
+                        ColumnDef::new(TrendingSettings::Gravity)
+                            .double()
+                            .not_null()
+                            .default(1.8), // 시간이 지날수록 점수가 줄어드는 정도
+                    )
+                    .col(ColumnDef::new(TrendingSettings::UpdatedBy).uuid().null())
+                    .col(
+                        ColumnDef::new(TrendingSettings::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::cust("CURRENT_TIMESTAMP")),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TrendingSettings::Table, TrendingSettings::UpdatedBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(INSERT_DEFAULT_TRENDING_SETTINGS)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TrendingSettings::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_posts_trending_score")
+                    .table(Posts::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Posts::Table)
+                    .drop_column(Posts::TrendingScore)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Posts {
+    Table,
+    CreatedAt,
+    TrendingScore,
+}
+
+#[derive(DeriveIden)]
+enum TrendingSettings {
+    Table,
+    Id,
+    LikeWeight,
+    CommentWeight,
+    ViewWeight,
+    Gravity,
+    UpdatedBy,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
@@ -0,0 +1,160 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 포스트별 일간 집계 (날짜는 UTC 기준)
+        manager
+            .create_table(
+                Table::create()
+                    .table(PostDailyStats::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(PostDailyStats::PostId).uuid().not_null())
+                    .col(ColumnDef::new(PostDailyStats::Day).date().not_null())
+                    .col(
+                        ColumnDef::new(PostDailyStats::Views)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(PostDailyStats::UniqueViewers)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(PostDailyStats::Likes)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(PostDailyStats::Comments)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(PostDailyStats::PostId)
+                            .col(PostDailyStats::Day),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(PostDailyStats::Table, PostDailyStats::PostId)
+                            .to(Posts::Table, Posts::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // 기간 내 순 방문자 수를 세기 위한 일별 방문자 (anonymous_user_id)
+        manager
+            .create_table(
+                Table::create()
+                    .table(PostDailyViewers::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(PostDailyViewers::PostId).uuid().not_null())
+                    .col(ColumnDef::new(PostDailyViewers::Day).date().not_null())
+                    .col(ColumnDef::new(PostDailyViewers::ViewerId).text().not_null())
+                    .primary_key(
+                        Index::create()
+                            .col(PostDailyViewers::PostId)
+                            .col(PostDailyViewers::Day)
+                            .col(PostDailyViewers::ViewerId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(PostDailyViewers::Table, PostDailyViewers::PostId)
+                            .to(Posts::Table, Posts::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // 유입 도메인별 일간 조회수 (빈 문자열은 직접 유입)
+        manager
+            .create_table(
+                Table::create()
+                    .table(PostDailyReferrers::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(PostDailyReferrers::PostId).uuid().not_null())
+                    .col(ColumnDef::new(PostDailyReferrers::Day).date().not_null())
+                    .col(ColumnDef::new(PostDailyReferrers::Domain).text().not_null())
+                    .col(
+                        ColumnDef::new(PostDailyReferrers::Views)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(PostDailyReferrers::PostId)
+                            .col(PostDailyReferrers::Day)
+                            .col(PostDailyReferrers::Domain),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(PostDailyReferrers::Table, PostDailyReferrers::PostId)
+                            .to(Posts::Table, Posts::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PostDailyReferrers::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(PostDailyViewers::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(PostDailyStats::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum PostDailyStats {
+    Table,
+    PostId,
+    Day,
+    Views,
+    UniqueViewers,
+    Likes,
+    Comments,
+}
+
+#[derive(DeriveIden)]
+enum PostDailyViewers {
+    Table,
+    PostId,
+    Day,
+    ViewerId,
+}
+
+#[derive(DeriveIden)]
+enum PostDailyReferrers {
+    Table,
+    PostId,
+    Day,
+    Domain,
+    Views,
+}
+
+#[derive(DeriveIden)]
+enum Posts {
+    Table,
+    Id,
+}
@@ -0,0 +1,122 @@
+use crate::common::OutboxStatus;
+use crate::extension::postgres::Type;
+use sea_orm_migration::prelude::*;
+use strum::IntoEnumIterator;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(OutboxStatus::Table)
+                    .values(
+                        OutboxStatus::iter()
+                            .filter(|s| !matches!(s, OutboxStatus::Table))
+                            .collect::<Vec<_>>(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(Outbox::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Outbox::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(ColumnDef::new(Outbox::EventType).text().not_null())
+                    .col(ColumnDef::new(Outbox::Payload).json_binary().not_null())
+                    // 수신 측에서 중복 전달을 걸러낼 수 있도록 함께 보내는 키
+                    .col(
+                        ColumnDef::new(Outbox::IdempotencyKey)
+                            .text()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(
+                        ColumnDef::new(Outbox::Status)
+                            .enumeration(OutboxStatus::Table, OutboxStatus::iter().skip(1))
+                            .not_null()
+                            .default(Expr::cust("'pending'::outbox_status")),
+                    )
+                    .col(
+                        ColumnDef::new(Outbox::Attempts)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(Outbox::NextAttemptAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::cust("CURRENT_TIMESTAMP")),
+                    )
+                    .col(ColumnDef::new(Outbox::LastError).text().null())
+                    .col(
+                        ColumnDef::new(Outbox::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::cust("CURRENT_TIMESTAMP")),
+                    )
+                    .col(
+                        ColumnDef::new(Outbox::DeliveredAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // 전달할 항목을 찾을 때 사용
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_outbox_status_next_attempt_at")
+                    .table(Outbox::Table)
+                    .col(Outbox::Status)
+                    .col(Outbox::NextAttemptAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Outbox::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_type(
+                Type::drop()
+                    .if_exists()
+                    .name(OutboxStatus::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Outbox {
+    Table,
+    Id,
+    EventType,
+    Payload,
+    IdempotencyKey,
+    Status,
+    Attempts,
+    NextAttemptAt,
+    LastError,
+    CreatedAt,
+    DeliveredAt,
+}
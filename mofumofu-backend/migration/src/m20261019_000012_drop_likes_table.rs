@@ -0,0 +1,39 @@
+use crate::m20250815_103031_create_likes_table;
+use sea_orm_migration::prelude::*;
+
+/// 좋아요는 reactions의 ❤️ 반응으로 옮겨졌으므로 더 이상 쓰지 않는 likes 테이블 삭제
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Likes::Table).if_exists().to_owned())
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        m20250815_103031_create_likes_table::Migration
+            .up(manager)
+            .await?;
+
+        // 롤백한 버전이 그동안 생긴 좋아요도 볼 수 있도록 ❤️ 반응을 되돌려 넣음
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "INSERT INTO likes (user_id, post_id, comment_id, target_type, created_at) \
+                 SELECT user_id, post_id, comment_id, target_type, created_at FROM reactions \
+                 WHERE emoji = '❤️' \
+                 ON CONFLICT DO NOTHING;",
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Likes {
+    Table,
+}
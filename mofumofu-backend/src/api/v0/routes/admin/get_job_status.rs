@@ -0,0 +1,39 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+
+use crate::{
+    dto::admin::response::JobStatusResponse, dto::auth::internal::access_token::AccessTokenClaims,
+    service::admin::get_job_status::service_get_job_status, service::error::errors::Errors,
+    state::AppState,
+};
+
+/// 백그라운드 작업 상태 조회
+#[utoipa::path(
+    get,
+    path = "/v0/admin/jobs/{task_id}",
+    summary = "Get background job status",
+    description = "Get the state and result of a background job from the native job queue or the task server. (Admin only)",
+    params(
+        ("task_id" = String, Path, description = "Task ID returned when the job was queued")
+    ),
+    responses(
+        (status = 200, description = "Job status retrieved successfully", body = JobStatusResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn get_job_status(
+    State(app_state): State<AppState>,
+    Extension(token_data): Extension<AccessTokenClaims>,
+    Path(task_id): Path<String>,
+) -> Result<JobStatusResponse, Errors> {
+    let response = service_get_job_status(&app_state, token_data.sub, &task_id).await?;
+
+    Ok(response)
+}
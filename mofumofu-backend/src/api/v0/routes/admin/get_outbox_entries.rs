@@ -0,0 +1,36 @@
+use axum::{Extension, extract::State};
+
+use crate::{
+    dto::admin::request::GetOutboxEntriesRequest, dto::admin::response::OutboxEntriesResponse,
+    dto::auth::internal::access_token::AccessTokenClaims,
+    service::admin::get_outbox_entries::service_get_outbox_entries, service::error::errors::Errors,
+    service::validator::form_validator::ValidatedForm, state::AppState,
+};
+
+/// 전달되지 못한 아웃박스 항목 조회
+#[utoipa::path(
+    get,
+    path = "/v0/admin/outbox",
+    summary = "List stuck outbox entries",
+    description = "List dead-lettered outbox entries, or pending entries that have failed at least once. (Admin only)",
+    params(GetOutboxEntriesRequest),
+    responses(
+        (status = 200, description = "Outbox entries retrieved successfully", body = OutboxEntriesResponse),
+        (status = 400, description = "Invalid input"),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn get_outbox_entries(
+    State(app_state): State<AppState>,
+    Extension(token_data): Extension<AccessTokenClaims>,
+    ValidatedForm(params): ValidatedForm<GetOutboxEntriesRequest>,
+) -> Result<OutboxEntriesResponse, Errors> {
+    let response = service_get_outbox_entries(&app_state.conn, token_data.sub, params).await?;
+
+    Ok(response)
+}
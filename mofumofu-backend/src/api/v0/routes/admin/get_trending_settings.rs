@@ -0,0 +1,33 @@
+use axum::{Extension, extract::State};
+
+use crate::{
+    dto::admin::response::TrendingSettingsResponse,
+    dto::auth::internal::access_token::AccessTokenClaims,
+    service::admin::get_trending_settings::service_get_trending_settings,
+    service::error::errors::Errors, state::AppState,
+};
+
+/// 트렌딩 가중치 조회
+#[utoipa::path(
+    get,
+    path = "/v0/admin/trending/settings",
+    summary = "Get trending settings",
+    description = "Get the weights used to compute post trending scores. (Moderator only)",
+    responses(
+        (status = 200, description = "Trending settings retrieved successfully", body = TrendingSettingsResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn get_trending_settings(
+    State(app_state): State<AppState>,
+    Extension(token_data): Extension<AccessTokenClaims>,
+) -> Result<TrendingSettingsResponse, Errors> {
+    let response = service_get_trending_settings(&app_state.conn, token_data.sub).await?;
+
+    Ok(response)
+}
@@ -1,10 +1,15 @@
 pub mod check_admin_status;
 pub mod cleanup_expired_tokens;
 pub mod cleanup_old_events;
+pub mod get_job_status;
+pub mod get_outbox_entries;
+pub mod get_trending_settings;
 pub mod meilisearch_health;
 pub mod reindex_all_posts;
+pub mod requeue_outbox_entries;
 pub mod routes;
 pub mod search_stats;
 pub mod sync_all_counts;
 pub mod sync_follows;
 pub mod sync_likes;
+pub mod update_trending_settings;
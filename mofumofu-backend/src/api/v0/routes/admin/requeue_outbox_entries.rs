@@ -0,0 +1,38 @@
+use axum::{Extension, extract::State};
+
+use crate::{
+    dto::admin::request::RequeueOutboxEntriesRequest,
+    dto::admin::response::RequeueOutboxEntriesResponse,
+    dto::auth::internal::access_token::AccessTokenClaims,
+    service::admin::requeue_outbox_entries::service_requeue_outbox_entries,
+    service::error::errors::Errors, service::validator::json_validator::ValidatedJson,
+    state::AppState,
+};
+
+/// 아웃박스 항목 재전달 요청
+#[utoipa::path(
+    post,
+    path = "/v0/admin/outbox/requeue",
+    summary = "Requeue outbox entries",
+    description = "Reset the given undelivered outbox entries so the dispatcher retries them immediately. (Admin only)",
+    request_body = RequeueOutboxEntriesRequest,
+    responses(
+        (status = 200, description = "Outbox entries requeued successfully", body = RequeueOutboxEntriesResponse),
+        (status = 400, description = "Invalid input"),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn requeue_outbox_entries(
+    State(app_state): State<AppState>,
+    Extension(token_data): Extension<AccessTokenClaims>,
+    ValidatedJson(payload): ValidatedJson<RequeueOutboxEntriesRequest>,
+) -> Result<RequeueOutboxEntriesResponse, Errors> {
+    let response = service_requeue_outbox_entries(&app_state.conn, token_data.sub, payload).await?;
+
+    Ok(response)
+}
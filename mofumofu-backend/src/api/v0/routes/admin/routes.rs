@@ -1,15 +1,18 @@
 use axum::{
     Router,
-    routing::{get, post},
+    routing::{get, post, put},
 };
 
 use crate::{middleware::auth::access_jwt_auth, state::AppState};
 
 use super::{
     check_admin_status::check_admin_status, cleanup_expired_tokens::cleanup_expired_tokens,
-    cleanup_old_events::cleanup_old_events, meilisearch_health::meilisearch_health,
-    reindex_all_posts::reindex_all_posts, search_stats::search_stats,
+    cleanup_old_events::cleanup_old_events, get_job_status::get_job_status,
+    get_outbox_entries::get_outbox_entries, get_trending_settings::get_trending_settings,
+    meilisearch_health::meilisearch_health, reindex_all_posts::reindex_all_posts,
+    requeue_outbox_entries::requeue_outbox_entries, search_stats::search_stats,
     sync_all_counts::sync_all_counts, sync_follows::sync_follows, sync_likes::sync_likes,
+    update_trending_settings::update_trending_settings,
 };
 
 pub fn admin_routes() -> Router<AppState> {
@@ -24,6 +27,14 @@ pub fn admin_routes() -> Router<AppState> {
         .route("/sync/likes", post(sync_likes))
         .route("/sync/follows", post(sync_follows))
         .route("/sync/all", post(sync_all_counts))
+        // Trending weight endpoints (moderators)
+        .route("/trending/settings", get(get_trending_settings))
+        .route("/trending/settings", put(update_trending_settings))
+        // Background job status
+        .route("/jobs/{task_id}", get(get_job_status))
+        // Outbox endpoints
+        .route("/outbox", get(get_outbox_entries))
+        .route("/outbox/requeue", post(requeue_outbox_entries))
         // Cleanup endpoints
         .route("/cleanup/tokens", post(cleanup_expired_tokens))
         .route("/cleanup/events", post(cleanup_old_events))
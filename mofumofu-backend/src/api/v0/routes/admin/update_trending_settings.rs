@@ -0,0 +1,39 @@
+use axum::{Extension, extract::State};
+
+use crate::{
+    dto::admin::request::UpdateTrendingSettingsRequest,
+    dto::admin::response::TrendingSettingsResponse,
+    dto::auth::internal::access_token::AccessTokenClaims,
+    service::admin::update_trending_settings::service_update_trending_settings,
+    service::error::errors::Errors, service::validator::json_validator::ValidatedJson,
+    state::AppState,
+};
+
+/// 트렌딩 가중치 수정
+#[utoipa::path(
+    put,
+    path = "/v0/admin/trending/settings",
+    summary = "Update trending settings",
+    description = "Update the weights used to compute post trending scores and refresh scores immediately. (Moderator only)",
+    request_body = UpdateTrendingSettingsRequest,
+    responses(
+        (status = 200, description = "Trending settings updated successfully", body = TrendingSettingsResponse),
+        (status = 400, description = "Invalid input"),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn update_trending_settings(
+    State(app_state): State<AppState>,
+    Extension(token_data): Extension<AccessTokenClaims>,
+    ValidatedJson(payload): ValidatedJson<UpdateTrendingSettingsRequest>,
+) -> Result<TrendingSettingsResponse, Errors> {
+    let response =
+        service_update_trending_settings(&app_state.conn, token_data.sub, payload).await?;
+
+    Ok(response)
+}
@@ -17,6 +17,7 @@ use tracing::info;
         (status = 400, description = "Email already verified: email:already_verified"),
         (status = 404, description = "User not found"),
         (status = 422, description = "Validation error"),
+        (status = 429, description = "Rate limit exceeded: rate_limit:exceeded"),
         (status = 500, description = "Internal server error")
     ),
     tag = "Auth"
@@ -30,7 +31,7 @@ pub async fn resend_verification(
         payload.email
     );
 
-    service_resend_verification(&state, &state.conn, payload).await?;
+    service_resend_verification(&state.conn, payload).await?;
 
     Ok(StatusCode::OK)
 }
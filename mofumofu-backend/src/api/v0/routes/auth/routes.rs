@@ -12,18 +12,43 @@ use crate::api::v0::routes::auth::sign_out::sign_out;
 use crate::api::v0::routes::auth::sign_up::sign_up;
 use crate::api::v0::routes::auth::unlink_oauth::unlink_oauth;
 use crate::api::v0::routes::auth::verify_email::verify_email;
+use crate::dto::rate_limit::internal::rate_limit_policy::{RateLimitKey, RateLimitPolicy};
 use crate::middleware::auth::{access_jwt_auth, refresh_jwt_auth};
+use crate::middleware::rate_limit::rate_limit;
 use crate::state::AppState;
 use axum::Router;
+use axum::middleware::from_fn_with_state;
 use axum::routing::{delete, get, post};
 
+// 가입/로그인/메일 발송: 계정이 없는 요청이므로 IP 기준
+const SIGN_UP_RATE_LIMIT: RateLimitPolicy =
+    RateLimitPolicy::sliding_window("auth:sign_up", 5, 3600, RateLimitKey::Ip);
+const SIGN_IN_RATE_LIMIT: RateLimitPolicy =
+    RateLimitPolicy::token_bucket("auth:sign_in", 10, 60, RateLimitKey::Ip);
+const SEND_EMAIL_RATE_LIMIT: RateLimitPolicy =
+    RateLimitPolicy::sliding_window("auth:send_email", 5, 3600, RateLimitKey::Ip);
+
 pub fn auth_routes() -> Router<AppState> {
     Router::new()
-        .route("/auth/sign_in", post(sign_in))
-        .route("/auth/sign_up", post(sign_up))
+        .route(
+            "/auth/sign_in",
+            post(sign_in).route_layer(from_fn_with_state(SIGN_IN_RATE_LIMIT, rate_limit)),
+        )
+        .route(
+            "/auth/sign_up",
+            post(sign_up).route_layer(from_fn_with_state(SIGN_UP_RATE_LIMIT, rate_limit)),
+        )
         .route("/auth/verify_email", post(verify_email))
-        .route("/auth/resend_verification", post(resend_verification))
-        .route("/auth/forgot_password", post(forgot_password))
+        .route(
+            "/auth/resend_verification",
+            post(resend_verification)
+                .route_layer(from_fn_with_state(SEND_EMAIL_RATE_LIMIT, rate_limit)),
+        )
+        .route(
+            "/auth/forgot_password",
+            post(forgot_password)
+                .route_layer(from_fn_with_state(SEND_EMAIL_RATE_LIMIT, rate_limit)),
+        )
         .route("/auth/reset_password", post(reset_password))
         .route(
             "/auth/set_password",
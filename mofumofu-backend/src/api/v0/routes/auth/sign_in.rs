@@ -22,6 +22,7 @@ use std::net::SocketAddr;
         (status = 401, description = "Invalid credentials"),
         (status = 404, description = "User not found"),
         (status = 422, description = "Validation error"),
+        (status = 429, description = "Rate limit exceeded: rate_limit:exceeded"),
         (status = 500, description = "Internal server error")
     ),
     tag = "Auth"
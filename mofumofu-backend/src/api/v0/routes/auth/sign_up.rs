@@ -17,6 +17,7 @@ use tracing::info;
         (status = 400, description = "Invalid input"),
         (status = 409, description = "Handle or email already exists"),
         (status = 422, description = "Validation error"),
+        (status = 429, description = "Rate limit exceeded: rate_limit:exceeded"),
         (status = 500, description = "Internal server error")
     ),
     tag = "Auth"
@@ -27,7 +27,7 @@ pub async fn verify_email(
 ) -> Result<impl IntoResponse, Errors> {
     info!("Received POST request to verify email");
 
-    service_verify_email(&state.conn, payload).await?;
+    service_verify_email(&state.conn, &state.redis, payload).await?;
 
     Ok(StatusCode::OK)
 }
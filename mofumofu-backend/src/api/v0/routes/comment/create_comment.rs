@@ -19,6 +19,7 @@ use tracing::info;
         (status = 201, description = "Comment created successfully", body = CreateCommentResponse),
         (status = StatusCode::NOT_FOUND, description = "Post not found or Parent comment not found"),
         (status = StatusCode::BAD_REQUEST, description = "Invalid parent comment or Cannot reply to deleted comment"),
+        (status = StatusCode::TOO_MANY_REQUESTS, description = "Rate limit exceeded: rate_limit:exceeded"),
         (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error")
     ),
     security(
@@ -34,7 +35,7 @@ pub async fn create_comment(
     info!("Received request to create comment: {:?}", payload);
     let user_uuid = claims.sub.clone();
 
-    let response = service_create_comment(&state.conn, &user_uuid, payload).await?;
+    let response = service_create_comment(&state.conn, &state.redis, &user_uuid, payload).await?;
 
     Ok(response)
 }
@@ -33,7 +33,7 @@ pub async fn delete_comment(
     info!("Received request to delete comment: {:?}", payload);
     let user_uuid = claims.sub.clone();
 
-    service_delete_comment(&state.conn, &user_uuid, payload).await?;
+    service_delete_comment(&state.conn, &state.redis, &user_uuid, payload).await?;
 
     Ok(StatusCode::NO_CONTENT)
 }
@@ -5,6 +5,7 @@ use crate::service::comment::get_comment_by_id::service_get_comment_by_id;
 use crate::service::error::errors::Errors;
 use crate::service::validator::json_validator::ValidatedJson;
 use crate::state::AppState;
+use axum::Extension;
 use axum::extract::State;
 use tracing::info;
 
@@ -21,11 +22,15 @@ use tracing::info;
 )]
 pub async fn get_comment_by_id(
     State(state): State<AppState>,
+    claims: Option<Extension<AccessTokenClaims>>,
     ValidatedJson(payload): ValidatedJson<GetCommentByIdRequest>,
 ) -> Result<CommentInfo, Errors> {
     info!("Received request to get comment by id: {:?}", payload);
 
-    let response = service_get_comment_by_id(&state.conn, payload.comment_id).await?;
+    // 로그인한 경우 본인의 반응을 함께 반환
+    let viewer_id = claims.map(|ext| ext.sub);
+
+    let response = service_get_comment_by_id(&state.conn, payload.comment_id, viewer_id).await?;
 
     Ok(response)
 }
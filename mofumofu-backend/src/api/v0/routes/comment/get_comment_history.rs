@@ -0,0 +1,37 @@
+use crate::dto::auth::internal::access_token::AccessTokenClaims;
+use crate::dto::comment::request::get_comment_history::GetCommentHistoryRequest;
+use crate::dto::comment::response::get_comment_history::GetCommentHistoryResponse;
+use crate::service::comment::get_comment_history::service_get_comment_history;
+use crate::service::error::errors::Errors;
+use crate::service::validator::json_validator::ValidatedJson;
+use crate::state::AppState;
+use axum::Extension;
+use axum::extract::State;
+use tracing::info;
+
+#[utoipa::path(
+    post,
+    path = "/v0/comment/history",
+    request_body = GetCommentHistoryRequest,
+    responses(
+        (status = 200, description = "Comment edit history retrieved successfully", body = GetCommentHistoryResponse),
+        (status = 401, description = "Only the post author and moderators can view edit history"),
+        (status = 404, description = "Comment not found"),
+        (status = 500, description = "Internal Server Error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Comment"
+)]
+pub async fn get_comment_history(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    ValidatedJson(payload): ValidatedJson<GetCommentHistoryRequest>,
+) -> Result<GetCommentHistoryResponse, Errors> {
+    info!("Received request to get comment history: {:?}", payload);
+
+    let response = service_get_comment_history(&state.conn, &claims.sub, payload).await?;
+
+    Ok(response)
+}
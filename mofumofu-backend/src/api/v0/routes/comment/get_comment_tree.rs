@@ -0,0 +1,37 @@
+use crate::dto::auth::internal::access_token::AccessTokenClaims;
+use crate::dto::comment::request::get_comment_tree::GetCommentTreeRequest;
+use crate::dto::comment::response::get_comment_tree::GetCommentTreeResponse;
+use crate::service::comment::get_comment_tree::service_get_comment_tree;
+use crate::service::error::errors::Errors;
+use crate::service::validator::json_validator::ValidatedJson;
+use crate::state::AppState;
+use axum::Extension;
+use axum::extract::State;
+use tracing::info;
+
+#[utoipa::path(
+    post,
+    path = "/v0/comment/tree",
+    request_body = GetCommentTreeRequest,
+    responses(
+        (status = 200, description = "Comment tree retrieved successfully", body = GetCommentTreeResponse),
+        (status = 400, description = "Invalid input"),
+        (status = 404, description = "Post or parent comment not found"),
+        (status = 500, description = "Internal Server Error")
+    ),
+    tag = "Comment"
+)]
+pub async fn get_comment_tree(
+    State(state): State<AppState>,
+    claims: Option<Extension<AccessTokenClaims>>,
+    ValidatedJson(payload): ValidatedJson<GetCommentTreeRequest>,
+) -> Result<GetCommentTreeResponse, Errors> {
+    info!("Received request to get comment tree: {:?}", payload);
+
+    // 로그인한 경우 본인의 반응과 숨긴 댓글을 함께 반환
+    let viewer_id = claims.map(|ext| ext.sub);
+
+    let response = service_get_comment_tree(&state.conn, payload, viewer_id).await?;
+
+    Ok(response)
+}
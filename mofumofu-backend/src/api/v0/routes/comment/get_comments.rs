@@ -1,9 +1,11 @@
+use crate::dto::auth::internal::access_token::AccessTokenClaims;
 use crate::dto::comment::request::get_comments::GetCommentsRequest;
 use crate::dto::comment::response::get_comments::GetCommentsResponse;
 use crate::service::comment::get_comments::service_get_comments;
 use crate::service::error::errors::Errors;
 use crate::service::validator::json_validator::ValidatedJson;
 use crate::state::AppState;
+use axum::Extension;
 use axum::extract::State;
 use axum::response::IntoResponse;
 use tracing::info;
@@ -21,11 +23,15 @@ use tracing::info;
 )]
 pub async fn get_comments(
     State(state): State<AppState>,
+    claims: Option<Extension<AccessTokenClaims>>,
     ValidatedJson(payload): ValidatedJson<GetCommentsRequest>,
 ) -> Result<GetCommentsResponse, Errors> {
     info!("Received request to get comments: {:?}", payload);
 
-    let response = service_get_comments(&state.conn, payload).await?;
+    // 로그인한 경우 본인의 반응을 함께 반환
+    let viewer_id = claims.map(|ext| ext.sub);
+
+    let response = service_get_comments(&state.conn, payload, viewer_id).await?;
 
     Ok(response)
 }
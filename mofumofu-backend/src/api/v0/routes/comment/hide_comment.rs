@@ -0,0 +1,38 @@
+use crate::dto::auth::internal::access_token::AccessTokenClaims;
+use crate::dto::comment::request::hide_comment::HideCommentRequest;
+use crate::service::comment::hide_comment::service_hide_comment;
+use crate::service::error::errors::Errors;
+use crate::service::validator::json_validator::ValidatedJson;
+use crate::state::AppState;
+use axum::Extension;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use tracing::info;
+
+#[utoipa::path(
+    post,
+    path = "/v0/comment/hide",
+    request_body = HideCommentRequest,
+    responses(
+        (status = StatusCode::NO_CONTENT, description = "Comment hidden status updated"),
+        (status = StatusCode::NOT_FOUND, description = "Comment not found"),
+        (status = StatusCode::UNAUTHORIZED, description = "Only the post author can moderate comments"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Comment"
+)]
+pub async fn hide_comment(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    ValidatedJson(payload): ValidatedJson<HideCommentRequest>,
+) -> Result<impl IntoResponse, Errors> {
+    info!("Received request to hide comment: {:?}", payload);
+
+    service_hide_comment(&state.conn, &claims.sub, payload).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,38 @@
+use crate::dto::auth::internal::access_token::AccessTokenClaims;
+use crate::dto::comment::request::lock_comment::LockCommentRequest;
+use crate::service::comment::lock_comment::service_lock_comment;
+use crate::service::error::errors::Errors;
+use crate::service::validator::json_validator::ValidatedJson;
+use crate::state::AppState;
+use axum::Extension;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use tracing::info;
+
+#[utoipa::path(
+    post,
+    path = "/v0/comment/lock",
+    request_body = LockCommentRequest,
+    responses(
+        (status = StatusCode::NO_CONTENT, description = "Comment lock status updated"),
+        (status = StatusCode::NOT_FOUND, description = "Comment not found"),
+        (status = StatusCode::UNAUTHORIZED, description = "Only the post author can moderate comments"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Comment"
+)]
+pub async fn lock_comment(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    ValidatedJson(payload): ValidatedJson<LockCommentRequest>,
+) -> Result<impl IntoResponse, Errors> {
+    info!("Received request to lock comment: {:?}", payload);
+
+    service_lock_comment(&state.conn, &claims.sub, payload).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
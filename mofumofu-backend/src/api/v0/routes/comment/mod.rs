@@ -1,7 +1,12 @@
 pub mod create_comment;
 pub mod delete_comment;
 pub mod get_comment_by_id;
+pub mod get_comment_history;
+pub mod get_comment_tree;
 pub mod get_comments;
 pub mod get_replies;
+pub mod hide_comment;
+pub mod lock_comment;
+pub mod pin_comment;
 pub mod routes;
 pub mod update_comment;
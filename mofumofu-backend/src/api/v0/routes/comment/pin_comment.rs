@@ -0,0 +1,39 @@
+use crate::dto::auth::internal::access_token::AccessTokenClaims;
+use crate::dto::comment::request::pin_comment::PinCommentRequest;
+use crate::service::comment::pin_comment::service_pin_comment;
+use crate::service::error::errors::Errors;
+use crate::service::validator::json_validator::ValidatedJson;
+use crate::state::AppState;
+use axum::Extension;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use tracing::info;
+
+#[utoipa::path(
+    post,
+    path = "/v0/comment/pin",
+    request_body = PinCommentRequest,
+    responses(
+        (status = StatusCode::NO_CONTENT, description = "Comment pin status updated"),
+        (status = StatusCode::BAD_REQUEST, description = "Reply cannot be pinned or pin limit exceeded"),
+        (status = StatusCode::NOT_FOUND, description = "Comment not found"),
+        (status = StatusCode::UNAUTHORIZED, description = "Only the post author can moderate comments"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Comment"
+)]
+pub async fn pin_comment(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    ValidatedJson(payload): ValidatedJson<PinCommentRequest>,
+) -> Result<impl IntoResponse, Errors> {
+    info!("Received request to pin comment: {:?}", payload);
+
+    service_pin_comment(&state.conn, &claims.sub, payload).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
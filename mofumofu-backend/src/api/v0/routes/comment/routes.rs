@@ -1,22 +1,35 @@
 use crate::api::v0::routes::comment::create_comment::create_comment;
 use crate::api::v0::routes::comment::delete_comment::delete_comment;
 use crate::api::v0::routes::comment::get_comment_by_id::get_comment_by_id;
+use crate::api::v0::routes::comment::get_comment_history::get_comment_history;
+use crate::api::v0::routes::comment::get_comment_tree::get_comment_tree;
 use crate::api::v0::routes::comment::get_comments::get_comments;
 use crate::api::v0::routes::comment::get_replies::get_replies;
+use crate::api::v0::routes::comment::hide_comment::hide_comment;
+use crate::api::v0::routes::comment::lock_comment::lock_comment;
+use crate::api::v0::routes::comment::pin_comment::pin_comment;
 use crate::api::v0::routes::comment::update_comment::update_comment;
-use crate::middleware::auth::access_jwt_auth;
+use crate::dto::rate_limit::internal::rate_limit_policy::{RateLimitKey, RateLimitPolicy};
+use crate::middleware::auth::{access_jwt_auth, optional_access_jwt_auth};
+use crate::middleware::rate_limit::rate_limit;
 use crate::state::AppState;
 use axum::{
     Router,
-    middleware::from_fn,
+    middleware::{from_fn, from_fn_with_state},
     routing::{delete as axum_delete, post, put},
 };
 
+// 댓글 작성: 사용자당 1분에 10개 (짧은 몰림은 허용)
+const CREATE_COMMENT_RATE_LIMIT: RateLimitPolicy =
+    RateLimitPolicy::token_bucket("comment:create", 10, 60, RateLimitKey::User);
+
 pub fn comment_routes() -> Router<AppState> {
     Router::new()
         .route(
             "/comment",
-            post(create_comment).route_layer(from_fn(access_jwt_auth)),
+            post(create_comment)
+                .route_layer(from_fn_with_state(CREATE_COMMENT_RATE_LIMIT, rate_limit))
+                .route_layer(from_fn(access_jwt_auth)),
         )
         .route(
             "/comment",
@@ -26,7 +39,36 @@ pub fn comment_routes() -> Router<AppState> {
             "/comment",
             axum_delete(delete_comment).route_layer(from_fn(access_jwt_auth)),
         )
-        .route("/comment/get", post(get_comment_by_id))
-        .route("/comment/list", post(get_comments))
-        .route("/comment/replies", post(get_replies))
+        .route(
+            "/comment/get",
+            post(get_comment_by_id).route_layer(from_fn(optional_access_jwt_auth)),
+        )
+        .route(
+            "/comment/list",
+            post(get_comments).route_layer(from_fn(optional_access_jwt_auth)),
+        )
+        .route(
+            "/comment/replies",
+            post(get_replies).route_layer(from_fn(optional_access_jwt_auth)),
+        )
+        .route(
+            "/comment/tree",
+            post(get_comment_tree).route_layer(from_fn(optional_access_jwt_auth)),
+        )
+        .route(
+            "/comment/history",
+            post(get_comment_history).route_layer(from_fn(access_jwt_auth)),
+        )
+        .route(
+            "/comment/pin",
+            post(pin_comment).route_layer(from_fn(access_jwt_auth)),
+        )
+        .route(
+            "/comment/hide",
+            post(hide_comment).route_layer(from_fn(access_jwt_auth)),
+        )
+        .route(
+            "/comment/lock",
+            post(lock_comment).route_layer(from_fn(access_jwt_auth)),
+        )
 }
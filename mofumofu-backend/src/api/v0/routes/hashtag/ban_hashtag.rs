@@ -0,0 +1,36 @@
+use crate::dto::auth::internal::access_token::AccessTokenClaims;
+use crate::dto::hashtag::request::ban_hashtag::BanHashtagRequest;
+use crate::service::error::errors::Errors;
+use crate::service::hashtag::ban_hashtag::service_ban_hashtag;
+use crate::service::validator::json_validator::ValidatedJson;
+use crate::state::AppState;
+use axum::Extension;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+
+#[utoipa::path(
+    post,
+    path = "/v0/hashtag/ban",
+    request_body = BanHashtagRequest,
+    responses(
+        (status = StatusCode::NO_CONTENT, description = "Hashtag ban status updated successfully"),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid input"),
+        (status = StatusCode::UNAUTHORIZED, description = "Admin/Moderator required"),
+        (status = StatusCode::NOT_FOUND, description = "Hashtag not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Hashtag"
+)]
+pub async fn ban_hashtag(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    ValidatedJson(payload): ValidatedJson<BanHashtagRequest>,
+) -> Result<impl IntoResponse, Errors> {
+    service_ban_hashtag(&state.conn, &state.redis, claims.sub, payload).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,37 @@
+use crate::dto::auth::internal::access_token::AccessTokenClaims;
+use crate::dto::hashtag::request::hashtag_alias::CreateHashtagAliasRequest;
+use crate::service::error::errors::Errors;
+use crate::service::hashtag::create_hashtag_alias::service_create_hashtag_alias;
+use crate::service::validator::json_validator::ValidatedJson;
+use crate::state::AppState;
+use axum::Extension;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+
+#[utoipa::path(
+    post,
+    path = "/v0/hashtag/alias",
+    request_body = CreateHashtagAliasRequest,
+    responses(
+        (status = StatusCode::NO_CONTENT, description = "Hashtag alias created successfully"),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid input"),
+        (status = StatusCode::UNAUTHORIZED, description = "Admin/Moderator required"),
+        (status = StatusCode::NOT_FOUND, description = "Hashtag not found"),
+        (status = StatusCode::CONFLICT, description = "Alias is already a hashtag or alias"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Hashtag"
+)]
+pub async fn create_hashtag_alias(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    ValidatedJson(payload): ValidatedJson<CreateHashtagAliasRequest>,
+) -> Result<impl IntoResponse, Errors> {
+    service_create_hashtag_alias(&state.conn, claims.sub, payload).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
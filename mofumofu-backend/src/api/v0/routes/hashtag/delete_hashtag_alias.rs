@@ -0,0 +1,36 @@
+use crate::dto::auth::internal::access_token::AccessTokenClaims;
+use crate::dto::hashtag::request::hashtag_alias::DeleteHashtagAliasRequest;
+use crate::service::error::errors::Errors;
+use crate::service::hashtag::delete_hashtag_alias::service_delete_hashtag_alias;
+use crate::service::validator::json_validator::ValidatedJson;
+use crate::state::AppState;
+use axum::Extension;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+
+#[utoipa::path(
+    delete,
+    path = "/v0/hashtag/alias",
+    request_body = DeleteHashtagAliasRequest,
+    responses(
+        (status = StatusCode::NO_CONTENT, description = "Hashtag alias deleted successfully"),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid input"),
+        (status = StatusCode::UNAUTHORIZED, description = "Admin/Moderator required"),
+        (status = StatusCode::NOT_FOUND, description = "Alias not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Hashtag"
+)]
+pub async fn delete_hashtag_alias(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    ValidatedJson(payload): ValidatedJson<DeleteHashtagAliasRequest>,
+) -> Result<impl IntoResponse, Errors> {
+    service_delete_hashtag_alias(&state.conn, claims.sub, payload).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
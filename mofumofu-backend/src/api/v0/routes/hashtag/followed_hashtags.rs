@@ -0,0 +1,28 @@
+use crate::dto::auth::internal::access_token::AccessTokenClaims;
+use crate::dto::hashtag::response::followed_hashtags::FollowedHashtagsResponse;
+use crate::service::error::errors::Errors;
+use crate::service::hashtag::get_followed_hashtags::service_get_followed_hashtags;
+use crate::state::AppState;
+use axum::Extension;
+use axum::extract::State;
+
+#[utoipa::path(
+    post,
+    path = "/v0/hashtag/following",
+    responses(
+        (status = StatusCode::OK, description = "Followed hashtags retrieved successfully", body = FollowedHashtagsResponse),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Hashtag"
+)]
+pub async fn followed_hashtags(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+) -> Result<FollowedHashtagsResponse, Errors> {
+    let response = service_get_followed_hashtags(&state.conn, claims.sub).await?;
+
+    Ok(response)
+}
@@ -0,0 +1,34 @@
+use crate::dto::auth::internal::access_token::AccessTokenClaims;
+use crate::dto::hashtag::request::hashtag_detail::HashtagDetailRequest;
+use crate::dto::hashtag::response::hashtag_detail::HashtagDetailResponse;
+use crate::service::error::errors::Errors;
+use crate::service::hashtag::get_hashtag_detail::service_get_hashtag_detail;
+use crate::service::validator::json_validator::ValidatedJson;
+use crate::state::AppState;
+use axum::Extension;
+use axum::extract::State;
+
+#[utoipa::path(
+    post,
+    path = "/v0/hashtag/detail",
+    request_body = HashtagDetailRequest,
+    responses(
+        (status = StatusCode::OK, description = "Hashtag detail retrieved successfully", body = HashtagDetailResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid input or cursor"),
+        (status = StatusCode::NOT_FOUND, description = "Hashtag not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+    tag = "Hashtag"
+)]
+pub async fn hashtag_detail(
+    State(state): State<AppState>,
+    claims: Option<Extension<AccessTokenClaims>>,
+    ValidatedJson(payload): ValidatedJson<HashtagDetailRequest>,
+) -> Result<HashtagDetailResponse, Errors> {
+    // 로그인한 경우 팔로우 여부를 함께 반환
+    let viewer_id = claims.map(|ext| ext.sub);
+
+    let response = service_get_hashtag_detail(&state.conn, viewer_id, payload).await?;
+
+    Ok(response)
+}
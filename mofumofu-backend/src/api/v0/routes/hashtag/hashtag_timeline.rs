@@ -0,0 +1,33 @@
+use crate::dto::auth::internal::access_token::AccessTokenClaims;
+use crate::dto::hashtag::request::hashtag_timeline::HashtagTimelineRequest;
+use crate::dto::hashtag::response::hashtag_timeline::HashtagTimelineResponse;
+use crate::service::error::errors::Errors;
+use crate::service::hashtag::get_hashtag_timeline::service_get_hashtag_timeline;
+use crate::service::validator::json_validator::ValidatedJson;
+use crate::state::AppState;
+use axum::Extension;
+use axum::extract::State;
+
+#[utoipa::path(
+    post,
+    path = "/v0/hashtag/timeline",
+    request_body = HashtagTimelineRequest,
+    responses(
+        (status = StatusCode::OK, description = "Hashtag timeline retrieved successfully", body = HashtagTimelineResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid input or cursor"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Hashtag"
+)]
+pub async fn hashtag_timeline(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    ValidatedJson(payload): ValidatedJson<HashtagTimelineRequest>,
+) -> Result<HashtagTimelineResponse, Errors> {
+    let response = service_get_hashtag_timeline(&state.conn, claims.sub, payload).await?;
+
+    Ok(response)
+}
@@ -0,0 +1,35 @@
+use crate::dto::auth::internal::access_token::AccessTokenClaims;
+use crate::dto::hashtag::request::merge_hashtags::MergeHashtagsRequest;
+use crate::dto::hashtag::response::merge_hashtags::MergeHashtagsResponse;
+use crate::service::error::errors::Errors;
+use crate::service::hashtag::merge_hashtags::service_merge_hashtags;
+use crate::service::validator::json_validator::ValidatedJson;
+use crate::state::AppState;
+use axum::Extension;
+use axum::extract::State;
+
+#[utoipa::path(
+    post,
+    path = "/v0/hashtag/merge",
+    request_body = MergeHashtagsRequest,
+    responses(
+        (status = StatusCode::OK, description = "Hashtags merged successfully", body = MergeHashtagsResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid input or merging a hashtag into itself"),
+        (status = StatusCode::UNAUTHORIZED, description = "Admin/Moderator required"),
+        (status = StatusCode::NOT_FOUND, description = "Hashtag not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Hashtag"
+)]
+pub async fn merge_hashtags(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    ValidatedJson(payload): ValidatedJson<MergeHashtagsRequest>,
+) -> Result<MergeHashtagsResponse, Errors> {
+    let response = service_merge_hashtags(&state.conn, &state.redis, claims.sub, payload).await?;
+
+    Ok(response)
+}
@@ -1,2 +1,14 @@
+pub mod ban_hashtag;
+pub mod create_hashtag_alias;
+pub mod delete_hashtag_alias;
+pub mod follow_hashtag;
+pub mod followed_hashtags;
+pub mod hashtag_detail;
+pub mod hashtag_timeline;
+pub mod merge_hashtags;
 pub mod routes;
+pub mod suggest_hashtags;
 pub mod trending_hashtags;
+pub mod unfollow_hashtag;
+pub mod update_hashtag;
+pub mod upload_hashtag_cover;
@@ -1,8 +1,77 @@
+use super::ban_hashtag::ban_hashtag;
+use super::create_hashtag_alias::create_hashtag_alias;
+use super::delete_hashtag_alias::delete_hashtag_alias;
+use super::follow_hashtag::follow_hashtag;
+use super::followed_hashtags::followed_hashtags;
+use super::hashtag_detail::hashtag_detail;
+use super::hashtag_timeline::hashtag_timeline;
+use super::merge_hashtags::merge_hashtags;
+use super::suggest_hashtags::suggest_hashtags;
 use super::trending_hashtags::trending_hashtags;
+use super::unfollow_hashtag::unfollow_hashtag;
+use super::update_hashtag::update_hashtag;
+use super::upload_hashtag_cover::upload_hashtag_cover;
+use crate::dto::rate_limit::internal::rate_limit_policy::{RateLimitKey, RateLimitPolicy};
+use crate::middleware::auth::{access_jwt_auth, optional_access_jwt_auth};
+use crate::middleware::rate_limit::rate_limit;
 use crate::state::AppState;
 use axum::Router;
-use axum::routing::post;
+use axum::middleware::{from_fn, from_fn_with_state};
+use axum::routing::{delete, get, post, put};
+
+// 커버 이미지 업로드: 사용자당 10분에 10개
+const UPLOAD_COVER_RATE_LIMIT: RateLimitPolicy =
+    RateLimitPolicy::sliding_window("hashtag:upload_cover", 10, 600, RateLimitKey::User);
 
 pub fn hashtag_routes() -> Router<AppState> {
-    Router::new().route("/trending", post(trending_hashtags))
+    Router::new()
+        .route("/trending", post(trending_hashtags))
+        .route("/suggest", get(suggest_hashtags))
+        .route(
+            "/detail",
+            post(hashtag_detail).route_layer(from_fn(optional_access_jwt_auth)),
+        )
+        .route(
+            "/follow",
+            post(follow_hashtag).route_layer(from_fn(access_jwt_auth)),
+        )
+        .route(
+            "/follow",
+            delete(unfollow_hashtag).route_layer(from_fn(access_jwt_auth)),
+        )
+        .route(
+            "/following",
+            post(followed_hashtags).route_layer(from_fn(access_jwt_auth)),
+        )
+        .route(
+            "/timeline",
+            post(hashtag_timeline).route_layer(from_fn(access_jwt_auth)),
+        )
+        // 관리자/모더레이터 전용
+        .route(
+            "/alias",
+            post(create_hashtag_alias).route_layer(from_fn(access_jwt_auth)),
+        )
+        .route(
+            "/alias",
+            delete(delete_hashtag_alias).route_layer(from_fn(access_jwt_auth)),
+        )
+        .route(
+            "/merge",
+            post(merge_hashtags).route_layer(from_fn(access_jwt_auth)),
+        )
+        .route(
+            "/ban",
+            post(ban_hashtag).route_layer(from_fn(access_jwt_auth)),
+        )
+        .route(
+            "/update",
+            put(update_hashtag).route_layer(from_fn(access_jwt_auth)),
+        )
+        .route(
+            "/cover",
+            post(upload_hashtag_cover)
+                .route_layer(from_fn_with_state(UPLOAD_COVER_RATE_LIMIT, rate_limit))
+                .route_layer(from_fn(access_jwt_auth)),
+        )
 }
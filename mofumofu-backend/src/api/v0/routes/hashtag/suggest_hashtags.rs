@@ -0,0 +1,27 @@
+use crate::dto::hashtag::request::suggest_hashtags::SuggestHashtagsRequest;
+use crate::dto::hashtag::response::suggest_hashtags::SuggestHashtagsResponse;
+use crate::service::error::errors::Errors;
+use crate::service::hashtag::suggest_hashtags::service_suggest_hashtags;
+use crate::service::validator::form_validator::ValidatedForm;
+use crate::state::AppState;
+use axum::extract::State;
+
+#[utoipa::path(
+    get,
+    path = "/v0/hashtag/suggest",
+    params(SuggestHashtagsRequest),
+    responses(
+        (status = StatusCode::OK, description = "Hashtag suggestions retrieved successfully", body = SuggestHashtagsResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid input"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+    tag = "Hashtag"
+)]
+pub async fn suggest_hashtags(
+    State(state): State<AppState>,
+    ValidatedForm(params): ValidatedForm<SuggestHashtagsRequest>,
+) -> Result<SuggestHashtagsResponse, Errors> {
+    let response = service_suggest_hashtags(&state.conn, params).await?;
+
+    Ok(response)
+}
@@ -21,7 +21,9 @@ pub async fn trending_hashtags(
     State(state): State<AppState>,
     ValidatedJson(payload): ValidatedJson<TrendingHashtagsRequest>,
 ) -> Result<TrendingHashtagsResponse, Errors> {
-    let response = service_get_trending_hashtags(&state.conn, payload.days, payload.limit).await?;
+    let response =
+        service_get_trending_hashtags(&state.conn, &state.redis, payload.days, payload.limit)
+            .await?;
 
     Ok(response)
 }
@@ -0,0 +1,34 @@
+use crate::dto::auth::internal::access_token::AccessTokenClaims;
+use crate::dto::hashtag::request::follow_hashtag::FollowHashtagRequest;
+use crate::service::error::errors::Errors;
+use crate::service::hashtag::unfollow_hashtag::service_unfollow_hashtag;
+use crate::service::validator::json_validator::ValidatedJson;
+use crate::state::AppState;
+use axum::Extension;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+
+#[utoipa::path(
+    delete,
+    path = "/v0/hashtag/follow",
+    request_body = FollowHashtagRequest,
+    responses(
+        (status = StatusCode::NO_CONTENT, description = "Successfully unfollowed hashtag"),
+        (status = StatusCode::NOT_FOUND, description = "Hashtag not found or not following"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Hashtag"
+)]
+pub async fn unfollow_hashtag(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    ValidatedJson(payload): ValidatedJson<FollowHashtagRequest>,
+) -> Result<impl IntoResponse, Errors> {
+    service_unfollow_hashtag(&state.conn, claims.sub, &payload.hashtag).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
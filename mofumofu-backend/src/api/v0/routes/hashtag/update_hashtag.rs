@@ -0,0 +1,36 @@
+use crate::dto::auth::internal::access_token::AccessTokenClaims;
+use crate::dto::hashtag::request::update_hashtag::UpdateHashtagRequest;
+use crate::service::error::errors::Errors;
+use crate::service::hashtag::update_hashtag::service_update_hashtag;
+use crate::service::validator::json_validator::ValidatedJson;
+use crate::state::AppState;
+use axum::Extension;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+
+#[utoipa::path(
+    put,
+    path = "/v0/hashtag/update",
+    request_body = UpdateHashtagRequest,
+    responses(
+        (status = StatusCode::NO_CONTENT, description = "Hashtag updated successfully"),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid input"),
+        (status = StatusCode::UNAUTHORIZED, description = "Admin/Moderator required"),
+        (status = StatusCode::NOT_FOUND, description = "Hashtag not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Hashtag"
+)]
+pub async fn update_hashtag(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    ValidatedJson(payload): ValidatedJson<UpdateHashtagRequest>,
+) -> Result<impl IntoResponse, Errors> {
+    service_update_hashtag(&state.conn, claims.sub, payload).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,46 @@
+use crate::dto::auth::internal::access_token::AccessTokenClaims;
+use crate::dto::hashtag::request::hashtag_cover::HashtagCoverForm;
+use crate::dto::hashtag::response::hashtag_cover::HashtagCoverUploadResponse;
+use crate::service::error::errors::Errors;
+use crate::service::hashtag::update_hashtag_cover::service_update_hashtag_cover;
+use crate::state::AppState;
+use axum::Extension;
+use axum::extract::{Multipart, State};
+use axum::response::IntoResponse;
+use tracing::info;
+
+#[utoipa::path(
+    post,
+    path = "/v0/hashtag/cover",
+    request_body(content = HashtagCoverForm, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "Hashtag cover image uploaded successfully", body = HashtagCoverUploadResponse),
+        (status = 400, description = "Invalid file or parameters"),
+        (status = 401, description = "Admin/Moderator required"),
+        (status = 404, description = "Hashtag not found"),
+        (status = 413, description = "File too large"),
+        (status = 422, description = "Unsupported image format"),
+        (status = 429, description = "Rate limit exceeded: rate_limit:exceeded"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Hashtag"
+)]
+pub async fn upload_hashtag_cover(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    multipart: Multipart,
+) -> Result<impl IntoResponse, Errors> {
+    info!(
+        "Received hashtag cover upload request by user: {}",
+        claims.sub
+    );
+
+    let public_url =
+        service_update_hashtag_cover(&state.conn, &state.cloudflare_r2, claims.sub, multipart)
+            .await?;
+
+    Ok(HashtagCoverUploadResponse { public_url })
+}
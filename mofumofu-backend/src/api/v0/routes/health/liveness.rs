@@ -0,0 +1,19 @@
+use crate::dto::health::response::LivenessResponse;
+
+/// 프로세스가 요청을 처리할 수 있는지만 확인 (의존성은 확인하지 않음)
+#[utoipa::path(
+    get,
+    path = "/health",
+    summary = "Liveness probe",
+    description = "Returns 200 while the server process is running. Dependencies are not checked.",
+    responses(
+        (status = StatusCode::OK, description = "Server is alive", body = LivenessResponse)
+    ),
+    tag = "Health"
+)]
+pub async fn liveness() -> LivenessResponse {
+    LivenessResponse {
+        status: "ok".to_string(),
+        version: env!("CARGO_PKG_VERSION").to_string(),
+    }
+}
@@ -0,0 +1,34 @@
+use crate::config::db_config::DbConfig;
+use crate::state::AppState;
+use crate::utils::metrics::{DB_POOL_CONNECTIONS, DB_POOL_MAX_CONNECTIONS, render_metrics};
+use axum::extract::State;
+use axum::http::header::CONTENT_TYPE;
+use axum::response::IntoResponse;
+use metrics::gauge;
+
+/// Prometheus 스크랩 엔드포인트
+#[utoipa::path(
+    get,
+    path = "/metrics",
+    summary = "Prometheus metrics",
+    description = "Exposes HTTP, DB pool, Redis, external call and business metrics in the Prometheus text format. Served only on the internal METRICS_HOST:METRICS_PORT listener, not on the public API port.",
+    responses(
+        (status = StatusCode::OK, description = "Metrics in the Prometheus text format", content_type = "text/plain")
+    ),
+    tag = "Health"
+)]
+pub async fn metrics(State(state): State<AppState>) -> impl IntoResponse {
+    // 커넥션 풀 상태는 스크랩 시점에 갱신
+    let pool = state.conn.get_postgres_connection_pool();
+    let idle = pool.num_idle() as f64;
+    let total = pool.size() as f64;
+
+    gauge!(DB_POOL_CONNECTIONS, "state" => "idle").set(idle);
+    gauge!(DB_POOL_CONNECTIONS, "state" => "in_use").set(total - idle);
+    gauge!(DB_POOL_MAX_CONNECTIONS).set(DbConfig::get().db_max_connection as f64);
+
+    (
+        [(CONTENT_TYPE, "text/plain; version=0.0.4")],
+        render_metrics(),
+    )
+}
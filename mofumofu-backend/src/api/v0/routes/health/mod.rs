@@ -0,0 +1,4 @@
+pub mod liveness;
+pub mod metrics;
+pub mod readiness;
+pub mod routes;
@@ -0,0 +1,20 @@
+use crate::dto::health::response::ReadinessResponse;
+use crate::service::health::check_readiness::service_check_readiness;
+use crate::state::AppState;
+use axum::extract::State;
+
+/// 의존성별 상태와 지연 시간을 확인
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    summary = "Readiness probe",
+    description = "Checks Postgres, Redis, Meilisearch, R2, the markdown service and the task server, each with a timeout. The result is reused for 5 seconds. Returns 503 if any required dependency is down.",
+    responses(
+        (status = StatusCode::OK, description = "All required dependencies are up", body = ReadinessResponse),
+        (status = StatusCode::SERVICE_UNAVAILABLE, description = "At least one dependency is down", body = ReadinessResponse)
+    ),
+    tag = "Health"
+)]
+pub async fn readiness(State(state): State<AppState>) -> ReadinessResponse {
+    service_check_readiness(&state).await
+}
@@ -0,0 +1,18 @@
+use crate::api::v0::routes::health::liveness::liveness;
+use crate::api::v0::routes::health::metrics::metrics;
+use crate::api::v0::routes::health::readiness::readiness;
+use crate::state::AppState;
+use axum::{Router, routing::get};
+
+// 준비 상태 확인은 프로브가 429로 실패하지 않도록 요청 제한을 걸지 않고,
+// 대신 확인 결과를 몇 초 동안 재사용해 의존성에 가는 요청 수를 제한함
+pub fn health_routes() -> Router<AppState> {
+    Router::new()
+        .route("/health", get(liveness))
+        .route("/health/ready", get(readiness))
+}
+
+/// 내부 전용 리스너(METRICS_HOST:METRICS_PORT)에서만 제공하는 라우트
+pub fn metrics_routes() -> Router<AppState> {
+    Router::new().route("/metrics", get(metrics))
+}
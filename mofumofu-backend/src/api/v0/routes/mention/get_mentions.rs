@@ -0,0 +1,38 @@
+use crate::dto::auth::internal::access_token::AccessTokenClaims;
+use crate::dto::mention::request::get_mentions::GetMentionsRequest;
+use crate::dto::mention::response::get_mentions::GetMentionsResponse;
+use crate::service::error::errors::Errors;
+use crate::service::mention::get_mentions::service_get_mentions;
+use crate::service::validator::json_validator::ValidatedJson;
+use crate::state::AppState;
+use axum::Extension;
+use axum::extract::State;
+use tracing::info;
+
+#[utoipa::path(
+    post,
+    path = "/v0/mentions",
+    request_body = GetMentionsRequest,
+    responses(
+        (status = 200, description = "Mentions retrieved successfully", body = GetMentionsResponse),
+        (status = 400, description = "Invalid input"),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal Server Error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Mention"
+)]
+pub async fn get_mentions(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    ValidatedJson(payload): ValidatedJson<GetMentionsRequest>,
+) -> Result<GetMentionsResponse, Errors> {
+    info!("Received request to get mentions: {:?}", payload);
+    let user_uuid = claims.sub;
+
+    let response = service_get_mentions(&state.conn, &user_uuid, payload).await?;
+
+    Ok(response)
+}
@@ -0,0 +1,11 @@
+use crate::api::v0::routes::mention::get_mentions::get_mentions;
+use crate::middleware::auth::access_jwt_auth;
+use crate::state::AppState;
+use axum::{Router, middleware::from_fn, routing::post};
+
+pub fn mention_routes() -> Router<AppState> {
+    Router::new().route(
+        "/mentions",
+        post(get_mentions).route_layer(from_fn(access_jwt_auth)),
+    )
+}
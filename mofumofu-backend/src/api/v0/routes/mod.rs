@@ -4,9 +4,12 @@ mod comment;
 mod draft;
 mod follow;
 mod hashtag;
+mod health;
 mod like;
+mod mention;
 mod openapi;
 mod post;
+mod reaction;
 mod report;
 pub mod routes;
 mod user;
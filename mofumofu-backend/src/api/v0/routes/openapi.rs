@@ -1,4 +1,8 @@
-use crate::dto::admin::response::{AdminStatusResponse, AdminTaskResponse};
+use crate::dto::admin::request::{RequeueOutboxEntriesRequest, UpdateTrendingSettingsRequest};
+use crate::dto::admin::response::{
+    AdminStatusResponse, AdminTaskResponse, JobStatusResponse, OutboxEntriesResponse, OutboxEntry,
+    RequeueOutboxEntriesResponse, TrendingSettingsResponse,
+};
 use crate::dto::auth::request::forgot_password::ForgotPasswordRequest;
 use crate::dto::auth::request::link_oauth::LinkOAuthRequest;
 use crate::dto::auth::request::login::AuthLoginRequest;
@@ -12,9 +16,20 @@ use crate::dto::auth::response::oauth_connections::OAuthConnectionsResponse;
 use crate::dto::comment::request::create_comment::CreateCommentRequest;
 use crate::dto::comment::request::delete_comment::DeleteCommentRequest;
 use crate::dto::comment::request::get_comment_by_id::GetCommentByIdRequest;
+use crate::dto::comment::request::get_comment_history::GetCommentHistoryRequest;
+use crate::dto::comment::request::get_comment_tree::GetCommentTreeRequest;
 use crate::dto::comment::request::get_comments::{GetCommentsRequest, GetRepliesRequest};
+use crate::dto::comment::request::hide_comment::HideCommentRequest;
+use crate::dto::comment::request::lock_comment::LockCommentRequest;
+use crate::dto::comment::request::pin_comment::PinCommentRequest;
 use crate::dto::comment::request::update_comment::UpdateCommentRequest;
 use crate::dto::comment::response::comment_info::CommentInfo;
+use crate::dto::comment::response::get_comment_history::{
+    CommentEditInfo, GetCommentHistoryResponse,
+};
+use crate::dto::comment::response::get_comment_tree::{
+    CommentTreeCursor, CommentTreeNode, GetCommentTreeResponse,
+};
 use crate::dto::comment::response::get_comments::{GetCommentsResponse, GetRepliesResponse};
 use crate::dto::draft::request::create_draft::CreateDraftRequest;
 use crate::dto::draft::request::delete_draft::DeleteDraftRequest;
@@ -30,8 +45,27 @@ use crate::dto::follow::request::get_count::GetFollowCountRequest;
 use crate::dto::follow::response::follow_count::FollowCountResponse;
 use crate::dto::follow::response::follow_list::FollowListResponse;
 use crate::dto::follow::response::follow_status::FollowStatusResponse;
+use crate::dto::hashtag::request::ban_hashtag::BanHashtagRequest;
+use crate::dto::hashtag::request::follow_hashtag::FollowHashtagRequest;
+use crate::dto::hashtag::request::hashtag_alias::{
+    CreateHashtagAliasRequest, DeleteHashtagAliasRequest,
+};
+use crate::dto::hashtag::request::hashtag_cover::HashtagCoverForm;
+use crate::dto::hashtag::request::hashtag_detail::{HashtagDetailRequest, HashtagPostSort};
+use crate::dto::hashtag::request::hashtag_timeline::HashtagTimelineRequest;
+use crate::dto::hashtag::request::merge_hashtags::MergeHashtagsRequest;
 use crate::dto::hashtag::request::trending_hashtags::TrendingHashtagsRequest;
+use crate::dto::hashtag::request::update_hashtag::UpdateHashtagRequest;
+use crate::dto::hashtag::response::followed_hashtags::{FollowedHashtag, FollowedHashtagsResponse};
+use crate::dto::hashtag::response::hashtag_cover::HashtagCoverUploadResponse;
+use crate::dto::hashtag::response::hashtag_detail::{HashtagDetailResponse, HashtagTopAuthor};
+use crate::dto::hashtag::response::hashtag_timeline::HashtagTimelineResponse;
+use crate::dto::hashtag::response::merge_hashtags::MergeHashtagsResponse;
+use crate::dto::hashtag::response::suggest_hashtags::{HashtagSuggestion, SuggestHashtagsResponse};
 use crate::dto::hashtag::response::trending_hashtags::TrendingHashtagsResponse;
+use crate::dto::health::response::{
+    DependencyCheck, DependencyStatus, LivenessResponse, ReadinessResponse,
+};
 use crate::dto::like::request::check_comment_like_status::CheckCommentLikeStatusRequest;
 use crate::dto::like::request::check_like_status::CheckLikeStatusRequest;
 use crate::dto::like::request::create_comment_like::CreateCommentLikeRequest;
@@ -39,6 +73,9 @@ use crate::dto::like::request::create_like::CreateLikeRequest;
 use crate::dto::like::request::delete_comment_like::DeleteCommentLikeRequest;
 use crate::dto::like::request::delete_like::DeleteLikeRequest;
 use crate::dto::like::response::like_status::LikeStatusResponse;
+use crate::dto::mention::request::get_mentions::GetMentionsRequest;
+use crate::dto::mention::response::get_mentions::GetMentionsResponse;
+use crate::dto::mention::response::mention_info::MentionInfo;
 use crate::dto::post::request::GetPostByHandleAndSlugRequest;
 use crate::dto::post::request::GetPostByUuidRequest;
 use crate::dto::post::request::create_post::CreatePostRequest;
@@ -46,16 +83,23 @@ use crate::dto::post::request::delete_post::DeletePostRequest;
 use crate::dto::post::request::get_post_for_edit::GetPostForEditRequest;
 use crate::dto::post::request::image_upload::ImageUploadForm;
 use crate::dto::post::request::thumbnail_image::PostThumbnailForm;
+use crate::dto::post::request::update_comment_settings::UpdatePostCommentSettingsRequest;
 use crate::dto::post::request::update_post::UpdatePostRequest;
 use crate::dto::post::request::{
-    GetPostsRequest, GetUserPostsRequest, PostSortOrder, SearchPostsRequest,
+    GetPostAnalyticsRequest, GetPostsRequest, GetRelatedPostsRequest, GetUserPostsRequest,
+    IncrementViewRequest, PostSortOrder, SearchPostsRequest,
 };
 use crate::dto::post::response::create_post::CreatePostResponse;
 use crate::dto::post::response::post_edit_info::PostEditInfoResponse;
 use crate::dto::post::response::post_info::{PostAuthor, PostInfoResponse};
 use crate::dto::post::response::{
-    GetPostsResponse, ImageUploadResponse, PostListItem, UserPostsResponse,
+    GetPostsResponse, ImageUploadResponse, PostAnalyticsResponse, PostDailyAnalytics, PostListItem,
+    PostReferrerAnalytics, RelatedPostsResponse, UserPostsResponse,
 };
+use crate::dto::reaction::request::toggle_comment_reaction::ToggleCommentReactionRequest;
+use crate::dto::reaction::request::toggle_post_reaction::TogglePostReactionRequest;
+use crate::dto::reaction::response::reaction_count::ReactionCount;
+use crate::dto::reaction::response::toggle_reaction::ToggleReactionResponse;
 use crate::dto::report::request::{CreateReportRequest, GetReportsRequest, ProcessReportRequest};
 use crate::dto::report::response::{CreateReportResponse, GetReportsResponse, ReportInfo};
 use crate::dto::user::request::avatar_image::ProfileAvatarForm;
@@ -65,7 +109,10 @@ use crate::dto::user::request::get_profile::GetUserProfileRequest;
 use crate::dto::user::request::update_profile::UpdateProfileRequest;
 use crate::dto::user::response::handle_check::HandleCheckResponse;
 use crate::dto::user::response::info::UserInfoResponse;
-use crate::entity::common::{OAuthProvider, ReportReason, ReportStatus, ReportTargetType};
+use crate::dto::user::response::suggest_users::{SuggestUsersResponse, UserSuggestion};
+use crate::entity::common::{
+    OAuthProvider, OutboxStatus, ReportReason, ReportStatus, ReportTargetType,
+};
 use crate::service::error::errors::ErrorResponse;
 use utoipa::openapi::security::{ApiKey, ApiKeyValue};
 use utoipa::{
@@ -96,13 +143,17 @@ use utoipa::{
         crate::api::v0::routes::user::update_profile::update_profile,
         crate::api::v0::routes::user::upload_avatar::upload_avatar,
         crate::api::v0::routes::user::upload_banner::upload_banner,
+        crate::api::v0::routes::user::suggest_users::suggest_users,
         crate::api::v0::routes::post::create_post::create_post,
         crate::api::v0::routes::post::delete_post::delete_post,
         crate::api::v0::routes::post::get_post::get_post,
         crate::api::v0::routes::post::get_post_by_handle_and_slug::get_post_by_handle_and_slug,
         crate::api::v0::routes::post::get_post_for_edit::get_post_for_edit,
         crate::api::v0::routes::post::update_post::update_post,
+        crate::api::v0::routes::post::update_comment_settings::update_comment_settings,
         crate::api::v0::routes::post::get_posts::get_posts,
+        crate::api::v0::routes::post::get_post_analytics::get_post_analytics,
+        crate::api::v0::routes::post::get_related_posts::get_related_posts,
         crate::api::v0::routes::post::get_user_posts::get_user_posts,
         crate::api::v0::routes::post::increment_view::increment_view,
         crate::api::v0::routes::post::search_posts::search_posts,
@@ -116,18 +167,38 @@ use utoipa::{
         crate::api::v0::routes::follow::get_following_count::api_get_following_count,
         crate::api::v0::routes::follow::get_following_list::get_following,
         crate::api::v0::routes::hashtag::trending_hashtags::trending_hashtags,
+        crate::api::v0::routes::hashtag::suggest_hashtags::suggest_hashtags,
+        crate::api::v0::routes::hashtag::hashtag_detail::hashtag_detail,
+        crate::api::v0::routes::hashtag::follow_hashtag::follow_hashtag,
+        crate::api::v0::routes::hashtag::unfollow_hashtag::unfollow_hashtag,
+        crate::api::v0::routes::hashtag::followed_hashtags::followed_hashtags,
+        crate::api::v0::routes::hashtag::hashtag_timeline::hashtag_timeline,
+        crate::api::v0::routes::hashtag::create_hashtag_alias::create_hashtag_alias,
+        crate::api::v0::routes::hashtag::delete_hashtag_alias::delete_hashtag_alias,
+        crate::api::v0::routes::hashtag::merge_hashtags::merge_hashtags,
+        crate::api::v0::routes::hashtag::ban_hashtag::ban_hashtag,
+        crate::api::v0::routes::hashtag::update_hashtag::update_hashtag,
+        crate::api::v0::routes::hashtag::upload_hashtag_cover::upload_hashtag_cover,
         crate::api::v0::routes::like::check_like_status::check_like_status,
         crate::api::v0::routes::like::create_like::create_like,
         crate::api::v0::routes::like::delete_like::delete_like,
         crate::api::v0::routes::like::check_comment_like_status::check_comment_like_status,
         crate::api::v0::routes::like::create_comment_like::create_comment_like,
         crate::api::v0::routes::like::delete_comment_like::delete_comment_like,
+        crate::api::v0::routes::reaction::toggle_post_reaction::toggle_post_reaction,
+        crate::api::v0::routes::reaction::toggle_comment_reaction::toggle_comment_reaction,
+        crate::api::v0::routes::mention::get_mentions::get_mentions,
         crate::api::v0::routes::comment::create_comment::create_comment,
         crate::api::v0::routes::comment::delete_comment::delete_comment,
         crate::api::v0::routes::comment::get_comment_by_id::get_comment_by_id,
         crate::api::v0::routes::comment::get_comments::get_comments,
         crate::api::v0::routes::comment::get_replies::get_replies,
+        crate::api::v0::routes::comment::get_comment_tree::get_comment_tree,
+        crate::api::v0::routes::comment::get_comment_history::get_comment_history,
         crate::api::v0::routes::comment::update_comment::update_comment,
+        crate::api::v0::routes::comment::pin_comment::pin_comment,
+        crate::api::v0::routes::comment::hide_comment::hide_comment,
+        crate::api::v0::routes::comment::lock_comment::lock_comment,
         crate::api::v0::routes::draft::create_draft::create_draft,
         crate::api::v0::routes::draft::delete_draft::delete_draft,
         crate::api::v0::routes::draft::get_draft::get_draft,
@@ -136,6 +207,10 @@ use utoipa::{
         crate::api::v0::routes::report::create_report::create_report,
         crate::api::v0::routes::report::get_reports::get_reports,
         crate::api::v0::routes::report::process_report::process_report,
+        // Health endpoints
+        crate::api::v0::routes::health::liveness::liveness,
+        crate::api::v0::routes::health::readiness::readiness,
+        crate::api::v0::routes::health::metrics::metrics,
         // Admin endpoints
         crate::api::v0::routes::admin::check_admin_status::check_admin_status,
         crate::api::v0::routes::admin::reindex_all_posts::reindex_all_posts,
@@ -145,7 +220,12 @@ use utoipa::{
         crate::api::v0::routes::admin::sync_follows::sync_follows,
         crate::api::v0::routes::admin::sync_all_counts::sync_all_counts,
         crate::api::v0::routes::admin::cleanup_expired_tokens::cleanup_expired_tokens,
-        crate::api::v0::routes::admin::cleanup_old_events::cleanup_old_events
+        crate::api::v0::routes::admin::cleanup_old_events::cleanup_old_events,
+        crate::api::v0::routes::admin::get_trending_settings::get_trending_settings,
+        crate::api::v0::routes::admin::update_trending_settings::update_trending_settings,
+        crate::api::v0::routes::admin::get_outbox_entries::get_outbox_entries,
+        crate::api::v0::routes::admin::get_job_status::get_job_status,
+        crate::api::v0::routes::admin::requeue_outbox_entries::requeue_outbox_entries
     ),
     components(
         schemas(
@@ -167,8 +247,12 @@ use utoipa::{
             GetPostByHandleAndSlugRequest,
             GetPostForEditRequest,
             UpdatePostRequest,
+            UpdatePostCommentSettingsRequest,
             GetPostsRequest,
+            GetPostAnalyticsRequest,
+            GetRelatedPostsRequest,
             GetUserPostsRequest,
+            IncrementViewRequest,
             SearchPostsRequest,
             CreatePostResponse,
             PostSortOrder,
@@ -177,6 +261,10 @@ use utoipa::{
             PostAuthor,
             PostListItem,
             GetPostsResponse,
+            RelatedPostsResponse,
+            PostAnalyticsResponse,
+            PostDailyAnalytics,
+            PostReferrerAnalytics,
             ImageUploadResponse,
             UserPostsResponse,
             CheckFollowStatusRequest,
@@ -190,6 +278,8 @@ use utoipa::{
             UpdateProfileRequest,
             HandleCheckResponse,
             UserInfoResponse,
+            UserSuggestion,
+            SuggestUsersResponse,
             ErrorResponse,
             ImageUploadForm,
             ProfileAvatarForm,
@@ -197,6 +287,25 @@ use utoipa::{
             PostThumbnailForm,
             TrendingHashtagsRequest,
             TrendingHashtagsResponse,
+            FollowHashtagRequest,
+            HashtagTimelineRequest,
+            HashtagDetailRequest,
+            HashtagPostSort,
+            FollowedHashtag,
+            FollowedHashtagsResponse,
+            HashtagTimelineResponse,
+            HashtagDetailResponse,
+            HashtagTopAuthor,
+            CreateHashtagAliasRequest,
+            DeleteHashtagAliasRequest,
+            MergeHashtagsRequest,
+            MergeHashtagsResponse,
+            BanHashtagRequest,
+            UpdateHashtagRequest,
+            HashtagCoverForm,
+            HashtagCoverUploadResponse,
+            HashtagSuggestion,
+            SuggestHashtagsResponse,
             CheckLikeStatusRequest,
             CreateLikeRequest,
             DeleteLikeRequest,
@@ -204,15 +313,32 @@ use utoipa::{
             CreateCommentLikeRequest,
             DeleteCommentLikeRequest,
             LikeStatusResponse,
+            TogglePostReactionRequest,
+            ToggleCommentReactionRequest,
+            ReactionCount,
+            ToggleReactionResponse,
+            GetMentionsRequest,
+            GetMentionsResponse,
+            MentionInfo,
             CreateCommentRequest,
             DeleteCommentRequest,
             GetCommentByIdRequest,
             GetCommentsRequest,
             GetRepliesRequest,
+            GetCommentTreeRequest,
+            GetCommentHistoryRequest,
             UpdateCommentRequest,
+            PinCommentRequest,
+            HideCommentRequest,
+            LockCommentRequest,
             CommentInfo,
             GetCommentsResponse,
             GetRepliesResponse,
+            GetCommentTreeResponse,
+            CommentTreeNode,
+            CommentTreeCursor,
+            GetCommentHistoryResponse,
+            CommentEditInfo,
             CreateDraftRequest,
             DeleteDraftRequest,
             GetDraftRequest,
@@ -229,9 +355,22 @@ use utoipa::{
             ReportReason,
             ReportStatus,
             ReportTargetType,
+            // Health schemas
+            LivenessResponse,
+            ReadinessResponse,
+            DependencyCheck,
+            DependencyStatus,
             // Admin schemas
             AdminStatusResponse,
             AdminTaskResponse,
+            UpdateTrendingSettingsRequest,
+            TrendingSettingsResponse,
+            RequeueOutboxEntriesRequest,
+            OutboxEntriesResponse,
+            OutboxEntry,
+            OutboxStatus,
+            RequeueOutboxEntriesResponse,
+            JobStatusResponse,
         )
     ),
     tags(
@@ -243,8 +382,11 @@ use utoipa::{
         (name = "Follow", description = "Follow endpoints"),
         (name = "Hashtag", description = "Hashtag endpoints"),
         (name = "Like", description = "Like endpoints"),
+        (name = "Reaction", description = "Emoji reaction endpoints"),
+        (name = "Mention", description = "Mention inbox endpoints"),
         (name = "Report", description = "Report endpoints"),
-        (name = "Admin", description = "Admin management endpoints")
+        (name = "Admin", description = "Admin management endpoints"),
+        (name = "Health", description = "Liveness, readiness and metrics endpoints")
     ),
     modifiers(&SecurityAddon) // 보안 스키마 등록
 )]
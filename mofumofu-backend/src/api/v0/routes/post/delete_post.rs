@@ -38,7 +38,7 @@ pub async fn delete_post(
 
     require_verified_user(&state.conn, &claims).await?;
 
-    service_delete_post(&state.conn, &state.http_client, payload, &user_uuid).await?;
+    service_delete_post(&state.conn, &state.redis, payload, &user_uuid).await?;
 
     Ok(StatusCode::NO_CONTENT)
 }
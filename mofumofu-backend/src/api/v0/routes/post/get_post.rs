@@ -1,9 +1,11 @@
+use crate::dto::auth::internal::access_token::AccessTokenClaims;
 use crate::dto::post::request::GetPostByUuidRequest;
 use crate::dto::post::response::post_info::PostInfoResponse;
 use crate::service::error::errors::Errors;
 use crate::service::post::get_post_by_uuid::service_get_post_by_uuid;
 use crate::service::validator::json_validator::ValidatedJson;
 use crate::state::AppState;
+use axum::Extension;
 use axum::extract::State;
 
 #[utoipa::path(
@@ -23,9 +25,19 @@ use axum::extract::State;
 )]
 pub async fn get_post(
     State(state): State<AppState>,
+    claims: Option<Extension<AccessTokenClaims>>,
     ValidatedJson(req_body): ValidatedJson<GetPostByUuidRequest>,
 ) -> Result<PostInfoResponse, Errors> {
-    let post = service_get_post_by_uuid(&state.conn, &state.http_client, &req_body.post_id).await?;
+    // 로그인한 경우 본인의 반응을 함께 반환
+    let viewer_id = claims.map(|ext| ext.sub);
+
+    let post = service_get_post_by_uuid(
+        &state.conn,
+        &state.http_client,
+        &req_body.post_id,
+        viewer_id,
+    )
+    .await?;
 
     Ok(post)
 }
@@ -0,0 +1,36 @@
+use crate::dto::auth::internal::access_token::AccessTokenClaims;
+use crate::dto::post::request::GetPostAnalyticsRequest;
+use crate::dto::post::response::PostAnalyticsResponse;
+use crate::service::error::errors::Errors;
+use crate::service::post::get_post_analytics::service_get_post_analytics;
+use crate::service::validator::json_validator::ValidatedJson;
+use crate::state::AppState;
+use axum::Extension;
+use axum::extract::State;
+use axum::response::IntoResponse;
+
+#[utoipa::path(
+    post,
+    path = "/v0/post/analytics",
+    request_body = GetPostAnalyticsRequest,
+    responses(
+        (status = StatusCode::OK, description = "Post analytics retrieved successfully", body = PostAnalyticsResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid date range"),
+        (status = StatusCode::NOT_FOUND, description = "Post not found"),
+        (status = StatusCode::UNAUTHORIZED, description = "Only the post author can view analytics"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Post"
+)]
+pub async fn get_post_analytics(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    ValidatedJson(payload): ValidatedJson<GetPostAnalyticsRequest>,
+) -> Result<impl IntoResponse, Errors> {
+    let response = service_get_post_analytics(&state.conn, &claims.sub, payload).await?;
+
+    Ok(response)
+}
@@ -0,0 +1,41 @@
+use crate::dto::auth::internal::anonymous_user::AnonymousUserContext;
+use crate::dto::post::request::GetRelatedPostsRequest;
+use crate::dto::post::response::RelatedPostsResponse;
+use crate::service::error::errors::Errors;
+use crate::service::post::get_related_posts::service_get_related_posts;
+use crate::service::validator::json_validator::ValidatedJson;
+use crate::state::AppState;
+use axum::Extension;
+use axum::extract::State;
+use axum::response::IntoResponse;
+
+#[utoipa::path(
+    post,
+    path = "/v0/post/related",
+    request_body = GetRelatedPostsRequest,
+    responses(
+        (status = StatusCode::OK, description = "Related posts retrieved successfully", body = RelatedPostsResponse),
+        (status = StatusCode::NOT_FOUND, description = "Post not found"),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid input"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+    security(
+        ("anonymous_id_cookie" = [])
+    ),
+    tag = "Post"
+)]
+pub async fn get_related_posts(
+    State(state): State<AppState>,
+    Extension(anonymous_ctx): Extension<AnonymousUserContext>,
+    ValidatedJson(payload): ValidatedJson<GetRelatedPostsRequest>,
+) -> Result<impl IntoResponse, Errors> {
+    let response = service_get_related_posts(
+        &state.conn,
+        &state.redis,
+        payload,
+        Some(&anonymous_ctx.anonymous_user_id),
+    )
+    .await?;
+
+    Ok(response)
+}
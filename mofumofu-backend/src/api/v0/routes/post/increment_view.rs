@@ -1,22 +1,26 @@
 use crate::dto::auth::internal::anonymous_user::AnonymousUserContext;
-use crate::dto::post::request::GetPostByUuidRequest;
+use crate::dto::post::request::IncrementViewRequest;
 use crate::service::error::errors::Errors;
 use crate::service::post::increment_view_service::service_increment_view;
 use crate::service::validator::json_validator::ValidatedJson;
 use crate::state::AppState;
+use crate::utils::extract_user_agent::extract_user_agent;
 use axum::Extension;
 use axum::extract::State;
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
+use axum_extra::TypedHeader;
+use axum_extra::headers::UserAgent;
 
 #[utoipa::path(
     post,
     path = "/v0/post/view",
-    request_body = GetPostByUuidRequest,
+    request_body = IncrementViewRequest,
     responses(
         (status = StatusCode::NO_CONTENT, description = "View count incremented successfully"),
         (status = StatusCode::NOT_FOUND, description = "Post not found"),
         (status = StatusCode::BAD_REQUEST, description = "Invalid input"),
+        (status = StatusCode::TOO_MANY_REQUESTS, description = "Rate limit exceeded: rate_limit:exceeded"),
         (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error")
     ),
     security(
@@ -25,9 +29,10 @@ use axum::response::IntoResponse;
     tag = "Post"
 )]
 pub async fn increment_view(
+    user_agent: Option<TypedHeader<UserAgent>>,
     State(state): State<AppState>,
     Extension(anonymous_ctx): Extension<AnonymousUserContext>,
-    ValidatedJson(req_body): ValidatedJson<GetPostByUuidRequest>,
+    ValidatedJson(req_body): ValidatedJson<IncrementViewRequest>,
 ) -> Result<impl IntoResponse, Errors> {
     println!("{}", anonymous_ctx.anonymous_user_id);
     service_increment_view(
@@ -35,6 +40,8 @@ pub async fn increment_view(
         &state.conn,
         &req_body.post_id,
         Some(&anonymous_ctx.anonymous_user_id),
+        req_body.referrer.as_deref(),
+        &extract_user_agent(user_agent),
     )
     .await?;
 
@@ -1,23 +1,38 @@
 use crate::api::v0::routes::post::create_post::create_post;
 use crate::api::v0::routes::post::delete_post::delete_post;
 use crate::api::v0::routes::post::get_post::get_post;
+use crate::api::v0::routes::post::get_post_analytics::get_post_analytics;
 use crate::api::v0::routes::post::get_post_by_handle_and_slug::get_post_by_handle_and_slug;
 use crate::api::v0::routes::post::get_post_for_edit::get_post_for_edit;
 use crate::api::v0::routes::post::get_posts::get_posts;
+use crate::api::v0::routes::post::get_related_posts::get_related_posts;
 use crate::api::v0::routes::post::get_user_posts::get_user_posts;
 use crate::api::v0::routes::post::increment_view::increment_view;
 use crate::api::v0::routes::post::search_posts::search_posts;
+use crate::api::v0::routes::post::update_comment_settings::update_comment_settings;
 use crate::api::v0::routes::post::update_post::update_post;
 use crate::api::v0::routes::post::upload_image::upload_image;
 use crate::api::v0::routes::post::upload_thumbnail::upload_thumbnail;
+use crate::dto::rate_limit::internal::rate_limit_policy::{RateLimitKey, RateLimitPolicy};
 use crate::middleware::anonymous_user::anonymous_user_middleware;
+use crate::middleware::auth::optional_access_jwt_auth;
+use crate::middleware::rate_limit::rate_limit;
 use crate::{middleware::auth::access_jwt_auth, state::AppState};
 use axum::{
     Router,
-    middleware::from_fn,
+    middleware::{from_fn, from_fn_with_state},
     routing::{delete, get, post, put},
 };
 
+// 이미지 업로드: 사용자당 1분에 썸네일 10개, 본문 이미지 30개
+const UPLOAD_THUMBNAIL_RATE_LIMIT: RateLimitPolicy =
+    RateLimitPolicy::token_bucket("post:upload_thumbnail", 10, 60, RateLimitKey::User);
+const UPLOAD_IMAGE_RATE_LIMIT: RateLimitPolicy =
+    RateLimitPolicy::token_bucket("post:upload_image", 30, 60, RateLimitKey::User);
+// 조회수 증가: IP당 1분에 60번 (익명 사용자 쿠키는 새로 받으면 그만이라 기준으로 쓰지 않음)
+const INCREMENT_VIEW_RATE_LIMIT: RateLimitPolicy =
+    RateLimitPolicy::token_bucket("post:increment_view", 60, 60, RateLimitKey::Ip);
+
 pub fn post_routes() -> Router<AppState> {
     Router::new()
         .route(
@@ -32,26 +47,47 @@ pub fn post_routes() -> Router<AppState> {
             "/post",
             delete(delete_post).route_layer(from_fn(access_jwt_auth)),
         )
+        .route(
+            "/post/comment_settings",
+            post(update_comment_settings).route_layer(from_fn(access_jwt_auth)),
+        )
         .route(
             "/post/thumbnail",
-            post(upload_thumbnail).route_layer(from_fn(access_jwt_auth)),
+            post(upload_thumbnail)
+                .route_layer(from_fn_with_state(UPLOAD_THUMBNAIL_RATE_LIMIT, rate_limit))
+                .route_layer(from_fn(access_jwt_auth)),
         )
         .route(
             "/post/image",
-            post(upload_image).route_layer(from_fn(access_jwt_auth)),
+            post(upload_image)
+                .route_layer(from_fn_with_state(UPLOAD_IMAGE_RATE_LIMIT, rate_limit))
+                .route_layer(from_fn(access_jwt_auth)),
+        )
+        .route(
+            "/post/get",
+            post(get_post).route_layer(from_fn(optional_access_jwt_auth)),
         )
-        .route("/post/get", post(get_post))
         .route(
             "/post/get_by_handle_and_slug",
-            post(get_post_by_handle_and_slug),
+            post(get_post_by_handle_and_slug).route_layer(from_fn(optional_access_jwt_auth)),
         )
         .route(
             "/post/edit",
             post(get_post_for_edit).route_layer(from_fn(access_jwt_auth)),
         )
+        .route(
+            "/post/analytics",
+            post(get_post_analytics).route_layer(from_fn(access_jwt_auth)),
+        )
         .route(
             "/post/view",
-            post(increment_view).route_layer(axum::middleware::from_fn(anonymous_user_middleware)),
+            post(increment_view)
+                .route_layer(from_fn_with_state(INCREMENT_VIEW_RATE_LIMIT, rate_limit))
+                .route_layer(axum::middleware::from_fn(anonymous_user_middleware)),
+        )
+        .route(
+            "/post/related",
+            post(get_related_posts).route_layer(from_fn(anonymous_user_middleware)),
         )
         .route("/posts", post(get_posts))
         .route("/posts/user", post(get_user_posts))
@@ -0,0 +1,41 @@
+use crate::dto::auth::internal::access_token::AccessTokenClaims;
+use crate::dto::post::request::update_comment_settings::UpdatePostCommentSettingsRequest;
+use crate::service::error::errors::Errors;
+use crate::service::post::update_comment_settings::service_update_post_comment_settings;
+use crate::service::validator::json_validator::ValidatedJson;
+use crate::state::AppState;
+use axum::Extension;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use tracing::info;
+
+#[utoipa::path(
+    post,
+    path = "/v0/post/comment_settings",
+    request_body = UpdatePostCommentSettingsRequest,
+    responses(
+        (status = StatusCode::NO_CONTENT, description = "Comment settings updated successfully"),
+        (status = StatusCode::NOT_FOUND, description = "Post not found"),
+        (status = StatusCode::UNAUTHORIZED, description = "Only the post author can change comment settings"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Post"
+)]
+pub async fn update_comment_settings(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    ValidatedJson(payload): ValidatedJson<UpdatePostCommentSettingsRequest>,
+) -> Result<impl IntoResponse, Errors> {
+    info!(
+        "Received request to update post comment settings: {:?}",
+        payload
+    );
+
+    service_update_post_comment_settings(&state.conn, &state.redis, &claims.sub, payload).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
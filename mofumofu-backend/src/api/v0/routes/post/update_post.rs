@@ -38,7 +38,14 @@ pub async fn update_post(
 
     require_verified_user(&state.conn, &claims).await?;
 
-    service_update_post(&state.conn, &state.http_client, payload, &user_uuid).await?;
+    service_update_post(
+        &state.conn,
+        &state.redis,
+        &state.http_client,
+        payload,
+        &user_uuid,
+    )
+    .await?;
 
     Ok(StatusCode::NO_CONTENT)
 }
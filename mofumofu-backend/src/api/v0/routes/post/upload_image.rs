@@ -20,6 +20,7 @@ use tracing::info;
         (status = 401, description = "Unauthorized or email not verified"),
         (status = 413, description = "File too large"),
         (status = 422, description = "Unsupported image format"),
+        (status = 429, description = "Rate limit exceeded: rate_limit:exceeded"),
         (status = 500, description = "Internal server error")
     ),
     security(
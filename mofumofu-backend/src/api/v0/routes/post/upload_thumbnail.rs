@@ -22,6 +22,7 @@ use tracing::info;
         (status = 404, description = "Post not found"),
         (status = 413, description = "File too large"),
         (status = 422, description = "Unsupported image format"),
+        (status = 429, description = "Rate limit exceeded: rate_limit:exceeded"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -41,9 +42,14 @@ pub async fn upload_thumbnail(
 
     require_verified_user(&state.conn, &claims).await?;
 
-    let public_url =
-        service_update_post_thumbnail(&state.conn, &state.cloudflare_r2, &claims.sub, multipart)
-            .await?;
+    let public_url = service_update_post_thumbnail(
+        &state.conn,
+        &state.redis,
+        &state.cloudflare_r2,
+        &claims.sub,
+        multipart,
+    )
+    .await?;
 
     Ok(ThumbnailUploadResponse { public_url })
 }
@@ -0,0 +1,3 @@
+pub mod routes;
+pub mod toggle_comment_reaction;
+pub mod toggle_post_reaction;
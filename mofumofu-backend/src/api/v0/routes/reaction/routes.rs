@@ -0,0 +1,17 @@
+use crate::api::v0::routes::reaction::toggle_comment_reaction::toggle_comment_reaction;
+use crate::api::v0::routes::reaction::toggle_post_reaction::toggle_post_reaction;
+use crate::middleware::auth::access_jwt_auth;
+use crate::state::AppState;
+use axum::{Router, middleware::from_fn, routing::post};
+
+pub fn reaction_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/reaction",
+            post(toggle_post_reaction).route_layer(from_fn(access_jwt_auth)),
+        )
+        .route(
+            "/comment/reaction",
+            post(toggle_comment_reaction).route_layer(from_fn(access_jwt_auth)),
+        )
+}
@@ -0,0 +1,44 @@
+use crate::dto::auth::internal::access_token::AccessTokenClaims;
+use crate::dto::reaction::request::toggle_comment_reaction::ToggleCommentReactionRequest;
+use crate::dto::reaction::response::toggle_reaction::ToggleReactionResponse;
+use crate::service::error::errors::Errors;
+use crate::service::reaction::toggle_comment_reaction::service_toggle_comment_reaction;
+use crate::service::validator::json_validator::ValidatedJson;
+use crate::state::AppState;
+use axum::Extension;
+use axum::extract::State;
+use tracing::info;
+
+#[utoipa::path(
+    post,
+    path = "/v0/comment/reaction",
+    request_body = ToggleCommentReactionRequest,
+    responses(
+        (status = StatusCode::OK, description = "Comment reaction toggled successfully", body = ToggleReactionResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Emoji not allowed: reaction:invalid_emoji"),
+        (status = StatusCode::NOT_FOUND, description = "Comment not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Reaction"
+)]
+pub async fn toggle_comment_reaction(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    ValidatedJson(payload): ValidatedJson<ToggleCommentReactionRequest>,
+) -> Result<ToggleReactionResponse, Errors> {
+    info!("Received request to toggle comment reaction: {:?}", payload);
+    let user_uuid = claims.sub;
+
+    let response = service_toggle_comment_reaction(
+        &state.conn,
+        &user_uuid,
+        &payload.comment_id,
+        &payload.emoji,
+    )
+    .await?;
+
+    Ok(response)
+}
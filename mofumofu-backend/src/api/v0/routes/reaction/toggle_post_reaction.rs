@@ -0,0 +1,45 @@
+use crate::dto::auth::internal::access_token::AccessTokenClaims;
+use crate::dto::reaction::request::toggle_post_reaction::TogglePostReactionRequest;
+use crate::dto::reaction::response::toggle_reaction::ToggleReactionResponse;
+use crate::service::error::errors::Errors;
+use crate::service::reaction::toggle_post_reaction::service_toggle_post_reaction;
+use crate::service::validator::json_validator::ValidatedJson;
+use crate::state::AppState;
+use axum::Extension;
+use axum::extract::State;
+use tracing::info;
+
+#[utoipa::path(
+    post,
+    path = "/v0/reaction",
+    request_body = TogglePostReactionRequest,
+    responses(
+        (status = StatusCode::OK, description = "Post reaction toggled successfully", body = ToggleReactionResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Emoji not allowed: reaction:invalid_emoji"),
+        (status = StatusCode::NOT_FOUND, description = "Post not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Reaction"
+)]
+pub async fn toggle_post_reaction(
+    State(state): State<AppState>,
+    Extension(claims): Extension<AccessTokenClaims>,
+    ValidatedJson(payload): ValidatedJson<TogglePostReactionRequest>,
+) -> Result<ToggleReactionResponse, Errors> {
+    info!("Received request to toggle post reaction: {:?}", payload);
+    let user_uuid = claims.sub;
+
+    let response = service_toggle_post_reaction(
+        &state.conn,
+        &state.redis,
+        &user_uuid,
+        &payload.post_id,
+        &payload.emoji,
+    )
+    .await?;
+
+    Ok(response)
+}
@@ -17,6 +17,7 @@ use tracing::info;
     responses(
         (status = 201, description = "Report created successfully", body = CreateReportResponse),
         (status = StatusCode::BAD_REQUEST, description = "Invalid request"),
+        (status = StatusCode::TOO_MANY_REQUESTS, description = "Rate limit exceeded: rate_limit:exceeded"),
         (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error")
     ),
     description = "Create a report. Authentication is optional - logged in users will have their ID recorded, anonymous users will create anonymous reports.",
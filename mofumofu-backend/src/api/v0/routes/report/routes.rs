@@ -1,19 +1,27 @@
 use crate::api::v0::routes::report::create_report::create_report;
 use crate::api::v0::routes::report::get_reports::get_reports;
 use crate::api::v0::routes::report::process_report::process_report;
+use crate::dto::rate_limit::internal::rate_limit_policy::{RateLimitKey, RateLimitPolicy};
 use crate::middleware::auth::{access_jwt_auth, optional_access_jwt_auth};
+use crate::middleware::rate_limit::rate_limit;
 use crate::state::AppState;
 use axum::{
     Router,
-    middleware::from_fn,
+    middleware::{from_fn, from_fn_with_state},
     routing::{post, put},
 };
 
+// 신고: 로그인 사용자는 사용자 ID, 비로그인은 IP 기준으로 1시간에 10개
+const CREATE_REPORT_RATE_LIMIT: RateLimitPolicy =
+    RateLimitPolicy::sliding_window("report:create", 10, 3600, RateLimitKey::User);
+
 pub fn report_routes() -> Router<AppState> {
     Router::new()
         .route(
             "/report",
-            post(create_report).route_layer(from_fn(optional_access_jwt_auth)), // 선택적 인증
+            post(create_report)
+                .route_layer(from_fn_with_state(CREATE_REPORT_RATE_LIMIT, rate_limit))
+                .route_layer(from_fn(optional_access_jwt_auth)), // 선택적 인증
         )
         .route(
             "/report/list",
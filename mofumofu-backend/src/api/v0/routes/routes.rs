@@ -5,8 +5,11 @@ use crate::api::v0::routes::comment::routes::comment_routes;
 use crate::api::v0::routes::draft::routes::draft_routes;
 use crate::api::v0::routes::follow::routes::follow_routes;
 use crate::api::v0::routes::hashtag::routes::hashtag_routes;
+use crate::api::v0::routes::health::routes::{health_routes, metrics_routes};
 use crate::api::v0::routes::like::routes::like_routes;
+use crate::api::v0::routes::mention::routes::mention_routes;
 use crate::api::v0::routes::post::routes::post_routes;
+use crate::api::v0::routes::reaction::routes::reaction_routes;
 use crate::api::v0::routes::report::routes::report_routes;
 use crate::api::v0::routes::user::routes::user_routes;
 use crate::service::error::errors::handler_404;
@@ -19,6 +22,7 @@ use utoipa_swagger_ui::SwaggerUi;
 pub fn api_routes() -> Router<AppState> {
     Router::new()
         .merge(SwaggerUi::new("/docs").url("/swagger.json", ApiDoc::openapi()))
+        .merge(health_routes())
         .nest("/v0", auth_routes())
         .nest("/v0", user_routes())
         .nest("/v0", post_routes())
@@ -26,8 +30,15 @@ pub fn api_routes() -> Router<AppState> {
         .nest("/v0", comment_routes())
         .nest("/v0", follow_routes())
         .nest("/v0", like_routes())
+        .nest("/v0", reaction_routes())
+        .nest("/v0", mention_routes())
         .nest("/v0/hashtag", hashtag_routes())
         .nest("/v0", report_routes())
         .nest("/v0/admin", admin_routes())
         .fallback(handler_404)
 }
+
+/// 내부 전용 리스너 라우터 (Prometheus 스크랩)
+pub fn internal_routes() -> Router<AppState> {
+    Router::new().merge(metrics_routes())
+}
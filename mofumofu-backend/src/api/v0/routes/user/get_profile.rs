@@ -24,6 +24,6 @@ pub async fn get_profile(
 ) -> Result<UserInfoResponse, Errors> {
     info!("Received GET request for user with ID: {}", payload.handle);
 
-    let user = service_get_user_by_handle(&state.conn, &payload.handle).await?;
+    let user = service_get_user_by_handle(&state.conn, &state.redis, &payload.handle).await?;
     Ok(user)
 }
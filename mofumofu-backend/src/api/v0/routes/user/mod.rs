@@ -2,6 +2,7 @@ pub mod check_handle;
 pub mod get_my_profile;
 pub mod get_profile;
 pub mod routes;
+pub mod suggest_users;
 pub mod update_profile;
 pub mod upload_avatar;
 pub mod upload_banner;
@@ -1,18 +1,31 @@
 use crate::api::v0::routes::user::check_handle::check_handle_availability;
 use crate::api::v0::routes::user::get_my_profile::get_my_profile;
 use crate::api::v0::routes::user::get_profile::get_profile;
+use crate::api::v0::routes::user::suggest_users::suggest_users;
 use crate::api::v0::routes::user::update_profile::update_profile;
 use crate::api::v0::routes::user::upload_avatar::upload_avatar;
 use crate::api::v0::routes::user::upload_banner::upload_banner;
-use crate::middleware::auth::access_jwt_auth;
+use crate::dto::rate_limit::internal::rate_limit_policy::{RateLimitKey, RateLimitPolicy};
+use crate::middleware::auth::{access_jwt_auth, optional_access_jwt_auth};
+use crate::middleware::rate_limit::rate_limit;
 use crate::state::AppState;
 use axum::Router;
 use axum::routing::{get, post, put};
 
+// 프로필 이미지 업로드: 사용자당 10분에 각각 10개
+const UPLOAD_AVATAR_RATE_LIMIT: RateLimitPolicy =
+    RateLimitPolicy::sliding_window("user:upload_avatar", 10, 600, RateLimitKey::User);
+const UPLOAD_BANNER_RATE_LIMIT: RateLimitPolicy =
+    RateLimitPolicy::sliding_window("user:upload_banner", 10, 600, RateLimitKey::User);
+
 pub fn user_routes() -> Router<AppState> {
     Router::new()
         .route("/user/check-handle", post(check_handle_availability))
         .route("/user/profile", post(get_profile))
+        .route(
+            "/user/suggest",
+            get(suggest_users).route_layer(axum::middleware::from_fn(optional_access_jwt_auth)),
+        )
         // 보호된 사용자 프로필 API
         .route(
             "/user/my_profile",
@@ -25,10 +38,20 @@ pub fn user_routes() -> Router<AppState> {
         // 이미지 업로드 API
         .route(
             "/user/profile/avatar",
-            post(upload_avatar).route_layer(axum::middleware::from_fn(access_jwt_auth)),
+            post(upload_avatar)
+                .route_layer(axum::middleware::from_fn_with_state(
+                    UPLOAD_AVATAR_RATE_LIMIT,
+                    rate_limit,
+                ))
+                .route_layer(axum::middleware::from_fn(access_jwt_auth)),
         )
         .route(
             "/user/profile/banner",
-            post(upload_banner).route_layer(axum::middleware::from_fn(access_jwt_auth)),
+            post(upload_banner)
+                .route_layer(axum::middleware::from_fn_with_state(
+                    UPLOAD_BANNER_RATE_LIMIT,
+                    rate_limit,
+                ))
+                .route_layer(axum::middleware::from_fn(access_jwt_auth)),
         )
 }
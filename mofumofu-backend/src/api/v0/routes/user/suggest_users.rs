@@ -0,0 +1,33 @@
+use crate::dto::auth::internal::access_token::AccessTokenClaims;
+use crate::dto::user::request::suggest_users::SuggestUsersRequest;
+use crate::dto::user::response::suggest_users::SuggestUsersResponse;
+use crate::service::error::errors::Errors;
+use crate::service::user::suggest_users::service_suggest_users;
+use crate::service::validator::form_validator::ValidatedForm;
+use crate::state::AppState;
+use axum::Extension;
+use axum::extract::State;
+
+#[utoipa::path(
+    get,
+    path = "/v0/user/suggest",
+    params(SuggestUsersRequest),
+    responses(
+        (status = StatusCode::OK, description = "User suggestions retrieved successfully", body = SuggestUsersResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid input"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+    tag = "User"
+)]
+pub async fn suggest_users(
+    State(state): State<AppState>,
+    claims: Option<Extension<AccessTokenClaims>>,
+    ValidatedForm(params): ValidatedForm<SuggestUsersRequest>,
+) -> Result<SuggestUsersResponse, Errors> {
+    // 로그인한 경우 팔로우 중인 사용자를 먼저 보여줌
+    let viewer_id = claims.map(|ext| ext.sub);
+
+    let response = service_suggest_users(&state.conn, viewer_id, params).await?;
+
+    Ok(response)
+}
@@ -42,7 +42,8 @@ pub async fn update_profile(
 
     require_verified_user(&state.conn, &claims).await?;
 
-    let updated_user = service_update_user_profile(&state.conn, &claims.sub, payload).await?;
+    let updated_user =
+        service_update_user_profile(&state.conn, &state.redis, &claims.sub, payload).await?;
 
     Ok(updated_user)
 }
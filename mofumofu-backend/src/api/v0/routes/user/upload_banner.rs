@@ -19,6 +19,7 @@ use tracing::info;
         (status = 400, description = "File errors: file:not_found, file:read_error"),
         (status = 401, description = "Unauthorized or email not verified"),
         (status = 413, description = "File too large"),
+        (status = 429, description = "Rate limit exceeded: rate_limit:exceeded"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -38,9 +39,14 @@ pub async fn upload_banner(
 
     require_verified_user(&state.conn, &claims).await?;
 
-    let public_url =
-        service_update_user_banner(&state.conn, &state.cloudflare_r2, &claims.sub, multipart)
-            .await?;
+    let public_url = service_update_user_banner(
+        &state.conn,
+        &state.redis,
+        &state.cloudflare_r2,
+        &claims.sub,
+        multipart,
+    )
+    .await?;
 
     Ok(ImageUploadResponse { public_url })
 }
@@ -0,0 +1,269 @@
+use std::collections::HashMap;
+use std::env;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+/// 기본 설정 파일 경로 (CONFIG_FILE로 변경 가능)
+const DEFAULT_CONFIG_FILE: &str = "config.toml";
+
+/// 프로세스 시작 시점의 실제 환경 변수 (.env 적용 전)
+///
+/// .env 값을 프로세스 환경에 넣은 뒤에도 재로딩 시 .env 파일을 다시 읽어
+/// 반영할 수 있도록 원래 환경 변수만 따로 보관한다.
+static PROCESS_ENV: LazyLock<HashMap<String, String>> = LazyLock::new(|| {
+    let vars = env::vars().collect();
+    // 다른 라이브러리(OTEL 등)도 .env 값을 읽을 수 있도록 프로세스 환경에 반영
+    dotenvy::dotenv().ok();
+    vars
+});
+
+/// 설정 값 하나에 대한 문제
+#[derive(Debug, Clone)]
+pub enum ConfigIssue {
+    Missing { key: String },
+    Invalid { key: String, reason: String },
+}
+
+impl fmt::Display for ConfigIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigIssue::Missing { key } => write!(f, "{}: missing required value", key),
+            ConfigIssue::Invalid { key, reason } => write!(f, "{}: {}", key, reason),
+        }
+    }
+}
+
+/// 검증에 실패한 모든 설정 값 목록
+#[derive(Debug, Clone)]
+pub struct ConfigError {
+    pub issues: Vec<ConfigIssue>,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Invalid configuration ({} problems):", self.issues.len())?;
+        for issue in &self.issues {
+            writeln!(f, "  - {}", issue)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// 기본값 < TOML 파일 < .env 파일 < 환경 변수 순으로 덮어쓴 설정 값
+///
+/// 값을 읽으면서 누락되거나 잘못된 키를 모두 모아 두었다가 한 번에 보고한다.
+pub struct ConfigSource {
+    values: HashMap<String, String>,
+    issues: Vec<ConfigIssue>,
+}
+
+impl ConfigSource {
+    pub fn load() -> Self {
+        let mut source = ConfigSource {
+            values: HashMap::new(),
+            issues: Vec::new(),
+        };
+
+        // .env에 지정한 CONFIG_FILE도 반영되도록 먼저 초기화
+        let process_env = &*PROCESS_ENV;
+
+        source.load_file();
+
+        // 재로딩 시에도 최신 내용이 반영되도록 .env 파일은 매번 다시 읽음
+        if let Ok(entries) = dotenvy::dotenv_iter() {
+            source.values.extend(entries.flatten());
+        }
+
+        source
+            .values
+            .extend(process_env.iter().map(|(k, v)| (k.clone(), v.clone())));
+
+        source
+    }
+
+    fn load_file(&mut self) {
+        let (path, explicit) = match env::var("CONFIG_FILE") {
+            Ok(path) if !path.is_empty() => (path, true),
+            _ => (DEFAULT_CONFIG_FILE.to_string(), false),
+        };
+
+        if !Path::new(&path).exists() {
+            if explicit {
+                self.invalid("CONFIG_FILE", format!("file '{}' does not exist", path));
+            }
+            return;
+        }
+
+        let table = match std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|content| content.parse::<toml::Table>().map_err(|e| e.to_string()))
+        {
+            Ok(table) => table,
+            Err(e) => {
+                self.invalid("CONFIG_FILE", format!("failed to read '{}': {}", path, e));
+                return;
+            }
+        };
+
+        flatten_table("", &table, &mut self.values);
+    }
+
+    fn invalid(&mut self, key: &str, reason: String) {
+        self.issues.push(ConfigIssue::Invalid {
+            key: key.to_string(),
+            reason,
+        });
+    }
+
+    /// 값이 없거나 비어 있으면 None
+    pub fn optional(&self, key: &str) -> Option<String> {
+        self.values
+            .get(key)
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    }
+
+    pub fn required(&mut self, key: &str) -> String {
+        match self.optional(key) {
+            Some(value) => value,
+            None => {
+                self.issues.push(ConfigIssue::Missing {
+                    key: key.to_string(),
+                });
+                String::new()
+            }
+        }
+    }
+
+    pub fn string_or(&self, key: &str, default: &str) -> String {
+        self.optional(key).unwrap_or_else(|| default.to_string())
+    }
+
+    /// 값이 없으면 기본값, 있는데 해석할 수 없으면 문제로 기록
+    pub fn parse_or<T>(&mut self, key: &str, default: T) -> T
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        match self.optional(key) {
+            None => default,
+            Some(value) => match value.parse() {
+                Ok(parsed) => parsed,
+                Err(e) => {
+                    self.invalid(key, format!("cannot parse '{}': {}", value, e));
+                    default
+                }
+            },
+        }
+    }
+
+    pub fn parse_optional<T>(&mut self, key: &str) -> Option<T>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let value = self.optional(key)?;
+        match value.parse() {
+            Ok(parsed) => Some(parsed),
+            Err(e) => {
+                self.invalid(key, format!("cannot parse '{}': {}", value, e));
+                None
+            }
+        }
+    }
+
+    /// 허용된 값 중 하나인지 확인
+    pub fn choice(&mut self, key: &str, allowed: &[&str], default: &str) -> String {
+        let value = self.string_or(key, default);
+        if !allowed.contains(&value.as_str()) {
+            self.invalid(
+                key,
+                format!("'{}' is not one of {}", value, allowed.join(", ")),
+            );
+            return default.to_string();
+        }
+        value
+    }
+
+    /// 쉼표로 구분된 목록 (값이 없으면 None)
+    pub fn list(&self, key: &str) -> Option<Vec<String>> {
+        self.values.get(key).map(|values| {
+            values
+                .split(',')
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+    }
+
+    /// 목록의 각 항목을 변환하고 실패한 항목은 문제로 기록
+    pub fn parse_list<T, F, E>(&mut self, key: &str, parse: F) -> Vec<T>
+    where
+        F: Fn(&str) -> Result<T, E>,
+        E: fmt::Display,
+    {
+        let mut parsed = Vec::new();
+        for value in self.list(key).unwrap_or_default() {
+            match parse(&value) {
+                Ok(item) => parsed.push(item),
+                Err(e) => self.invalid(key, format!("invalid entry '{}': {}", value, e)),
+            }
+        }
+        parsed
+    }
+
+    /// 여러 값을 함께 봐야 하는 검증 실패 기록
+    pub fn check(&mut self, key: &str, ok: bool, reason: &str) {
+        if !ok {
+            self.invalid(key, reason.to_string());
+        }
+    }
+
+    pub fn finish<T>(self, config: T) -> Result<T, ConfigError> {
+        if self.issues.is_empty() {
+            Ok(config)
+        } else {
+            Err(ConfigError {
+                issues: self.issues,
+            })
+        }
+    }
+}
+
+/// [postgres] host = "..." 형태를 POSTGRES_HOST 키로 변환
+fn flatten_table(prefix: &str, table: &toml::Table, values: &mut HashMap<String, String>) {
+    for (key, value) in table {
+        let key = if prefix.is_empty() {
+            key.to_uppercase()
+        } else {
+            format!("{}_{}", prefix, key.to_uppercase())
+        };
+
+        match value {
+            toml::Value::Table(nested) => flatten_table(&key, nested, values),
+            toml::Value::Array(items) => {
+                let joined = items
+                    .iter()
+                    .map(toml_scalar_to_string)
+                    .collect::<Vec<_>>()
+                    .join(",");
+                values.insert(key, joined);
+            }
+            scalar => {
+                values.insert(key, toml_scalar_to_string(scalar));
+            }
+        }
+    }
+}
+
+fn toml_scalar_to_string(value: &toml::Value) -> String {
+    match value {
+        toml::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
@@ -1,14 +1,18 @@
+use crate::config::config_source::{ConfigError, ConfigSource};
+use crate::config::secret::Secret;
+use crate::service::markdown::sanitizer::CLEAN_CONTENT_TAGS;
 use axum::http::{HeaderName, HeaderValue};
-use dotenvy::dotenv;
-use std::env;
-use std::sync::LazyLock;
-use tracing::warn;
+use ipnet::IpNet;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::{Arc, LazyLock, PoisonError, RwLock};
+use tracing::info;
 
 #[derive(Debug, Clone)]
 pub struct DbConfig {
     pub is_dev: bool,
 
-    pub jwt_secret: String,
+    pub jwt_secret: Secret,
     pub auth_access_token_expire_time: i64,
     pub auth_refresh_token_expire_time: i64,
     pub auth_email_verification_token_expire_time: i64,
@@ -16,13 +20,13 @@ pub struct DbConfig {
 
     // Google
     pub google_client_id: String,
-    pub google_client_secret: String,
+    pub google_client_secret: Secret,
     pub google_redirect_uri: String,
     pub google_link_redirect_uri: String,
 
     // Github
     pub github_client_id: String,
-    pub github_client_secret: String,
+    pub github_client_secret: Secret,
     pub github_redirect_uri: String,
 
     // Cloudflare
@@ -30,10 +34,10 @@ pub struct DbConfig {
     pub r2_account_id: String,
     pub r2_bucket_name: String,
     pub r2_access_key_id: String,
-    pub r2_secret_access_key: String,
+    pub r2_secret_access_key: Secret,
 
     pub db_user: String,
-    pub db_password: String,
+    pub db_password: Secret,
     pub db_host: String,
     pub db_port: String,
     pub db_name: String,
@@ -54,6 +58,9 @@ pub struct DbConfig {
     // pub opensearch_verify_certs: bool,
     pub server_host: String,
     pub server_port: String,
+    pub metrics_host: String,
+    pub metrics_port: String,
+    pub shutdown_drain_timeout: u64,
 
     // Task Server
     pub task_server_host: String,
@@ -62,187 +69,426 @@ pub struct DbConfig {
     // Markdown Service
     pub markdown_service_host: String,
     pub markdown_service_port: String,
+    pub markdown_native_renderer: bool,
+
+    // Background jobs (native이면 태스크 서버 대신 Redis 기반 내장 작업 큐 사용)
+    pub native_job_runner: bool,
+    pub job_worker_concurrency: usize,
+    pub job_poll_interval: u64,
+    pub job_max_attempts: u32,
+    pub job_retry_backoff: u64,
+    pub job_timeout: u64,
+    pub job_result_ttl: u64,
+    pub job_schedule_cleanup_tokens: Option<String>,
+    pub job_schedule_cleanup_events: Option<String>,
+    pub job_schedule_reindex_posts: Option<String>,
+    pub job_schedule_sync_counts: Option<String>,
+
+    // Email (내장 작업 큐에서 SMTP로 직접 발송)
+    pub smtp_host: Option<String>,
+    pub smtp_port: u16,
+    pub smtp_tls: bool,
+    pub smtp_ssl: bool,
+    pub smtp_user: Option<String>,
+    pub smtp_password: Option<Secret>,
+    pub emails_from_email: Option<String>,
+    pub emails_from_name: Option<String>,
+    pub project_name: String,
+    pub frontend_host: String,
+
+    // HTML Sanitizer (None이면 기본 허용 목록 사용)
+    pub html_sanitizer_allowed_tags: Option<Vec<String>>,
+    pub html_sanitizer_allowed_attributes: Option<Vec<String>>,
+    pub html_sanitizer_url_schemes: Option<Vec<String>>,
+
+    // Drafts
+    pub draft_max_per_user: u64,
+
+    // Rate limiting (라우트별 정책은 각 routes.rs에 선언)
+    pub rate_limit_enabled: bool,
+    pub rate_limit_overrides: HashMap<String, (u32, u64)>,
+    pub trusted_proxies: Vec<IpNet>,
+
+    // Mentions
+    pub mention_max_per_item: usize,
+
+    // Comments
+    pub comment_max_pinned_per_post: u64,
+    pub comment_tree_max_depth: u32,
+    pub comment_tree_replies_per_level: u64,
+
+    // Related posts
+    pub related_posts_cache_ttl: u64,
+    pub viewed_posts_history_size: isize,
+
+    // Response cache
+    pub cache_post_detail_ttl: u64,
+    pub cache_feed_ttl: u64,
+    pub cache_feed_max_page: u32,
+    pub cache_profile_ttl: u64,
+    pub cache_trending_hashtags_ttl: u64,
+
+    // Trending
+    pub trending_refresh_interval: u64,
+    pub trending_window_days: i64,
+
+    // View counting
+    pub view_count_buffered: bool,
+    pub view_count_flush_interval: u64,
+    pub view_count_viewer_salt: Secret,
+
+    // Outbox
+    pub outbox_poll_interval: u64,
+    pub outbox_batch_size: u64,
+    pub outbox_max_attempts: i32,
+    pub outbox_backoff_base: u64,
+    pub outbox_backoff_max: u64,
+    pub outbox_retention_days: i64,
 
     // Meilisearch
     pub meilisearch_host: String,
-    pub meilisearch_api_key: Option<String>,
+    pub meilisearch_api_key: Option<Secret>,
+
+    // Health check
+    pub health_check_timeout_ms: u64,
+
+    // Query count
+    pub db_query_warn_threshold: u32,
+
+    // Tracing
+    pub otel_exporter_otlp_endpoint: Option<String>,
+    pub otel_service_name: String,
 
     pub cors_allowed_origins: Vec<HeaderValue>,
     pub cors_allowed_headers: Vec<HeaderName>,
     pub cors_max_age: Option<u64>,
 }
 
-// LazyLock으로 자동 초기화
-static CONFIG: LazyLock<DbConfig> = LazyLock::new(|| {
-    dotenv().ok();
-
-    let is_dev = matches!(
-        env::var("ENVIRONMENT").as_deref(),
-        Ok("dev") | Ok("development")
-    );
-
-    let cors_origins: Vec<HeaderValue> = match env::var("CORS_ALLOWED_ORIGINS").ok() {
-        Some(origins) => origins
-            .split(',')
-            .filter_map(|s| {
-                let trimmed_s = s.trim();
-                if trimmed_s.is_empty() {
-                    warn!("Empty origin found in CORS_ALLOWED_ORIGINS.");
-                    None
-                } else {
-                    HeaderValue::from_str(trimmed_s).ok().or_else(|| {
-                        warn!(
-                            "Invalid origin '{}' found in CORS_ALLOWED_ORIGINS.",
-                            trimmed_s
-                        );
-                        None
-                    })
-                }
-            })
-            .collect(),
-        None => {
-            vec![]
-        }
-    };
-
-    let cors_headers: Vec<HeaderName> = match env::var("CORS_ALLOWED_HEADERS").ok() {
-        Some(headers) => headers
-            .split(',')
-            .filter_map(|s| {
-                let trimmed_s = s.trim();
-                if trimmed_s.is_empty() {
-                    warn!("Empty header name found in CORS_ALLOWED_HEADERS.");
-                    None
-                } else {
-                    HeaderName::from_bytes(trimmed_s.as_bytes())
-                        .ok()
-                        .or_else(|| {
-                            warn!(
-                                "Invalid header name '{}' found in CORS_ALLOWED_HEADERS.",
-                                trimmed_s
-                            );
-                            None
-                        })
-                }
-            })
-            .collect(),
-        None => {
-            vec![]
-        }
-    };
-
-    DbConfig {
-        is_dev,
-        jwt_secret: env::var("JWT_SECRET").expect("JWT_SECRET must be set"),
-
-        auth_access_token_expire_time: env::var("AUTH_ACCESS_TOKEN_EXPIRE_TIME")
-            .ok()
-            .and_then(|v| v.parse().ok())
-            .unwrap_or(30), // 기본값 30분
-        auth_refresh_token_expire_time: env::var("AUTH_REFRESH_TOKEN_EXPIRE_TIME")
-            .ok()
-            .and_then(|v| v.parse().ok())
-            .unwrap_or(14), // 기본값 14일 (일주일)
-        auth_email_verification_token_expire_time: env::var(
-            "AUTH_EMAIL_VERIFICATION_TOKEN_EXPIRE_TIME",
-        )
-        .ok()
-        .and_then(|v| v.parse().ok())
-        .unwrap_or(1), // 기본값 1시간
-        auth_password_reset_token_expire_time: env::var("AUTH_PASSWORD_RESET_TOKEN_EXPIRE_TIME")
-            .ok()
-            .and_then(|v| v.parse().ok())
-            .unwrap_or(1), // 기본값 1시간
-
-        // Google
-        google_client_id: env::var("GOOGLE_CLIENT_ID").expect("GOOGLE_CLIENT_ID must be set"),
-        google_client_secret: env::var("GOOGLE_CLIENT_SECRET")
-            .expect("GOOGLE_CLIENT_SECRET must be set"),
-        google_redirect_uri: env::var("GOOGLE_REDIRECT_URI")
-            .expect("GOOGLE_REDIRECT_URI must be set"),
-        google_link_redirect_uri: env::var("GOOGLE_LINK_REDIRECT_URI")
-            .expect("GOOGLE_LINK_REDIRECT_URI must be set"),
-
-        // Github
-        github_client_id: env::var("GITHUB_CLIENT_ID").expect("GITHUB_CLIENT_ID must be set"),
-        github_client_secret: env::var("GITHUB_CLIENT_SECRET")
-            .expect("GITHUB_CLIENT_SECRET must be set"),
-        github_redirect_uri: env::var("GITHUB_REDIRECT_URI")
-            .expect("GITHUB_REDIRECT_URI must be set"),
-
-        // Cloudflare
-        r2_public_domain: env::var("R2_PUBLIC_DOMAIN").expect("R2_PUBLIC_DOMAIN must be set"),
-        r2_account_id: env::var("R2_ACCOUNT_ID").expect("R2_ACCOUNT_ID must be set"),
-        r2_bucket_name: env::var("R2_BUCKET_NAME").expect("R2_BUCKET_NAME must be set"),
-        r2_access_key_id: env::var("R2_ACCESS_KEY_ID").expect("R2_ACCESS_KEY_ID must be set"),
-        r2_secret_access_key: env::var("R2_SECRET_ACCESS_KEY")
-            .expect("R2_SECRET_ACCESS_KEY must be set"),
-
-        db_user: env::var("POSTGRES_USER").expect("POSTGRES_USER must be set"),
-        db_password: env::var("POSTGRES_PASSWORD").expect("POSTGRES_PASSWORD must be set"),
-        db_host: env::var("POSTGRES_HOST").expect("POSTGRES_HOST must be set"),
-        db_port: env::var("POSTGRES_PORT").expect("POSTGRES_PORT must be set"),
-        db_name: env::var("POSTGRES_NAME").expect("POSTGRES_NAME must be set"),
-        db_max_connection: env::var("POSTGRES_MAX_CONNECTION")
-            .ok()
-            .and_then(|v| v.parse().ok())
-            .unwrap_or(100),
-        db_min_connection: env::var("POSTGRES_MIN_CONNECTION")
-            .ok()
-            .and_then(|v| v.parse().ok())
-            .unwrap_or(10),
-
-        // Redis
-        redis_host: env::var("REDIS_HOST").expect("REDIS_HOST must be set"),
-        redis_port: env::var("REDIS_PORT").expect("REDIS_PORT must be set"),
-        redis_ttl: env::var("REDIS_TTL")
-            .ok()
-            .and_then(|v| v.parse().ok())
-            .unwrap_or(3600),
-
-        // Opensearch
-        /*
-         opensearch_host: env::var("OPENSEARCH_HOST").expect("OPENSEARCH_HOST must be set"),
-         opensearch_port: env::var("OPENSEARCH_PORT").expect("OPENSEARCH_HOST must be set"),
-         opensearch_scheme: env::var("OPENSEARCH_SCHEME")
-           .ok()
-            .unwrap_or("http".to_string()),
-         opensearch_username: env::var("OPENSEARCH_USERNAME").expect("OPENSEARCH_USERNAME must be set"),
-         opensearch_password:env::var("OPENSEARCH_PASSWORD").expect("OPENSEARCH_PASSWORD must be set"),
-        opensearch_verify_certs: env::var("OPENSEARCH_VERIFY_CERTS")
-            .unwrap_or_else(|_| "false".to_string())
-            .parse()
-            .unwrap_or(false),
-        */
-        server_host: env::var("HOST").expect("HOST must be set in .env file"),
-        server_port: env::var("PORT").expect("PORT must be set in .env file"),
-
-        // Task Server
-        task_server_host: env::var("TASK_SERVER_HOST").unwrap_or_else(|_| "127.0.0.1".to_string()),
-        task_server_port: env::var("TASK_SERVER_PORT").unwrap_or_else(|_| "7000".to_string()),
-
-        // Markdown Service
-        markdown_service_host: env::var("MARKDOWN_SERVICE_HOST")
-            .unwrap_or_else(|_| "127.0.0.1".to_string()),
-        markdown_service_port: env::var("MARKDOWN_SERVICE_PORT")
-            .unwrap_or_else(|_| "6700".to_string()),
-
-        // Meilisearch
-        meilisearch_host: env::var("MEILISEARCH_HOST")
-            .unwrap_or_else(|_| "http://localhost:7700".to_string()),
-        meilisearch_api_key: env::var("MEILISEARCH_API_KEY")
-            .ok()
-            .filter(|key| !key.is_empty()),
-
-        cors_allowed_origins: cors_origins,
-        cors_allowed_headers: cors_headers,
-        cors_max_age: env::var("CORS_MAX_AGE").ok().and_then(|v| v.parse().ok()),
-    }
+/// 현재 설정
+///
+/// 재로딩하면 Arc를 통째로 교체한다. 이전 설정을 받아 둔 쪽은 Arc를 놓을 때까지
+/// 이전 값을 그대로 보고, 마지막 참조가 사라지면 해제된다.
+static CONFIG: LazyLock<RwLock<Arc<DbConfig>>> = LazyLock::new(|| {
+    let config = DbConfig::load().unwrap_or_else(|e| {
+        // tracing 초기화 전일 수 있으므로 표준 에러로 출력
+        eprintln!("{}", e);
+        std::process::exit(1);
+    });
+    RwLock::new(Arc::new(config))
 });
 
 impl DbConfig {
-    // 이제 단순히 CONFIG에 접근만 하면 됨
-    pub fn get() -> &'static DbConfig {
-        &CONFIG
+    pub fn get() -> Arc<DbConfig> {
+        CONFIG
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// 기본값 < TOML 파일 < 환경 변수 순으로 설정을 읽고 한 번에 검증
+    pub fn load() -> Result<DbConfig, ConfigError> {
+        let mut source = ConfigSource::load();
+
+        let is_dev = matches!(
+            source.optional("ENVIRONMENT").as_deref(),
+            Some("dev") | Some("development")
+        );
+
+        let jwt_secret = source.required("JWT_SECRET");
+
+        let config = DbConfig {
+            is_dev,
+            jwt_secret: Secret::new(jwt_secret.clone()),
+
+            auth_access_token_expire_time: source.parse_or("AUTH_ACCESS_TOKEN_EXPIRE_TIME", 30), // 기본값 30분
+            auth_refresh_token_expire_time: source.parse_or("AUTH_REFRESH_TOKEN_EXPIRE_TIME", 14), // 기본값 14일 (일주일)
+            auth_email_verification_token_expire_time: source
+                .parse_or("AUTH_EMAIL_VERIFICATION_TOKEN_EXPIRE_TIME", 1), // 기본값 1시간
+            auth_password_reset_token_expire_time: source
+                .parse_or("AUTH_PASSWORD_RESET_TOKEN_EXPIRE_TIME", 1), // 기본값 1시간
+
+            // Google
+            google_client_id: source.required("GOOGLE_CLIENT_ID"),
+            google_client_secret: Secret::new(source.required("GOOGLE_CLIENT_SECRET")),
+            google_redirect_uri: source.required("GOOGLE_REDIRECT_URI"),
+            google_link_redirect_uri: source.required("GOOGLE_LINK_REDIRECT_URI"),
+
+            // Github
+            github_client_id: source.required("GITHUB_CLIENT_ID"),
+            github_client_secret: Secret::new(source.required("GITHUB_CLIENT_SECRET")),
+            github_redirect_uri: source.required("GITHUB_REDIRECT_URI"),
+
+            // Cloudflare
+            r2_public_domain: source.required("R2_PUBLIC_DOMAIN"),
+            r2_account_id: source.required("R2_ACCOUNT_ID"),
+            r2_bucket_name: source.required("R2_BUCKET_NAME"),
+            r2_access_key_id: source.required("R2_ACCESS_KEY_ID"),
+            r2_secret_access_key: Secret::new(source.required("R2_SECRET_ACCESS_KEY")),
+
+            db_user: source.required("POSTGRES_USER"),
+            db_password: Secret::new(source.required("POSTGRES_PASSWORD")),
+            db_host: source.required("POSTGRES_HOST"),
+            db_port: source.required("POSTGRES_PORT"),
+            db_name: source.required("POSTGRES_NAME"),
+            db_max_connection: source.parse_or("POSTGRES_MAX_CONNECTION", 100),
+            db_min_connection: source.parse_or("POSTGRES_MIN_CONNECTION", 10),
+
+            // Redis
+            redis_host: source.required("REDIS_HOST"),
+            redis_port: source.required("REDIS_PORT"),
+            redis_ttl: source.parse_or("REDIS_TTL", 3600),
+
+            server_host: source.required("HOST"),
+            server_port: source.required("PORT"),
+            metrics_host: source.string_or("METRICS_HOST", "127.0.0.1"), // /metrics 전용 내부 리스너 (공개 포트에서는 제공하지 않음)
+            metrics_port: source.string_or("METRICS_PORT", "9100"),
+            shutdown_drain_timeout: source.parse_or("SHUTDOWN_DRAIN_TIMEOUT", 30), // 종료 시 처리 중인 요청과 백그라운드 작업을 기다리는 최대 시간 (초)
+
+            // Task Server
+            task_server_host: source.string_or("TASK_SERVER_HOST", "127.0.0.1"),
+            task_server_port: source.string_or("TASK_SERVER_PORT", "7000"),
+
+            // Markdown Service
+            markdown_service_host: source.string_or("MARKDOWN_SERVICE_HOST", "127.0.0.1"),
+            markdown_service_port: source.string_or("MARKDOWN_SERVICE_PORT", "6700"),
+            markdown_native_renderer: source.choice(
+                "MARKDOWN_RENDERER",
+                &["remote", "native"],
+                "remote",
+            ) == "native",
+
+            // Background jobs
+            native_job_runner: source.choice("JOB_EXECUTOR", &["remote", "native"], "remote")
+                == "native",
+            job_worker_concurrency: source.parse_or("JOB_WORKER_CONCURRENCY", 4), // 동시에 실행할 최대 작업 수
+            job_poll_interval: source.parse_or("JOB_POLL_INTERVAL", 1), // 대기열 확인 주기 (초)
+            job_max_attempts: source.parse_or("JOB_MAX_ATTEMPTS", 3), // 실패 시 재시도를 포함한 최대 실행 횟수
+            job_retry_backoff: source.parse_or("JOB_RETRY_BACKOFF", 10), // 재시도 대기 시간 기준값 (초, 실패마다 2배)
+            job_timeout: source.parse_or("JOB_TIMEOUT", 300),            // 작업 실행 제한 시간 (초)
+            job_result_ttl: source.parse_or("JOB_RESULT_TTL", 3600), // 완료된 작업 상태 보관 시간 (초)
+            job_schedule_cleanup_tokens: source.optional("JOB_SCHEDULE_CLEANUP_TOKENS"),
+            job_schedule_cleanup_events: source.optional("JOB_SCHEDULE_CLEANUP_EVENTS"),
+            job_schedule_reindex_posts: source.optional("JOB_SCHEDULE_REINDEX_POSTS"),
+            job_schedule_sync_counts: source.optional("JOB_SCHEDULE_SYNC_COUNTS"),
+
+            // Email
+            smtp_host: source.optional("SMTP_HOST"),
+            smtp_port: source.parse_or("SMTP_PORT", 587),
+            smtp_tls: source.parse_or("SMTP_TLS", true), // STARTTLS
+            smtp_ssl: source.parse_or("SMTP_SSL", false), // 암시적 TLS (보통 465 포트)
+            smtp_user: source.optional("SMTP_USER"),
+            smtp_password: source.optional("SMTP_PASSWORD").map(Secret::new),
+            emails_from_email: source.optional("EMAILS_FROM_EMAIL"),
+            emails_from_name: source.optional("EMAILS_FROM_NAME"),
+            project_name: source.string_or("PROJECT_NAME", "Mofumofu"),
+            frontend_host: source.string_or("FRONTEND_HOST", "http://localhost:5173"),
+
+            // HTML Sanitizer
+            html_sanitizer_allowed_tags: source.list("HTML_SANITIZER_ALLOWED_TAGS"),
+            html_sanitizer_allowed_attributes: source.list("HTML_SANITIZER_ALLOWED_ATTRIBUTES"),
+            html_sanitizer_url_schemes: source.list("HTML_SANITIZER_URL_SCHEMES"),
+
+            // Drafts
+            draft_max_per_user: source.parse_or("DRAFT_MAX_PER_USER", 10), // 사용자당 최대 임시저장 수
+
+            // Rate limiting
+            rate_limit_enabled: source.parse_or("RATE_LIMIT_ENABLED", true),
+            rate_limit_overrides: source
+                .parse_list("RATE_LIMIT_OVERRIDES", parse_rate_limit_override)
+                .into_iter()
+                .collect(), // 정책 이름=요청 수/초 (예: comment:create=20/60)
+            trusted_proxies: source.parse_list("TRUSTED_PROXIES", parse_trusted_proxy), // 이 주소에서 온 요청만 CF-Connecting-IP/X-Forwarded-For를 믿음
+
+            // Mentions
+            mention_max_per_item: source.parse_or("MENTION_MAX_PER_ITEM", 10), // 포스트/댓글 하나당 최대 멘션 수
+
+            // Comments
+            comment_max_pinned_per_post: source.parse_or("COMMENT_MAX_PINNED_PER_POST", 3), // 포스트당 최대 고정 댓글 수
+            comment_tree_max_depth: source.parse_or("COMMENT_TREE_MAX_DEPTH", 3), // 댓글 트리 조회 시 최대 답글 깊이
+            comment_tree_replies_per_level: source.parse_or("COMMENT_TREE_REPLIES_PER_LEVEL", 3), // 댓글 트리 조회 시 부모마다 불러올 답글 수
+
+            // Related posts
+            related_posts_cache_ttl: source.parse_or("RELATED_POSTS_CACHE_TTL", 120), // 관련 포스트 후보 캐시 유지 시간 (초, 좋아요한 포스트 외의 후보는 이 시간이 지나야 반영됨)
+            viewed_posts_history_size: source.parse_or("VIEWED_POSTS_HISTORY_SIZE", 500), // 관련 포스트에서 제외할 최근 조회 포스트 수
+
+            // Response cache (유지 시간 0이면 해당 API는 캐시하지 않음)
+            cache_post_detail_ttl: source.parse_or("CACHE_POST_DETAIL_TTL", 60), // 포스트 상세 캐시 유지 시간 (초)
+            cache_feed_ttl: source.parse_or("CACHE_FEED_TTL", 30), // 포스트 목록 캐시 유지 시간 (초)
+            cache_feed_max_page: source.parse_or("CACHE_FEED_MAX_PAGE", 3), // 캐시할 포스트 목록 페이지 수 (앞에서부터)
+            cache_profile_ttl: source.parse_or("CACHE_PROFILE_TTL", 300), // 프로필 헤더 캐시 유지 시간 (초)
+            cache_trending_hashtags_ttl: source.parse_or("CACHE_TRENDING_HASHTAGS_TTL", 300), // 인기 해시태그 캐시 유지 시간 (초)
+
+            // Trending
+            trending_refresh_interval: source.parse_or("TRENDING_REFRESH_INTERVAL", 300), // 트렌딩 점수 갱신 주기 (초)
+            trending_window_days: source.parse_or("TRENDING_WINDOW_DAYS", 30), // 트렌딩 점수를 계산할 최근 포스트 기간 (일)
+
+            // View counting
+            view_count_buffered: source.parse_or("VIEW_COUNT_BUFFERED", true), // 조회수를 Redis에 모았다가 일괄 반영 (false면 조회마다 DB에 바로 기록)
+            view_count_flush_interval: source.parse_or("VIEW_COUNT_FLUSH_INTERVAL", 30), // Redis에 모인 조회수를 DB에 반영하는 주기 (초)
+            view_count_viewer_salt: Secret::new(
+                source
+                    .optional("VIEW_COUNT_VIEWER_SALT")
+                    .unwrap_or(jwt_secret), // 순 방문자 집계에 저장하는 익명 ID 해시용 (기본값은 JWT_SECRET)
+            ),
+
+            // Outbox
+            outbox_poll_interval: source.parse_or("OUTBOX_POLL_INTERVAL", 5), // 아웃박스 폴링 주기 (초)
+            outbox_batch_size: source.parse_or("OUTBOX_BATCH_SIZE", 50), // 한 번에 전달할 최대 항목 수
+            outbox_max_attempts: source.parse_or("OUTBOX_MAX_ATTEMPTS", 10), // 이 횟수만큼 실패하면 dead 상태로 전환
+            outbox_backoff_base: source.parse_or("OUTBOX_BACKOFF_BASE", 10), // 재시도 대기 시간 기준값 (초, 실패마다 2배)
+            outbox_backoff_max: source.parse_or("OUTBOX_BACKOFF_MAX", 3600), // 재시도 대기 시간 상한 (초)
+            outbox_retention_days: source.parse_or("OUTBOX_RETENTION_DAYS", 7), // 전달 완료된 항목 보관 기간 (일)
+
+            // Meilisearch
+            meilisearch_host: source.string_or("MEILISEARCH_HOST", "http://localhost:7700"),
+            meilisearch_api_key: source.optional("MEILISEARCH_API_KEY").map(Secret::new),
+
+            // Health check
+            health_check_timeout_ms: source.parse_or("HEALTH_CHECK_TIMEOUT_MS", 2000), // 준비 상태 확인 시 의존성별 타임아웃 (밀리초)
+
+            // Query count
+            db_query_warn_threshold: source.parse_or("DB_QUERY_WARN_THRESHOLD", 20), // 요청 하나의 DB 쿼리 수가 이 값을 넘으면 경고 (0이면 끔)
+
+            // Tracing
+            otel_exporter_otlp_endpoint: source.optional("OTEL_EXPORTER_OTLP_ENDPOINT"), // 설정 시 OTLP(HTTP)로 trace 전송
+            otel_service_name: source.string_or("OTEL_SERVICE_NAME", "mofumofu-backend"),
+
+            cors_allowed_origins: source.parse_list("CORS_ALLOWED_ORIGINS", |origin| {
+                HeaderValue::from_str(origin)
+            }),
+            cors_allowed_headers: source.parse_list("CORS_ALLOWED_HEADERS", |header| {
+                HeaderName::from_bytes(header.as_bytes())
+            }),
+            cors_max_age: source.parse_optional("CORS_MAX_AGE"),
+        };
+
+        // 값 하나만으로는 알 수 없는 조건 검증
+        source.check(
+            "PORT",
+            config.server_port.is_empty() || config.server_port.parse::<u16>().is_ok(),
+            "must be a valid port number",
+        );
+        source.check(
+            "METRICS_PORT",
+            config.metrics_port.parse::<u16>().is_ok(),
+            "must be a valid port number",
+        );
+        source.check(
+            "POSTGRES_MIN_CONNECTION",
+            config.db_min_connection <= config.db_max_connection,
+            "must not be greater than POSTGRES_MAX_CONNECTION",
+        );
+        source.check(
+            "HTML_SANITIZER_ALLOWED_TAGS",
+            config
+                .html_sanitizer_allowed_tags
+                .iter()
+                .flatten()
+                .all(|tag| !CLEAN_CONTENT_TAGS.contains(&tag.to_ascii_lowercase().as_str())),
+            "must not include script or style",
+        );
+        source.check(
+            "JOB_WORKER_CONCURRENCY",
+            config.job_worker_concurrency >= 1,
+            "must be at least 1",
+        );
+        for (key, schedule) in [
+            (
+                "JOB_SCHEDULE_CLEANUP_TOKENS",
+                &config.job_schedule_cleanup_tokens,
+            ),
+            (
+                "JOB_SCHEDULE_CLEANUP_EVENTS",
+                &config.job_schedule_cleanup_events,
+            ),
+            (
+                "JOB_SCHEDULE_REINDEX_POSTS",
+                &config.job_schedule_reindex_posts,
+            ),
+            ("JOB_SCHEDULE_SYNC_COUNTS", &config.job_schedule_sync_counts),
+        ] {
+            if let Some(expression) = schedule {
+                source.check(
+                    key,
+                    cron::Schedule::from_str(expression).is_ok(),
+                    "is not a valid cron expression",
+                );
+            }
+        }
+
+        source.finish(config)
+    }
+
+    /// 설정을 다시 읽어 재시작 없이 바꿀 수 있는 값만 반영
+    ///
+    /// 검증에 실패하면 현재 설정을 그대로 유지한다.
+    pub fn reload() -> Result<(), ConfigError> {
+        let fresh = DbConfig::load()?;
+
+        let mut next = DbConfig::clone(&DbConfig::get());
+        next.apply_reloadable(fresh);
+
+        *CONFIG.write().unwrap_or_else(PoisonError::into_inner) = Arc::new(next);
+        info!("Configuration reloaded (structural settings require a restart)");
+        Ok(())
     }
+
+    /// 요청마다 DbConfig::get()으로 읽는 값들
+    ///
+    /// 연결, 포트, 시크릿, 백그라운드 작업 주기처럼 시작 시 한 번만 쓰이는 값은
+    /// 여기에 넣지 않는다.
+    fn apply_reloadable(&mut self, fresh: DbConfig) {
+        self.cors_allowed_origins = fresh.cors_allowed_origins;
+        self.markdown_native_renderer = fresh.markdown_native_renderer;
+        self.view_count_buffered = fresh.view_count_buffered;
+        self.html_sanitizer_allowed_tags = fresh.html_sanitizer_allowed_tags;
+        self.html_sanitizer_allowed_attributes = fresh.html_sanitizer_allowed_attributes;
+        self.html_sanitizer_url_schemes = fresh.html_sanitizer_url_schemes;
+        self.draft_max_per_user = fresh.draft_max_per_user;
+        self.rate_limit_enabled = fresh.rate_limit_enabled;
+        self.rate_limit_overrides = fresh.rate_limit_overrides;
+        self.mention_max_per_item = fresh.mention_max_per_item;
+        self.comment_max_pinned_per_post = fresh.comment_max_pinned_per_post;
+        self.comment_tree_max_depth = fresh.comment_tree_max_depth;
+        self.comment_tree_replies_per_level = fresh.comment_tree_replies_per_level;
+        self.related_posts_cache_ttl = fresh.related_posts_cache_ttl;
+        self.viewed_posts_history_size = fresh.viewed_posts_history_size;
+        self.cache_post_detail_ttl = fresh.cache_post_detail_ttl;
+        self.cache_feed_ttl = fresh.cache_feed_ttl;
+        self.cache_feed_max_page = fresh.cache_feed_max_page;
+        self.cache_profile_ttl = fresh.cache_profile_ttl;
+        self.cache_trending_hashtags_ttl = fresh.cache_trending_hashtags_ttl;
+        self.health_check_timeout_ms = fresh.health_check_timeout_ms;
+        self.db_query_warn_threshold = fresh.db_query_warn_threshold;
+    }
+}
+
+/// "10.0.0.0/8" 같은 CIDR 또는 단일 IP를 IpNet으로 변환
+fn parse_trusted_proxy(entry: &str) -> Result<IpNet, ipnet::AddrParseError> {
+    entry.parse::<IpNet>().or_else(|e| {
+        entry
+            .parse::<std::net::IpAddr>()
+            .map(IpNet::from)
+            .map_err(|_| e)
+    })
+}
+
+/// "comment:create=20/60" 형식을 (정책 이름, (요청 수, 초))로 변환
+fn parse_rate_limit_override(entry: &str) -> Result<(String, (u32, u64)), String> {
+    let (name, limits) = entry
+        .split_once('=')
+        .ok_or("expected <policy>=<limit>/<seconds>")?;
+    let (limit, window) = limits
+        .split_once('/')
+        .ok_or("expected <policy>=<limit>/<seconds>")?;
+    let limit: u32 = limit.trim().parse().map_err(|_| "limit must be a number")?;
+    let window: u64 = window
+        .trim()
+        .parse()
+        .map_err(|_| "window must be a number")?;
+    if limit == 0 || window == 0 {
+        return Err("limit and window must be greater than 0".to_string());
+    }
+
+    Ok((name.trim().to_string(), (limit, window)))
 }
@@ -1 +1,4 @@
+pub mod config_source;
 pub mod db_config;
+pub mod reload;
+pub mod secret;
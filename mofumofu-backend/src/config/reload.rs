@@ -0,0 +1,44 @@
+use crate::config::db_config::DbConfig;
+use crate::utils::shutdown::BackgroundTasks;
+use tracing::{error, info};
+
+/// SIGHUP을 받으면 설정 파일과 환경 변수를 다시 읽어 재로딩 가능한 값만 반영
+#[cfg(unix)]
+pub fn spawn_config_reloader(tasks: &BackgroundTasks) {
+    use tokio::signal::unix::{SignalKind, signal};
+
+    let mut hangup = match signal(SignalKind::hangup()) {
+        Ok(hangup) => hangup,
+        Err(e) => {
+            error!("Failed to listen for SIGHUP, config reload disabled: {}", e);
+            return;
+        }
+    };
+
+    let shutdown = tasks.clone();
+
+    tasks.spawn(async move {
+        loop {
+            tokio::select! {
+                _ = shutdown.cancelled() => break,
+                received = hangup.recv() => {
+                    if received.is_none() {
+                        break;
+                    }
+                }
+            }
+
+            info!("Received SIGHUP, reloading configuration");
+            if let Err(e) = DbConfig::reload() {
+                // 잘못된 설정은 반영하지 않고 기존 설정 유지
+                error!(
+                    "Configuration reload failed, keeping current settings\n{}",
+                    e
+                );
+            }
+        }
+    });
+}
+
+#[cfg(not(unix))]
+pub fn spawn_config_reloader(_tasks: &BackgroundTasks) {}
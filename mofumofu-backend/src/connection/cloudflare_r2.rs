@@ -111,6 +111,16 @@ impl R2Client {
         Ok(())
     }
 
+    // 버킷 접근 가능 여부 확인 (헬스 체크용)
+    pub async fn head_bucket(&self) -> Result<(), S3Error> {
+        self.client
+            .head_bucket()
+            .bucket(&self.bucket)
+            .send()
+            .await?;
+        Ok(())
+    }
+
     pub fn get_r2_public_url(&self, key: &str) -> String {
         format!("{}/{}", self.public_domain, key)
     }
@@ -128,7 +138,7 @@ pub async fn establish_r2_connection() -> Result<R2Client, Box<dyn std::error::E
         .endpoint_url(&r2_endpoint)
         .credentials_provider(aws_sdk_s3::config::Credentials::new(
             &config.r2_access_key_id,
-            &config.r2_secret_access_key,
+            config.r2_secret_access_key.expose(),
             None,
             None,
             "r2-credentials",
@@ -1,4 +1,5 @@
 use crate::config::db_config::DbConfig;
+use crate::utils::query_counter::record_query;
 use sea_orm::{ConnectOptions, Database, DatabaseConnection};
 use std::time::Duration;
 use tracing::{error, info};
@@ -18,7 +19,7 @@ pub async fn establish_connection() -> DatabaseConnection {
     let database_url = format!(
         "postgres://{}:{}@{}:{}/{}",
         &DbConfig::get().db_user,
-        &DbConfig::get().db_password,
+        DbConfig::get().db_password.expose(),
         &DbConfig::get().db_host,
         &DbConfig::get().db_port,
         &DbConfig::get().db_name
@@ -40,9 +41,11 @@ pub async fn establish_connection() -> DatabaseConnection {
 
     // 데이터베이스 연결 시도 및 결과 처리
     match Database::connect(options).await {
-        Ok(connection) => {
+        Ok(mut connection) => {
             // 연결 성공 시 로그 출력 후 연결 객체 반환
             info!("Successfully connected to the connection.");
+            // 요청별 쿼리 수 집계 (N+1 쿼리 감지용)
+            connection.set_metric_callback(|_| record_query());
             connection
         }
         Err(err) => {
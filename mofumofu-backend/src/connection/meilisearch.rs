@@ -1,26 +1,97 @@
 use crate::config::db_config::DbConfig;
+use crate::utils::metrics::observe_external_call;
+use async_trait::async_trait;
 use meilisearch_sdk::client::Client;
+use meilisearch_sdk::errors::Error;
+use meilisearch_sdk::request::{HttpClient, Method};
+use meilisearch_sdk::reqwest::ReqwestClient;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
+/// 요청마다 지연 시간과 실패 횟수를 기록하는 Meilisearch HTTP 클라이언트
+#[derive(Debug, Clone)]
+pub struct InstrumentedHttpClient {
+    inner: ReqwestClient,
+}
+
+#[async_trait]
+impl HttpClient for InstrumentedHttpClient {
+    async fn stream_request<
+        Query: Serialize + Send + Sync,
+        Body: futures_io::AsyncRead + Send + Sync + 'static,
+        Output: DeserializeOwned + 'static,
+    >(
+        &self,
+        url: &str,
+        method: Method<Query, Body>,
+        content_type: &str,
+        expected_status_code: u16,
+    ) -> Result<Output, Error> {
+        let verb = match &method {
+            Method::Get { .. } => "GET",
+            Method::Post { .. } => "POST",
+            Method::Patch { .. } => "PATCH",
+            Method::Put { .. } => "PUT",
+            Method::Delete { .. } => "DELETE",
+        };
+        let operation = format!("{} {}", verb, operation_path(url));
+
+        observe_external_call(
+            "meilisearch",
+            operation,
+            self.inner
+                .stream_request(url, method, content_type, expected_status_code),
+        )
+        .await
+    }
+}
+
+/// 문서 ID 등이 라벨에 들어가지 않도록 경로를 앞부분만 남김
+/// 예: /indexes/posts/documents/{id} -> /indexes/posts/documents
+fn operation_path(url: &str) -> String {
+    let path = url
+        .split_once("://")
+        .map_or(url, |(_, rest)| rest)
+        .split_once('/')
+        .map_or("", |(_, path)| path);
+    let path = path.split('?').next().unwrap_or_default();
+
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+    let keep = if segments.first() == Some(&"indexes") {
+        3
+    } else {
+        1
+    };
+
+    format!(
+        "/{}",
+        segments
+            .into_iter()
+            .take(keep)
+            .collect::<Vec<_>>()
+            .join("/")
+    )
+}
+
 #[derive(Debug, Clone)]
 pub struct MeilisearchClient {
-    pub client: Client,
+    pub client: Client<InstrumentedHttpClient>,
 }
 
 impl MeilisearchClient {
-    pub fn new() -> Result<Self, meilisearch_sdk::errors::Error> {
+    pub fn new() -> Result<Self, Error> {
         let config = DbConfig::get();
+        let api_key = config.meilisearch_api_key.as_deref();
 
-        let client = if let Some(api_key) = &config.meilisearch_api_key {
-            Client::new(&config.meilisearch_host, Some(api_key.as_str()))?
-        } else {
-            Client::new(&config.meilisearch_host, None::<&str>)?
+        let http_client = InstrumentedHttpClient {
+            inner: ReqwestClient::new(api_key)?,
         };
+        let client = Client::new_with_client(&config.meilisearch_host, api_key, http_client);
 
         Ok(MeilisearchClient { client })
     }
 
-    pub fn get_client(&self) -> &meilisearch_sdk::client::Client {
+    pub fn get_client(&self) -> &Client<InstrumentedHttpClient> {
         &self.client
     }
 }
@@ -39,6 +110,7 @@ pub struct MeilisearchPost {
     pub like_count: i32,
     pub comment_count: i32,
     pub view_count: i32,
+    pub language: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -65,6 +137,7 @@ impl MeilisearchPost {
             like_count: post.like_count,
             comment_count: post.comment_count,
             view_count: post.view_count,
+            language: post.language.clone(),
         }
     }
 }
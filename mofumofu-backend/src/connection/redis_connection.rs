@@ -1,9 +1,62 @@
 use crate::config::db_config::DbConfig;
-use redis::aio::ConnectionManager;
-use redis::{Client, RedisResult};
+use crate::utils::metrics::{REDIS_COMMAND_DURATION_SECONDS, REDIS_COMMAND_ERRORS_TOTAL};
+use metrics::{counter, histogram};
+use redis::aio::{ConnectionLike, ConnectionManager};
+use redis::{Arg, Client, Cmd, Pipeline, RedisFuture, RedisResult, Value};
+use std::time::Instant;
 use tracing::info;
 
-pub async fn establish_redis_connection() -> RedisResult<ConnectionManager> {
+/// 명령별 지연 시간과 실패 횟수를 기록하는 Redis 연결
+#[derive(Clone)]
+pub struct RedisConnection {
+    inner: ConnectionManager,
+}
+
+impl ConnectionLike for RedisConnection {
+    fn req_packed_command<'a>(&'a mut self, cmd: &'a Cmd) -> RedisFuture<'a, Value> {
+        // 명령 이름만 라벨로 사용 (키는 라벨에 넣지 않음)
+        let command = match cmd.args_iter().next() {
+            Some(Arg::Simple(name)) => String::from_utf8_lossy(name).to_uppercase(),
+            _ => "UNKNOWN".to_string(),
+        };
+
+        Box::pin(async move {
+            let started = Instant::now();
+            let result = self.inner.req_packed_command(cmd).await;
+            record_command(command, started, result.is_err());
+            result
+        })
+    }
+
+    fn req_packed_commands<'a>(
+        &'a mut self,
+        cmd: &'a Pipeline,
+        offset: usize,
+        count: usize,
+    ) -> RedisFuture<'a, Vec<Value>> {
+        Box::pin(async move {
+            let started = Instant::now();
+            let result = self.inner.req_packed_commands(cmd, offset, count).await;
+            record_command("PIPELINE".to_string(), started, result.is_err());
+            result
+        })
+    }
+
+    fn get_db(&self) -> i64 {
+        self.inner.get_db()
+    }
+}
+
+fn record_command(command: String, started: Instant, failed: bool) {
+    histogram!(REDIS_COMMAND_DURATION_SECONDS, "command" => command.clone())
+        .record(started.elapsed().as_secs_f64());
+
+    if failed {
+        counter!(REDIS_COMMAND_ERRORS_TOTAL, "command" => command).increment(1);
+    }
+}
+
+pub async fn establish_redis_connection() -> RedisResult<RedisConnection> {
     let redis_url = format!(
         "redis://{}:{}",
         &DbConfig::get().redis_host,
@@ -15,5 +68,7 @@ pub async fn establish_redis_connection() -> RedisResult<ConnectionManager> {
     let conn_manager = ConnectionManager::new(client).await?;
 
     info!("Successfully connected to Redis");
-    Ok(conn_manager)
+    Ok(RedisConnection {
+        inner: conn_manager,
+    })
 }
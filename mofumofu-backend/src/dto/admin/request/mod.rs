@@ -0,0 +1,5 @@
+pub mod outbox;
+pub mod trending_settings;
+
+pub use outbox::*;
+pub use trending_settings::*;
@@ -0,0 +1,21 @@
+use crate::entity::common::OutboxStatus;
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct GetOutboxEntriesRequest {
+    /// 조회할 상태 (기본값 Dead, Pending은 실패 이력이 있는 항목만)
+    pub status: Option<OutboxStatus>,
+
+    #[validate(range(min = 1, max = 100, message = "limit must be between 1 and 100"))]
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct RequeueOutboxEntriesRequest {
+    #[validate(length(min = 1, max = 100, message = "ids must contain 1 to 100 entries"))]
+    pub ids: Vec<Uuid>,
+}
@@ -0,0 +1,31 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateTrendingSettingsRequest {
+    #[validate(range(
+        min = 0.0,
+        max = 1000.0,
+        message = "like_weight must be between 0 and 1000"
+    ))]
+    pub like_weight: f64,
+
+    #[validate(range(
+        min = 0.0,
+        max = 1000.0,
+        message = "comment_weight must be between 0 and 1000"
+    ))]
+    pub comment_weight: f64,
+
+    #[validate(range(
+        min = 0.0,
+        max = 1000.0,
+        message = "view_weight must be between 0 and 1000"
+    ))]
+    pub view_weight: f64,
+
+    /// 시간 감쇠 지수 (클수록 오래된 포스트의 점수가 빨리 줄어듦)
+    #[validate(range(min = 0.0, max = 5.0, message = "gravity must be between 0 and 5"))]
+    pub gravity: f64,
+}
@@ -0,0 +1,42 @@
+use crate::dto::job::internal::job_record::{JobRecord, JobState};
+use axum::Json;
+use axum::response::{IntoResponse, Response};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// 작업 상태 (태스크 서버의 상태 조회 응답과 같은 형태)
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct JobStatusResponse {
+    /// PENDING, STARTED, RETRY, SUCCESS, FAILURE
+    pub state: String,
+    pub status: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl From<JobRecord> for JobStatusResponse {
+    fn from(record: JobRecord) -> Self {
+        let status = match record.state {
+            JobState::Pending => "작업이 대기 중입니다",
+            JobState::Started => "작업이 진행 중입니다",
+            JobState::Retry => "작업이 재시도를 기다리는 중입니다",
+            JobState::Success => "작업이 완료되었습니다",
+            JobState::Failure => "작업이 실패했습니다",
+        };
+
+        Self {
+            state: record.state.as_str().to_string(),
+            status: status.to_string(),
+            result: record.result,
+            error: record.error,
+        }
+    }
+}
+
+impl IntoResponse for JobStatusResponse {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
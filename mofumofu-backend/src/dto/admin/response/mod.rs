@@ -1,5 +1,11 @@
 pub mod admin_status;
+pub mod job_status;
+pub mod outbox;
 pub mod task_response;
+pub mod trending_settings;
 
 pub use admin_status::*;
+pub use job_status::*;
+pub use outbox::*;
 pub use task_response::*;
+pub use trending_settings::*;
@@ -0,0 +1,58 @@
+use crate::entity::common::OutboxStatus;
+use crate::entity::outbox::Model as OutboxModel;
+use axum::Json;
+use axum::response::{IntoResponse, Response};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// 아웃박스 항목 (payload에는 토큰이 들어 있을 수 있어 노출하지 않음)
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct OutboxEntry {
+    pub id: Uuid,
+    pub event_type: String,
+    pub idempotency_key: String,
+    pub status: OutboxStatus,
+    pub attempts: i32,
+    pub next_attempt_at: DateTime<Utc>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<OutboxModel> for OutboxEntry {
+    fn from(entry: OutboxModel) -> Self {
+        Self {
+            id: entry.id,
+            event_type: entry.event_type,
+            idempotency_key: entry.idempotency_key,
+            status: entry.status,
+            attempts: entry.attempts,
+            next_attempt_at: entry.next_attempt_at,
+            last_error: entry.last_error,
+            created_at: entry.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct OutboxEntriesResponse {
+    pub entries: Vec<OutboxEntry>,
+}
+
+impl IntoResponse for OutboxEntriesResponse {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RequeueOutboxEntriesResponse {
+    pub requeued: u64,
+}
+
+impl IntoResponse for RequeueOutboxEntriesResponse {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
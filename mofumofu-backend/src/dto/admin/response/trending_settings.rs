@@ -0,0 +1,36 @@
+use crate::entity::trending_settings::Model as TrendingSettingsModel;
+use axum::Json;
+use axum::response::{IntoResponse, Response};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct TrendingSettingsResponse {
+    pub like_weight: f64,
+    pub comment_weight: f64,
+    pub view_weight: f64,
+    pub gravity: f64,
+    pub updated_by: Option<Uuid>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<TrendingSettingsModel> for TrendingSettingsResponse {
+    fn from(settings: TrendingSettingsModel) -> Self {
+        Self {
+            like_weight: settings.like_weight,
+            comment_weight: settings.comment_weight,
+            view_weight: settings.view_weight,
+            gravity: settings.gravity,
+            updated_by: settings.updated_by,
+            updated_at: settings.updated_at,
+        }
+    }
+}
+
+impl IntoResponse for TrendingSettingsResponse {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
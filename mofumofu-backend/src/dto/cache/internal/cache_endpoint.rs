@@ -0,0 +1,37 @@
+use crate::config::db_config::DbConfig;
+
+/// 응답을 캐시하는 조회 API (엔드포인트별로 설정에서 켜고 끔)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheEndpoint {
+    /// 포스트 상세 (handle + slug)
+    PostDetail,
+    /// 포스트 목록 앞쪽 페이지
+    Feed,
+    /// 프로필 헤더
+    Profile,
+    /// 인기 해시태그
+    TrendingHashtags,
+}
+
+impl CacheEndpoint {
+    /// Redis 키와 메트릭 라벨에서 쓰는 이름
+    pub fn name(self) -> &'static str {
+        match self {
+            CacheEndpoint::PostDetail => "post_detail",
+            CacheEndpoint::Feed => "feed",
+            CacheEndpoint::Profile => "profile",
+            CacheEndpoint::TrendingHashtags => "trending_hashtags",
+        }
+    }
+
+    /// 캐시 유지 시간 (초, 0이면 캐시하지 않음)
+    pub fn ttl_seconds(self) -> u64 {
+        let config = DbConfig::get();
+        match self {
+            CacheEndpoint::PostDetail => config.cache_post_detail_ttl,
+            CacheEndpoint::Feed => config.cache_feed_ttl,
+            CacheEndpoint::Profile => config.cache_profile_ttl,
+            CacheEndpoint::TrendingHashtags => config.cache_trending_hashtags_ttl,
+        }
+    }
+}
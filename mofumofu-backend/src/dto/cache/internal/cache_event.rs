@@ -0,0 +1,52 @@
+use crate::dto::cache::internal::cache_tag::CacheTag;
+use uuid::Uuid;
+
+/// 캐시된 응답을 무효화해야 하는 변경 사항
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CacheEvent {
+    /// 새 포스트 작성 (목록과 인기 해시태그가 바뀜)
+    PostCreated,
+    /// 제목/본문/해시태그 수정
+    PostContentChanged {
+        post_id: Uuid,
+    },
+    /// 썸네일, 댓글 설정, 좋아요/반응/댓글 수 변경
+    PostChanged {
+        post_id: Uuid,
+    },
+    PostDeleted {
+        post_id: Uuid,
+    },
+    /// 프로필, 아바타, 배너, 인증 상태 변경
+    UserChanged {
+        user_id: Uuid,
+    },
+    /// 해시태그 차단/병합 (병합 시 해시태그가 바뀐 포스트 포함)
+    HashtagsChanged {
+        post_ids: Vec<Uuid>,
+    },
+}
+
+impl CacheEvent {
+    /// 이 변경으로 무효화할 태그
+    pub fn tags(&self) -> Vec<CacheTag> {
+        match self {
+            CacheEvent::PostCreated => vec![CacheTag::Feed, CacheTag::TrendingHashtags],
+            CacheEvent::PostContentChanged { post_id } => {
+                vec![CacheTag::Post(*post_id), CacheTag::TrendingHashtags]
+            }
+            CacheEvent::PostChanged { post_id } => vec![CacheTag::Post(*post_id)],
+            CacheEvent::PostDeleted { post_id } => vec![
+                CacheTag::Post(*post_id),
+                CacheTag::Feed,
+                CacheTag::TrendingHashtags,
+            ],
+            CacheEvent::UserChanged { user_id } => vec![CacheTag::User(*user_id)],
+            CacheEvent::HashtagsChanged { post_ids } => {
+                let mut tags = vec![CacheTag::TrendingHashtags];
+                tags.extend(post_ids.iter().map(|post_id| CacheTag::Post(*post_id)));
+                tags
+            }
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use uuid::Uuid;
+
+/// 무효화할 때마다 1씩 올리는 전역 세대 번호
+///
+/// 태그별 세대 키에는 마지막으로 무효화된 시점의 세대 번호를 기록함
+pub const CACHE_GENERATION_KEY: &str = "cache_generation";
+
+/// 캐시 항목이 어떤 데이터에 의존하는지 나타내는 태그
+///
+/// 태그마다 Redis set에 캐시 키를 모아 두고, 데이터가 바뀌면 해당 set의 키를 모두 지움
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheTag {
+    /// 포스트 내용, 좋아요/댓글 수, 반응
+    Post(Uuid),
+    /// 사용자 프로필 (작성자 이름, 아바타 포함)
+    User(Uuid),
+    /// 포스트 목록 구성 (포스트 추가/삭제)
+    Feed,
+    /// 인기 해시태그 목록
+    TrendingHashtags,
+}
+
+impl CacheTag {
+    pub fn key(&self) -> String {
+        match self {
+            CacheTag::Post(post_id) => format!("cache_tag:post:{}", post_id),
+            CacheTag::User(user_id) => format!("cache_tag:user:{}", user_id),
+            CacheTag::Feed => "cache_tag:feed".to_string(),
+            CacheTag::TrendingHashtags => "cache_tag:trending_hashtags".to_string(),
+        }
+    }
+
+    /// 이 태그가 마지막으로 무효화된 세대 번호를 저장하는 키
+    pub fn generation_key(&self) -> String {
+        format!("{}:generation", self.key())
+    }
+}
@@ -0,0 +1,3 @@
+pub mod cache_endpoint;
+pub mod cache_event;
+pub mod cache_tag;
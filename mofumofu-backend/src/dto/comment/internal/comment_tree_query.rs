@@ -0,0 +1,18 @@
+use crate::dto::comment::internal::hidden_comment_visibility::HiddenCommentVisibility;
+use crate::dto::comment::request::get_comments::CommentSortOrder;
+use uuid::Uuid;
+
+/// 댓글 트리 조회 조건
+#[derive(Debug, Clone)]
+pub struct CommentTreeQuery<'a> {
+    pub post_id: Uuid,
+    /// 이 댓글 아래를 조회 (없으면 최상위 댓글부터)
+    pub parent_id: Option<Uuid>,
+    pub offset: u64,
+    pub limit: u64,
+    /// 첫 단계 아래에서 부모마다 가져올 답글 수
+    pub replies_per_level: u64,
+    pub depth: u32,
+    pub sort: &'a CommentSortOrder,
+    pub visibility: HiddenCommentVisibility,
+}
@@ -0,0 +1,25 @@
+use crate::entity::comments::Model as CommentModel;
+use sea_orm::{DbErr, FromQueryResult, QueryResult};
+
+/// 재귀 CTE로 조회한 댓글 트리의 한 행
+#[derive(Debug, Clone)]
+pub struct CommentTreeRow {
+    pub comment: CommentModel,
+    /// 조회 시작 지점 기준 깊이 (0부터)
+    pub depth: i32,
+    /// 형제 댓글 사이의 정렬 순서 (1부터)
+    pub position: i64,
+    /// 조회자에게 보이는 직속 답글 수
+    pub child_count: i64,
+}
+
+impl FromQueryResult for CommentTreeRow {
+    fn from_query_result(res: &QueryResult, pre: &str) -> Result<Self, DbErr> {
+        Ok(Self {
+            comment: CommentModel::from_query_result(res, pre)?,
+            depth: res.try_get(pre, "depth")?,
+            position: res.try_get(pre, "position")?,
+            child_count: res.try_get(pre, "child_count")?,
+        })
+    }
+}
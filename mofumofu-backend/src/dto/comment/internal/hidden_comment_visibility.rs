@@ -0,0 +1,31 @@
+use uuid::Uuid;
+
+/// 숨긴 댓글을 조회자에게 보여줄 범위
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HiddenCommentVisibility {
+    /// 모든 숨긴 댓글 (포스트 작성자)
+    All,
+    /// 본인이 작성한 숨긴 댓글만
+    OwnOnly(Uuid),
+    /// 숨긴 댓글 제외 (비로그인)
+    Nothing,
+}
+
+impl HiddenCommentVisibility {
+    pub fn for_viewer(viewer_id: Option<Uuid>, post_author_id: Uuid) -> Self {
+        match viewer_id {
+            Some(viewer_id) if viewer_id == post_author_id => Self::All,
+            Some(viewer_id) => Self::OwnOnly(viewer_id),
+            None => Self::Nothing,
+        }
+    }
+
+    /// 작성자가 author_id인 숨긴 댓글을 볼 수 있는지 여부
+    pub fn can_see(&self, author_id: Uuid) -> bool {
+        match self {
+            Self::All => true,
+            Self::OwnOnly(viewer_id) => *viewer_id == author_id,
+            Self::Nothing => false,
+        }
+    }
+}
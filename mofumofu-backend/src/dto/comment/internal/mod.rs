@@ -1 +1,4 @@
 // 내부 DTO들이 필요하면 여기에 추가
+pub mod comment_tree_query;
+pub mod comment_tree_row;
+pub mod hidden_comment_visibility;
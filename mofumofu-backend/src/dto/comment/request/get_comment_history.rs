@@ -0,0 +1,9 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Deserialize, ToSchema, Debug, Validate)]
+pub struct GetCommentHistoryRequest {
+    pub comment_id: Uuid,
+}
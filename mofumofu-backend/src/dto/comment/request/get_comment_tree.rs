@@ -0,0 +1,32 @@
+use crate::dto::comment::request::get_comments::CommentSortOrder;
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Deserialize, ToSchema, Debug, Validate)]
+pub struct GetCommentTreeRequest {
+    pub post_id: Uuid,
+
+    /// 이어서 불러올 부모 댓글 (없으면 최상위 댓글부터)
+    pub parent_id: Option<Uuid>,
+
+    /// 부모 댓글 아래에서 건너뛸 댓글 수
+    #[serde(default)]
+    pub offset: u64,
+
+    /// 첫 단계에서 불러올 댓글 수
+    #[serde(default = "default_limit")]
+    #[validate(range(min = 1, max = 100, message = "limit은 1~100 사이여야 합니다."))]
+    pub limit: u64,
+
+    /// 불러올 답글 깊이 (서버 최대값으로 제한됨)
+    pub depth: Option<u32>,
+
+    #[serde(default)]
+    pub sort: CommentSortOrder,
+}
+
+fn default_limit() -> u64 {
+    20
+}
@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, ToSchema, Debug)]
+pub struct HideCommentRequest {
+    pub comment_id: Uuid,
+    /// true면 숨김, false면 숨김 해제
+    pub hidden: bool,
+}
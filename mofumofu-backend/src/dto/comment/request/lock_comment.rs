@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, ToSchema, Debug)]
+pub struct LockCommentRequest {
+    pub comment_id: Uuid,
+    /// true면 잠금, false면 잠금 해제
+    pub locked: bool,
+}
@@ -1,11 +1,21 @@
 pub mod create_comment;
 pub mod delete_comment;
 pub mod get_comment_by_id;
+pub mod get_comment_history;
+pub mod get_comment_tree;
 pub mod get_comments;
+pub mod hide_comment;
+pub mod lock_comment;
+pub mod pin_comment;
 pub mod update_comment;
 
 pub use create_comment::*;
 pub use delete_comment::*;
 pub use get_comment_by_id::*;
+pub use get_comment_history::*;
+pub use get_comment_tree::*;
 pub use get_comments::*;
+pub use hide_comment::*;
+pub use lock_comment::*;
+pub use pin_comment::*;
 pub use update_comment::*;
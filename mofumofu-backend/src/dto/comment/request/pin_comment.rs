@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, ToSchema, Debug)]
+pub struct PinCommentRequest {
+    pub comment_id: Uuid,
+    /// true면 고정, false면 고정 해제
+    pub pinned: bool,
+}
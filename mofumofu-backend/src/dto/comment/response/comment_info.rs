@@ -1,3 +1,4 @@
+use crate::dto::reaction::response::reaction_count::ReactionCount;
 use axum::Json;
 use axum::response::{IntoResponse, Response};
 use chrono::{DateTime, Utc};
@@ -8,7 +9,10 @@ use uuid::Uuid;
 #[derive(Serialize, ToSchema)]
 pub struct CommentInfo {
     pub id: Uuid,
+    /// 마크다운 원문
     pub content: Option<String>,
+    /// 렌더링/sanitize된 HTML
+    pub render: Option<String>,
     pub post_id: Uuid,
     pub user_id: Option<Uuid>,
     pub user_handle: Option<String>,
@@ -20,6 +24,18 @@ pub struct CommentInfo {
     pub is_deleted: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: Option<DateTime<Utc>>,
+    /// 이모지별 반응 수
+    pub reactions: Vec<ReactionCount>,
+    /// 조회한 사용자가 남긴 반응 (비로그인 시 빈 배열)
+    pub my_reactions: Vec<String>,
+    /// 게시글 작성자가 고정한 댓글인지 여부
+    pub is_pinned: bool,
+    /// 게시글 작성자가 숨긴 댓글인지 여부 (작성자 본인과 게시글 작성자에게만 노출)
+    pub is_hidden: bool,
+    /// 답글 작성이 잠긴 스레드인지 여부
+    pub is_locked: bool,
+    /// 수정 횟수 (0이면 수정되지 않음)
+    pub edit_count: i32,
 }
 
 impl IntoResponse for CommentInfo {
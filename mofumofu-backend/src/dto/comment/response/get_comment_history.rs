@@ -0,0 +1,36 @@
+use axum::Json;
+use axum::response::{IntoResponse, Response};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Serialize, ToSchema)]
+pub struct CommentEditInfo {
+    /// 수정 전 마크다운 원문
+    pub content: String,
+    /// 수정 전 내용을 렌더링한 HTML
+    pub render: String,
+    /// 수정 전 내용이 작성된 시각
+    pub written_at: DateTime<Utc>,
+    /// 수정된 시각
+    pub edited_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct GetCommentHistoryResponse {
+    pub comment_id: Uuid,
+    /// 현재 마크다운 원문
+    pub content: String,
+    /// 현재 내용을 렌더링한 HTML
+    pub render: String,
+    pub edit_count: i32,
+    /// 이전 버전들 (최근 수정 순)
+    pub edits: Vec<CommentEditInfo>,
+}
+
+impl IntoResponse for GetCommentHistoryResponse {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
@@ -0,0 +1,36 @@
+use crate::dto::comment::response::CommentInfo;
+use axum::Json;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// "더 보기" 요청에 그대로 넘기는 커서 (GetCommentTreeRequest의 parent_id, offset)
+#[derive(Serialize, ToSchema, Debug, Clone)]
+pub struct CommentTreeCursor {
+    pub parent_id: Option<Uuid>,
+    pub offset: u64,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct CommentTreeNode {
+    pub comment: CommentInfo,
+    #[schema(no_recursion)]
+    pub replies: Vec<CommentTreeNode>,
+    /// 불러오지 않은 답글이 남아 있으면 다음 커서
+    pub next_replies_cursor: Option<CommentTreeCursor>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct GetCommentTreeResponse {
+    pub comments: Vec<CommentTreeNode>,
+    pub total_count: u64,
+    /// 불러오지 않은 같은 단계의 댓글이 남아 있으면 다음 커서
+    pub next_cursor: Option<CommentTreeCursor>,
+}
+
+impl IntoResponse for GetCommentTreeResponse {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
@@ -1,7 +1,11 @@
 pub mod comment_info;
 pub mod create_comment;
+pub mod get_comment_history;
+pub mod get_comment_tree;
 pub mod get_comments;
 
 pub use comment_info::*;
 pub use create_comment::*;
+pub use get_comment_history::*;
+pub use get_comment_tree::*;
 pub use get_comments::*;
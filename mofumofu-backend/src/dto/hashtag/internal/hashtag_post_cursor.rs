@@ -0,0 +1,52 @@
+use crate::entity::posts::Model as PostModel;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// 해시태그 포스트 목록의 keyset 커서
+///
+/// "좋아요수_트렌딩점수_작성시각(마이크로초)_포스트ID" 형태의 문자열로 주고받음
+#[derive(Debug, Clone, PartialEq)]
+pub struct HashtagPostCursor {
+    pub like_count: i32,
+    pub trending_score: f64,
+    pub created_at: DateTime<Utc>,
+    pub id: Uuid,
+}
+
+impl HashtagPostCursor {
+    /// 목록의 마지막 포스트 다음부터 가리키는 커서
+    pub fn after(post: &PostModel) -> Self {
+        Self {
+            like_count: post.like_count,
+            trending_score: post.trending_score,
+            created_at: post.created_at,
+            id: post.id,
+        }
+    }
+
+    pub fn encode(&self) -> String {
+        format!(
+            "{}_{}_{}_{}",
+            self.like_count,
+            self.trending_score,
+            self.created_at.timestamp_micros(),
+            self.id.simple()
+        )
+    }
+
+    /// 형식이 잘못된 커서는 None
+    pub fn decode(cursor: &str) -> Option<Self> {
+        let mut parts = cursor.splitn(4, '_');
+        let like_count = parts.next()?.parse().ok()?;
+        let trending_score = parts.next()?.parse().ok()?;
+        let created_at = DateTime::from_timestamp_micros(parts.next()?.parse().ok()?)?;
+        let id = Uuid::parse_str(parts.next()?).ok()?;
+
+        Some(Self {
+            like_count,
+            trending_score,
+            created_at,
+            id,
+        })
+    }
+}
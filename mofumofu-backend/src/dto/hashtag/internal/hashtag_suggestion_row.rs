@@ -0,0 +1,7 @@
+use sea_orm::FromQueryResult;
+
+#[derive(Debug, FromQueryResult)]
+pub struct HashtagSuggestionRow {
+    pub name: String,
+    pub usage_count: i32,
+}
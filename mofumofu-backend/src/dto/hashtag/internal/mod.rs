@@ -0,0 +1,2 @@
+pub mod hashtag_post_cursor;
+pub mod hashtag_suggestion_row;
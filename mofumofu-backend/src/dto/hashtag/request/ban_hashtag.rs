@@ -0,0 +1,15 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct BanHashtagRequest {
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "hashtag must be between 1 and 50 characters"
+    ))]
+    pub hashtag: String,
+    /// true면 금지, false면 금지 해제
+    pub banned: bool,
+}
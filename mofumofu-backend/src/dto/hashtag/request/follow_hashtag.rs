@@ -0,0 +1,13 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct FollowHashtagRequest {
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "hashtag must be between 1 and 50 characters"
+    ))]
+    pub hashtag: String,
+}
@@ -0,0 +1,31 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateHashtagAliasRequest {
+    /// 대표 해시태그로 연결할 별칭
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "alias must be between 1 and 50 characters"
+    ))]
+    pub alias: String,
+    /// 별칭이 가리킬 대표 해시태그
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "hashtag must be between 1 and 50 characters"
+    ))]
+    pub hashtag: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct DeleteHashtagAliasRequest {
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "alias must be between 1 and 50 characters"
+    ))]
+    pub alias: String,
+}
@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+/// OpenAPI 문서용 스키마 (실제 요청은 핸들러에서 multipart로 직접 읽음)
+#[allow(dead_code)]
+#[derive(Deserialize, ToSchema)]
+pub struct HashtagCoverForm {
+    pub hashtag: String,
+    #[schema(format = Binary, content_media_type = "image/*")]
+    file: String,
+}
@@ -0,0 +1,32 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Clone, Default, Deserialize, PartialEq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum HashtagPostSort {
+    #[default]
+    Latest,
+    Popular,
+    /// 경과 시간으로 감쇠한 트렌딩 점수 순
+    Trending,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct HashtagDetailRequest {
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "hashtag must be between 1 and 50 characters"
+    ))]
+    pub hashtag: String,
+
+    #[serde(default)]
+    pub sort: HashtagPostSort,
+
+    /// 이전 응답의 next_cursor (없으면 처음부터)
+    pub cursor: Option<String>,
+
+    #[validate(range(min = 1, max = 50, message = "limit must be between 1 and 50"))]
+    pub limit: Option<u64>,
+}
@@ -0,0 +1,12 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct HashtagTimelineRequest {
+    /// 이전 응답의 next_cursor (없으면 처음부터)
+    pub cursor: Option<String>,
+
+    #[validate(range(min = 1, max = 50, message = "limit must be between 1 and 50"))]
+    pub limit: Option<u64>,
+}
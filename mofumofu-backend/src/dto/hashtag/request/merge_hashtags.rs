@@ -0,0 +1,21 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct MergeHashtagsRequest {
+    /// 합쳐진 뒤 삭제되고 별칭으로 남는 해시태그
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "source must be between 1 and 50 characters"
+    ))]
+    pub source: String,
+    /// 남길 해시태그
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "target must be between 1 and 50 characters"
+    ))]
+    pub target: String,
+}
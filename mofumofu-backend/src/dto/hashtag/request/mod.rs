@@ -1 +1,10 @@
+pub mod ban_hashtag;
+pub mod follow_hashtag;
+pub mod hashtag_alias;
+pub mod hashtag_cover;
+pub mod hashtag_detail;
+pub mod hashtag_timeline;
+pub mod merge_hashtags;
+pub mod suggest_hashtags;
 pub mod trending_hashtags;
+pub mod update_hashtag;
@@ -0,0 +1,16 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateHashtagRequest {
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "hashtag must be between 1 and 50 characters"
+    ))]
+    pub hashtag: String,
+    /// 해시태그 설명 (null이면 삭제)
+    #[validate(length(max = 500, message = "description must be at most 500 characters"))]
+    pub description: Option<String>,
+}
@@ -0,0 +1,24 @@
+use axum::Json;
+use axum::response::{IntoResponse, Response};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct FollowedHashtag {
+    pub name: String,
+    pub usage_count: i32,
+    pub follower_count: i32,
+    pub followed_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct FollowedHashtagsResponse {
+    pub hashtags: Vec<FollowedHashtag>,
+}
+
+impl IntoResponse for FollowedHashtagsResponse {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
@@ -0,0 +1,15 @@
+use axum::Json;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+pub struct HashtagCoverUploadResponse {
+    pub public_url: String,
+}
+
+impl IntoResponse for HashtagCoverUploadResponse {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
@@ -0,0 +1,42 @@
+use crate::dto::post::response::PostListItem;
+use axum::Json;
+use axum::response::{IntoResponse, Response};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct HashtagTopAuthor {
+    pub handle: String,
+    pub name: String,
+    pub profile_image: Option<String>,
+    /// 이 해시태그로 작성한 포스트 수
+    pub post_count: i64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct HashtagDetailResponse {
+    pub name: String,
+    pub description: Option<String>,
+    pub cover_image: Option<String>,
+    /// 이 해시태그로 연결되는 별칭
+    pub aliases: Vec<String>,
+    pub usage_count: i32,
+    /// 현재 이 해시태그가 달린 포스트 수
+    pub post_count: u64,
+    pub follower_count: i32,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    /// 조회한 사용자의 팔로우 여부 (비로그인 시 false)
+    pub is_following: bool,
+    pub top_authors: Vec<HashtagTopAuthor>,
+    pub posts: Vec<PostListItem>,
+    /// 다음 페이지 커서 (마지막 페이지면 null)
+    pub next_cursor: Option<String>,
+}
+
+impl IntoResponse for HashtagDetailResponse {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
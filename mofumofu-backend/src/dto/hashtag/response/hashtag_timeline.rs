@@ -0,0 +1,18 @@
+use crate::dto::post::response::PostListItem;
+use axum::Json;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct HashtagTimelineResponse {
+    pub posts: Vec<PostListItem>,
+    /// 다음 페이지 커서 (마지막 페이지면 null)
+    pub next_cursor: Option<String>,
+}
+
+impl IntoResponse for HashtagTimelineResponse {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
@@ -0,0 +1,20 @@
+use axum::Json;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MergeHashtagsResponse {
+    /// 남은 해시태그 이름
+    pub name: String,
+    pub usage_count: i32,
+    pub follower_count: i32,
+    /// 원본 해시태그에서 옮겨진 포스트 수
+    pub moved_post_count: usize,
+}
+
+impl IntoResponse for MergeHashtagsResponse {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
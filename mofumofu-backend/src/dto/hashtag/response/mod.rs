@@ -1 +1,7 @@
+pub mod followed_hashtags;
+pub mod hashtag_cover;
+pub mod hashtag_detail;
+pub mod hashtag_timeline;
+pub mod merge_hashtags;
+pub mod suggest_hashtags;
 pub mod trending_hashtags;
@@ -0,0 +1,21 @@
+use axum::Json;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct HashtagSuggestion {
+    pub name: String,
+    pub usage_count: i32,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SuggestHashtagsResponse {
+    pub hashtags: Vec<HashtagSuggestion>,
+}
+
+impl IntoResponse for SuggestHashtagsResponse {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
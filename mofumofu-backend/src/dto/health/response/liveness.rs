@@ -0,0 +1,16 @@
+use axum::Json;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct LivenessResponse {
+    pub status: String,
+    pub version: String,
+}
+
+impl IntoResponse for LivenessResponse {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
@@ -0,0 +1,5 @@
+pub mod liveness;
+pub mod readiness;
+
+pub use liveness::*;
+pub use readiness::*;
@@ -0,0 +1,40 @@
+use axum::Json;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DependencyStatus {
+    Up,
+    Down,
+    /// 설정상 사용하지 않는 의존성 (예: 네이티브 렌더러 사용 시 마크다운 서비스)
+    Skipped,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct DependencyCheck {
+    pub name: String,
+    pub status: DependencyStatus,
+    pub latency_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ReadinessResponse {
+    pub ready: bool,
+    pub dependencies: Vec<DependencyCheck>,
+}
+
+impl IntoResponse for ReadinessResponse {
+    fn into_response(self) -> Response {
+        // 하나라도 응답하지 않으면 503으로 트래픽에서 제외되도록 함
+        let status = if self.ready {
+            StatusCode::OK
+        } else {
+            StatusCode::SERVICE_UNAVAILABLE
+        };
+
+        (status, Json(self)).into_response()
+    }
+}
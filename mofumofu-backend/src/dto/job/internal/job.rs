@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// 내장 작업 큐에서 실행되는 작업 (태스크 서버의 Celery 태스크와 1:1 대응)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Job {
+    SendEmailVerification {
+        email_to: String,
+        username: String,
+        verification_token: String,
+    },
+    SendResetPasswordEmail {
+        email_to: String,
+        email: String,
+        token: String,
+    },
+    IndexPost {
+        post_id: Uuid,
+    },
+    UpdatePost {
+        post_id: Uuid,
+    },
+    DeletePost {
+        post_id: Uuid,
+    },
+    ReindexAllPosts,
+    SyncLikeCounts,
+    SyncFollowCounts,
+    SyncAllCounts,
+    CleanupExpiredTokens,
+    CleanupOldEvents,
+}
+
+impl Job {
+    /// 로그와 상태 조회에 쓰이는 작업 이름 (태스크 서버의 태스크 이름과 동일)
+    pub fn name(&self) -> &'static str {
+        match self {
+            Job::SendEmailVerification { .. } => "send_email_verification_task",
+            Job::SendResetPasswordEmail { .. } => "send_reset_password_email_task",
+            Job::IndexPost { .. } => "index_single_post",
+            Job::UpdatePost { .. } => "update_single_post",
+            Job::DeletePost { .. } => "delete_single_post",
+            Job::ReindexAllPosts => "reindex_all_posts",
+            Job::SyncLikeCounts => "sync_post_like_counts",
+            Job::SyncFollowCounts => "sync_user_follow_counts",
+            Job::SyncAllCounts => "sync_all_counts",
+            Job::CleanupExpiredTokens => "cleanup_expired_refresh_tokens",
+            Job::CleanupOldEvents => "cleanup_old_system_events",
+        }
+    }
+}
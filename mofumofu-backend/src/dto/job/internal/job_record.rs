@@ -0,0 +1,55 @@
+use crate::dto::job::internal::job::Job;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 작업 상태 (Celery 상태 이름과 동일)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum JobState {
+    Pending,
+    Started,
+    Retry,
+    Success,
+    Failure,
+}
+
+impl JobState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JobState::Pending => "PENDING",
+            JobState::Started => "STARTED",
+            JobState::Retry => "RETRY",
+            JobState::Success => "SUCCESS",
+            JobState::Failure => "FAILURE",
+        }
+    }
+}
+
+/// Redis에 저장되는 작업 정보와 실행 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobRecord {
+    pub id: String,
+    pub job: Job,
+    pub state: JobState,
+    pub attempts: u32,
+    pub result: Option<serde_json::Value>,
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl JobRecord {
+    pub fn new(id: String, job: Job) -> Self {
+        let now = Utc::now();
+        Self {
+            id,
+            job,
+            state: JobState::Pending,
+            attempts: 0,
+            result: None,
+            error: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
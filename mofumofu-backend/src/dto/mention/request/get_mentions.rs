@@ -0,0 +1,22 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Deserialize, ToSchema, Debug, Validate)]
+pub struct GetMentionsRequest {
+    #[serde(default = "default_page")]
+    #[validate(range(min = 1))]
+    pub page: u32,
+
+    #[serde(default = "default_per_page")]
+    #[validate(range(min = 1, max = 50))]
+    pub per_page: u32,
+}
+
+fn default_page() -> u32 {
+    1
+}
+
+fn default_per_page() -> u32 {
+    20
+}
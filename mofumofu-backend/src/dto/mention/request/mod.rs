@@ -0,0 +1 @@
+pub mod get_mentions;
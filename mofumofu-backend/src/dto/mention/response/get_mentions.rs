@@ -0,0 +1,20 @@
+use crate::dto::mention::response::mention_info::MentionInfo;
+use axum::Json;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+pub struct GetMentionsResponse {
+    pub mentions: Vec<MentionInfo>,
+    pub total_count: u64,
+    pub page: u32,
+    pub per_page: u32,
+    pub has_next: bool,
+}
+
+impl IntoResponse for GetMentionsResponse {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
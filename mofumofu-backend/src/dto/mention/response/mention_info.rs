@@ -0,0 +1,21 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct MentionInfo {
+    pub id: Uuid,
+    pub post_id: Uuid,
+    pub post_title: String,
+    pub post_slug: String,
+    pub post_author_handle: String,
+    /// 댓글에서 멘션된 경우 댓글 ID (포스트 본문이면 None)
+    pub comment_id: Option<Uuid>,
+    /// 댓글 내용 (포스트 본문이거나 삭제된 댓글이면 None)
+    pub comment_content: Option<String>,
+    pub mentioner_handle: String,
+    pub mentioner_name: String,
+    pub mentioner_profile_image: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
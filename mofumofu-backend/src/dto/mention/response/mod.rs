@@ -0,0 +1,2 @@
+pub mod get_mentions;
+pub mod mention_info;
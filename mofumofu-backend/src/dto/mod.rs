@@ -1,12 +1,19 @@
 pub mod admin;
 pub mod auth;
+pub mod cache;
 pub mod comment;
 pub mod common;
 pub mod draft;
 pub mod follow;
 pub mod hashtag;
+pub mod health;
+pub mod job;
 pub mod like;
+pub mod mention;
 pub mod oauth;
+pub mod outbox;
 pub mod post;
+pub mod rate_limit;
+pub mod reaction;
 pub mod report;
 pub mod user;
@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// 트랜잭션과 함께 아웃박스에 기록되어 디스패처가 태스크 서버로 전달하는 메시지
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum OutboxMessage {
+    SearchIndexPost {
+        post_id: Uuid,
+    },
+    SearchUpdatePost {
+        post_id: Uuid,
+    },
+    SearchDeletePost {
+        post_id: Uuid,
+    },
+    VerificationEmail {
+        email: String,
+        username: String,
+        token: String,
+    },
+    ResetPasswordEmail {
+        email: String,
+        token: String,
+    },
+}
+
+impl OutboxMessage {
+    /// 관리자 조회용 이벤트 이름 (payload의 type 태그와 동일)
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            OutboxMessage::SearchIndexPost { .. } => "search_index_post",
+            OutboxMessage::SearchUpdatePost { .. } => "search_update_post",
+            OutboxMessage::SearchDeletePost { .. } => "search_delete_post",
+            OutboxMessage::VerificationEmail { .. } => "verification_email",
+            OutboxMessage::ResetPasswordEmail { .. } => "reset_password_email",
+        }
+    }
+}
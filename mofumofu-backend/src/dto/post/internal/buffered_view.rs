@@ -0,0 +1,48 @@
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+/// Redis 해시 필드 구분자 (UUID, 날짜, 도메인, 익명 ID에는 들어가지 않음)
+const FIELD_SEPARATOR: char = '|';
+
+/// 포스트/날짜별로 Redis에 모인 조회수
+#[derive(Debug, Clone)]
+pub struct BufferedViewCount {
+    pub post_id: Uuid,
+    pub day: NaiveDate,
+    pub views: i32,
+}
+
+/// 포스트/날짜별로 Redis에 모인 방문자
+#[derive(Debug, Clone)]
+pub struct BufferedViewer {
+    pub post_id: Uuid,
+    pub day: NaiveDate,
+    pub viewer_id: String,
+}
+
+/// 포스트/날짜/유입 도메인별로 Redis에 모인 조회수 (빈 도메인은 직접 유입)
+#[derive(Debug, Clone)]
+pub struct BufferedReferrer {
+    pub post_id: Uuid,
+    pub day: NaiveDate,
+    pub domain: String,
+    pub views: i32,
+}
+
+/// "포스트ID|날짜" 또는 "포스트ID|날짜|값" 형태의 해시 필드
+pub fn buffered_view_field(post_id: &Uuid, day: NaiveDate, value: Option<&str>) -> String {
+    match value {
+        Some(value) => format!("{post_id}{FIELD_SEPARATOR}{day}{FIELD_SEPARATOR}{value}"),
+        None => format!("{post_id}{FIELD_SEPARATOR}{day}"),
+    }
+}
+
+/// 형식이 잘못된 필드는 None
+pub fn parse_buffered_view_field(field: &str) -> Option<(Uuid, NaiveDate, Option<String>)> {
+    let mut parts = field.splitn(3, FIELD_SEPARATOR);
+    let post_id = Uuid::parse_str(parts.next()?).ok()?;
+    let day = parts.next()?.parse().ok()?;
+    let value = parts.next().map(str::to_string);
+
+    Some((post_id, day, value))
+}
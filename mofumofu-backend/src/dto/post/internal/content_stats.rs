@@ -0,0 +1,9 @@
+/// 렌더링 시점에 계산되는 본문 통계
+#[derive(Debug, Clone, Default)]
+pub struct ContentStats {
+    pub word_count: i32,
+    /// 예상 읽기 시간 (분)
+    pub reading_time: i32,
+    /// 감지된 언어 (ISO 639-3, 예: kor, jpn, eng)
+    pub language: Option<String>,
+}
@@ -1 +1,4 @@
-
+pub mod buffered_view;
+pub mod content_stats;
+pub mod post_analytics_row;
+pub mod related_post_row;
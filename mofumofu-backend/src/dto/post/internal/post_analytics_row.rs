@@ -0,0 +1,22 @@
+use chrono::NaiveDate;
+use sea_orm::FromQueryResult;
+
+#[derive(Debug, FromQueryResult)]
+pub struct PostDailyStatRow {
+    pub day: NaiveDate,
+    pub views: i32,
+    pub unique_viewers: i32,
+    pub likes: i32,
+    pub comments: i32,
+}
+
+#[derive(Debug, FromQueryResult)]
+pub struct PostReferrerRow {
+    pub domain: String,
+    pub views: i64,
+}
+
+#[derive(Debug, FromQueryResult)]
+pub struct PostUniqueViewersRow {
+    pub unique_viewers: i64,
+}
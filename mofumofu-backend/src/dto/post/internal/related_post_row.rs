@@ -0,0 +1,7 @@
+use sea_orm::FromQueryResult;
+use uuid::Uuid;
+
+#[derive(Debug, FromQueryResult)]
+pub struct RelatedPostRow {
+    pub post_id: Uuid,
+}
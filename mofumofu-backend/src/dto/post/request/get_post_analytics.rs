@@ -0,0 +1,16 @@
+use chrono::NaiveDate;
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct GetPostAnalyticsRequest {
+    pub post_id: Uuid,
+
+    /// 시작일 (UTC, 포함). 없으면 종료일 기준 최근 30일
+    pub from: Option<NaiveDate>,
+
+    /// 종료일 (UTC, 포함). 없으면 오늘
+    pub to: Option<NaiveDate>,
+}
@@ -1,12 +1,14 @@
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
-use validator::Validate;
+use validator::{Validate, ValidationError};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum PostSortOrder {
     Latest,
     Popular,
+    /// 좋아요/댓글/조회수를 경과 시간으로 감쇠한 점수 순
+    Trending,
     Oldest,
 }
 
@@ -25,6 +27,20 @@ pub struct GetPostsRequest {
     pub page_size: Option<u32>,
 
     pub sort: Option<PostSortOrder>,
+
+    /// 언어 필터 (ISO 639-3, 예: kor, jpn, eng)
+    #[validate(custom(function = "validate_language_code"))]
+    pub language: Option<String>,
+}
+
+/// 소문자 3글자 ISO 639-3 코드만 허용 (검색 필터와 캐시 키에 그대로 들어가므로)
+pub fn validate_language_code(language: &str) -> Result<(), ValidationError> {
+    if language.len() != 3 || !language.bytes().all(|b| b.is_ascii_lowercase()) {
+        let mut error = ValidationError::new("invalid_language");
+        error.message = Some("Language must be a 3-letter ISO 639-3 code.".into());
+        return Err(error);
+    }
+    Ok(())
 }
 
 impl Default for GetPostsRequest {
@@ -33,6 +49,7 @@ impl Default for GetPostsRequest {
             page: Some(1),
             page_size: Some(20),
             sort: Some(PostSortOrder::Latest),
+            language: None,
         }
     }
 }
@@ -0,0 +1,12 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct GetRelatedPostsRequest {
+    pub post_id: Uuid,
+
+    #[validate(range(min = 1, max = 20, message = "Limit must be between 1 and 20."))]
+    pub limit: Option<u32>,
+}
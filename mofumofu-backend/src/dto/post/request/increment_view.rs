@@ -0,0 +1,13 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct IncrementViewRequest {
+    pub post_id: Uuid,
+
+    /// 포스트로 들어오기 전 페이지 (브라우저의 document.referrer, 없으면 직접 유입)
+    #[validate(length(max = 2048, message = "Referrer must be at most 2048 characters."))]
+    pub referrer: Option<String>,
+}
@@ -2,16 +2,24 @@ pub mod create_post;
 pub mod delete_post;
 pub mod get_by_handle_and_slug;
 pub mod get_by_uuid;
+pub mod get_post_analytics;
 pub mod get_post_for_edit;
 pub mod get_posts;
+pub mod get_related_posts;
 pub mod get_user_posts;
 pub mod image_upload;
+pub mod increment_view;
 pub mod search_posts;
 pub mod thumbnail_image;
+pub mod update_comment_settings;
 pub mod update_post;
 
 pub use get_by_handle_and_slug::GetPostByHandleAndSlugRequest;
 pub use get_by_uuid::GetPostByUuidRequest;
+pub use get_post_analytics::GetPostAnalyticsRequest;
 pub use get_posts::{GetPostsRequest, PostSortOrder};
+pub use get_related_posts::GetRelatedPostsRequest;
 pub use get_user_posts::GetUserPostsRequest;
+pub use increment_view::IncrementViewRequest;
 pub use search_posts::SearchPostsRequest;
+pub use update_comment_settings::UpdatePostCommentSettingsRequest;
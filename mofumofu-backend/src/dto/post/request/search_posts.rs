@@ -1,4 +1,5 @@
 use super::PostSortOrder;
+use super::get_posts::validate_language_code;
 use chrono::{DateTime, Utc};
 use serde::Deserialize;
 use utoipa::ToSchema;
@@ -26,6 +27,10 @@ pub struct SearchPostsRequest {
     #[validate(range(min = 0, message = "Min likes must be non-negative."))]
     pub min_likes: Option<i32>,
 
+    /// 언어 필터 (ISO 639-3, 예: kor, jpn, eng)
+    #[validate(custom(function = "validate_language_code"))]
+    pub language: Option<String>,
+
     /// 정렬 방식
     pub sort: Option<PostSortOrder>,
 
@@ -47,6 +52,7 @@ impl Default for SearchPostsRequest {
             date_from: None,
             date_to: None,
             min_likes: None,
+            language: None,
             sort: Some(PostSortOrder::Latest),
             page: Some(1),
             page_size: Some(20),
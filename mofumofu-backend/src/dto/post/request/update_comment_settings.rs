@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, ToSchema, Debug)]
+pub struct UpdatePostCommentSettingsRequest {
+    pub post_id: Uuid,
+    /// true면 새 댓글 작성을 막음
+    pub comments_disabled: bool,
+}
@@ -1,11 +1,11 @@
 use axum::Json;
 use axum::response::{IntoResponse, Response};
 use chrono::{DateTime, Utc};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct PostListItem {
     pub id: Uuid,
     pub title: String,
@@ -20,9 +20,14 @@ pub struct PostListItem {
     pub view_count: i32,
     pub slug: String,
     pub hashtags: Vec<String>,
+    pub word_count: i32,
+    /// 예상 읽기 시간 (분)
+    pub reading_time: i32,
+    /// 감지된 언어 (ISO 639-3)
+    pub language: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct GetPostsResponse {
     pub posts: Vec<PostListItem>,
     pub current_page: u32,
@@ -1,13 +1,17 @@
 pub mod create_post;
 pub mod get_posts;
 pub mod image_upload;
+pub mod post_analytics;
 pub mod post_edit_info;
 pub mod post_info;
+pub mod related_posts;
 pub mod thumbnail_upload;
 pub mod user_posts;
 
 pub use create_post::CreatePostResponse;
 pub use get_posts::{GetPostsResponse, PostListItem};
 pub use image_upload::ImageUploadResponse;
+pub use post_analytics::{PostAnalyticsResponse, PostDailyAnalytics, PostReferrerAnalytics};
+pub use related_posts::RelatedPostsResponse;
 pub use thumbnail_upload::ThumbnailUploadResponse;
 pub use user_posts::UserPostsResponse;
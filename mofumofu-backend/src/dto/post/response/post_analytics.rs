@@ -0,0 +1,43 @@
+use axum::Json;
+use axum::response::{IntoResponse, Response};
+use chrono::NaiveDate;
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PostDailyAnalytics {
+    pub date: NaiveDate,
+    pub views: i32,
+    pub unique_viewers: i32,
+    pub likes: i32,
+    pub comments: i32,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PostReferrerAnalytics {
+    /// 유입 도메인 (null이면 직접 유입)
+    pub domain: Option<String>,
+    pub views: i64,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PostAnalyticsResponse {
+    pub post_id: Uuid,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub total_views: i64,
+    /// 기간 전체의 순 방문자 수 (일별 순 방문자 합계와 다를 수 있음)
+    pub unique_viewers: i64,
+    pub total_likes: i64,
+    pub total_comments: i64,
+    /// 기간 내 모든 날짜 (기록이 없는 날은 0)
+    pub daily: Vec<PostDailyAnalytics>,
+    pub referrers: Vec<PostReferrerAnalytics>,
+}
+
+impl IntoResponse for PostAnalyticsResponse {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
@@ -1,3 +1,4 @@
+use crate::dto::reaction::response::reaction_count::ReactionCount;
 use axum::Json;
 use axum::response::{IntoResponse, Response};
 use chrono::{DateTime, Utc};
@@ -5,7 +6,7 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct PostInfoResponse {
     pub id: Uuid,
     pub title: String,
@@ -21,6 +22,17 @@ pub struct PostInfoResponse {
     pub view_count: i32,
     pub slug: String,
     pub tags: Vec<String>,
+    pub word_count: i32,
+    /// 예상 읽기 시간 (분)
+    pub reading_time: i32,
+    /// 감지된 언어 (ISO 639-3)
+    pub language: Option<String>,
+    /// 이모지별 반응 수
+    pub reactions: Vec<ReactionCount>,
+    /// 조회한 사용자가 남긴 반응 (비로그인 시 빈 배열)
+    pub my_reactions: Vec<String>,
+    /// 게시글 작성자가 댓글 작성을 막았는지 여부
+    pub comments_disabled: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -30,7 +42,7 @@ pub struct TocItem {
     pub id: String,
 }
 
-#[derive(Debug, Clone, Serialize, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct PostAuthor {
     pub handle: String,
     pub name: String,
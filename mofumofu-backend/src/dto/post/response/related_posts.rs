@@ -0,0 +1,16 @@
+use crate::dto::post::response::PostListItem;
+use axum::Json;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RelatedPostsResponse {
+    pub posts: Vec<PostListItem>,
+}
+
+impl IntoResponse for RelatedPostsResponse {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
@@ -0,0 +1,10 @@
+/// 요청 제한 확인 결과 (RateLimit-* 응답 헤더에 사용)
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimitDecision {
+    pub allowed: bool,
+    pub limit: u32,
+    pub window_seconds: u64,
+    pub remaining: u32,
+    /// 한도가 다시 차기까지 남은 시간 (초)
+    pub reset_seconds: u64,
+}
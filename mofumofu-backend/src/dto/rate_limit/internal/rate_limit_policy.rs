@@ -0,0 +1,71 @@
+use crate::config::db_config::DbConfig;
+
+/// 요청 수를 세는 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitAlgorithm {
+    /// 최근 window 동안의 요청 수를 정확히 제한
+    SlidingWindow,
+    /// window 동안 limit개가 고르게 채워지는 버킷 (순간적인 몰림 허용)
+    TokenBucket,
+}
+
+/// 요청을 누구 기준으로 셀지
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitKey {
+    /// 로그인 사용자 ID (AccessTokenClaims), 없으면 IP
+    User,
+    /// 클라이언트 IP
+    Ip,
+}
+
+/// 라우트별 요청 제한 정책 (각 routes.rs에서 라우트와 함께 선언)
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimitPolicy {
+    /// Redis 키와 RATE_LIMIT_OVERRIDES에서 쓰는 이름 (예: comment:create)
+    pub name: &'static str,
+    pub limit: u32,
+    pub window_seconds: u64,
+    pub algorithm: RateLimitAlgorithm,
+    pub key: RateLimitKey,
+}
+
+impl RateLimitPolicy {
+    pub const fn sliding_window(
+        name: &'static str,
+        limit: u32,
+        window_seconds: u64,
+        key: RateLimitKey,
+    ) -> Self {
+        Self {
+            name,
+            limit,
+            window_seconds,
+            algorithm: RateLimitAlgorithm::SlidingWindow,
+            key,
+        }
+    }
+
+    pub const fn token_bucket(
+        name: &'static str,
+        limit: u32,
+        window_seconds: u64,
+        key: RateLimitKey,
+    ) -> Self {
+        Self {
+            name,
+            limit,
+            window_seconds,
+            algorithm: RateLimitAlgorithm::TokenBucket,
+            key,
+        }
+    }
+
+    /// RATE_LIMIT_OVERRIDES로 바꾼 값이 있으면 그 값을 사용 (limit, window_seconds)
+    pub fn effective_limits(&self) -> (u32, u64) {
+        DbConfig::get()
+            .rate_limit_overrides
+            .get(self.name)
+            .copied()
+            .unwrap_or((self.limit, self.window_seconds))
+    }
+}
@@ -0,0 +1,8 @@
+use crate::dto::reaction::response::reaction_count::ReactionCount;
+
+/// 대상 하나의 반응 집계와 조회한 사용자의 반응
+#[derive(Debug, Clone, Default)]
+pub struct ReactionSummary {
+    pub reactions: Vec<ReactionCount>,
+    pub my_reactions: Vec<String>,
+}
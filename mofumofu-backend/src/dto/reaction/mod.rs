@@ -0,0 +1,3 @@
+pub mod internal;
+pub mod request;
+pub mod response;
@@ -0,0 +1,2 @@
+pub mod toggle_comment_reaction;
+pub mod toggle_post_reaction;
@@ -0,0 +1,15 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+pub struct TogglePostReactionRequest {
+    pub post_id: Uuid,
+    #[validate(length(
+        min = 1,
+        max = 32,
+        message = "Emoji must be between 1 and 32 characters."
+    ))]
+    pub emoji: String,
+}
@@ -0,0 +1,2 @@
+pub mod reaction_count;
+pub mod toggle_reaction;
@@ -0,0 +1,20 @@
+use crate::dto::reaction::response::reaction_count::ReactionCount;
+use axum::Json;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ToggleReactionResponse {
+    pub emoji: String,
+    /// 토글 후 현재 사용자가 이 반응을 남긴 상태인지 여부
+    pub reacted: bool,
+    /// 대상의 이모지별 반응 수
+    pub reactions: Vec<ReactionCount>,
+}
+
+impl IntoResponse for ToggleReactionResponse {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
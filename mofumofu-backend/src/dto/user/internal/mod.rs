@@ -1 +1,2 @@
 pub mod update_user;
+pub mod user_suggestion_row;
@@ -0,0 +1,9 @@
+use sea_orm::FromQueryResult;
+
+#[derive(Debug, FromQueryResult)]
+pub struct UserSuggestionRow {
+    pub handle: String,
+    pub name: String,
+    pub profile_image: Option<String>,
+    pub is_following: bool,
+}
@@ -2,4 +2,5 @@ pub mod avatar_image;
 pub mod banner_image;
 pub mod create;
 pub mod get_profile;
+pub mod suggest_users;
 pub mod update_profile;
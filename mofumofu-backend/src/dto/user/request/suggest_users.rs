@@ -0,0 +1,14 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SuggestUsersRequest {
+    /// 입력 중인 핸들 또는 이름 ('@' 포함 가능)
+    #[validate(length(min = 1, max = 20, message = "q must be between 1 and 20 characters"))]
+    pub q: String,
+
+    #[validate(range(min = 1, max = 20, message = "limit must be between 1 and 20"))]
+    pub limit: Option<u64>,
+}
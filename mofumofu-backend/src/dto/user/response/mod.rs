@@ -1,3 +1,4 @@
 pub mod handle_check;
 pub mod image_upload;
 pub mod info;
+pub mod suggest_users;
@@ -0,0 +1,24 @@
+use axum::Json;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UserSuggestion {
+    pub handle: String,
+    pub name: String,
+    pub profile_image: Option<String>,
+    /// 조회한 사용자가 팔로우 중인지 (비로그인 시 false)
+    pub is_following: bool,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SuggestUsersResponse {
+    pub users: Vec<UserSuggestion>,
+}
+
+impl IntoResponse for SuggestUsersResponse {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
@@ -0,0 +1,42 @@
+use sea_orm::prelude::*;
+use uuid::Uuid;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "comment_edits")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: Uuid,
+
+    #[sea_orm(column_type = "Uuid", not_null)]
+    pub comment_id: Uuid,
+
+    // 수정 전 내용
+    #[sea_orm(column_type = "Text", not_null)]
+    pub content: String,
+
+    // 수정 전 내용이 작성된 시각
+    #[sea_orm(column_type = "TimestampWithTimeZone", not_null)]
+    pub written_at: DateTimeUtc,
+
+    #[sea_orm(column_type = "TimestampWithTimeZone", not_null)]
+    pub edited_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::comments::Entity",
+        from = "Column::CommentId",
+        to = "super::comments::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Comment,
+}
+
+impl Related<super::comments::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Comment.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
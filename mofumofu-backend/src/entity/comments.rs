@@ -10,6 +10,10 @@ pub struct Model {
     #[sea_orm(column_type = "Text", not_null, string_len = 300)]
     pub content: String,
 
+    // 렌더링/sanitize된 HTML (content가 원본)
+    #[sea_orm(column_type = "Text", nullable)]
+    pub render: Option<String>,
+
     #[sea_orm(column_type = "Uuid", not_null)]
     pub post_id: Uuid,
 
@@ -33,6 +37,23 @@ pub struct Model {
 
     #[sea_orm(column_type = "Integer", not_null, default_value = "0")]
     pub reply_count: i32,
+
+    #[sea_orm(column_type = "Boolean", not_null, default_value = "false")]
+    pub is_pinned: bool,
+
+    #[sea_orm(column_type = "TimestampWithTimeZone", nullable)]
+    pub pinned_at: Option<DateTimeUtc>,
+
+    // 댓글 작성자(와 포스트 작성자)에게만 보임
+    #[sea_orm(column_type = "Boolean", not_null, default_value = "false")]
+    pub is_hidden: bool,
+
+    // 새 답글 금지
+    #[sea_orm(column_type = "Boolean", not_null, default_value = "false")]
+    pub is_locked: bool,
+
+    #[sea_orm(column_type = "Integer", not_null, default_value = "0")]
+    pub edit_count: i32,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -51,6 +51,8 @@ pub enum ActionType {
     LikeCreated,
     #[sea_orm(string_value = "like_deleted")]
     LikeDeleted,
+    #[sea_orm(string_value = "mention_created")]
+    MentionCreated,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
@@ -131,3 +133,16 @@ pub enum ReportStatus {
     #[sea_orm(string_value = "dismissed")]
     Dismissed,
 }
+
+#[derive(
+    Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize, ToSchema,
+)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "outbox_status")]
+pub enum OutboxStatus {
+    #[sea_orm(string_value = "pending")]
+    Pending,
+    #[sea_orm(string_value = "delivered")]
+    Delivered,
+    #[sea_orm(string_value = "dead")]
+    Dead,
+}
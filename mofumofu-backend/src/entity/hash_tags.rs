@@ -18,6 +18,18 @@ pub struct Model {
 
     #[sea_orm(column_type = "TimestampWithTimeZone", nullable)]
     pub last_used_at: Option<DateTimeUtc>,
+
+    #[sea_orm(column_type = "Integer", not_null, default_value = "0")]
+    pub follower_count: i32,
+
+    #[sea_orm(column_type = "Text", nullable)]
+    pub description: Option<String>,
+
+    #[sea_orm(column_type = "Text", nullable)]
+    pub cover_image: Option<String>,
+
+    #[sea_orm(column_type = "Boolean", not_null, default_value = "false")]
+    pub is_banned: bool,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -0,0 +1,51 @@
+use sea_orm::prelude::*;
+use uuid::Uuid;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "hashtag_follows")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: Uuid,
+
+    #[sea_orm(column_type = "Uuid", not_null)]
+    pub user_id: Uuid,
+
+    #[sea_orm(column_type = "Uuid", not_null)]
+    pub hash_tag_id: Uuid,
+
+    #[sea_orm(column_type = "TimestampWithTimeZone", not_null)]
+    pub created_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::UserId",
+        to = "super::users::Column::Id",
+        on_delete = "Cascade"
+    )]
+    User,
+
+    #[sea_orm(
+        belongs_to = "super::hash_tags::Entity",
+        from = "Column::HashTagId",
+        to = "super::hash_tags::Column::Id",
+        on_delete = "Cascade"
+    )]
+    HashTag,
+}
+
+impl Related<super::users::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::User.def()
+    }
+}
+
+impl Related<super::hash_tags::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::HashTag.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -1,13 +1,19 @@
+pub mod comment_edits;
 pub mod comments;
 pub mod common;
 pub mod drafts;
 pub mod follows;
 pub(crate) mod hash_tags;
-pub mod likes;
+pub mod hashtag_aliases;
+pub mod hashtag_follows;
+pub mod mentions;
+pub mod outbox;
 pub(crate) mod post_hash_tags;
 pub mod posts;
+pub mod reactions;
 pub mod reports;
 pub mod system_events;
+pub mod trending_settings;
 pub(crate) mod user_oauth_connections;
 pub mod user_refresh_tokens;
 pub mod users;
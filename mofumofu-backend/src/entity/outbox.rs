@@ -0,0 +1,41 @@
+use super::common::OutboxStatus;
+use sea_orm::prelude::*;
+use uuid::Uuid;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "outbox")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: Uuid,
+
+    #[sea_orm(column_type = "Text", not_null)]
+    pub event_type: String,
+
+    #[sea_orm(column_type = "JsonBinary", not_null)]
+    pub payload: Json,
+
+    #[sea_orm(column_type = "Text", unique, not_null)]
+    pub idempotency_key: String,
+
+    pub status: OutboxStatus,
+
+    #[sea_orm(column_type = "Integer", not_null, default_value = "0")]
+    pub attempts: i32,
+
+    #[sea_orm(column_type = "TimestampWithTimeZone", not_null)]
+    pub next_attempt_at: DateTimeUtc,
+
+    #[sea_orm(column_type = "Text", nullable)]
+    pub last_error: Option<String>,
+
+    #[sea_orm(column_type = "TimestampWithTimeZone", not_null)]
+    pub created_at: DateTimeUtc,
+
+    #[sea_orm(column_type = "TimestampWithTimeZone", nullable)]
+    pub delivered_at: Option<DateTimeUtc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
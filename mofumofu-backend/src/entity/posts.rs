@@ -45,6 +45,21 @@ pub struct Model {
 
     #[sea_orm(column_type = "Json", nullable)]
     pub toc: Option<serde_json::Value>,
+
+    #[sea_orm(column_type = "Integer", not_null, default_value = "0")]
+    pub word_count: i32,
+
+    #[sea_orm(column_type = "Integer", not_null, default_value = "0")]
+    pub reading_time: i32,
+
+    #[sea_orm(column_type = "Text", nullable, string_len = 8)]
+    pub language: Option<String>,
+
+    #[sea_orm(column_type = "Boolean", not_null, default_value = "false")]
+    pub comments_disabled: bool,
+
+    #[sea_orm(column_type = "Double", not_null, default_value = "0")]
+    pub trending_score: f64,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -3,7 +3,7 @@ use sea_orm::prelude::*;
 use uuid::Uuid;
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
-#[sea_orm(table_name = "likes")]
+#[sea_orm(table_name = "reactions")]
 pub struct Model {
     #[sea_orm(primary_key)]
     pub id: Uuid,
@@ -19,6 +19,9 @@ pub struct Model {
 
     pub target_type: LikeTargetType,
 
+    #[sea_orm(column_type = "Text", not_null, string_len = 32)]
+    pub emoji: String,
+
     #[sea_orm(column_type = "TimestampWithTimeZone", not_null)]
     pub created_at: DateTimeUtc,
 }
@@ -0,0 +1,47 @@
+use sea_orm::prelude::*;
+use uuid::Uuid;
+
+/// 트렌딩 점수 가중치 (id = 1인 행 하나만 존재)
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "trending_settings")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: i16,
+
+    #[sea_orm(column_type = "Double", not_null)]
+    pub like_weight: f64,
+
+    #[sea_orm(column_type = "Double", not_null)]
+    pub comment_weight: f64,
+
+    #[sea_orm(column_type = "Double", not_null)]
+    pub view_weight: f64,
+
+    #[sea_orm(column_type = "Double", not_null)]
+    pub gravity: f64,
+
+    #[sea_orm(column_type = "Uuid", nullable)]
+    pub updated_by: Option<Uuid>,
+
+    #[sea_orm(column_type = "TimestampWithTimeZone", not_null)]
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::UpdatedBy",
+        to = "super::users::Column::Id",
+        on_delete = "SetNull"
+    )]
+    User,
+}
+
+impl Related<super::users::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::User.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -1,17 +1,32 @@
-use crate::api::v0::routes::routes::api_routes;
+use crate::api::v0::routes::routes::{api_routes, internal_routes};
 use crate::config::db_config::DbConfig;
+use crate::config::reload::spawn_config_reloader;
 use crate::connection::cloudflare_r2::establish_r2_connection;
 use crate::connection::database::establish_connection;
 use crate::connection::http::create_http_client;
 use crate::connection::meilisearch::MeilisearchClient;
 use crate::connection::redis_connection::establish_redis_connection;
 use crate::middleware::cors::cors_layer;
+use crate::middleware::metrics::http_metrics_middleware;
+use crate::middleware::request_id::request_id_middleware;
+use crate::service::job::job_scheduler::spawn_job_scheduler;
+use crate::service::job::job_worker::spawn_job_worker;
+use crate::service::outbox::dispatch_outbox::spawn_outbox_dispatcher;
+use crate::service::post::backfill_content_stats::spawn_content_stats_backfill;
+use crate::service::post::trending_scores::spawn_trending_score_refresher;
+use crate::service::post::view_buffer::spawn_view_buffer_flusher;
 use crate::state::AppState;
-use crate::utils::logger::init_tracing;
-use axum::Router;
+use crate::utils::logger::{init_tracing, shutdown_tracing};
+use crate::utils::metrics::init_metrics;
+use crate::utils::shutdown::{BackgroundTasks, shutdown_signal};
+use axum::{Extension, Router};
+use axum::middleware::from_fn;
+use std::future::IntoFuture;
 use std::net::SocketAddr;
+use std::time::Duration;
 use tower_http::compression::CompressionLayer;
-use tracing::{error, info};
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info, warn};
 
 mod api;
 mod config;
@@ -26,6 +41,9 @@ mod state;
 mod utils;
 
 pub async fn run_server() -> anyhow::Result<()> {
+    // Prometheus 메트릭 레코더 설치
+    init_metrics();
+
     let conn = establish_connection().await;
     let cloudflare_r2 = establish_r2_connection().await.map_err(|e| {
         error!("Failed to establish cloudflare_r2 connection: {}", e);
@@ -53,31 +71,128 @@ pub async fn run_server() -> anyhow::Result<()> {
         info!("Meilisearch posts index setup completed");
     }
 
+    // 종료 시 함께 멈추고 완료를 기다릴 백그라운드 작업
+    let tasks = BackgroundTasks::new();
+
+    // SIGHUP 시 재시작 없이 설정 재로딩
+    spawn_config_reloader(&tasks);
+
+    // 본문 통계 컬럼 추가 전에 작성된 포스트의 통계 계산 (시작 시 한 번)
+    spawn_content_stats_backfill(&tasks, conn.clone());
+
+    // 트렌딩 점수 주기적 갱신
+    spawn_trending_score_refresher(&tasks, conn.clone());
+
+    // Redis에 모인 조회수 주기적 반영 (버퍼링을 끈 뒤에도 남은 값은 반영)
+    spawn_view_buffer_flusher(&tasks, conn.clone(), redis.clone());
+
+    // 아웃박스에 기록된 검색/이메일 작업을 작업 큐(또는 태스크 서버)로 전달
+    spawn_outbox_dispatcher(&tasks, conn.clone(), redis.clone(), http_client.clone());
+
+    let state = AppState {
+        conn,
+        cloudflare_r2,
+        redis,
+        http_client,
+        meilisearch,
+    };
+
+    // 네이티브 작업 큐 사용 시 워커와 주기 작업 스케줄러 실행
+    if DbConfig::get().native_job_runner {
+        spawn_job_worker(&tasks, state.clone());
+        spawn_job_scheduler(&tasks, state.redis.clone());
+    }
+
+    // 모든 작업이 끝난 뒤 마지막에 닫기 위해 보관
+    let db = state.conn.clone();
+    let redis = state.redis.clone();
+
     let server_url = format!(
         "{}:{}",
         &DbConfig::get().server_host,
         &DbConfig::get().server_port
     );
+    // /metrics는 공개 포트가 아닌 내부 전용 리스너에서만 제공
+    let metrics_url = format!(
+        "{}:{}",
+        &DbConfig::get().metrics_host,
+        &DbConfig::get().metrics_port
+    );
+    let metrics_app = internal_routes().with_state(state.clone());
+
     let app = Router::new()
         .merge(api_routes())
+        // 요청 제한 미들웨어에서 사용
+        .layer(Extension(redis.clone()))
+        .layer(from_fn(http_metrics_middleware))
         .layer(cors_layer())
         .layer(CompressionLayer::new())
-        .with_state(AppState {
-            conn,
-            cloudflare_r2,
-            redis,
-            http_client,
-            meilisearch,
-        });
+        .layer(from_fn(request_id_middleware))
+        .with_state(state);
 
     info!("Starting server at: {}", server_url);
 
     let listener = tokio::net::TcpListener::bind(&server_url).await?;
-    axum::serve(
+    let metrics_listener = tokio::net::TcpListener::bind(&metrics_url).await?;
+    info!("Serving metrics at: {}", metrics_url);
+
+    let shutdown = CancellationToken::new();
+    tokio::spawn({
+        let shutdown = shutdown.clone();
+        async move {
+            shutdown_signal().await;
+            shutdown.cancel();
+        }
+    });
+
+    tokio::spawn({
+        let shutdown = shutdown.clone();
+        async move {
+            let result = axum::serve(metrics_listener, metrics_app)
+                .with_graceful_shutdown(shutdown.cancelled_owned())
+                .await;
+            if let Err(e) = result {
+                error!("Metrics server failed: {}", e);
+            }
+        }
+    });
+
+    // 종료 신호를 받으면 새 연결은 받지 않고 처리 중인 요청이 끝나기를 기다림
+    let drain_timeout = Duration::from_secs(DbConfig::get().shutdown_drain_timeout);
+    let server = axum::serve(
         listener,
         app.into_make_service_with_connect_info::<SocketAddr>(),
     )
-    .await?;
+    .with_graceful_shutdown(shutdown.clone().cancelled_owned())
+    .into_future();
+    let drain_deadline = async {
+        shutdown.cancelled().await;
+        tokio::time::sleep(drain_timeout).await;
+    };
+
+    tokio::select! {
+        result = server => result?,
+        _ = drain_deadline => warn!(
+            "In-flight requests did not finish within {}s, closing remaining connections",
+            drain_timeout.as_secs()
+        ),
+    }
+    info!("HTTP server stopped");
+
+    // 백그라운드 작업 종료 (조회수 버퍼 반영, 실행 중인 작업 완료 대기)
+    if !tasks.shutdown(drain_timeout).await {
+        warn!(
+            "Background tasks did not finish within {}s, abandoning them",
+            drain_timeout.as_secs()
+        );
+    }
+
+    // DB 풀과 Redis 연결은 마지막에 정리
+    if let Err(e) = db.close().await {
+        warn!("Failed to close database pool: {}", e);
+    }
+    drop(redis);
+    info!("Shutdown complete");
 
     Ok(())
 }
@@ -90,4 +205,7 @@ async fn main() {
     if let Err(err) = run_server().await {
         eprintln!("Application error: {}", err);
     }
+
+    // 버퍼에 남은 span 전송
+    shutdown_tracing();
 }
@@ -1,4 +1,6 @@
 use crate::config::db_config::DbConfig;
+use crate::utils::metrics::InstrumentedRequest;
+use crate::utils::trace_context::TracedRequest;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use std::sync::LazyLock;
@@ -65,7 +67,8 @@ pub async fn trigger_reindex_all_posts(
 
     let response = http_client
         .post(&format!("{}/tasks/search-reindex/reindex", task_server_url))
-        .send()
+        .with_trace_context()
+        .send_with_metrics("task_server", "trigger_reindex_all_posts")
         .await?;
 
     if !response.status().is_success() {
@@ -83,6 +86,25 @@ pub async fn trigger_reindex_all_posts(
     Ok(task_response)
 }
 
+/// 태스크 서버 헬스 체크
+pub async fn check_task_server_health(
+    http_client: &Client,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let task_server_url = get_task_server_url();
+
+    let response = http_client
+        .get(format!("{}/tasks/tasks/health", task_server_url))
+        .with_trace_context()
+        .send_with_metrics("task_server", "check_task_server_health")
+        .await?;
+
+    if !response.status().is_success() {
+        return Err(format!("Task server health check failed: {}", response.status()).into());
+    }
+
+    Ok(())
+}
+
 /// Meilisearch 헬스체크 실행
 pub async fn check_meilisearch_health(
     http_client: &Client,
@@ -92,7 +114,8 @@ pub async fn check_meilisearch_health(
 
     let response = http_client
         .get(&format!("{}/tasks/search-reindex/health", task_server_url))
-        .send()
+        .with_trace_context()
+        .send_with_metrics("task_server", "check_meilisearch_health")
         .await?;
 
     if !response.status().is_success() {
@@ -122,7 +145,8 @@ pub async fn get_search_stats(
 
     let response = http_client
         .get(&format!("{}/tasks/search-reindex/stats", task_server_url))
-        .send()
+        .with_trace_context()
+        .send_with_metrics("task_server", "get_search_stats")
         .await?;
 
     if !response.status().is_success() {
@@ -151,7 +175,8 @@ pub async fn sync_post_like_counts(
 
     let response = http_client
         .post(&format!("{}/tasks/count/sync/likes", task_server_url))
-        .send()
+        .with_trace_context()
+        .send_with_metrics("task_server", "sync_post_like_counts")
         .await?;
 
     if !response.status().is_success() {
@@ -178,7 +203,8 @@ pub async fn sync_user_follow_counts(
 
     let response = http_client
         .post(&format!("{}/tasks/count/sync/follows", task_server_url))
-        .send()
+        .with_trace_context()
+        .send_with_metrics("task_server", "sync_user_follow_counts")
         .await?;
 
     if !response.status().is_success() {
@@ -208,7 +234,8 @@ pub async fn sync_all_counts(
 
     let response = http_client
         .post(&format!("{}/tasks/count/sync/all", task_server_url))
-        .send()
+        .with_trace_context()
+        .send_with_metrics("task_server", "sync_all_counts")
         .await?;
 
     if !response.status().is_success() {
@@ -237,7 +264,8 @@ pub async fn cleanup_expired_refresh_tokens(
 
     let response = http_client
         .post(&format!("{}/tasks/token-cleanup/cleanup", task_server_url))
-        .send()
+        .with_trace_context()
+        .send_with_metrics("task_server", "cleanup_expired_refresh_tokens")
         .await?;
 
     if !response.status().is_success() {
@@ -267,7 +295,8 @@ pub async fn cleanup_old_system_events(
             "{}/tasks/token-cleanup/cleanup-events",
             task_server_url
         ))
-        .send()
+        .with_trace_context()
+        .send_with_metrics("task_server", "cleanup_old_system_events")
         .await?;
 
     if !response.status().is_success() {
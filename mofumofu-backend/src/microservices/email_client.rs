@@ -1,4 +1,6 @@
 use crate::config::db_config::DbConfig;
+use crate::utils::metrics::InstrumentedRequest;
+use crate::utils::trace_context::TracedRequest;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use std::sync::LazyLock;
@@ -45,7 +47,8 @@ pub async fn queue_send_email(
     let response = http_client
         .post(&format!("{}/tasks/email/send", task_server_url))
         .json(&request_body)
-        .send()
+        .with_trace_context()
+        .send_with_metrics("task_server", "queue_send_email")
         .await?;
 
     if !response.status().is_success() {
@@ -70,6 +73,7 @@ pub async fn queue_send_reset_password_email(
     email_to: &str,
     email: &str,
     token: &str,
+    idempotency_key: &str,
 ) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
     let task_server_url = get_task_server_url();
 
@@ -86,8 +90,10 @@ pub async fn queue_send_reset_password_email(
             "{}/tasks/email/send-reset-password",
             task_server_url
         ))
+        .header("Idempotency-Key", idempotency_key)
         .json(&request_body)
-        .send()
+        .with_trace_context()
+        .send_with_metrics("task_server", "queue_send_reset_password_email")
         .await?;
 
     if !response.status().is_success() {
@@ -118,6 +124,7 @@ pub async fn queue_send_email_verification(
     email_to: &str,
     username: &str,
     verification_token: &str,
+    idempotency_key: &str,
 ) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
     let task_server_url = get_task_server_url();
 
@@ -134,8 +141,10 @@ pub async fn queue_send_email_verification(
             "{}/tasks/email/send-verification",
             task_server_url
         ))
+        .header("Idempotency-Key", idempotency_key)
         .json(&request_body)
-        .send()
+        .with_trace_context()
+        .send_with_metrics("task_server", "queue_send_email_verification")
         .await?;
 
     if !response.status().is_success() {
@@ -174,7 +183,8 @@ pub async fn get_email_task_status(
             "{}/tasks/email/status/{}",
             task_server_url, task_id
         ))
-        .send()
+        .with_trace_context()
+        .send_with_metrics("task_server", "get_email_task_status")
         .await?;
 
     if !response.status().is_success() {
@@ -1,4 +1,6 @@
 use crate::config::db_config::DbConfig;
+use crate::utils::metrics::InstrumentedRequest;
+use crate::utils::trace_context::TracedRequest;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use std::sync::LazyLock;
@@ -79,6 +81,29 @@ fn get_tasks_service_url() -> &'static str {
     &TASKS_SERVICE_URL
 }
 
+/// 마크다운 서비스 헬스 체크
+pub async fn check_markdown_service_health(
+    http_client: &Client,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let service_url = get_markdown_service_url();
+
+    let response = http_client
+        .get(format!("{}/health", service_url))
+        .with_trace_context()
+        .send_with_metrics("markdown_service", "check_markdown_service_health")
+        .await?;
+
+    if !response.status().is_success() {
+        return Err(format!(
+            "Markdown service health check failed: {}",
+            response.status()
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
 /// 마크다운을 HTML로 렌더링
 pub async fn render_markdown(
     http_client: &Client,
@@ -95,7 +120,8 @@ pub async fn render_markdown(
     let response = http_client
         .post(&format!("{}/render", service_url))
         .json(&request)
-        .send()
+        .with_trace_context()
+        .send_with_metrics("markdown_service", "render_markdown")
         .await?;
 
     if !response.status().is_success() {
@@ -166,7 +192,8 @@ pub async fn queue_render_markdown(
     let response = http_client
         .post(&format!("{}/tasks/markdown/render", service_url))
         .json(&request)
-        .send()
+        .with_trace_context()
+        .send_with_metrics("task_server", "queue_render_markdown")
         .await?;
 
     if !response.status().is_success() {
@@ -1,4 +1,6 @@
 use crate::config::db_config::DbConfig;
+use crate::utils::metrics::InstrumentedRequest;
+use crate::utils::trace_context::TracedRequest;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use std::sync::LazyLock;
@@ -30,6 +32,7 @@ fn get_task_server_url() -> &'static str {
 pub async fn queue_index_post(
     http_client: &Client,
     post_id: &Uuid,
+    idempotency_key: &str,
 ) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
     let task_server_url = get_task_server_url();
 
@@ -41,8 +44,10 @@ pub async fn queue_index_post(
 
     let response = http_client
         .post(&format!("{}/tasks/search/index", task_server_url))
+        .header("Idempotency-Key", idempotency_key)
         .json(&request_body)
-        .send()
+        .with_trace_context()
+        .send_with_metrics("task_server", "queue_index_post")
         .await?;
 
     if !response.status().is_success() {
@@ -71,6 +76,7 @@ pub async fn queue_index_post(
 pub async fn queue_update_post(
     http_client: &Client,
     post_id: &Uuid,
+    idempotency_key: &str,
 ) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
     let task_server_url = get_task_server_url();
 
@@ -82,8 +88,10 @@ pub async fn queue_update_post(
 
     let response = http_client
         .put(&format!("{}/tasks/search/update", task_server_url))
+        .header("Idempotency-Key", idempotency_key)
         .json(&request_body)
-        .send()
+        .with_trace_context()
+        .send_with_metrics("task_server", "queue_update_post")
         .await?;
 
     if !response.status().is_success() {
@@ -106,6 +114,7 @@ pub async fn queue_update_post(
 pub async fn queue_delete_post(
     http_client: &Client,
     post_id: &Uuid,
+    idempotency_key: &str,
 ) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
     let task_server_url = get_task_server_url();
 
@@ -117,8 +126,10 @@ pub async fn queue_delete_post(
 
     let response = http_client
         .delete(&format!("{}/tasks/search/delete", task_server_url))
+        .header("Idempotency-Key", idempotency_key)
         .json(&request_body)
-        .send()
+        .with_trace_context()
+        .send_with_metrics("task_server", "queue_delete_post")
         .await?;
 
     if !response.status().is_success() {
@@ -1,19 +1,28 @@
 use crate::config::db_config::DbConfig;
-use axum::http::Method;
+use crate::middleware::rate_limit::{
+    RATE_LIMIT_LIMIT_HEADER, RATE_LIMIT_POLICY_HEADER, RATE_LIMIT_REMAINING_HEADER,
+    RATE_LIMIT_RESET_HEADER,
+};
+use crate::utils::trace_context::REQUEST_ID_HEADER;
+use axum::http::header::RETRY_AFTER;
+use axum::http::{HeaderName, Method};
 use tower_http::cors::{AllowHeaders, AllowOrigin, CorsLayer};
 use tracing::{info, warn};
 
 pub fn cors_layer() -> CorsLayer {
-    let allowed_origins = if DbConfig::get().cors_allowed_origins.is_empty() {
-        warn!("CORS_ALLOWED_ORIGINS is not set, allowing all origins.");
-        AllowOrigin::any()
+    if DbConfig::get().cors_allowed_origins.is_empty() {
+        warn!("CORS_ALLOWED_ORIGINS is not set, denying all cross-origin requests.");
     } else {
         info!(
             "CORS_ALLOWED_ORIGINS is set to {:?}",
             DbConfig::get().cors_allowed_origins
         );
-        AllowOrigin::list(DbConfig::get().cors_allowed_origins.clone())
-    };
+    }
+
+    // SIGHUP으로 설정을 다시 읽으면 바뀐 목록이 바로 적용되도록 요청마다 확인
+    // 자격 증명을 허용하므로 목록이 비어 있으면 모든 출처를 거부함
+    let allowed_origins =
+        AllowOrigin::predicate(|origin, _| DbConfig::get().cors_allowed_origins.contains(origin));
 
     let allowed_headers = if DbConfig::get().cors_allowed_headers.is_empty() {
         warn!("CORS_ALLOWED_HEADERS is not set, allowing all headers.");
@@ -39,6 +48,15 @@ pub fn cors_layer() -> CorsLayer {
             Method::PATCH,
         ])
         .allow_headers(allowed_headers)
+        // 프론트엔드에서 오류 문의 시 요청 ID와 요청 제한 상태를 확인할 수 있도록 노출
+        .expose_headers([
+            HeaderName::from_static(REQUEST_ID_HEADER),
+            HeaderName::from_static(RATE_LIMIT_LIMIT_HEADER),
+            HeaderName::from_static(RATE_LIMIT_REMAINING_HEADER),
+            HeaderName::from_static(RATE_LIMIT_RESET_HEADER),
+            HeaderName::from_static(RATE_LIMIT_POLICY_HEADER),
+            RETRY_AFTER,
+        ])
         .allow_origin(allowed_origins)
         .allow_credentials(true)
         .max_age(std::time::Duration::from_secs(max_age))
@@ -0,0 +1,54 @@
+use crate::config::db_config::DbConfig;
+use crate::utils::metrics::{
+    HTTP_REQUEST_DB_QUERIES, HTTP_REQUEST_DURATION_SECONDS, HTTP_REQUESTS_TOTAL,
+};
+use crate::utils::query_counter::count_queries;
+use axum::body::Body;
+use axum::extract::MatchedPath;
+use axum::http::Request;
+use axum::middleware::Next;
+use axum::response::Response;
+use metrics::{counter, histogram};
+use std::time::Instant;
+use tracing::warn;
+
+/// 라우트(매칭된 경로 패턴)와 상태 코드별 요청 수와 지연 시간 기록
+///
+/// 목록 API 등에서 N+1 쿼리가 다시 생기지 않도록 요청당 DB 쿼리 수도 함께 기록하고,
+/// 설정한 값을 넘으면 경고를 남김
+pub async fn http_metrics_middleware(req: Request<Body>, next: Next) -> Response {
+    // 실제 경로 대신 패턴을 라벨로 사용해 라벨 수가 늘어나지 않도록 함
+    let path = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|matched| matched.as_str().to_string())
+        .unwrap_or_else(|| "unmatched".to_string());
+    let method = req.method().to_string();
+
+    let started = Instant::now();
+    let (response, query_count) = count_queries(next.run(req)).await;
+    let elapsed = started.elapsed().as_secs_f64();
+
+    let threshold = DbConfig::get().db_query_warn_threshold;
+    if threshold > 0 && query_count > threshold {
+        warn!(
+            method = %method,
+            path = %path,
+            query_count,
+            threshold,
+            "Request ran more database queries than expected"
+        );
+    }
+
+    let labels = [
+        ("method", method),
+        ("path", path),
+        ("status", response.status().as_u16().to_string()),
+    ];
+
+    counter!(HTTP_REQUESTS_TOTAL, &labels).increment(1);
+    histogram!(HTTP_REQUEST_DURATION_SECONDS, &labels).record(elapsed);
+    histogram!(HTTP_REQUEST_DB_QUERIES, &labels).record(query_count as f64);
+
+    response
+}
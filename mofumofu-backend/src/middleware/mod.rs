@@ -1,3 +1,6 @@
 pub mod anonymous_user;
 pub mod auth;
 pub mod cors;
+pub mod metrics;
+pub mod rate_limit;
+pub mod request_id;
@@ -0,0 +1,98 @@
+use crate::config::db_config::DbConfig;
+use crate::connection::redis_connection::RedisConnection;
+use crate::dto::auth::internal::access_token::AccessTokenClaims;
+use crate::dto::rate_limit::internal::rate_limit_decision::RateLimitDecision;
+use crate::dto::rate_limit::internal::rate_limit_policy::{RateLimitKey, RateLimitPolicy};
+use crate::service::error::errors::Errors;
+use crate::service::rate_limit::check_rate_limit::service_check_rate_limit;
+use crate::utils::extract_ip_address::extract_ip_address;
+use crate::utils::metrics::RATE_LIMITED_REQUESTS_TOTAL;
+use axum::Extension;
+use axum::body::Body;
+use axum::extract::{ConnectInfo, State};
+use axum::http::header::RETRY_AFTER;
+use axum::http::{HeaderMap, HeaderName, HeaderValue, Request};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use metrics::counter;
+use std::net::SocketAddr;
+use tracing::warn;
+
+pub const RATE_LIMIT_LIMIT_HEADER: &str = "ratelimit-limit";
+pub const RATE_LIMIT_REMAINING_HEADER: &str = "ratelimit-remaining";
+pub const RATE_LIMIT_RESET_HEADER: &str = "ratelimit-reset";
+pub const RATE_LIMIT_POLICY_HEADER: &str = "ratelimit-policy";
+
+/// 라우트별 요청 제한 미들웨어
+///
+/// routes.rs에서 `route_layer(from_fn_with_state(POLICY, rate_limit))`로 사용하며,
+/// 사용자 ID 기준 정책은 인증 미들웨어가 먼저 실행되도록 그보다 안쪽에 둔다.
+/// Redis 오류 시에는 요청을 막지 않는다.
+pub async fn rate_limit(
+    State(policy): State<RateLimitPolicy>,
+    Extension(redis): Extension<RedisConnection>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    if !DbConfig::get().rate_limit_enabled {
+        return next.run(req).await;
+    }
+
+    let subject = rate_limit_subject(&policy, &req, addr);
+    let decision = match service_check_rate_limit(&redis, &policy, &subject).await {
+        Ok(decision) => decision,
+        Err(e) => {
+            warn!("Rate limit check failed for {}: {}", policy.name, e);
+            return next.run(req).await;
+        }
+    };
+
+    let mut response = if decision.allowed {
+        next.run(req).await
+    } else {
+        counter!(RATE_LIMITED_REQUESTS_TOTAL, "policy" => policy.name).increment(1);
+        let mut response = Errors::RateLimitExceeded.into_response();
+        response
+            .headers_mut()
+            .insert(RETRY_AFTER, HeaderValue::from(decision.reset_seconds));
+        response
+    };
+
+    insert_rate_limit_headers(response.headers_mut(), &decision);
+    response
+}
+
+/// 정책의 기준에 따라 요청을 구분할 값 (없으면 IP로 대체)
+fn rate_limit_subject(policy: &RateLimitPolicy, req: &Request<Body>, addr: SocketAddr) -> String {
+    match policy.key {
+        RateLimitKey::User => {
+            if let Some(claims) = req.extensions().get::<AccessTokenClaims>() {
+                return format!("user:{}", claims.sub);
+            }
+        }
+        RateLimitKey::Ip => {}
+    }
+
+    format!("ip:{}", extract_ip_address(req.headers(), addr))
+}
+
+fn insert_rate_limit_headers(headers: &mut HeaderMap, decision: &RateLimitDecision) {
+    headers.insert(
+        HeaderName::from_static(RATE_LIMIT_LIMIT_HEADER),
+        HeaderValue::from(decision.limit),
+    );
+    headers.insert(
+        HeaderName::from_static(RATE_LIMIT_REMAINING_HEADER),
+        HeaderValue::from(decision.remaining),
+    );
+    headers.insert(
+        HeaderName::from_static(RATE_LIMIT_RESET_HEADER),
+        HeaderValue::from(decision.reset_seconds),
+    );
+    if let Ok(value) =
+        HeaderValue::from_str(&format!("{};w={}", decision.limit, decision.window_seconds))
+    {
+        headers.insert(HeaderName::from_static(RATE_LIMIT_POLICY_HEADER), value);
+    }
+}
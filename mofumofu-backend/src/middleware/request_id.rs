@@ -0,0 +1,54 @@
+use crate::utils::trace_context::{HeaderExtractor, REQUEST_ID, REQUEST_ID_HEADER};
+use axum::body::Body;
+use axum::http::{HeaderValue, Request};
+use axum::middleware::Next;
+use axum::response::Response;
+use opentelemetry::global;
+use tracing::{Instrument, info_span};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use uuid::Uuid;
+
+/// 클라이언트가 보낸 요청 ID로 허용할 최대 길이
+const MAX_REQUEST_ID_LENGTH: usize = 128;
+
+/// 요청 ID를 받거나 생성해 로그 span, 오류 응답, 응답 헤더에 포함
+pub async fn request_id_middleware(req: Request<Body>, next: Next) -> Response {
+    let request_id = req
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|value| is_valid_request_id(value))
+        .map(str::to_string)
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+
+    let span = info_span!(
+        "request",
+        request_id = %request_id,
+        method = %req.method(),
+        path = %req.uri().path(),
+    );
+
+    // 상위 서비스에서 전달한 trace context가 있으면 이어서 기록
+    let parent_context = global::get_text_map_propagator(|propagator| {
+        propagator.extract(&HeaderExtractor(req.headers()))
+    });
+    span.set_parent(parent_context);
+
+    let mut response = REQUEST_ID
+        .scope(request_id.clone(), next.run(req).instrument(span))
+        .await;
+
+    if let Ok(value) = HeaderValue::from_str(&request_id) {
+        response.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+
+    response
+}
+
+fn is_valid_request_id(value: &str) -> bool {
+    !value.is_empty()
+        && value.len() <= MAX_REQUEST_ID_LENGTH
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
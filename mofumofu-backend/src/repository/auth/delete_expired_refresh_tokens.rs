@@ -0,0 +1,27 @@
+use crate::entity::user_refresh_tokens::{
+    Column as RefreshTokenColumn, Entity as RefreshTokenEntity,
+};
+use chrono::Utc;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+
+/// 만료되었거나 폐기된 리프레시 토큰 삭제
+///
+/// (만료로 삭제된 수, 폐기로 삭제된 수)를 반환
+pub async fn repository_delete_expired_refresh_tokens<C>(
+    conn: &C,
+) -> Result<(u64, u64), sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let expired = RefreshTokenEntity::delete_many()
+        .filter(RefreshTokenColumn::ExpiresAt.lt(Utc::now()))
+        .exec(conn)
+        .await?;
+
+    let revoked = RefreshTokenEntity::delete_many()
+        .filter(RefreshTokenColumn::RevokedAt.is_not_null())
+        .exec(conn)
+        .await?;
+
+    Ok((expired.rows_affected, revoked.rows_affected))
+}
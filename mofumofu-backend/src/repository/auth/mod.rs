@@ -1,3 +1,4 @@
 pub mod create_refresh_token;
+pub mod delete_expired_refresh_tokens;
 pub mod find_refresh_token_by_jti_and_token;
 pub mod revoke_refresh_token;
@@ -7,6 +7,7 @@ pub async fn repository_create_comment<C>(
     user_id: Uuid,
     post_id: Uuid,
     content: &str,
+    render: &str,
     parent_id: Option<Uuid>,
 ) -> Result<CommentModel, sea_orm::DbErr>
 where
@@ -15,6 +16,7 @@ where
     let new_comment = CommentActiveModel {
         id: Default::default(),
         content: Set(content.to_string()),
+        render: Set(Some(render.to_string())),
         post_id: Set(post_id),
         user_id: Set(user_id),
         parent_id: Set(parent_id),
@@ -23,6 +25,11 @@ where
         is_deleted: Set(false),
         like_count: Set(0),
         reply_count: Set(0),
+        is_pinned: Set(false),
+        pinned_at: Set(None),
+        is_hidden: Set(false),
+        is_locked: Set(false),
+        edit_count: Set(0),
     };
 
     let created_comment = new_comment.insert(conn).await?;
@@ -0,0 +1,27 @@
+use crate::entity::comment_edits::{
+    ActiveModel as CommentEditActiveModel, Model as CommentEditModel,
+};
+use chrono::{DateTime, Utc};
+use sea_orm::{ActiveModelTrait, ConnectionTrait, Set};
+use uuid::Uuid;
+
+/// 수정 전 댓글 내용을 기록
+pub async fn repository_create_comment_edit<C>(
+    conn: &C,
+    comment_id: Uuid,
+    previous_content: &str,
+    written_at: DateTime<Utc>,
+) -> Result<CommentEditModel, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let new_edit = CommentEditActiveModel {
+        id: Default::default(),
+        comment_id: Set(comment_id),
+        content: Set(previous_content.to_string()),
+        written_at: Set(written_at),
+        edited_at: Set(Utc::now()),
+    };
+
+    new_edit.insert(conn).await
+}
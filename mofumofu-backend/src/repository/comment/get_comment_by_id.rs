@@ -1,7 +1,7 @@
 use crate::entity::comments::{
     Column as CommentColumn, Entity as CommentEntity, Model as CommentModel,
 };
-use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+use sea_orm::{ColumnTrait, ConnectionTrait, DbBackend, EntityTrait, QueryFilter, Statement};
 use uuid::Uuid;
 
 pub async fn repository_get_comment_by_id<C>(
@@ -18,3 +18,56 @@ where
 
     Ok(comment)
 }
+
+/// 여러 댓글을 한 번에 조회 (존재하지 않는 ID는 무시)
+pub async fn repository_get_comments_by_ids<C>(
+    conn: &C,
+    comment_ids: &[Uuid],
+) -> Result<Vec<CommentModel>, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    if comment_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let comments = CommentEntity::find()
+        .filter(CommentColumn::Id.is_in(comment_ids.iter().cloned()))
+        .all(conn)
+        .await?;
+
+    Ok(comments)
+}
+
+/// 댓글과 그 상위 댓글들을 한 번의 재귀 쿼리로 조회 (자기 자신부터 최상위 댓글 순)
+pub async fn repository_get_comment_with_ancestors<C>(
+    conn: &C,
+    comment_id: Uuid,
+) -> Result<Vec<CommentModel>, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let sql = r#"
+        WITH RECURSIVE ancestors AS (
+            SELECT c.*, 0 AS depth
+            FROM comments c
+            WHERE c.id = $1
+            UNION ALL
+            SELECT c.*, a.depth + 1
+            FROM comments c
+            JOIN ancestors a ON c.id = a.parent_id
+        )
+        SELECT * FROM ancestors ORDER BY depth
+    "#;
+
+    let comments = CommentEntity::find()
+        .from_raw_sql(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            sql,
+            [comment_id.into()],
+        ))
+        .all(conn)
+        .await?;
+
+    Ok(comments)
+}
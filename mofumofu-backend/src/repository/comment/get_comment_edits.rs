@@ -0,0 +1,20 @@
+use crate::entity::comment_edits::{
+    Column as CommentEditColumn, Entity as CommentEditEntity, Model as CommentEditModel,
+};
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder};
+use uuid::Uuid;
+
+/// 댓글의 수정 기록 (최근 수정 순)
+pub async fn repository_get_comment_edits<C>(
+    conn: &C,
+    comment_id: Uuid,
+) -> Result<Vec<CommentEditModel>, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    CommentEditEntity::find()
+        .filter(CommentEditColumn::CommentId.eq(comment_id))
+        .order_by_desc(CommentEditColumn::EditedAt)
+        .all(conn)
+        .await
+}
@@ -0,0 +1,104 @@
+use crate::dto::comment::internal::comment_tree_query::CommentTreeQuery;
+use crate::dto::comment::internal::comment_tree_row::CommentTreeRow;
+use crate::dto::comment::internal::hidden_comment_visibility::HiddenCommentVisibility;
+use crate::dto::comment::request::get_comments::CommentSortOrder;
+use sea_orm::{ConnectionTrait, DbBackend, FromQueryResult, Statement};
+
+const COMMENT_TREE_COLUMNS: &str = "c.id, c.content, c.render, c.post_id, c.user_id, c.parent_id, \
+     c.created_at, c.updated_at, c.is_deleted, c.like_count, c.reply_count, c.is_pinned, \
+     c.pinned_at, c.is_hidden, c.is_locked, c.edit_count";
+
+/// 정렬 순서에 맞는 ORDER BY 절 (같은 값일 때는 id로 고정)
+fn comment_tree_order(sort: &CommentSortOrder) -> &'static str {
+    match sort {
+        CommentSortOrder::Latest => "c.created_at DESC, c.id DESC",
+        CommentSortOrder::Oldest => "c.created_at ASC, c.id ASC",
+        CommentSortOrder::Popular => "c.like_count DESC, c.created_at DESC, c.id DESC",
+    }
+}
+
+/// 숨긴 댓글 필터 ($5: 모두 보기, $6: 본인 댓글 ID)
+fn comment_tree_visible(alias: &str) -> String {
+    format!("(NOT {alias}.is_hidden OR $5 OR {alias}.user_id = $6)")
+}
+
+/// 부모 댓글(없으면 최상위) 아래의 댓글 트리를 한 번의 재귀 쿼리로 조회
+///
+/// 첫 단계는 offset/limit, 그 아래 단계는 부모마다 replies_per_level개까지만 가져오며
+/// 각 행에 조회자에게 보이는 직속 답글 수를 함께 반환함
+pub async fn repository_get_comment_tree<C>(
+    conn: &C,
+    query: CommentTreeQuery<'_>,
+) -> Result<Vec<CommentTreeRow>, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let order = comment_tree_order(query.sort);
+    let (show_all_hidden, hidden_owner_id) = match query.visibility {
+        HiddenCommentVisibility::All => (true, None),
+        HiddenCommentVisibility::OwnOnly(viewer_id) => (false, Some(viewer_id)),
+        HiddenCommentVisibility::Nothing => (false, None),
+    };
+
+    let sql = format!(
+        r#"
+        WITH RECURSIVE tree AS (
+            SELECT anchor.*, 0 AS depth
+            FROM (
+                SELECT {columns},
+                       ROW_NUMBER() OVER (
+                           ORDER BY c.is_pinned DESC, c.pinned_at DESC NULLS LAST, {order}
+                       ) AS position
+                FROM comments c
+                WHERE c.post_id = $1
+                  AND (($2::uuid IS NULL AND c.parent_id IS NULL) OR c.parent_id = $2)
+                  AND {visible_c}
+                ORDER BY position
+                OFFSET $3 LIMIT $4
+            ) anchor
+            UNION ALL
+            SELECT reply.*, tree.depth + 1
+            FROM tree
+            CROSS JOIN LATERAL (
+                SELECT {columns},
+                       ROW_NUMBER() OVER (ORDER BY {order}) AS position
+                FROM comments c
+                WHERE c.parent_id = tree.id
+                  AND {visible_c}
+                ORDER BY position
+                LIMIT $7
+            ) reply
+            WHERE tree.depth < $8
+        )
+        SELECT tree.*,
+               (
+                   SELECT COUNT(*)
+                   FROM comments child
+                   WHERE child.parent_id = tree.id
+                     AND {visible_child}
+               ) AS child_count
+        FROM tree
+        "#,
+        columns = COMMENT_TREE_COLUMNS,
+        order = order,
+        visible_c = comment_tree_visible("c"),
+        visible_child = comment_tree_visible("child"),
+    );
+
+    let statement = Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        sql,
+        [
+            query.post_id.into(),
+            query.parent_id.into(),
+            (query.offset as i64).into(),
+            (query.limit as i64).into(),
+            show_all_hidden.into(),
+            hidden_owner_id.into(),
+            (query.replies_per_level as i64).into(),
+            (query.depth as i32).into(),
+        ],
+    );
+
+    CommentTreeRow::find_by_statement(statement).all(conn).await
+}
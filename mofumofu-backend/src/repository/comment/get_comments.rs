@@ -1,18 +1,32 @@
+use crate::dto::comment::internal::hidden_comment_visibility::HiddenCommentVisibility;
 use crate::dto::comment::request::get_comments::CommentSortOrder;
 use crate::entity::comments::{
     Column as CommentColumn, Entity as CommentEntity, Model as CommentModel,
 };
 use sea_orm::{
-    ColumnTrait, ConnectionTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect,
+    ColumnTrait, Condition, ConnectionTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    QuerySelect,
 };
 use uuid::Uuid;
 
+/// 숨긴 댓글 필터 조건
+fn hidden_comment_condition(visibility: HiddenCommentVisibility) -> Condition {
+    match visibility {
+        HiddenCommentVisibility::All => Condition::all(),
+        HiddenCommentVisibility::OwnOnly(viewer_id) => Condition::any()
+            .add(CommentColumn::IsHidden.eq(false))
+            .add(CommentColumn::UserId.eq(viewer_id)),
+        HiddenCommentVisibility::Nothing => Condition::all().add(CommentColumn::IsHidden.eq(false)),
+    }
+}
+
 pub async fn repository_get_comments<C>(
     conn: &C,
     post_id: Uuid,
     page: u32,
     per_page: u32,
     sort: CommentSortOrder,
+    visibility: HiddenCommentVisibility,
 ) -> Result<Vec<CommentModel>, sea_orm::DbErr>
 where
     C: ConnectionTrait,
@@ -21,7 +35,11 @@ where
 
     let mut query = CommentEntity::find()
         .filter(CommentColumn::PostId.eq(post_id))
-        .filter(CommentColumn::ParentId.is_null()); // 부모 댓글만
+        .filter(CommentColumn::ParentId.is_null()) // 부모 댓글만
+        .filter(hidden_comment_condition(visibility))
+        // 고정 댓글은 정렬과 관계없이 먼저 (최근에 고정한 순)
+        .order_by_desc(CommentColumn::IsPinned)
+        .order_by_desc(CommentColumn::PinnedAt);
 
     // 정렬 적용
     query = match sort {
@@ -45,13 +63,16 @@ pub async fn repository_get_replies<C>(
     page: u32,
     per_page: u32,
     sort: CommentSortOrder,
+    visibility: HiddenCommentVisibility,
 ) -> Result<Vec<CommentModel>, sea_orm::DbErr>
 where
     C: ConnectionTrait,
 {
     let offset = (page - 1) * per_page;
 
-    let mut query = CommentEntity::find().filter(CommentColumn::ParentId.eq(parent_comment_id));
+    let mut query = CommentEntity::find()
+        .filter(CommentColumn::ParentId.eq(parent_comment_id))
+        .filter(hidden_comment_condition(visibility));
 
     // 정렬 적용
     query = match sort {
@@ -69,13 +90,18 @@ where
     Ok(replies)
 }
 
-pub async fn repository_count_comments<C>(conn: &C, post_id: Uuid) -> Result<u64, sea_orm::DbErr>
+pub async fn repository_count_comments<C>(
+    conn: &C,
+    post_id: Uuid,
+    visibility: HiddenCommentVisibility,
+) -> Result<u64, sea_orm::DbErr>
 where
     C: ConnectionTrait,
 {
     let count = CommentEntity::find()
         .filter(CommentColumn::PostId.eq(post_id))
         .filter(CommentColumn::ParentId.is_null()) // 부모 댓글만
+        .filter(hidden_comment_condition(visibility))
         .count(conn)
         .await?;
 
@@ -85,12 +111,14 @@ where
 pub async fn repository_count_replies<C>(
     conn: &C,
     parent_comment_id: Uuid,
+    visibility: HiddenCommentVisibility,
 ) -> Result<u64, sea_orm::DbErr>
 where
     C: ConnectionTrait,
 {
     let count = CommentEntity::find()
         .filter(CommentColumn::ParentId.eq(parent_comment_id))
+        .filter(hidden_comment_condition(visibility))
         .count(conn)
         .await?;
 
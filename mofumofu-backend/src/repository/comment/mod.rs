@@ -1,17 +1,25 @@
 pub mod create_comment;
+pub mod create_comment_edit;
 pub mod delete_comment;
 pub mod get_comment_by_id;
+pub mod get_comment_edits;
+pub mod get_comment_tree;
 pub mod get_comments;
 pub mod get_reply_count;
 pub mod update_comment;
 pub mod update_like_count;
+pub mod update_moderation;
 pub mod update_reply_count;
 
 pub use create_comment::*;
+pub use create_comment_edit::*;
 pub use delete_comment::*;
 pub use get_comment_by_id::*;
+pub use get_comment_edits::*;
+pub use get_comment_tree::*;
 pub use get_comments::*;
 pub use get_reply_count::*;
 pub use update_comment::*;
 pub use update_like_count::*;
+pub use update_moderation::*;
 pub use update_reply_count::*;
@@ -8,6 +8,7 @@ pub async fn repository_update_comment<C>(
     conn: &C,
     comment_id: Uuid,
     content: &str,
+    render: &str,
 ) -> Result<CommentModel, sea_orm::DbErr>
 where
     C: ConnectionTrait,
@@ -19,8 +20,11 @@ where
             "Comment not found".to_string(),
         ))?;
 
+    let edit_count = comment.edit_count;
     let mut comment_active: CommentActiveModel = comment.into();
     comment_active.content = Set(content.to_string());
+    comment_active.render = Set(Some(render.to_string()));
+    comment_active.edit_count = Set(edit_count + 1);
     comment_active.updated_at = Set(Some(chrono::Utc::now()));
 
     let updated_comment = comment_active.update(conn).await?;
@@ -0,0 +1,78 @@
+use crate::entity::comments::{Column as CommentColumn, Entity as CommentEntity};
+use sea_orm::sea_query::Expr;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, PaginatorTrait, QueryFilter};
+use uuid::Uuid;
+
+pub async fn repository_set_comment_pinned<C>(
+    conn: &C,
+    comment_id: &Uuid,
+    pinned: bool,
+) -> Result<(), sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let pinned_at = pinned.then(chrono::Utc::now);
+
+    CommentEntity::update_many()
+        .filter(CommentColumn::Id.eq(*comment_id))
+        .col_expr(CommentColumn::IsPinned, Expr::value(pinned))
+        .col_expr(CommentColumn::PinnedAt, Expr::value(pinned_at))
+        .exec(conn)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn repository_set_comment_hidden<C>(
+    conn: &C,
+    comment_id: &Uuid,
+    hidden: bool,
+) -> Result<(), sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    CommentEntity::update_many()
+        .filter(CommentColumn::Id.eq(*comment_id))
+        .col_expr(CommentColumn::IsHidden, Expr::value(hidden))
+        .exec(conn)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn repository_set_comment_locked<C>(
+    conn: &C,
+    comment_id: &Uuid,
+    locked: bool,
+) -> Result<(), sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    CommentEntity::update_many()
+        .filter(CommentColumn::Id.eq(*comment_id))
+        .col_expr(CommentColumn::IsLocked, Expr::value(locked))
+        .exec(conn)
+        .await?;
+
+    Ok(())
+}
+
+/// 포스트에 고정된 댓글 수 (삭제된 댓글과 제외할 댓글은 세지 않음)
+pub async fn repository_count_pinned_comments<C>(
+    conn: &C,
+    post_id: &Uuid,
+    exclude_comment_id: &Uuid,
+) -> Result<u64, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let count = CommentEntity::find()
+        .filter(CommentColumn::PostId.eq(*post_id))
+        .filter(CommentColumn::IsPinned.eq(true))
+        .filter(CommentColumn::IsDeleted.eq(false))
+        .filter(CommentColumn::Id.ne(*exclude_comment_id))
+        .count(conn)
+        .await?;
+
+    Ok(count)
+}
@@ -1,16 +1,13 @@
 use crate::entity::common::{ActionType, TargetType};
-use crate::entity::hash_tags::{
-    ActiveModel as HashTagActiveModel, Column as HashTagColumn, Entity as HashTagEntity,
-};
+use crate::entity::hash_tags::ActiveModel as HashTagActiveModel;
 use crate::entity::post_hash_tags::{
     ActiveModel as PostHashTagActiveModel, Entity as PostHashTagEntity,
 };
+use crate::repository::hashtag::find_hashtag_by_name::repository_find_hashtag_by_normalized_name;
 use crate::repository::system_events::log_event::repository_log_event;
 use crate::service::error::errors::Errors;
 use crate::utils::hashtag_normalizer::normalize_hashtag;
-use sea_orm::{
-    ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, Set, TransactionTrait,
-};
+use sea_orm::{ActiveModelTrait, ConnectionTrait, Set, TransactionTrait};
 use uuid::Uuid;
 
 pub async fn repository_associate_post_hashtags<C>(
@@ -32,41 +29,46 @@ where
             continue;
         }
 
-        let hashtag = match HashTagEntity::find()
-            .filter(HashTagColumn::Name.eq(&normalized_name))
-            .one(&txn)
-            .await?
-        {
-            Some(tag) => {
-                let mut updated_tag: HashTagActiveModel = tag.clone().into();
-                updated_tag.usage_count = Set(tag.usage_count + 1);
-                updated_tag.last_used_at = Set(Some(chrono::Utc::now()));
-                updated_tag.update(&txn).await?
-            }
-            None => {
-                let new_hashtag = HashTagActiveModel {
-                    id: Set(Uuid::new_v4()),
-                    name: Set(normalized_name),
-                    usage_count: Set(1),
-                    created_at: Set(chrono::Utc::now()),
-                    last_used_at: Set(Some(chrono::Utc::now())),
-                };
-                let created_hashtag = new_hashtag.insert(&txn).await?;
+        let hashtag =
+            match repository_find_hashtag_by_normalized_name(&txn, &normalized_name).await? {
+                // 금지된 해시태그는 붙이지 않음
+                Some(tag) if tag.is_banned => continue,
+                // 별칭으로 같은 해시태그가 여러 번 들어온 경우
+                Some(tag) if hashtag_ids.contains(&tag.id) => continue,
+                Some(tag) => {
+                    let mut updated_tag: HashTagActiveModel = tag.clone().into();
+                    updated_tag.usage_count = Set(tag.usage_count + 1);
+                    updated_tag.last_used_at = Set(Some(chrono::Utc::now()));
+                    updated_tag.update(&txn).await?
+                }
+                None => {
+                    let new_hashtag = HashTagActiveModel {
+                        id: Set(Uuid::new_v4()),
+                        name: Set(normalized_name),
+                        usage_count: Set(1),
+                        created_at: Set(chrono::Utc::now()),
+                        last_used_at: Set(Some(chrono::Utc::now())),
+                        follower_count: Set(0),
+                        description: Set(None),
+                        cover_image: Set(None),
+                        is_banned: Set(false),
+                    };
+                    let created_hashtag = new_hashtag.insert(&txn).await?;
 
-                // 새 해시태그 생성 이벤트 로깅
-                repository_log_event(
-                    conn,
-                    Some(user_id),
-                    ActionType::HashtagCreated,
-                    Some(created_hashtag.id),
-                    Some(TargetType::Hashtag),
-                    None,
-                )
-                .await;
+                    // 새 해시태그 생성 이벤트 로깅
+                    repository_log_event(
+                        conn,
+                        Some(user_id),
+                        ActionType::HashtagCreated,
+                        Some(created_hashtag.id),
+                        Some(TargetType::Hashtag),
+                        None,
+                    )
+                    .await;
 
-                created_hashtag
-            }
-        };
+                    created_hashtag
+                }
+            };
 
         let post_hashtag = PostHashTagActiveModel {
             id: Set(Uuid::new_v4()),
@@ -0,0 +1,43 @@
+use crate::entity::hash_tags::{Column, Entity as HashTagEntity, Model as HashTagModel};
+use crate::entity::hashtag_aliases::{Column as AliasColumn, Entity as HashtagAliasEntity};
+use crate::service::error::errors::Errors;
+use crate::utils::hashtag_normalizer::normalize_hashtag;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+
+/// 정규화한 이름으로 해시태그 조회 (별칭이면 대표 해시태그를 반환)
+pub async fn repository_find_hashtag_by_name<C>(
+    conn: &C,
+    tag_name: &str,
+) -> Result<Option<HashTagModel>, Errors>
+where
+    C: ConnectionTrait,
+{
+    let normalized_name = normalize_hashtag(tag_name);
+
+    repository_find_hashtag_by_normalized_name(conn, &normalized_name).await
+}
+
+/// 이미 정규화된 이름으로 해시태그 조회 (별칭 포함)
+pub async fn repository_find_hashtag_by_normalized_name<C>(
+    conn: &C,
+    normalized_name: &str,
+) -> Result<Option<HashTagModel>, Errors>
+where
+    C: ConnectionTrait,
+{
+    if let Some(hashtag) = HashTagEntity::find()
+        .filter(Column::Name.eq(normalized_name))
+        .one(conn)
+        .await?
+    {
+        return Ok(Some(hashtag));
+    }
+
+    let alias = HashtagAliasEntity::find()
+        .filter(AliasColumn::Alias.eq(normalized_name))
+        .find_also_related(HashTagEntity)
+        .one(conn)
+        .await?;
+
+    Ok(alias.and_then(|(_, hashtag)| hashtag))
+}
@@ -1,9 +1,8 @@
-use crate::entity::hash_tags::{
-    ActiveModel as HashTagActiveModel, Column, Entity as HashTagEntity, Model as HashTagModel,
-};
+use crate::entity::hash_tags::{ActiveModel as HashTagActiveModel, Model as HashTagModel};
+use crate::repository::hashtag::find_hashtag_by_name::repository_find_hashtag_by_normalized_name;
 use crate::service::error::errors::Errors;
 use crate::utils::hashtag_normalizer::normalize_hashtag;
-use sea_orm::{ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, Set};
+use sea_orm::{ActiveModelTrait, ConnectionTrait, Set};
 use uuid::Uuid;
 
 pub async fn repository_find_or_create_hashtag<C>(
@@ -15,10 +14,8 @@ where
 {
     let normalized_name = normalize_hashtag(tag_name);
 
-    if let Some(existing_tag) = HashTagEntity::find()
-        .filter(Column::Name.eq(&normalized_name))
-        .one(conn)
-        .await?
+    if let Some(existing_tag) =
+        repository_find_hashtag_by_normalized_name(conn, &normalized_name).await?
     {
         return Ok(existing_tag);
     }
@@ -29,6 +26,10 @@ where
         usage_count: Set(1),
         created_at: Set(chrono::Utc::now()),
         last_used_at: Set(Some(chrono::Utc::now())),
+        follower_count: Set(0),
+        description: Set(None),
+        cover_image: Set(None),
+        is_banned: Set(false),
     };
 
     let created_tag = new_hashtag.insert(conn).await?;
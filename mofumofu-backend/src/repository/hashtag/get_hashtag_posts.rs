@@ -0,0 +1,123 @@
+use crate::dto::hashtag::internal::hashtag_post_cursor::HashtagPostCursor;
+use crate::dto::hashtag::request::hashtag_detail::HashtagPostSort;
+use crate::entity::post_hash_tags::{Column as PostHashTagColumn, Entity as PostHashTagEntity};
+use crate::entity::posts::{Column as PostColumn, Entity as PostEntity, Model as PostModel};
+use crate::service::error::errors::Errors;
+use sea_orm::sea_query::{Expr, Query, SelectStatement};
+use sea_orm::{
+    ColumnTrait, Condition, ConnectionTrait, EntityTrait, Order, PaginatorTrait, QueryFilter,
+    QueryOrder, QuerySelect,
+};
+use uuid::Uuid;
+
+/// 해시태그 중 하나라도 달린 포스트 ID 서브쿼리
+fn tagged_post_ids(hash_tag_ids: &[Uuid]) -> SelectStatement {
+    Query::select()
+        .column(PostHashTagColumn::PostId)
+        .from(PostHashTagEntity)
+        .and_where(PostHashTagColumn::HashTagId.is_in(hash_tag_ids.iter().cloned()))
+        .to_owned()
+}
+
+/// 커서 이후의 포스트 조건 (정렬 순서와 같은 기준)
+fn after_cursor(sort: &HashtagPostSort, cursor: &HashtagPostCursor) -> Condition {
+    let same_time_before = Condition::all()
+        .add(PostColumn::CreatedAt.eq(cursor.created_at))
+        .add(PostColumn::Id.lt(cursor.id));
+    let before = Condition::any()
+        .add(PostColumn::CreatedAt.lt(cursor.created_at))
+        .add(same_time_before);
+
+    match sort {
+        HashtagPostSort::Latest => before,
+        HashtagPostSort::Popular => Condition::any()
+            .add(PostColumn::LikeCount.lt(cursor.like_count))
+            .add(
+                Condition::all()
+                    .add(PostColumn::LikeCount.eq(cursor.like_count))
+                    .add(before),
+            ),
+        HashtagPostSort::Trending => Condition::any()
+            .add(PostColumn::TrendingScore.lt(cursor.trending_score))
+            .add(
+                Condition::all()
+                    .add(PostColumn::TrendingScore.eq(cursor.trending_score))
+                    .add(before),
+            ),
+    }
+}
+
+/// 주어진 해시태그가 달린 포스트를 keyset 방식으로 조회
+pub async fn repository_get_posts_by_hashtags<C>(
+    conn: &C,
+    hash_tag_ids: &[Uuid],
+    sort: &HashtagPostSort,
+    cursor: Option<&HashtagPostCursor>,
+    limit: u64,
+) -> Result<Vec<PostModel>, Errors>
+where
+    C: ConnectionTrait,
+{
+    if hash_tag_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut query = PostEntity::find()
+        .filter(Expr::col(PostColumn::Id).in_subquery(tagged_post_ids(hash_tag_ids)));
+
+    if let Some(cursor) = cursor {
+        query = query.filter(after_cursor(sort, cursor));
+    }
+
+    match sort {
+        HashtagPostSort::Latest => {}
+        HashtagPostSort::Popular => query = query.order_by(PostColumn::LikeCount, Order::Desc),
+        HashtagPostSort::Trending => query = query.order_by(PostColumn::TrendingScore, Order::Desc),
+    }
+
+    let posts = query
+        .order_by_desc(PostColumn::CreatedAt)
+        .order_by_desc(PostColumn::Id)
+        .limit(limit)
+        .all(conn)
+        .await?;
+
+    Ok(posts)
+}
+
+/// 해시태그가 달린 포스트 수
+pub async fn repository_count_posts_by_hashtag<C>(
+    conn: &C,
+    hash_tag_id: Uuid,
+) -> Result<u64, Errors>
+where
+    C: ConnectionTrait,
+{
+    Ok(PostHashTagEntity::find()
+        .filter(PostHashTagColumn::HashTagId.eq(hash_tag_id))
+        .count(conn)
+        .await?)
+}
+
+/// 해시태그로 포스트를 가장 많이 작성한 사용자 (user_id, 포스트 수)
+pub async fn repository_get_hashtag_top_authors<C>(
+    conn: &C,
+    hash_tag_id: Uuid,
+    limit: u64,
+) -> Result<Vec<(Uuid, i64)>, Errors>
+where
+    C: ConnectionTrait,
+{
+    Ok(PostEntity::find()
+        .filter(Expr::col(PostColumn::Id).in_subquery(tagged_post_ids(&[hash_tag_id])))
+        .select_only()
+        .column(PostColumn::UserId)
+        .column_as(PostColumn::Id.count(), "post_count")
+        .group_by(PostColumn::UserId)
+        .order_by_desc(PostColumn::Id.count())
+        .order_by_desc(PostColumn::CreatedAt.max())
+        .limit(limit)
+        .into_tuple::<(Uuid, i64)>()
+        .all(conn)
+        .await?)
+}
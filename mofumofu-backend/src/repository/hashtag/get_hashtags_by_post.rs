@@ -19,6 +19,7 @@ where
             crate::entity::hash_tags::Relation::PostHashTags.def(),
         )
         .filter(PostHashTagColumn::PostId.eq(post_id))
+        .filter(crate::entity::hash_tags::Column::IsBanned.eq(false))
         .all(conn)
         .await?;
 
@@ -40,8 +41,11 @@ where
         tag_id: Uuid,
         tag_name: String,
         usage_count: i32,
+        follower_count: i32,
         created_at: chrono::DateTime<chrono::Utc>,
         last_used_at: Option<chrono::DateTime<chrono::Utc>>,
+        description: Option<String>,
+        cover_image: Option<String>,
     }
 
     let results: Vec<PostHashTagResult> = PostHashTagEntity::find()
@@ -50,13 +54,20 @@ where
             crate::entity::post_hash_tags::Relation::HashTag.def(),
         )
         .filter(PostHashTagColumn::PostId.is_in(post_ids.iter().cloned()))
+        .filter(crate::entity::hash_tags::Column::IsBanned.eq(false))
         .select_only()
         .column(PostHashTagColumn::PostId)
         .column_as(crate::entity::hash_tags::Column::Id, "tag_id")
         .column_as(crate::entity::hash_tags::Column::Name, "tag_name")
         .column_as(crate::entity::hash_tags::Column::UsageCount, "usage_count")
+        .column_as(
+            crate::entity::hash_tags::Column::FollowerCount,
+            "follower_count",
+        )
         .column_as(crate::entity::hash_tags::Column::CreatedAt, "created_at")
         .column_as(crate::entity::hash_tags::Column::LastUsedAt, "last_used_at")
+        .column_as(crate::entity::hash_tags::Column::Description, "description")
+        .column_as(crate::entity::hash_tags::Column::CoverImage, "cover_image")
         .into_model::<PostHashTagResult>()
         .all(conn)
         .await?;
@@ -69,8 +80,12 @@ where
             id: result.tag_id,
             name: result.tag_name,
             usage_count: result.usage_count,
+            follower_count: result.follower_count,
             created_at: result.created_at,
             last_used_at: result.last_used_at,
+            description: result.description,
+            cover_image: result.cover_image,
+            is_banned: false,
         };
 
         post_hashtags
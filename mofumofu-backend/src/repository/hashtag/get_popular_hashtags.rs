@@ -16,6 +16,7 @@ where
     C: ConnectionTrait,
 {
     let hashtags = HashTagEntity::find()
+        .filter(Column::IsBanned.eq(false))
         .order_by_desc(Column::UsageCount)
         .limit(limit)
         .all(conn)
@@ -32,6 +33,7 @@ where
     C: ConnectionTrait,
 {
     let hashtags = HashTagEntity::find()
+        .filter(Column::IsBanned.eq(false))
         .order_by_desc(Column::LastUsedAt)
         .limit(limit)
         .all(conn)
@@ -68,6 +70,7 @@ where
     // 해당 해시태그들의 정보를 가져오기
     let hashtags = HashTagEntity::find()
         .filter(Column::Id.is_in(hashtag_ids))
+        .filter(Column::IsBanned.eq(false))
         .order_by_desc(Column::UsageCount)
         .all(conn)
         .await?;
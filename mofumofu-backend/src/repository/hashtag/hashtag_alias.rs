@@ -0,0 +1,78 @@
+use crate::entity::hashtag_aliases::{
+    ActiveModel as HashtagAliasActiveModel, Column as HashtagAliasColumn,
+    Entity as HashtagAliasEntity, Model as HashtagAliasModel,
+};
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder, Set,
+};
+use uuid::Uuid;
+
+pub async fn repository_create_hashtag_alias<C>(
+    conn: &C,
+    alias: &str,
+    hash_tag_id: Uuid,
+    created_by: Option<Uuid>,
+) -> Result<HashtagAliasModel, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let new_alias = HashtagAliasActiveModel {
+        id: Set(Uuid::new_v4()),
+        alias: Set(alias.to_string()),
+        hash_tag_id: Set(hash_tag_id),
+        created_by: Set(created_by),
+        created_at: Set(chrono::Utc::now()),
+    };
+
+    new_alias.insert(conn).await
+}
+
+/// 별칭을 삭제하고 실제로 삭제되었는지 반환
+pub async fn repository_delete_hashtag_alias<C>(
+    conn: &C,
+    alias: &str,
+) -> Result<bool, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let result = HashtagAliasEntity::delete_many()
+        .filter(HashtagAliasColumn::Alias.eq(alias))
+        .exec(conn)
+        .await?;
+
+    Ok(result.rows_affected > 0)
+}
+
+/// 해시태그에 등록된 별칭 목록 (이름순)
+pub async fn repository_get_hashtag_aliases<C>(
+    conn: &C,
+    hash_tag_id: Uuid,
+) -> Result<Vec<HashtagAliasModel>, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    HashtagAliasEntity::find()
+        .filter(HashtagAliasColumn::HashTagId.eq(hash_tag_id))
+        .order_by_asc(HashtagAliasColumn::Alias)
+        .all(conn)
+        .await
+}
+
+/// 병합 시 원본 해시태그의 별칭을 대상 해시태그로 옮김
+pub async fn repository_move_hashtag_aliases<C>(
+    conn: &C,
+    from_hash_tag_id: Uuid,
+    to_hash_tag_id: Uuid,
+) -> Result<(), sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    HashtagAliasEntity::update_many()
+        .filter(HashtagAliasColumn::HashTagId.eq(from_hash_tag_id))
+        .col_expr(HashtagAliasColumn::HashTagId, Expr::value(to_hash_tag_id))
+        .exec(conn)
+        .await?;
+
+    Ok(())
+}
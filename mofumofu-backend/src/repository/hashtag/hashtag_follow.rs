@@ -0,0 +1,144 @@
+use crate::entity::hash_tags::{
+    Column as HashTagColumn, Entity as HashTagEntity, Model as HashTagModel,
+};
+use crate::entity::hashtag_follows::{
+    ActiveModel as HashtagFollowActiveModel, Column as HashtagFollowColumn,
+    Entity as HashtagFollowEntity, Model as HashtagFollowModel, Relation as HashtagFollowRelation,
+};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, JoinType, QueryFilter, QueryOrder,
+    QuerySelect, RelationTrait, Set,
+};
+use uuid::Uuid;
+
+pub async fn repository_check_hashtag_follow_exists<C>(
+    conn: &C,
+    user_id: Uuid,
+    hash_tag_id: Uuid,
+) -> Result<bool, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let existing_follow = HashtagFollowEntity::find()
+        .filter(HashtagFollowColumn::UserId.eq(user_id))
+        .filter(HashtagFollowColumn::HashTagId.eq(hash_tag_id))
+        .one(conn)
+        .await?;
+
+    Ok(existing_follow.is_some())
+}
+
+pub async fn repository_create_hashtag_follow<C>(
+    conn: &C,
+    user_id: Uuid,
+    hash_tag_id: Uuid,
+) -> Result<HashtagFollowModel, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let new_follow = HashtagFollowActiveModel {
+        id: Default::default(),
+        user_id: Set(user_id),
+        hash_tag_id: Set(hash_tag_id),
+        created_at: Set(chrono::Utc::now()),
+    };
+
+    new_follow.insert(conn).await
+}
+
+/// 팔로우 관계를 삭제하고 실제로 삭제되었는지 반환
+pub async fn repository_delete_hashtag_follow<C>(
+    conn: &C,
+    user_id: Uuid,
+    hash_tag_id: Uuid,
+) -> Result<bool, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let result = HashtagFollowEntity::delete_many()
+        .filter(HashtagFollowColumn::UserId.eq(user_id))
+        .filter(HashtagFollowColumn::HashTagId.eq(hash_tag_id))
+        .exec(conn)
+        .await?;
+
+    Ok(result.rows_affected > 0)
+}
+
+/// 사용자가 팔로우하는 해시태그 ID 목록 (금지된 해시태그 제외)
+pub async fn repository_get_followed_hashtag_ids<C>(
+    conn: &C,
+    user_id: Uuid,
+) -> Result<Vec<Uuid>, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    HashtagFollowEntity::find()
+        .join(JoinType::InnerJoin, HashtagFollowRelation::HashTag.def())
+        .filter(HashtagFollowColumn::UserId.eq(user_id))
+        .filter(HashTagColumn::IsBanned.eq(false))
+        .select_only()
+        .column(HashtagFollowColumn::HashTagId)
+        .into_tuple::<Uuid>()
+        .all(conn)
+        .await
+}
+
+/// 사용자가 팔로우하는 해시태그 (최근 팔로우 순, 금지된 해시태그 제외)
+pub async fn repository_get_followed_hashtags<C>(
+    conn: &C,
+    user_id: Uuid,
+) -> Result<Vec<(HashtagFollowModel, HashTagModel)>, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let follows = HashtagFollowEntity::find()
+        .filter(HashtagFollowColumn::UserId.eq(user_id))
+        .order_by_desc(HashtagFollowColumn::CreatedAt)
+        .find_also_related(HashTagEntity)
+        .filter(HashTagColumn::IsBanned.eq(false))
+        .all(conn)
+        .await?;
+
+    Ok(follows
+        .into_iter()
+        .filter_map(|(follow, hashtag)| hashtag.map(|hashtag| (follow, hashtag)))
+        .collect())
+}
+
+pub async fn repository_increment_hashtag_follower_count<C>(
+    conn: &C,
+    hash_tag_id: Uuid,
+) -> Result<(), sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    HashTagEntity::update_many()
+        .filter(HashTagColumn::Id.eq(hash_tag_id))
+        .col_expr(
+            HashTagColumn::FollowerCount,
+            HashTagColumn::FollowerCount.into_expr().add(1),
+        )
+        .exec(conn)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn repository_decrement_hashtag_follower_count<C>(
+    conn: &C,
+    hash_tag_id: Uuid,
+) -> Result<(), sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    HashTagEntity::update_many()
+        .filter(HashTagColumn::Id.eq(hash_tag_id))
+        .col_expr(
+            HashTagColumn::FollowerCount,
+            HashTagColumn::FollowerCount.into_expr().sub(1),
+        )
+        .exec(conn)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,131 @@
+use crate::entity::hash_tags::{Column as HashTagColumn, Entity as HashTagEntity};
+use crate::entity::hashtag_follows::{
+    Column as HashtagFollowColumn, Entity as HashtagFollowEntity,
+};
+use crate::entity::post_hash_tags::{Column as PostHashTagColumn, Entity as PostHashTagEntity};
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, EntityTrait, PaginatorTrait, QueryFilter, QuerySelect,
+};
+use uuid::Uuid;
+
+/// 원본 해시태그의 포스트 연결을 대상 해시태그로 옮기고, 영향받은 포스트 ID를 반환
+/// (이미 대상 해시태그가 달린 포스트는 원본 연결만 삭제)
+pub async fn repository_move_post_hashtags<C>(
+    conn: &C,
+    source_id: Uuid,
+    target_id: Uuid,
+) -> Result<Vec<Uuid>, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let post_ids: Vec<Uuid> = PostHashTagEntity::find()
+        .filter(PostHashTagColumn::HashTagId.eq(source_id))
+        .select_only()
+        .column(PostHashTagColumn::PostId)
+        .into_tuple()
+        .all(conn)
+        .await?;
+
+    if post_ids.is_empty() {
+        return Ok(post_ids);
+    }
+
+    let already_tagged: Vec<Uuid> = PostHashTagEntity::find()
+        .filter(PostHashTagColumn::HashTagId.eq(target_id))
+        .filter(PostHashTagColumn::PostId.is_in(post_ids.iter().cloned()))
+        .select_only()
+        .column(PostHashTagColumn::PostId)
+        .into_tuple()
+        .all(conn)
+        .await?;
+
+    if !already_tagged.is_empty() {
+        PostHashTagEntity::delete_many()
+            .filter(PostHashTagColumn::HashTagId.eq(source_id))
+            .filter(PostHashTagColumn::PostId.is_in(already_tagged))
+            .exec(conn)
+            .await?;
+    }
+
+    PostHashTagEntity::update_many()
+        .filter(PostHashTagColumn::HashTagId.eq(source_id))
+        .col_expr(PostHashTagColumn::HashTagId, Expr::value(target_id))
+        .exec(conn)
+        .await?;
+
+    Ok(post_ids)
+}
+
+/// 원본 해시태그의 팔로우를 대상 해시태그로 옮김 (중복 팔로우는 삭제)
+pub async fn repository_move_hashtag_follows<C>(
+    conn: &C,
+    source_id: Uuid,
+    target_id: Uuid,
+) -> Result<(), sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let already_following: Vec<Uuid> = HashtagFollowEntity::find()
+        .filter(HashtagFollowColumn::HashTagId.eq(target_id))
+        .select_only()
+        .column(HashtagFollowColumn::UserId)
+        .into_tuple()
+        .all(conn)
+        .await?;
+
+    if !already_following.is_empty() {
+        HashtagFollowEntity::delete_many()
+            .filter(HashtagFollowColumn::HashTagId.eq(source_id))
+            .filter(HashtagFollowColumn::UserId.is_in(already_following))
+            .exec(conn)
+            .await?;
+    }
+
+    HashtagFollowEntity::update_many()
+        .filter(HashtagFollowColumn::HashTagId.eq(source_id))
+        .col_expr(HashtagFollowColumn::HashTagId, Expr::value(target_id))
+        .exec(conn)
+        .await?;
+
+    Ok(())
+}
+
+/// 실제 연결 수로 usage_count / follower_count 재계산
+pub async fn repository_recount_hashtag<C>(
+    conn: &C,
+    hash_tag_id: Uuid,
+) -> Result<(), sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let usage_count = PostHashTagEntity::find()
+        .filter(PostHashTagColumn::HashTagId.eq(hash_tag_id))
+        .count(conn)
+        .await?;
+    let follower_count = HashtagFollowEntity::find()
+        .filter(HashtagFollowColumn::HashTagId.eq(hash_tag_id))
+        .count(conn)
+        .await?;
+
+    HashTagEntity::update_many()
+        .filter(HashTagColumn::Id.eq(hash_tag_id))
+        .col_expr(HashTagColumn::UsageCount, Expr::value(usage_count as i32))
+        .col_expr(
+            HashTagColumn::FollowerCount,
+            Expr::value(follower_count as i32),
+        )
+        .exec(conn)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn repository_delete_hashtag<C>(conn: &C, hash_tag_id: Uuid) -> Result<(), sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    HashTagEntity::delete_by_id(hash_tag_id).exec(conn).await?;
+
+    Ok(())
+}
@@ -1,5 +1,12 @@
 pub mod associate_post_hashtags;
+pub mod find_hashtag_by_name;
 pub mod find_or_create_hashtag;
+pub mod get_hashtag_posts;
 pub mod get_hashtags_by_post;
 pub mod get_popular_hashtags;
+pub mod hashtag_alias;
+pub mod hashtag_follow;
+pub mod merge_hashtags;
 pub mod remove_post_hashtags;
+pub mod suggest_hashtags;
+pub mod update_hashtag;
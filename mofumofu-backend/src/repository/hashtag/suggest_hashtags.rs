@@ -0,0 +1,61 @@
+use crate::dto::hashtag::internal::hashtag_suggestion_row::HashtagSuggestionRow;
+use crate::utils::like_pattern::escape_like_pattern;
+use sea_orm::{ConnectionTrait, DbBackend, FromQueryResult, Statement};
+
+/// 접두사마다 순위를 매길 후보 수 (사용량 순으로 자름)
+const HASHTAG_SUGGEST_CANDIDATES: i64 = 100;
+
+/// 이름 또는 별칭이 접두사로 시작하는 해시태그 (정확히 일치 > 사용량/최근성 순)
+/// `prefix`는 이미 정규화된 값이어야 함
+pub async fn repository_suggest_hashtags<C>(
+    conn: &C,
+    prefix: &str,
+    limit: u64,
+) -> Result<Vec<HashtagSuggestionRow>, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    // 사용량을 마지막 사용 이후 지난 주 수로 나눠 최근 해시태그를 우선
+    let sql = r#"
+        WITH candidates AS (
+            (SELECT h.id, h.name, h.usage_count, h.last_used_at, h.created_at
+             FROM hash_tags h
+             WHERE h.name = $2 AND NOT h.is_banned)
+            UNION
+            (SELECT h.id, h.name, h.usage_count, h.last_used_at, h.created_at
+             FROM hash_tags h
+             WHERE h.name LIKE $1 AND NOT h.is_banned
+             ORDER BY h.usage_count DESC
+             LIMIT $4)
+            UNION
+            (SELECT h.id, h.name, h.usage_count, h.last_used_at, h.created_at
+             FROM hashtag_aliases a
+             JOIN hash_tags h ON h.id = a.hash_tag_id
+             WHERE a.alias LIKE $1 AND NOT h.is_banned
+             ORDER BY h.usage_count DESC
+             LIMIT $4)
+        )
+        SELECT name, usage_count
+        FROM candidates
+        ORDER BY
+            (name = $2) DESC,
+            usage_count / (1.0 + EXTRACT(EPOCH FROM NOW() - COALESCE(last_used_at, created_at)) / 604800.0) DESC,
+            name ASC
+        LIMIT $3
+    "#;
+
+    let statement = Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        sql,
+        [
+            format!("{}%", escape_like_pattern(prefix)).into(),
+            prefix.into(),
+            (limit as i64).into(),
+            HASHTAG_SUGGEST_CANDIDATES.into(),
+        ],
+    );
+
+    HashtagSuggestionRow::find_by_statement(statement)
+        .all(conn)
+        .await
+}
@@ -0,0 +1,55 @@
+use crate::entity::hash_tags::{Column as HashTagColumn, Entity as HashTagEntity};
+use sea_orm::sea_query::Expr;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+use uuid::Uuid;
+
+pub async fn repository_update_hashtag_description<C>(
+    conn: &C,
+    hash_tag_id: Uuid,
+    description: Option<String>,
+) -> Result<(), sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    HashTagEntity::update_many()
+        .filter(HashTagColumn::Id.eq(hash_tag_id))
+        .col_expr(HashTagColumn::Description, Expr::value(description))
+        .exec(conn)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn repository_update_hashtag_cover_image<C>(
+    conn: &C,
+    hash_tag_id: Uuid,
+    cover_image: Option<String>,
+) -> Result<(), sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    HashTagEntity::update_many()
+        .filter(HashTagColumn::Id.eq(hash_tag_id))
+        .col_expr(HashTagColumn::CoverImage, Expr::value(cover_image))
+        .exec(conn)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn repository_set_hashtag_banned<C>(
+    conn: &C,
+    hash_tag_id: Uuid,
+    banned: bool,
+) -> Result<(), sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    HashTagEntity::update_many()
+        .filter(HashTagColumn::Id.eq(hash_tag_id))
+        .col_expr(HashTagColumn::IsBanned, Expr::value(banned))
+        .exec(conn)
+        .await?;
+
+    Ok(())
+}
@@ -1,13 +1,16 @@
-use crate::entity::likes::{
-    Column as LikesColumn, Entity as LikesEntity, Relation as LikesRelation,
+use crate::entity::posts::{Column as PostColumn, Relation as PostRelation};
+use crate::entity::reactions::{
+    Column as ReactionsColumn, Entity as ReactionsEntity, Relation as ReactionsRelation,
 };
-use crate::entity::posts::{Column as PostColumn, Entity as PostEntity, Relation as PostRelation};
-use crate::entity::users::{Column as UserColumn, Entity as UserEntity, Relation as UserRelation};
+use crate::entity::users::Column as UserColumn;
+use crate::service::reaction::reaction_emoji::LIKE_REACTION_EMOJI;
 use sea_orm::{
     ColumnTrait, ConnectionTrait, EntityTrait, JoinType, QueryFilter, QuerySelect, RelationTrait,
 };
 use uuid::Uuid;
 
+// 좋아요는 ❤️ 반응으로 저장됨
+
 pub async fn repository_check_like_status<C>(
     conn: &C,
     user_id: &Uuid,
@@ -17,10 +20,11 @@ pub async fn repository_check_like_status<C>(
 where
     C: ConnectionTrait,
 {
-    let like_exists = LikesEntity::find()
-        .join(JoinType::InnerJoin, LikesRelation::Post.def())
+    let like_exists = ReactionsEntity::find()
+        .join(JoinType::InnerJoin, ReactionsRelation::Post.def())
         .join(JoinType::InnerJoin, PostRelation::User.def())
-        .filter(LikesColumn::UserId.eq(*user_id))
+        .filter(ReactionsColumn::UserId.eq(*user_id))
+        .filter(ReactionsColumn::Emoji.eq(LIKE_REACTION_EMOJI))
         .filter(PostColumn::Slug.eq(slug))
         .filter(UserColumn::Handle.eq(handle))
         .one(conn)
@@ -37,9 +41,10 @@ pub async fn repository_check_like_status_by_post_id<C>(
 where
     C: ConnectionTrait,
 {
-    let like_exists = LikesEntity::find()
-        .filter(LikesColumn::UserId.eq(*user_id))
-        .filter(LikesColumn::PostId.eq(*post_id))
+    let like_exists = ReactionsEntity::find()
+        .filter(ReactionsColumn::UserId.eq(*user_id))
+        .filter(ReactionsColumn::PostId.eq(*post_id))
+        .filter(ReactionsColumn::Emoji.eq(LIKE_REACTION_EMOJI))
         .one(conn)
         .await?;
 
@@ -54,9 +59,10 @@ pub async fn repository_check_like_status_by_comment_id<C>(
 where
     C: ConnectionTrait,
 {
-    let like_exists = LikesEntity::find()
-        .filter(LikesColumn::UserId.eq(*user_id))
-        .filter(LikesColumn::CommentId.eq(*comment_id))
+    let like_exists = ReactionsEntity::find()
+        .filter(ReactionsColumn::UserId.eq(*user_id))
+        .filter(ReactionsColumn::CommentId.eq(*comment_id))
+        .filter(ReactionsColumn::Emoji.eq(LIKE_REACTION_EMOJI))
         .one(conn)
         .await?;
 
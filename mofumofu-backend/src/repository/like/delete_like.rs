@@ -1,21 +1,16 @@
 use crate::entity::common::LikeTargetType;
-use crate::entity::likes::{
-    ActiveModel as LikesActiveModel, Column as LikesColumn, Entity as LikesEntity,
-    Relation as LikesRelation,
-};
-use crate::entity::posts::{Column as PostColumn, Entity as PostEntity, Relation as PostRelation};
-use crate::entity::users::{Column as UserColumn, Entity as UserEntity};
-use sea_orm::{
-    ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, JoinType, QueryFilter,
-    QuerySelect, RelationTrait,
+use crate::entity::reactions::{
+    Column as ReactionsColumn, Entity as ReactionsEntity, Model as ReactionModel,
 };
+use crate::service::reaction::reaction_emoji::LIKE_REACTION_EMOJI;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
 use uuid::Uuid;
 
 pub async fn repository_delete_like_by_post_id<C>(
     conn: &C,
     user_id: Uuid,
     post_id: Uuid,
-) -> Result<bool, sea_orm::DbErr>
+) -> Result<Option<ReactionModel>, sea_orm::DbErr>
 where
     C: ConnectionTrait,
 {
@@ -26,39 +21,33 @@ pub async fn repository_delete_like_by_comment_id<C>(
     conn: &C,
     user_id: Uuid,
     comment_id: Uuid,
-) -> Result<bool, sea_orm::DbErr>
+) -> Result<Option<ReactionModel>, sea_orm::DbErr>
 where
     C: ConnectionTrait,
 {
     repository_delete_like(conn, user_id, LikeTargetType::Comment, comment_id).await
 }
 
+/// ❤️ 반응으로 저장된 좋아요 삭제 (삭제된 좋아요를 반환, 없었으면 None)
 pub async fn repository_delete_like<C>(
     conn: &C,
     user_id: Uuid,
     target_type: LikeTargetType,
     target_id: Uuid,
-) -> Result<bool, sea_orm::DbErr>
+) -> Result<Option<ReactionModel>, sea_orm::DbErr>
 where
     C: ConnectionTrait,
 {
-    let mut query = LikesEntity::find()
-        .filter(LikesColumn::UserId.eq(user_id))
-        .filter(LikesColumn::TargetType.eq(target_type.clone()));
+    let mut query = ReactionsEntity::delete_many()
+        .filter(ReactionsColumn::UserId.eq(user_id))
+        .filter(ReactionsColumn::Emoji.eq(LIKE_REACTION_EMOJI));
 
     query = match target_type {
-        LikeTargetType::Post => query.filter(LikesColumn::PostId.eq(target_id)),
-        LikeTargetType::Comment => query.filter(LikesColumn::CommentId.eq(target_id)),
+        LikeTargetType::Post => query.filter(ReactionsColumn::PostId.eq(target_id)),
+        LikeTargetType::Comment => query.filter(ReactionsColumn::CommentId.eq(target_id)),
     };
 
-    let existing_like = query.one(conn).await?;
+    let deleted = query.exec_with_returning(conn).await?;
 
-    match existing_like {
-        Some(like_record) => {
-            let like_active_model: LikesActiveModel = like_record.into();
-            like_active_model.delete(conn).await?;
-            Ok(true)
-        }
-        None => Ok(false),
-    }
+    Ok(deleted.into_iter().next())
 }
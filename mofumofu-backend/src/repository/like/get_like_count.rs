@@ -1,4 +1,5 @@
-use crate::entity::likes::{Column as LikesColumn, Entity as LikesEntity};
+use crate::entity::reactions::{Column as ReactionsColumn, Entity as ReactionsEntity};
+use crate::service::reaction::reaction_emoji::LIKE_REACTION_EMOJI;
 use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, PaginatorTrait, QueryFilter};
 use uuid::Uuid;
 
@@ -9,8 +10,9 @@ pub async fn repository_get_like_count_by_post_id<C>(
 where
     C: ConnectionTrait,
 {
-    let count = LikesEntity::find()
-        .filter(LikesColumn::PostId.eq(post_id))
+    let count = ReactionsEntity::find()
+        .filter(ReactionsColumn::PostId.eq(post_id))
+        .filter(ReactionsColumn::Emoji.eq(LIKE_REACTION_EMOJI))
         .count(conn)
         .await?;
 
@@ -24,8 +26,9 @@ pub async fn repository_get_like_count_by_comment_id<C>(
 where
     C: ConnectionTrait,
 {
-    let count = LikesEntity::find()
-        .filter(LikesColumn::CommentId.eq(comment_id))
+    let count = ReactionsEntity::find()
+        .filter(ReactionsColumn::CommentId.eq(comment_id))
+        .filter(ReactionsColumn::Emoji.eq(LIKE_REACTION_EMOJI))
         .count(conn)
         .await?;
 
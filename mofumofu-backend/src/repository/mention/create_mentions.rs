@@ -0,0 +1,35 @@
+use crate::entity::mentions::{ActiveModel as MentionsActiveModel, Entity as MentionsEntity};
+use sea_orm::{ConnectionTrait, EntityTrait, Set};
+use uuid::Uuid;
+
+pub async fn repository_create_mentions<C>(
+    conn: &C,
+    mentioner_id: Uuid,
+    post_id: Uuid,
+    comment_id: Option<Uuid>,
+    mentioned_user_ids: &[Uuid],
+) -> Result<(), sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    if mentioned_user_ids.is_empty() {
+        return Ok(());
+    }
+
+    let mentions = mentioned_user_ids
+        .iter()
+        .map(|mentioned_user_id| MentionsActiveModel {
+            id: Default::default(),
+            mentioned_user_id: Set(*mentioned_user_id),
+            mentioner_id: Set(mentioner_id),
+            post_id: Set(post_id),
+            comment_id: Set(comment_id),
+            created_at: Default::default(),
+        });
+
+    MentionsEntity::insert_many(mentions)
+        .exec_without_returning(conn)
+        .await?;
+
+    Ok(())
+}
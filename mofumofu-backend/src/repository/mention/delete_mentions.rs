@@ -0,0 +1,31 @@
+use crate::entity::mentions::{Column as MentionsColumn, Entity as MentionsEntity};
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+use uuid::Uuid;
+
+/// 포스트 본문(comment_id = None) 또는 댓글 하나에서 지정한 사용자들의 멘션을 삭제
+pub async fn repository_delete_mentions<C>(
+    conn: &C,
+    post_id: Uuid,
+    comment_id: Option<Uuid>,
+    mentioned_user_ids: &[Uuid],
+) -> Result<u64, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    if mentioned_user_ids.is_empty() {
+        return Ok(0);
+    }
+
+    let query = MentionsEntity::delete_many()
+        .filter(MentionsColumn::PostId.eq(post_id))
+        .filter(MentionsColumn::MentionedUserId.is_in(mentioned_user_ids.iter().cloned()));
+
+    let query = match comment_id {
+        Some(comment_id) => query.filter(MentionsColumn::CommentId.eq(comment_id)),
+        None => query.filter(MentionsColumn::CommentId.is_null()),
+    };
+
+    let result = query.exec(conn).await?;
+
+    Ok(result.rows_affected)
+}
@@ -0,0 +1,71 @@
+use crate::entity::mentions::{
+    Column as MentionsColumn, Entity as MentionsEntity, Model as MentionsModel,
+};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect,
+};
+use uuid::Uuid;
+
+/// 포스트 본문(comment_id = None) 또는 댓글 하나에서 멘션된 사용자 ID 목록
+pub async fn repository_get_mentioned_user_ids_by_target<C>(
+    conn: &C,
+    post_id: Uuid,
+    comment_id: Option<Uuid>,
+) -> Result<Vec<Uuid>, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let query = MentionsEntity::find().filter(MentionsColumn::PostId.eq(post_id));
+
+    let query = match comment_id {
+        Some(comment_id) => query.filter(MentionsColumn::CommentId.eq(comment_id)),
+        None => query.filter(MentionsColumn::CommentId.is_null()),
+    };
+
+    let user_ids = query
+        .select_only()
+        .column(MentionsColumn::MentionedUserId)
+        .into_tuple()
+        .all(conn)
+        .await?;
+
+    Ok(user_ids)
+}
+
+/// 사용자가 멘션된 목록 (최신순)
+pub async fn repository_get_mentions_by_user<C>(
+    conn: &C,
+    user_id: Uuid,
+    page: u32,
+    per_page: u32,
+) -> Result<Vec<MentionsModel>, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let offset = (page - 1) * per_page;
+
+    let mentions = MentionsEntity::find()
+        .filter(MentionsColumn::MentionedUserId.eq(user_id))
+        .order_by_desc(MentionsColumn::CreatedAt)
+        .offset(offset as u64)
+        .limit(per_page as u64)
+        .all(conn)
+        .await?;
+
+    Ok(mentions)
+}
+
+pub async fn repository_count_mentions_by_user<C>(
+    conn: &C,
+    user_id: Uuid,
+) -> Result<u64, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let count = MentionsEntity::find()
+        .filter(MentionsColumn::MentionedUserId.eq(user_id))
+        .count(conn)
+        .await?;
+
+    Ok(count)
+}
@@ -0,0 +1,3 @@
+pub mod create_mentions;
+pub mod delete_mentions;
+pub mod get_mentions;
@@ -4,8 +4,11 @@ pub mod draft;
 pub mod follow;
 pub mod hashtag;
 pub mod like;
+pub mod mention;
 pub mod oauth;
+pub mod outbox;
 pub mod post;
+pub mod reaction;
 pub mod report;
 pub mod system_events;
 pub mod user;
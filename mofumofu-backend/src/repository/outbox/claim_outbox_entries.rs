@@ -0,0 +1,38 @@
+use crate::entity::outbox::{Entity as OutboxEntity, Model as OutboxModel};
+use sea_orm::{ConnectionTrait, DbBackend, EntityTrait, Statement};
+
+/// 전달 시점이 된 pending 항목을 가져오면서 lease_seconds 동안 다른 디스패처가 가져가지 못하게 선점
+///
+/// 선점 시 attempts를 1 증가시키므로, 전달 도중 서버가 죽어도 lease가 끝나면 다시 시도됨
+/// (enum 컬럼은 문자열로 읽을 수 있도록 text로 캐스팅)
+pub async fn repository_claim_outbox_entries<C>(
+    conn: &C,
+    limit: u64,
+    lease_seconds: i64,
+) -> Result<Vec<OutboxModel>, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let sql = r#"
+        UPDATE outbox
+        SET attempts = attempts + 1,
+            next_attempt_at = NOW() + make_interval(secs => $2::double precision)
+        WHERE id IN (
+            SELECT id FROM outbox
+            WHERE status = 'pending' AND next_attempt_at <= NOW()
+            ORDER BY next_attempt_at
+            LIMIT $1
+            FOR UPDATE SKIP LOCKED
+        )
+        RETURNING id, event_type, payload, idempotency_key, status::text AS status,
+                  attempts, next_attempt_at, last_error, created_at, delivered_at
+    "#;
+
+    let stmt = Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        sql,
+        [(limit as i64).into(), lease_seconds.into()],
+    );
+
+    OutboxEntity::find().from_raw_sql(stmt).all(conn).await
+}
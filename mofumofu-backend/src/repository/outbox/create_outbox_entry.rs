@@ -0,0 +1,42 @@
+use crate::entity::common::OutboxStatus;
+use crate::entity::outbox::{
+    ActiveModel as OutboxActiveModel, Column as OutboxColumn, Entity as OutboxEntity,
+};
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{ConnectionTrait, EntityTrait, Set};
+use serde_json::Value;
+
+/// 아웃박스 항목 추가 (같은 멱등성 키가 이미 있으면 아무것도 하지 않음)
+pub async fn repository_create_outbox_entry<C>(
+    conn: &C,
+    event_type: &str,
+    payload: Value,
+    idempotency_key: &str,
+) -> Result<(), sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let new_entry = OutboxActiveModel {
+        id: Default::default(),
+        event_type: Set(event_type.to_string()),
+        payload: Set(payload),
+        idempotency_key: Set(idempotency_key.to_string()),
+        status: Set(OutboxStatus::Pending),
+        attempts: Default::default(),
+        next_attempt_at: Default::default(),
+        last_error: Default::default(),
+        created_at: Default::default(),
+        delivered_at: Default::default(),
+    };
+
+    OutboxEntity::insert(new_entry)
+        .on_conflict(
+            OnConflict::column(OutboxColumn::IdempotencyKey)
+                .do_nothing()
+                .to_owned(),
+        )
+        .exec_without_returning(conn)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use crate::entity::common::OutboxStatus;
+use crate::entity::outbox::{Column as OutboxColumn, Entity as OutboxEntity};
+use chrono::{Duration, Utc};
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+
+/// 보관 기간이 지난 전달 완료 항목 삭제
+pub async fn repository_delete_delivered_outbox_entries<C>(
+    conn: &C,
+    retention_days: i64,
+) -> Result<u64, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let cutoff = Utc::now() - Duration::days(retention_days);
+
+    let result = OutboxEntity::delete_many()
+        .filter(OutboxColumn::Status.eq(OutboxStatus::Delivered))
+        .filter(OutboxColumn::DeliveredAt.lt(cutoff))
+        .exec(conn)
+        .await?;
+
+    Ok(result.rows_affected)
+}
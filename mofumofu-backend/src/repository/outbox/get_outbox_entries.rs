@@ -0,0 +1,25 @@
+use crate::entity::common::OutboxStatus;
+use crate::entity::outbox::{Column as OutboxColumn, Entity as OutboxEntity, Model as OutboxModel};
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect};
+
+/// 상태별 아웃박스 항목 조회 (pending은 실패 이력이 있는 항목만)
+pub async fn repository_get_stuck_outbox_entries<C>(
+    conn: &C,
+    status: OutboxStatus,
+    limit: u64,
+) -> Result<Vec<OutboxModel>, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let mut query = OutboxEntity::find().filter(OutboxColumn::Status.eq(status.clone()));
+
+    if status == OutboxStatus::Pending {
+        query = query.filter(OutboxColumn::LastError.is_not_null());
+    }
+
+    query
+        .order_by_asc(OutboxColumn::CreatedAt)
+        .limit(limit)
+        .all(conn)
+        .await
+}
@@ -0,0 +1,6 @@
+pub mod claim_outbox_entries;
+pub mod create_outbox_entry;
+pub mod delete_delivered_outbox_entries;
+pub mod get_outbox_entries;
+pub mod requeue_outbox_entries;
+pub mod update_outbox_entry;
@@ -0,0 +1,32 @@
+use crate::entity::common::OutboxStatus;
+use crate::entity::outbox::{Column as OutboxColumn, Entity as OutboxEntity};
+use chrono::Utc;
+use sea_orm::sea_query::Expr;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+use uuid::Uuid;
+
+/// 전달되지 않은 항목을 즉시 다시 시도하도록 되돌림 (시도 횟수 초기화)
+///
+/// 재큐된 항목 수를 반환
+pub async fn repository_requeue_outbox_entries<C>(
+    conn: &C,
+    ids: &[Uuid],
+) -> Result<u64, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    if ids.is_empty() {
+        return Ok(0);
+    }
+
+    let result = OutboxEntity::update_many()
+        .col_expr(OutboxColumn::Status, Expr::value(OutboxStatus::Pending))
+        .col_expr(OutboxColumn::Attempts, Expr::value(0))
+        .col_expr(OutboxColumn::NextAttemptAt, Expr::value(Utc::now()))
+        .filter(OutboxColumn::Id.is_in(ids.iter().copied()))
+        .filter(OutboxColumn::Status.ne(OutboxStatus::Delivered))
+        .exec(conn)
+        .await?;
+
+    Ok(result.rows_affected)
+}
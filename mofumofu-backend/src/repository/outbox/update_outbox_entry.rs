@@ -0,0 +1,48 @@
+use crate::entity::common::OutboxStatus;
+use crate::entity::outbox::{Column as OutboxColumn, Entity as OutboxEntity};
+use chrono::{DateTime, Utc};
+use sea_orm::sea_query::Expr;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+use uuid::Uuid;
+
+/// 전달 완료 처리
+pub async fn repository_mark_outbox_delivered<C>(conn: &C, id: Uuid) -> Result<(), sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    OutboxEntity::update_many()
+        .col_expr(OutboxColumn::Status, Expr::value(OutboxStatus::Delivered))
+        .col_expr(OutboxColumn::DeliveredAt, Expr::value(Utc::now()))
+        .col_expr(OutboxColumn::LastError, Expr::value(Option::<String>::None))
+        .filter(OutboxColumn::Id.eq(id))
+        .exec(conn)
+        .await?;
+
+    Ok(())
+}
+
+/// 전달 실패 처리 (next_attempt_at이 None이면 더 이상 재시도하지 않고 dead 상태로 전환)
+pub async fn repository_mark_outbox_failed<C>(
+    conn: &C,
+    id: Uuid,
+    error: &str,
+    next_attempt_at: Option<DateTime<Utc>>,
+) -> Result<(), sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let update = OutboxEntity::update_many()
+        .col_expr(OutboxColumn::LastError, Expr::value(error.to_string()))
+        .filter(OutboxColumn::Id.eq(id));
+
+    let update = match next_attempt_at {
+        Some(next_attempt_at) => {
+            update.col_expr(OutboxColumn::NextAttemptAt, Expr::value(next_attempt_at))
+        }
+        None => update.col_expr(OutboxColumn::Status, Expr::value(OutboxStatus::Dead)),
+    };
+
+    update.exec(conn).await?;
+
+    Ok(())
+}
@@ -0,0 +1,164 @@
+use crate::dto::post::internal::buffered_view::{
+    BufferedReferrer, BufferedViewCount, BufferedViewer,
+};
+use sea_orm::{ConnectionTrait, DbBackend, Statement, Value};
+
+/// 한 번의 INSERT/UPDATE에 넣을 최대 행 수 (Postgres 바인딩 파라미터 한도 이내)
+const BATCH_ROWS: usize = 1000;
+
+/// "($1::uuid, $2::date), ($3::uuid, $4::date)" 형태의 VALUES 목록
+fn values_list(row_count: usize, casts: &[&str]) -> String {
+    (0..row_count)
+        .map(|row| {
+            let columns: Vec<String> = casts
+                .iter()
+                .enumerate()
+                .map(|(col, cast)| format!("${}::{}", row * casts.len() + col + 1, cast))
+                .collect();
+            format!("({})", columns.join(", "))
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// 모인 조회수를 포스트 조회수와 일간 집계에 한 번에 반영
+/// 그 사이 삭제된 포스트의 조회수는 버림
+pub async fn repository_apply_buffered_view_counts<C>(
+    conn: &C,
+    counts: &[BufferedViewCount],
+) -> Result<(), sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    for chunk in counts.chunks(BATCH_ROWS) {
+        let sql = format!(
+            r#"
+            WITH buffered (post_id, day, views) AS (VALUES {values}),
+            live AS (
+                SELECT buffered.*
+                FROM buffered
+                JOIN posts p ON p.id = buffered.post_id
+            ),
+            bumped AS (
+                UPDATE posts p
+                SET view_count = p.view_count + totals.views
+                FROM (SELECT post_id, SUM(views)::int AS views FROM live GROUP BY post_id) totals
+                WHERE p.id = totals.post_id
+            )
+            INSERT INTO post_daily_stats (post_id, day, views)
+            SELECT post_id, day, views FROM live
+            ON CONFLICT (post_id, day) DO UPDATE
+            SET views = post_daily_stats.views + EXCLUDED.views
+            "#,
+            values = values_list(chunk.len(), &["uuid", "date", "int"])
+        );
+
+        let values: Vec<Value> = chunk
+            .iter()
+            .flat_map(|count| [count.post_id.into(), count.day.into(), count.views.into()])
+            .collect();
+
+        conn.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            sql,
+            values,
+        ))
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// 모인 방문자를 기록하고, 그날 처음 본 방문자 수만큼 순 방문자 수를 늘림
+pub async fn repository_apply_buffered_viewers<C>(
+    conn: &C,
+    viewers: &[BufferedViewer],
+) -> Result<(), sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    for chunk in viewers.chunks(BATCH_ROWS) {
+        let sql = format!(
+            r#"
+            WITH buffered (post_id, day, viewer_id) AS (VALUES {values}),
+            inserted AS (
+                INSERT INTO post_daily_viewers (post_id, day, viewer_id)
+                SELECT buffered.post_id, buffered.day, buffered.viewer_id
+                FROM buffered
+                JOIN posts p ON p.id = buffered.post_id
+                ON CONFLICT DO NOTHING
+                RETURNING post_id, day
+            )
+            INSERT INTO post_daily_stats (post_id, day, unique_viewers)
+            SELECT post_id, day, COUNT(*) FROM inserted GROUP BY post_id, day
+            ON CONFLICT (post_id, day) DO UPDATE
+            SET unique_viewers = post_daily_stats.unique_viewers + EXCLUDED.unique_viewers
+            "#,
+            values = values_list(chunk.len(), &["uuid", "date", "text"])
+        );
+
+        let values: Vec<Value> = chunk
+            .iter()
+            .flat_map(|viewer| {
+                [
+                    viewer.post_id.into(),
+                    viewer.day.into(),
+                    viewer.viewer_id.clone().into(),
+                ]
+            })
+            .collect();
+
+        conn.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            sql,
+            values,
+        ))
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// 모인 유입 도메인별 조회수를 일간 집계에 반영
+pub async fn repository_apply_buffered_referrers<C>(
+    conn: &C,
+    referrers: &[BufferedReferrer],
+) -> Result<(), sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    for chunk in referrers.chunks(BATCH_ROWS) {
+        let sql = format!(
+            r#"
+            INSERT INTO post_daily_referrers (post_id, day, domain, views)
+            SELECT buffered.post_id, buffered.day, buffered.domain, buffered.views
+            FROM (VALUES {values}) AS buffered (post_id, day, domain, views)
+            JOIN posts p ON p.id = buffered.post_id
+            ON CONFLICT (post_id, day, domain) DO UPDATE
+            SET views = post_daily_referrers.views + EXCLUDED.views
+            "#,
+            values = values_list(chunk.len(), &["uuid", "date", "text", "int"])
+        );
+
+        let values: Vec<Value> = chunk
+            .iter()
+            .flat_map(|referrer| {
+                [
+                    referrer.post_id.into(),
+                    referrer.day.into(),
+                    referrer.domain.clone().into(),
+                    referrer.views.into(),
+                ]
+            })
+            .collect();
+
+        conn.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            sql,
+            values,
+        ))
+        .await?;
+    }
+
+    Ok(())
+}
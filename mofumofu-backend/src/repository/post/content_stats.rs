@@ -0,0 +1,55 @@
+use crate::dto::post::internal::content_stats::ContentStats;
+use crate::entity::posts::{Column as PostColumn, Entity as PostEntity};
+use sea_orm::sea_query::Expr;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect};
+use uuid::Uuid;
+
+/// 본문 통계가 아직 계산되지 않은 포스트의 (id, 본문)을 id 순으로 limit개씩 조회
+///
+/// 통계 컬럼 추가 전에 작성된 포스트는 단어 수가 기본값 0으로 남아 있다.
+pub async fn repository_get_posts_missing_content_stats<C>(
+    conn: &C,
+    after: Option<Uuid>,
+    limit: u64,
+) -> Result<Vec<(Uuid, String)>, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let mut query = PostEntity::find()
+        .select_only()
+        .column(PostColumn::Id)
+        .column(PostColumn::Content)
+        .filter(PostColumn::WordCount.eq(0))
+        .filter(PostColumn::Content.ne(""));
+
+    if let Some(after) = after {
+        query = query.filter(PostColumn::Id.gt(after));
+    }
+
+    query
+        .order_by_asc(PostColumn::Id)
+        .limit(limit)
+        .into_tuple()
+        .all(conn)
+        .await
+}
+
+/// 본문 통계만 갱신 (수정 시각은 그대로 둠)
+pub async fn repository_update_post_content_stats<C>(
+    conn: &C,
+    post_id: &Uuid,
+    stats: ContentStats,
+) -> Result<(), sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    PostEntity::update_many()
+        .filter(PostColumn::Id.eq(*post_id))
+        .col_expr(PostColumn::WordCount, Expr::value(stats.word_count))
+        .col_expr(PostColumn::ReadingTime, Expr::value(stats.reading_time))
+        .col_expr(PostColumn::Language, Expr::value(stats.language))
+        .exec(conn)
+        .await?;
+
+    Ok(())
+}
@@ -1,3 +1,4 @@
+use crate::dto::post::internal::content_stats::ContentStats;
 use crate::dto::post::request::create_post::CreatePostRequest;
 use crate::entity::posts::{ActiveModel as PostActiveModel, Model as PostModel};
 use crate::service::error::errors::Errors;
@@ -12,6 +13,7 @@ pub async fn repository_create_post<C>(
     user_uuid: &Uuid,
     render_html: Option<String>,
     toc_json: Option<JsonValue>,
+    content_stats: ContentStats,
 ) -> Result<PostModel, Errors>
 where
     C: ConnectionTrait + TransactionTrait,
@@ -31,6 +33,11 @@ where
         slug: Set(payload.slug),
         render: Set(render_html),
         toc: Set(toc_json),
+        word_count: Set(content_stats.word_count),
+        reading_time: Set(content_stats.reading_time),
+        language: Set(content_stats.language),
+        comments_disabled: Set(false),
+        trending_score: Set(0.0),
     };
 
     // Insert the new post
@@ -1,6 +1,6 @@
 use crate::entity::posts;
 use crate::service::error::errors::Errors;
-use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QuerySelect};
 use tracing::{error, info};
 use uuid::Uuid;
 
@@ -36,3 +36,40 @@ where
         }
     }
 }
+
+/// 여러 포스트를 한 번에 조회 (존재하지 않는 ID는 무시)
+pub async fn repository_get_posts_by_uuids<C>(
+    conn: &C,
+    post_ids: &[Uuid],
+) -> Result<Vec<posts::Model>, Errors>
+where
+    C: ConnectionTrait,
+{
+    if post_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let posts = posts::Entity::find()
+        .filter(posts::Column::Id.is_in(post_ids.iter().cloned()))
+        .all(conn)
+        .await?;
+
+    Ok(posts)
+}
+
+/// 포스트 행을 트랜잭션이 끝날 때까지 잠그고 조회 (SELECT ... FOR UPDATE)
+pub async fn repository_get_post_by_uuid_for_update<C>(
+    conn: &C,
+    post_id: &Uuid,
+) -> Result<posts::Model, Errors>
+where
+    C: ConnectionTrait,
+{
+    let post = posts::Entity::find()
+        .filter(posts::Column::Id.eq(*post_id))
+        .lock_exclusive()
+        .one(conn)
+        .await?;
+
+    post.ok_or(Errors::PostNotFound)
+}
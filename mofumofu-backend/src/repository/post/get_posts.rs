@@ -13,6 +13,7 @@ pub async fn repository_get_posts<C>(
     page: u32,
     page_size: u32,
     sort_order: &PostSortOrder,
+    language: Option<&str>,
 ) -> Result<Vec<PostModel>, Errors>
 where
     C: ConnectionTrait,
@@ -21,22 +22,29 @@ where
 
     let mut query = PostEntity::find();
 
+    if let Some(language) = language {
+        query = query.filter(Column::Language.eq(language));
+    }
+
     // 정렬 조건 적용
     match sort_order {
         PostSortOrder::Latest => {
             query = query.order_by_desc(Column::CreatedAt);
         }
         PostSortOrder::Popular => {
-            // 최근 2주간 PostViewed 이벤트 수 기반으로 trending 정렬
-            let two_weeks_ago = chrono::Utc::now() - chrono::Duration::weeks(2);
-
+            // 최근 2주간 일간 집계의 조회수 합 기준으로 정렬 (UTC 날짜 기준)
             query = query
-                .order_by_desc(Expr::cust(&format!(
-                    "(SELECT COUNT(*) FROM system_events WHERE target_id = posts.id AND action_type = 'post_viewed' AND target_type = 'post' AND created_at >= '{}')",
-                    two_weeks_ago.format("%Y-%m-%d %H:%M:%S%.3f+00:00")
-                )))
+                .order_by_desc(Expr::cust(
+                    "(SELECT COALESCE(SUM(views), 0) FROM post_daily_stats \
+                     WHERE post_id = posts.id AND day >= (NOW() AT TIME ZONE 'UTC')::date - 13)",
+                ))
                 .order_by_desc(Column::CreatedAt); // 같은 view count일 때는 최신순
         }
+        PostSortOrder::Trending => {
+            query = query
+                .order_by_desc(Column::TrendingScore)
+                .order_by_desc(Column::CreatedAt);
+        }
         PostSortOrder::Oldest => {
             query = query.order_by_asc(Column::CreatedAt);
         }
@@ -80,6 +88,11 @@ where
         PostSortOrder::Popular => {
             query = query.order_by_desc(Column::LikeCount);
         }
+        PostSortOrder::Trending => {
+            query = query
+                .order_by_desc(Column::TrendingScore)
+                .order_by_desc(Column::CreatedAt);
+        }
         PostSortOrder::Oldest => {
             query = query.order_by_asc(Column::CreatedAt);
         }
@@ -94,11 +107,17 @@ where
     Ok(posts)
 }
 
-pub async fn repository_get_posts_count<C>(conn: &C) -> Result<u64, Errors>
+pub async fn repository_get_posts_count<C>(conn: &C, language: Option<&str>) -> Result<u64, Errors>
 where
     C: ConnectionTrait,
 {
-    let count = PostEntity::find().count(conn).await?;
+    let mut query = PostEntity::find();
+
+    if let Some(language) = language {
+        query = query.filter(Column::Language.eq(language));
+    }
+
+    let count = query.count(conn).await?;
 
     Ok(count)
 }
@@ -127,3 +146,22 @@ where
 
     Ok(posts)
 }
+
+pub async fn repository_get_posts_by_uuids<C>(
+    conn: &C,
+    post_ids: &[Uuid],
+) -> Result<Vec<PostModel>, Errors>
+where
+    C: ConnectionTrait,
+{
+    if post_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let posts = PostEntity::find()
+        .filter(Column::Id.is_in(post_ids.iter().copied()))
+        .all(conn)
+        .await?;
+
+    Ok(posts)
+}
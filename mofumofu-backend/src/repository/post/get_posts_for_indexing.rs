@@ -0,0 +1,46 @@
+use crate::entity::posts::{Column as PostColumn, Entity as PostEntity, Model as PostModel};
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect};
+use uuid::Uuid;
+
+/// 검색 재색인용 포스트를 id 순으로 limit개씩 조회 (after 이후부터)
+pub async fn repository_get_posts_for_indexing<C>(
+    conn: &C,
+    after: Option<Uuid>,
+    limit: u64,
+) -> Result<Vec<PostModel>, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let mut query = PostEntity::find();
+
+    if let Some(after) = after {
+        query = query.filter(PostColumn::Id.gt(after));
+    }
+
+    query
+        .order_by_asc(PostColumn::Id)
+        .limit(limit)
+        .all(conn)
+        .await
+}
+
+/// post_ids 중 DB에 남아 있는 포스트 ID (재색인 후 지울 문서를 고를 때 사용)
+pub async fn repository_get_existing_post_ids<C>(
+    conn: &C,
+    post_ids: &[Uuid],
+) -> Result<Vec<Uuid>, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    if post_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    PostEntity::find()
+        .select_only()
+        .column(PostColumn::Id)
+        .filter(PostColumn::Id.is_in(post_ids.iter().copied()))
+        .into_tuple()
+        .all(conn)
+        .await
+}
@@ -0,0 +1,84 @@
+use crate::dto::post::internal::related_post_row::RelatedPostRow;
+use crate::service::reaction::reaction_emoji::LIKE_REACTION_EMOJI;
+use sea_orm::{ConnectionTrait, DbBackend, FromQueryResult, Statement};
+use uuid::Uuid;
+
+/// 같은 작성자의 최근 포스트 중 후보로 볼 개수
+const SAME_AUTHOR_CANDIDATES: i64 = 10;
+/// 함께 좋아요한 포스트를 찾을 때 살펴볼 최근 좋아요 사용자 수
+const CO_LIKE_LIKERS: i64 = 200;
+
+/// 관련 포스트 후보 ID를 점수 순으로 반환
+/// 점수 = 공유 해시태그 수 * 3 + 같은 작성자 최근 포스트 1.5 / 순위 + ln(1 + 함께 좋아요한 사용자 수) * 2
+pub async fn repository_get_related_post_ids<C>(
+    conn: &C,
+    post_id: Uuid,
+    author_id: Uuid,
+    limit: u64,
+) -> Result<Vec<RelatedPostRow>, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let sql = r#"
+        WITH tag_overlap AS (
+            SELECT other.post_id, COUNT(*) * 3.0 AS score
+            FROM post_hash_tags own
+            JOIN hash_tags h ON h.id = own.hash_tag_id AND NOT h.is_banned
+            JOIN post_hash_tags other ON other.hash_tag_id = own.hash_tag_id
+            WHERE own.post_id = $1 AND other.post_id <> $1
+            GROUP BY other.post_id
+        ),
+        same_author AS (
+            SELECT id AS post_id, 1.5 / ROW_NUMBER() OVER (ORDER BY created_at DESC) AS score
+            FROM (
+                SELECT id, created_at
+                FROM posts
+                WHERE user_id = $2 AND id <> $1
+                ORDER BY created_at DESC
+                LIMIT $4
+            ) recent
+        ),
+        likers AS (
+            SELECT user_id
+            FROM reactions
+            WHERE post_id = $1 AND emoji = $6
+            ORDER BY created_at DESC
+            LIMIT $5
+        ),
+        co_liked AS (
+            SELECT l.post_id, LN(1 + COUNT(*)) * 2.0 AS score
+            FROM reactions l
+            JOIN likers ON likers.user_id = l.user_id
+            WHERE l.emoji = $6 AND l.post_id IS NOT NULL AND l.post_id <> $1
+            GROUP BY l.post_id
+        ),
+        scored AS (
+            SELECT post_id, score FROM tag_overlap
+            UNION ALL
+            SELECT post_id, score FROM same_author
+            UNION ALL
+            SELECT post_id, score FROM co_liked
+        )
+        SELECT s.post_id
+        FROM scored s
+        JOIN posts p ON p.id = s.post_id
+        GROUP BY s.post_id, p.created_at
+        ORDER BY SUM(s.score) DESC, p.created_at DESC
+        LIMIT $3
+    "#;
+
+    let statement = Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        sql,
+        [
+            post_id.into(),
+            author_id.into(),
+            (limit as i64).into(),
+            SAME_AUTHOR_CANDIDATES.into(),
+            CO_LIKE_LIKERS.into(),
+            LIKE_REACTION_EMOJI.into(),
+        ],
+    );
+
+    RelatedPostRow::find_by_statement(statement).all(conn).await
+}
@@ -1,3 +1,5 @@
+pub mod apply_buffered_views;
+pub mod content_stats;
 pub mod create_post;
 pub mod delete_post;
 mod find_post_by_handle_and_slug;
@@ -5,9 +7,16 @@ pub mod get_post_by_handle_and_slug;
 pub mod get_post_by_user_and_slug;
 pub mod get_post_by_uuid;
 pub mod get_posts;
+pub mod get_posts_for_indexing;
+pub mod get_related_post_ids;
 pub mod get_user_posts;
 pub mod increment_view_count;
+pub mod post_analytics;
+pub mod refresh_trending_scores;
+pub mod sync_like_counts;
+pub mod trending_settings;
 pub mod update_comment_count;
+pub mod update_comments_disabled;
 pub mod update_like_count;
 pub mod update_post;
 pub mod update_post_thumbnail;
@@ -0,0 +1,256 @@
+use crate::dto::post::internal::post_analytics_row::{
+    PostDailyStatRow, PostReferrerRow, PostUniqueViewersRow,
+};
+use chrono::NaiveDate;
+use sea_orm::{ConnectionTrait, DbBackend, FromQueryResult, Statement};
+use uuid::Uuid;
+
+/// 집계 날짜는 서버 시간대와 관계없이 UTC 기준
+const TODAY_UTC: &str = "(NOW() AT TIME ZONE 'UTC')::date";
+
+/// 조회 한 건을 일간 집계에 반영 (처음 보는 방문자면 순 방문자 수도 증가)
+/// `viewer_id`는 익명 ID를 해시한 값
+/// `referrer_domain`이 None이면 직접 유입으로 기록
+pub async fn repository_record_post_view<C>(
+    conn: &C,
+    post_id: Uuid,
+    viewer_id: &str,
+    referrer_domain: Option<&str>,
+) -> Result<(), sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let view_sql = format!(
+        r#"
+        WITH new_viewer AS (
+            INSERT INTO post_daily_viewers (post_id, day, viewer_id)
+            VALUES ($1, {today}, $2)
+            ON CONFLICT DO NOTHING
+            RETURNING 1
+        )
+        INSERT INTO post_daily_stats (post_id, day, views, unique_viewers)
+        VALUES ($1, {today}, 1, (SELECT COUNT(*) FROM new_viewer))
+        ON CONFLICT (post_id, day) DO UPDATE
+        SET views = post_daily_stats.views + 1,
+            unique_viewers = post_daily_stats.unique_viewers + EXCLUDED.unique_viewers
+        "#,
+        today = TODAY_UTC
+    );
+
+    let referrer_sql = format!(
+        r#"
+        INSERT INTO post_daily_referrers (post_id, day, domain, views)
+        VALUES ($1, {today}, $2, 1)
+        ON CONFLICT (post_id, day, domain) DO UPDATE
+        SET views = post_daily_referrers.views + 1
+        "#,
+        today = TODAY_UTC
+    );
+
+    conn.execute(Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        view_sql,
+        [post_id.into(), viewer_id.into()],
+    ))
+    .await?;
+
+    conn.execute(Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        referrer_sql,
+        [post_id.into(), referrer_domain.unwrap_or_default().into()],
+    ))
+    .await?;
+
+    Ok(())
+}
+
+/// 오늘 받은 좋아요 수 증가
+pub async fn repository_increment_post_daily_likes<C>(
+    conn: &C,
+    post_id: Uuid,
+) -> Result<(), sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    increment_post_daily_counter(conn, post_id, "likes").await
+}
+
+/// 오늘 달린 댓글 수 증가
+pub async fn repository_increment_post_daily_comments<C>(
+    conn: &C,
+    post_id: Uuid,
+) -> Result<(), sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    increment_post_daily_counter(conn, post_id, "comments").await
+}
+
+async fn increment_post_daily_counter<C>(
+    conn: &C,
+    post_id: Uuid,
+    column: &'static str,
+) -> Result<(), sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let sql = format!(
+        r#"
+        INSERT INTO post_daily_stats (post_id, day, {column})
+        VALUES ($1, {today}, 1)
+        ON CONFLICT (post_id, day) DO UPDATE
+        SET {column} = post_daily_stats.{column} + 1
+        "#,
+        column = column,
+        today = TODAY_UTC
+    );
+
+    conn.execute(Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        sql,
+        [post_id.into()],
+    ))
+    .await?;
+
+    Ok(())
+}
+
+/// 좋아요가 취소되면 좋아요를 받은 날의 좋아요 수 감소
+pub async fn repository_decrement_post_daily_likes<C>(
+    conn: &C,
+    post_id: Uuid,
+    day: NaiveDate,
+) -> Result<(), sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    decrement_post_daily_counter(conn, post_id, day, "likes").await
+}
+
+/// 댓글이 삭제되면 댓글이 달린 날의 댓글 수 감소
+pub async fn repository_decrement_post_daily_comments<C>(
+    conn: &C,
+    post_id: Uuid,
+    day: NaiveDate,
+) -> Result<(), sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    decrement_post_daily_counter(conn, post_id, day, "comments").await
+}
+
+async fn decrement_post_daily_counter<C>(
+    conn: &C,
+    post_id: Uuid,
+    day: NaiveDate,
+    column: &'static str,
+) -> Result<(), sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    // 집계 도입 전에 생긴 좋아요/댓글은 해당 날의 행이 없거나 0이므로 음수가 되지 않게 함
+    let sql = format!(
+        r#"
+        UPDATE post_daily_stats
+        SET {column} = GREATEST({column} - 1, 0)
+        WHERE post_id = $1 AND day = $2
+        "#,
+        column = column
+    );
+
+    conn.execute(Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        sql,
+        [post_id.into(), day.into()],
+    ))
+    .await?;
+
+    Ok(())
+}
+
+/// 기간 내 일간 집계 (집계가 없는 날은 포함되지 않음)
+pub async fn repository_get_post_daily_stats<C>(
+    conn: &C,
+    post_id: Uuid,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<Vec<PostDailyStatRow>, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let sql = r#"
+        SELECT day, views, unique_viewers, likes, comments
+        FROM post_daily_stats
+        WHERE post_id = $1 AND day BETWEEN $2 AND $3
+        ORDER BY day ASC
+    "#;
+
+    PostDailyStatRow::find_by_statement(Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        sql,
+        [post_id.into(), from.into(), to.into()],
+    ))
+    .all(conn)
+    .await
+}
+
+/// 기간 전체의 순 방문자 수 (여러 날 방문한 사용자는 한 번만)
+pub async fn repository_count_post_unique_viewers<C>(
+    conn: &C,
+    post_id: Uuid,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<i64, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let sql = r#"
+        SELECT COUNT(DISTINCT viewer_id) AS unique_viewers
+        FROM post_daily_viewers
+        WHERE post_id = $1 AND day BETWEEN $2 AND $3
+    "#;
+
+    let row = PostUniqueViewersRow::find_by_statement(Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        sql,
+        [post_id.into(), from.into(), to.into()],
+    ))
+    .one(conn)
+    .await?;
+
+    Ok(row.map(|row| row.unique_viewers).unwrap_or(0))
+}
+
+/// 기간 내 유입 도메인별 조회수 (많은 순)
+pub async fn repository_get_post_referrers<C>(
+    conn: &C,
+    post_id: Uuid,
+    from: NaiveDate,
+    to: NaiveDate,
+    limit: u64,
+) -> Result<Vec<PostReferrerRow>, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let sql = r#"
+        SELECT domain, SUM(views)::bigint AS views
+        FROM post_daily_referrers
+        WHERE post_id = $1 AND day BETWEEN $2 AND $3
+        GROUP BY domain
+        ORDER BY views DESC, domain ASC
+        LIMIT $4
+    "#;
+
+    PostReferrerRow::find_by_statement(Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        sql,
+        [
+            post_id.into(),
+            from.into(),
+            to.into(),
+            (limit as i64).into(),
+        ],
+    ))
+    .all(conn)
+    .await
+}
@@ -0,0 +1,49 @@
+use sea_orm::{ConnectionTrait, DbBackend, Statement};
+
+/// 최근 포스트의 트렌딩 점수를 다시 계산하고 갱신된 포스트 수를 반환
+///
+/// 점수 = (좋아요 * w_like + 댓글 * w_comment + 조회 * w_view) / (경과 시간 + 2)^gravity
+/// 기간이 지난 포스트는 0으로 내려 트렌딩에서 빠지도록 함
+pub async fn repository_refresh_trending_scores<C>(
+    conn: &C,
+    window_days: i64,
+) -> Result<u64, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let refresh_sql = r#"
+        UPDATE posts p
+        SET trending_score = (
+                p.like_count * s.like_weight
+                + p.comment_count * s.comment_weight
+                + p.view_count * s.view_weight
+            ) / POWER(GREATEST(EXTRACT(EPOCH FROM NOW() - p.created_at) / 3600.0, 0) + 2, s.gravity)
+        FROM trending_settings s
+        WHERE s.id = 1
+          AND p.created_at >= NOW() - make_interval(days => $1::int)
+    "#;
+
+    let expire_sql = r#"
+        UPDATE posts
+        SET trending_score = 0
+        WHERE created_at < NOW() - make_interval(days => $1::int)
+          AND trending_score <> 0
+    "#;
+
+    let refreshed = conn
+        .execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            refresh_sql,
+            [window_days.into()],
+        ))
+        .await?;
+
+    conn.execute(Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        expire_sql,
+        [window_days.into()],
+    ))
+    .await?;
+
+    Ok(refreshed.rows_affected())
+}
@@ -0,0 +1,31 @@
+use crate::service::reaction::reaction_emoji::LIKE_REACTION_EMOJI;
+use sea_orm::{ConnectionTrait, DbBackend, Statement};
+
+/// 모든 포스트의 like_count를 ❤️ 반응의 실제 개수로 맞추고 수정된 포스트 수를 반환
+pub async fn repository_sync_post_like_counts<C>(conn: &C) -> Result<u64, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let sql = r#"
+        UPDATE posts p
+        SET like_count = c.actual_count
+        FROM (
+            SELECT p2.id, COUNT(l.id)::int AS actual_count
+            FROM posts p2
+            LEFT JOIN reactions l ON l.post_id = p2.id AND l.emoji = $1
+            GROUP BY p2.id
+        ) c
+        WHERE p.id = c.id
+          AND p.like_count <> c.actual_count
+    "#;
+
+    let result = conn
+        .execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            sql,
+            [LIKE_REACTION_EMOJI.into()],
+        ))
+        .await?;
+
+    Ok(result.rows_affected())
+}
@@ -0,0 +1,44 @@
+use crate::entity::trending_settings::{
+    ActiveModel as TrendingSettingsActiveModel, Entity as TrendingSettingsEntity,
+    Model as TrendingSettingsModel,
+};
+use crate::service::error::errors::Errors;
+use sea_orm::{ActiveModelTrait, ConnectionTrait, EntityTrait, Set};
+use uuid::Uuid;
+
+/// 가중치가 저장된 유일한 행의 ID
+const TRENDING_SETTINGS_ID: i16 = 1;
+
+pub async fn repository_get_trending_settings<C>(conn: &C) -> Result<TrendingSettingsModel, Errors>
+where
+    C: ConnectionTrait,
+{
+    TrendingSettingsEntity::find_by_id(TRENDING_SETTINGS_ID)
+        .one(conn)
+        .await?
+        .ok_or_else(|| Errors::SysInternalError("trending settings row is missing".to_string()))
+}
+
+pub async fn repository_update_trending_settings<C>(
+    conn: &C,
+    like_weight: f64,
+    comment_weight: f64,
+    view_weight: f64,
+    gravity: f64,
+    updated_by: Uuid,
+) -> Result<TrendingSettingsModel, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    TrendingSettingsActiveModel {
+        id: Set(TRENDING_SETTINGS_ID),
+        like_weight: Set(like_weight),
+        comment_weight: Set(comment_weight),
+        view_weight: Set(view_weight),
+        gravity: Set(gravity),
+        updated_by: Set(Some(updated_by)),
+        updated_at: Set(chrono::Utc::now()),
+    }
+    .update(conn)
+    .await
+}
@@ -0,0 +1,21 @@
+use crate::entity::posts::{Column as PostColumn, Entity as PostEntity};
+use sea_orm::sea_query::Expr;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+use uuid::Uuid;
+
+pub async fn repository_set_post_comments_disabled<C>(
+    conn: &C,
+    post_id: &Uuid,
+    disabled: bool,
+) -> Result<(), sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    PostEntity::update_many()
+        .filter(PostColumn::Id.eq(*post_id))
+        .col_expr(PostColumn::CommentsDisabled, Expr::value(disabled))
+        .exec(conn)
+        .await?;
+
+    Ok(())
+}
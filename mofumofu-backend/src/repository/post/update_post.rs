@@ -1,3 +1,4 @@
+use crate::dto::post::internal::content_stats::ContentStats;
 use crate::dto::post::request::update_post::UpdatePostRequest;
 use crate::entity::posts::{
     ActiveModel as PostActiveModel, Column, Entity as PostEntity, Model as PostModel,
@@ -16,6 +17,7 @@ pub async fn repository_update_post<C>(
     user_uuid: &Uuid,
     render_html: Option<String>,
     toc_json: Option<JsonValue>,
+    content_stats: Option<ContentStats>,
 ) -> Result<PostModel, Errors>
 where
     C: ConnectionTrait + TransactionTrait,
@@ -47,6 +49,11 @@ where
         if let Some(toc) = toc_json {
             active_post.toc = Set(Some(toc));
         }
+        if let Some(stats) = content_stats {
+            active_post.word_count = Set(stats.word_count);
+            active_post.reading_time = Set(stats.reading_time);
+            active_post.language = Set(stats.language);
+        }
     }
 
     if let Some(new_slug) = payload.new_slug {
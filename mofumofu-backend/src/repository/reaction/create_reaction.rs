@@ -0,0 +1,51 @@
+use crate::entity::common::LikeTargetType;
+use crate::entity::reactions::{
+    ActiveModel as ReactionsActiveModel, Column as ReactionsColumn, Entity as ReactionsEntity,
+};
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{ConnectionTrait, EntityTrait, Set};
+use uuid::Uuid;
+
+/// 반응 생성 (이미 같은 반응이 있으면 아무것도 하지 않음)
+///
+/// 새로 생성되었으면 true를 반환
+pub async fn repository_create_reaction<C>(
+    conn: &C,
+    user_id: Uuid,
+    target_type: LikeTargetType,
+    target_id: Uuid,
+    emoji: &str,
+) -> Result<bool, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let (post_id, comment_id, target_column) = match target_type {
+        LikeTargetType::Post => (Some(target_id), None, ReactionsColumn::PostId),
+        LikeTargetType::Comment => (None, Some(target_id), ReactionsColumn::CommentId),
+    };
+
+    let new_reaction = ReactionsActiveModel {
+        id: Default::default(),
+        user_id: Set(user_id),
+        post_id: Set(post_id),
+        comment_id: Set(comment_id),
+        target_type: Set(target_type),
+        emoji: Set(emoji.to_string()),
+        created_at: Default::default(),
+    };
+
+    let inserted = ReactionsEntity::insert(new_reaction)
+        .on_conflict(
+            OnConflict::columns([
+                ReactionsColumn::UserId,
+                target_column,
+                ReactionsColumn::Emoji,
+            ])
+            .do_nothing()
+            .to_owned(),
+        )
+        .exec_without_returning(conn)
+        .await?;
+
+    Ok(inserted > 0)
+}
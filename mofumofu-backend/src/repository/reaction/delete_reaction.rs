@@ -0,0 +1,29 @@
+use crate::entity::common::LikeTargetType;
+use crate::entity::reactions::{Column as ReactionsColumn, Entity as ReactionsEntity};
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+use uuid::Uuid;
+
+/// 반응 삭제, 삭제된 반응이 있으면 true를 반환
+pub async fn repository_delete_reaction<C>(
+    conn: &C,
+    user_id: Uuid,
+    target_type: LikeTargetType,
+    target_id: Uuid,
+    emoji: &str,
+) -> Result<bool, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let mut query = ReactionsEntity::delete_many()
+        .filter(ReactionsColumn::UserId.eq(user_id))
+        .filter(ReactionsColumn::Emoji.eq(emoji));
+
+    query = match target_type {
+        LikeTargetType::Post => query.filter(ReactionsColumn::PostId.eq(target_id)),
+        LikeTargetType::Comment => query.filter(ReactionsColumn::CommentId.eq(target_id)),
+    };
+
+    let result = query.exec(conn).await?;
+
+    Ok(result.rows_affected > 0)
+}
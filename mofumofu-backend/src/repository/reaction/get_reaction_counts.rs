@@ -0,0 +1,53 @@
+use crate::dto::reaction::response::reaction_count::ReactionCount;
+use crate::entity::common::LikeTargetType;
+use crate::entity::reactions::{Column as ReactionsColumn, Entity as ReactionsEntity};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, EntityTrait, Order, QueryFilter, QueryOrder, QuerySelect,
+};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// 여러 대상의 이모지별 반응 수를 한 번에 집계
+///
+/// 대상마다 반응 수가 많은 순으로 정렬되며, 반응이 없는 대상은 결과에 포함되지 않는다.
+pub async fn repository_get_reaction_counts<C>(
+    conn: &C,
+    target_type: LikeTargetType,
+    target_ids: &[Uuid],
+) -> Result<HashMap<Uuid, Vec<ReactionCount>>, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    if target_ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let target_column = match target_type {
+        LikeTargetType::Post => ReactionsColumn::PostId,
+        LikeTargetType::Comment => ReactionsColumn::CommentId,
+    };
+
+    let rows: Vec<(Uuid, String, i64)> = ReactionsEntity::find()
+        .filter(target_column.is_in(target_ids.iter().cloned()))
+        .select_only()
+        .column(target_column)
+        .column(ReactionsColumn::Emoji)
+        .column_as(ReactionsColumn::Id.count(), "count")
+        .group_by(target_column)
+        .group_by(ReactionsColumn::Emoji)
+        .order_by(ReactionsColumn::Id.count(), Order::Desc)
+        .order_by_asc(ReactionsColumn::Emoji)
+        .into_tuple()
+        .all(conn)
+        .await?;
+
+    let mut counts: HashMap<Uuid, Vec<ReactionCount>> = HashMap::new();
+    for (target_id, emoji, count) in rows {
+        counts
+            .entry(target_id)
+            .or_default()
+            .push(ReactionCount { emoji, count });
+    }
+
+    Ok(counts)
+}
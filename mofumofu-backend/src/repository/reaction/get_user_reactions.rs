@@ -0,0 +1,43 @@
+use crate::entity::common::LikeTargetType;
+use crate::entity::reactions::{Column as ReactionsColumn, Entity as ReactionsEntity};
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// 사용자가 여러 대상에 남긴 반응 이모지 목록을 한 번에 조회
+pub async fn repository_get_user_reactions<C>(
+    conn: &C,
+    user_id: Uuid,
+    target_type: LikeTargetType,
+    target_ids: &[Uuid],
+) -> Result<HashMap<Uuid, Vec<String>>, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    if target_ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let target_column = match target_type {
+        LikeTargetType::Post => ReactionsColumn::PostId,
+        LikeTargetType::Comment => ReactionsColumn::CommentId,
+    };
+
+    let rows: Vec<(Uuid, String)> = ReactionsEntity::find()
+        .filter(ReactionsColumn::UserId.eq(user_id))
+        .filter(target_column.is_in(target_ids.iter().cloned()))
+        .select_only()
+        .column(target_column)
+        .column(ReactionsColumn::Emoji)
+        .order_by_asc(ReactionsColumn::CreatedAt)
+        .into_tuple()
+        .all(conn)
+        .await?;
+
+    let mut reactions: HashMap<Uuid, Vec<String>> = HashMap::new();
+    for (target_id, emoji) in rows {
+        reactions.entry(target_id).or_default().push(emoji);
+    }
+
+    Ok(reactions)
+}
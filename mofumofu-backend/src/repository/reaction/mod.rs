@@ -0,0 +1,4 @@
+pub mod create_reaction;
+pub mod delete_reaction;
+pub mod get_reaction_counts;
+pub mod get_user_reactions;
@@ -0,0 +1,21 @@
+use crate::entity::system_events::{Column as SystemEventsColumn, Entity as SystemEventsEntity};
+use chrono::{Duration, Utc};
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+
+/// retention_days보다 오래된 시스템 이벤트 삭제 후 삭제된 수를 반환
+pub async fn repository_delete_old_events<C>(
+    conn: &C,
+    retention_days: i64,
+) -> Result<u64, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let cutoff = Utc::now() - Duration::days(retention_days);
+
+    let result = SystemEventsEntity::delete_many()
+        .filter(SystemEventsColumn::CreatedAt.lt(cutoff))
+        .exec(conn)
+        .await?;
+
+    Ok(result.rows_affected)
+}
@@ -1 +1,2 @@
+pub mod delete_old_events;
 pub mod log_event;
@@ -0,0 +1,21 @@
+use crate::entity::users::{Column, Entity as UserEntity, Model as UserModel};
+use crate::service::error::errors::Errors;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+
+/// 여러 핸들에 해당하는 사용자를 한 번에 조회 (존재하지 않는 핸들은 무시)
+pub async fn repository_find_users_by_handles<C>(
+    conn: &C,
+    handles: &[String],
+) -> Result<Vec<UserModel>, Errors>
+where
+    C: ConnectionTrait,
+{
+    if handles.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    Ok(UserEntity::find()
+        .filter(Column::Handle.is_in(handles.iter().cloned()))
+        .all(conn)
+        .await?)
+}
@@ -0,0 +1,22 @@
+use crate::entity::users::{Column, Entity as UserEntity, Model as UserModel};
+use crate::service::error::errors::Errors;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+use uuid::Uuid;
+
+/// 여러 사용자를 한 번에 조회 (존재하지 않는 ID는 무시)
+pub async fn repository_find_users_by_uuids<C>(
+    conn: &C,
+    user_uuids: &[Uuid],
+) -> Result<Vec<UserModel>, Errors>
+where
+    C: ConnectionTrait,
+{
+    if user_uuids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    Ok(UserEntity::find()
+        .filter(Column::Id.is_in(user_uuids.iter().cloned()))
+        .all(conn)
+        .await?)
+}
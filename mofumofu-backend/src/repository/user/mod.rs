@@ -2,7 +2,11 @@ pub mod create_user;
 pub mod find_user_by_email;
 pub mod find_user_by_handle;
 pub mod find_user_by_uuid;
+pub mod find_users_by_handles;
+pub mod find_users_by_uuids;
 pub mod get_user_by_handle;
 pub mod get_user_by_uuid;
+pub mod suggest_users;
+pub mod sync_follow_counts;
 pub mod update_follow_count;
 pub mod update_user;
@@ -0,0 +1,74 @@
+use crate::dto::user::internal::user_suggestion_row::UserSuggestionRow;
+use crate::utils::like_pattern::escape_like_pattern;
+use sea_orm::{ConnectionTrait, DbBackend, FromQueryResult, Statement};
+use uuid::Uuid;
+
+/// 순위를 매길 후보 수 (팔로워 순으로 자름)
+const USER_SUGGEST_CANDIDATES: i64 = 100;
+
+/// 핸들 접두사 또는 이름 부분 일치로 사용자 추천
+/// (팔로우 중 > 핸들 정확히 일치 > 핸들 접두사 > 팔로워 수 순, 본인 제외)
+/// `query`는 소문자여야 함
+pub async fn repository_suggest_users<C>(
+    conn: &C,
+    query: &str,
+    viewer_id: Option<Uuid>,
+    limit: u64,
+) -> Result<Vec<UserSuggestionRow>, sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let sql = r#"
+        WITH candidates AS (
+            (SELECT u.id FROM users u
+             WHERE LOWER(u.handle) = $4)
+            UNION
+            (SELECT u.id FROM users u
+             WHERE LOWER(u.handle) LIKE $1
+             ORDER BY u.follower_count DESC
+             LIMIT $5)
+            UNION
+            (SELECT u.id FROM users u
+             WHERE LOWER(u.name) LIKE $2
+             ORDER BY u.follower_count DESC
+             LIMIT $5)
+            UNION
+            (SELECT u.id FROM follows f
+             JOIN users u ON u.id = f.followee_id
+             WHERE f.follower_id = $3
+               AND (LOWER(u.handle) LIKE $1 OR LOWER(u.name) LIKE $2)
+             LIMIT $5)
+        )
+        SELECT u.handle, u.name, u.profile_image, (f.id IS NOT NULL) AS is_following
+        FROM candidates c
+        JOIN users u ON u.id = c.id
+        LEFT JOIN follows f ON f.follower_id = $3 AND f.followee_id = u.id
+        WHERE $3::uuid IS NULL OR u.id <> $3
+        ORDER BY
+            is_following DESC,
+            (LOWER(u.handle) = $4) DESC,
+            (LOWER(u.handle) LIKE $1) DESC,
+            u.follower_count DESC,
+            u.handle ASC
+        LIMIT $6
+    "#;
+
+    let escaped = escape_like_pattern(query);
+
+    let statement = Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        sql,
+        [
+            format!("{}%", escaped).into(),
+            format!("%{}%", escaped).into(),
+            viewer_id.into(),
+            query.into(),
+            USER_SUGGEST_CANDIDATES.into(),
+            (limit as i64).into(),
+        ],
+    );
+
+    UserSuggestionRow::find_by_statement(statement)
+        .all(conn)
+        .await
+}
@@ -0,0 +1,44 @@
+use sea_orm::{ConnectionTrait, DbBackend, Statement};
+
+/// 모든 유저의 follower_count/following_count를 follows 테이블의 실제 개수로 맞춤
+///
+/// (follower_count가 수정된 유저 수, following_count가 수정된 유저 수)를 반환
+pub async fn repository_sync_user_follow_counts<C>(conn: &C) -> Result<(u64, u64), sea_orm::DbErr>
+where
+    C: ConnectionTrait,
+{
+    let follower_sql = r#"
+        UPDATE users u
+        SET follower_count = c.actual_count
+        FROM (
+            SELECT u2.id, COUNT(f.id)::int AS actual_count
+            FROM users u2
+            LEFT JOIN follows f ON f.followee_id = u2.id
+            GROUP BY u2.id
+        ) c
+        WHERE u.id = c.id
+          AND u.follower_count <> c.actual_count
+    "#;
+
+    let following_sql = r#"
+        UPDATE users u
+        SET following_count = c.actual_count
+        FROM (
+            SELECT u2.id, COUNT(f.id)::int AS actual_count
+            FROM users u2
+            LEFT JOIN follows f ON f.follower_id = u2.id
+            GROUP BY u2.id
+        ) c
+        WHERE u.id = c.id
+          AND u.following_count <> c.actual_count
+    "#;
+
+    let followers = conn
+        .execute(Statement::from_string(DbBackend::Postgres, follower_sql))
+        .await?;
+    let following = conn
+        .execute(Statement::from_string(DbBackend::Postgres, following_sql))
+        .await?;
+
+    Ok((followers.rows_affected(), following.rows_affected()))
+}
@@ -1,6 +1,9 @@
 use crate::{
+    config::db_config::DbConfig,
     dto::admin::response::AdminTaskResponse,
+    dto::job::internal::job::Job,
     microservices::admin_tasks_client::cleanup_expired_refresh_tokens,
+    service::admin::enqueue_admin_job::service_enqueue_admin_job,
     service::auth::role_check::require_admin,
     service::error::errors::{Errors, ServiceResult},
     state::AppState,
@@ -17,6 +20,15 @@ pub async fn service_cleanup_expired_tokens(
 
     info!("Admin user {} triggering expired tokens cleanup", user_id);
 
+    if DbConfig::get().native_job_runner {
+        return service_enqueue_admin_job(
+            app_state,
+            Job::CleanupExpiredTokens,
+            "만료된 토큰 정리 작업이 시작되었습니다",
+        )
+        .await;
+    }
+
     match cleanup_expired_refresh_tokens(&app_state.http_client).await {
         Ok(response) => {
             info!("Successfully triggered expired tokens cleanup");
@@ -1,6 +1,9 @@
 use crate::{
+    config::db_config::DbConfig,
     dto::admin::response::AdminTaskResponse,
+    dto::job::internal::job::Job,
     microservices::admin_tasks_client::cleanup_old_system_events,
+    service::admin::enqueue_admin_job::service_enqueue_admin_job,
     service::auth::role_check::require_admin,
     service::error::errors::{Errors, ServiceResult},
     state::AppState,
@@ -17,6 +20,15 @@ pub async fn service_cleanup_old_events(
 
     info!("Admin user {} triggering old events cleanup", user_id);
 
+    if DbConfig::get().native_job_runner {
+        return service_enqueue_admin_job(
+            app_state,
+            Job::CleanupOldEvents,
+            "오래된 시스템 이벤트 정리 작업이 시작되었습니다",
+        )
+        .await;
+    }
+
     match cleanup_old_system_events(&app_state.http_client).await {
         Ok(response) => {
             info!("Successfully triggered old events cleanup");
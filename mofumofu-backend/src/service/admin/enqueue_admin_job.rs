@@ -0,0 +1,37 @@
+use crate::{
+    dto::admin::response::AdminTaskResponse,
+    dto::job::internal::job::Job,
+    service::error::errors::{Errors, ServiceResult},
+    service::job::job_queue::service_enqueue_job,
+    state::AppState,
+};
+use serde_json::json;
+use tracing::{error, info};
+
+/// 관리자 작업을 내장 작업 큐에 등록 (JOB_EXECUTOR=native일 때 태스크 서버 대신 사용)
+pub async fn service_enqueue_admin_job(
+    app_state: &AppState,
+    job: Job,
+    message: &str,
+) -> ServiceResult<AdminTaskResponse> {
+    let job_name = job.name();
+
+    let task_id = service_enqueue_job(&app_state.redis, job)
+        .await
+        .map_err(|e| {
+            error!("Failed to enqueue job {}: {}", job_name, e);
+            Errors::SysInternalError(e.to_string())
+        })?;
+
+    info!("Enqueued job {} ({})", task_id, job_name);
+
+    Ok(AdminTaskResponse {
+        success: true,
+        message: message.to_string(),
+        data: Some(json!({
+            "task_id": task_id,
+            "status": "PENDING",
+            "message": message,
+        })),
+    })
+}
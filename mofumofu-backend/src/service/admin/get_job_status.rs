@@ -0,0 +1,52 @@
+use crate::{
+    config::db_config::DbConfig,
+    dto::admin::response::JobStatusResponse,
+    microservices::email_client::get_email_task_status,
+    service::auth::role_check::require_admin,
+    service::error::errors::{Errors, ServiceResult},
+    service::job::job_queue::service_get_job_record,
+    state::AppState,
+};
+use tracing::error;
+use uuid::Uuid;
+
+pub async fn service_get_job_status(
+    app_state: &AppState,
+    user_id: Uuid,
+    task_id: &str,
+) -> ServiceResult<JobStatusResponse> {
+    // Admin 권한 확인
+    require_admin(&app_state.conn, user_id).await?;
+
+    if !DbConfig::get().native_job_runner {
+        let response = get_email_task_status(&app_state.http_client, task_id)
+            .await
+            .map_err(|e| {
+                error!("Failed to get task status: {}", e);
+                Errors::SysInternalError(e.to_string())
+            })?;
+
+        return serde_json::from_value(response).map_err(|e| {
+            error!("Invalid task status response: {}", e);
+            Errors::SysInternalError(e.to_string())
+        });
+    }
+
+    let record = service_get_job_record(&app_state.redis, task_id)
+        .await
+        .map_err(|e| {
+            error!("Failed to get job status: {}", e);
+            Errors::SysInternalError(e.to_string())
+        })?;
+
+    // Celery와 마찬가지로 알 수 없는(또는 결과가 만료된) 작업은 PENDING으로 응답
+    Ok(match record {
+        Some(record) => record.into(),
+        None => JobStatusResponse {
+            state: "PENDING".to_string(),
+            status: "작업이 대기 중입니다".to_string(),
+            result: None,
+            error: None,
+        },
+    })
+}
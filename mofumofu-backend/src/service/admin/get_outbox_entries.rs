@@ -0,0 +1,30 @@
+use crate::dto::admin::request::GetOutboxEntriesRequest;
+use crate::dto::admin::response::{OutboxEntriesResponse, OutboxEntry};
+use crate::entity::common::OutboxStatus;
+use crate::repository::outbox::get_outbox_entries::repository_get_stuck_outbox_entries;
+use crate::service::auth::role_check::require_admin;
+use crate::service::error::errors::ServiceResult;
+use sea_orm::ConnectionTrait;
+use uuid::Uuid;
+
+const DEFAULT_LIMIT: u64 = 50;
+
+pub async fn service_get_outbox_entries<C>(
+    conn: &C,
+    user_id: Uuid,
+    payload: GetOutboxEntriesRequest,
+) -> ServiceResult<OutboxEntriesResponse>
+where
+    C: ConnectionTrait,
+{
+    require_admin(conn, user_id).await?;
+
+    let status = payload.status.unwrap_or(OutboxStatus::Dead);
+    let limit = payload.limit.unwrap_or(DEFAULT_LIMIT);
+
+    let entries = repository_get_stuck_outbox_entries(conn, status, limit).await?;
+
+    Ok(OutboxEntriesResponse {
+        entries: entries.into_iter().map(OutboxEntry::from).collect(),
+    })
+}
@@ -0,0 +1,20 @@
+use crate::dto::admin::response::TrendingSettingsResponse;
+use crate::repository::post::trending_settings::repository_get_trending_settings;
+use crate::service::auth::role_check::require_moderator;
+use crate::service::error::errors::ServiceResult;
+use sea_orm::ConnectionTrait;
+use uuid::Uuid;
+
+pub async fn service_get_trending_settings<C>(
+    conn: &C,
+    user_id: Uuid,
+) -> ServiceResult<TrendingSettingsResponse>
+where
+    C: ConnectionTrait,
+{
+    require_moderator(conn, user_id).await?;
+
+    let settings = repository_get_trending_settings(conn).await?;
+
+    Ok(settings.into())
+}
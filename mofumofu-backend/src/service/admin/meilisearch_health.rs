@@ -1,4 +1,5 @@
 use crate::{
+    config::db_config::DbConfig,
     dto::admin::response::AdminTaskResponse,
     microservices::admin_tasks_client::check_meilisearch_health,
     service::auth::role_check::require_admin,
@@ -18,7 +19,22 @@ pub async fn service_meilisearch_health(
 
     info!("Admin user {} checking Meilisearch health", user_id);
 
-    match check_meilisearch_health(&app_state.http_client).await {
+    // 내장 작업 큐 사용 시 태스크 서버 없이 Meilisearch에 직접 확인
+    let result = if DbConfig::get().native_job_runner {
+        app_state
+            .meilisearch
+            .get_client()
+            .health()
+            .await
+            .map(|health| json!({ "status": "success", "health": { "status": health.status } }))
+            .map_err(|e| e.to_string())
+    } else {
+        check_meilisearch_health(&app_state.http_client)
+            .await
+            .map_err(|e| e.to_string())
+    };
+
+    match result {
         Ok(response) => {
             info!("Successfully checked Meilisearch health");
             Ok(AdminTaskResponse {
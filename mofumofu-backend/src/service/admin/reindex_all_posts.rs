@@ -1,6 +1,9 @@
 use crate::{
+    config::db_config::DbConfig,
     dto::admin::response::AdminTaskResponse,
+    dto::job::internal::job::Job,
     microservices::admin_tasks_client::trigger_reindex_all_posts,
+    service::admin::enqueue_admin_job::service_enqueue_admin_job,
     service::auth::role_check::require_admin,
     service::error::errors::{Errors, ServiceResult},
     state::AppState,
@@ -18,6 +21,15 @@ pub async fn service_reindex_all_posts(
 
     info!("Admin user {} triggering reindex all posts", user_id);
 
+    if DbConfig::get().native_job_runner {
+        return service_enqueue_admin_job(
+            app_state,
+            Job::ReindexAllPosts,
+            "전체 포스트 재색인 작업이 시작되었습니다",
+        )
+        .await;
+    }
+
     match trigger_reindex_all_posts(&app_state.http_client).await {
         Ok(response) => {
             info!("Successfully triggered reindex all posts task");
@@ -0,0 +1,25 @@
+use crate::dto::admin::request::RequeueOutboxEntriesRequest;
+use crate::dto::admin::response::RequeueOutboxEntriesResponse;
+use crate::repository::outbox::requeue_outbox_entries::repository_requeue_outbox_entries;
+use crate::service::auth::role_check::require_admin;
+use crate::service::error::errors::ServiceResult;
+use sea_orm::ConnectionTrait;
+use tracing::info;
+use uuid::Uuid;
+
+pub async fn service_requeue_outbox_entries<C>(
+    conn: &C,
+    user_id: Uuid,
+    payload: RequeueOutboxEntriesRequest,
+) -> ServiceResult<RequeueOutboxEntriesResponse>
+where
+    C: ConnectionTrait,
+{
+    require_admin(conn, user_id).await?;
+
+    let requeued = repository_requeue_outbox_entries(conn, &payload.ids).await?;
+
+    info!("Admin {} requeued {} outbox entries", user_id, requeued);
+
+    Ok(RequeueOutboxEntriesResponse { requeued })
+}
@@ -1,8 +1,10 @@
 use crate::{
+    config::db_config::DbConfig,
     dto::admin::response::AdminTaskResponse,
     microservices::admin_tasks_client::get_search_stats,
     service::auth::role_check::require_admin,
     service::error::errors::{Errors, ServiceResult},
+    service::meilisearch::post_indexer::get_posts_index_stats,
     state::AppState,
 };
 use serde_json::json;
@@ -18,7 +20,19 @@ pub async fn service_search_stats(
 
     info!("Admin user {} getting search stats", user_id);
 
-    match get_search_stats(&app_state.http_client).await {
+    // 내장 작업 큐 사용 시 태스크 서버 없이 Meilisearch에 직접 조회
+    let result = if DbConfig::get().native_job_runner {
+        get_posts_index_stats(&app_state.meilisearch)
+            .await
+            .map(|stats| json!({ "status": "success", "stats": stats }))
+            .map_err(|e| e.to_string())
+    } else {
+        get_search_stats(&app_state.http_client)
+            .await
+            .map_err(|e| e.to_string())
+    };
+
+    match result {
         Ok(response) => {
             info!("Successfully retrieved search stats");
             Ok(AdminTaskResponse {
@@ -1,6 +1,9 @@
 use crate::{
+    config::db_config::DbConfig,
     dto::admin::response::AdminTaskResponse,
+    dto::job::internal::job::Job,
     microservices::admin_tasks_client::sync_user_follow_counts,
+    service::admin::enqueue_admin_job::service_enqueue_admin_job,
     service::auth::role_check::require_admin,
     service::error::errors::{Errors, ServiceResult},
     state::AppState,
@@ -18,6 +21,15 @@ pub async fn service_sync_follows(
 
     info!("Admin user {} triggering follow counts sync", user_id);
 
+    if DbConfig::get().native_job_runner {
+        return service_enqueue_admin_job(
+            app_state,
+            Job::SyncFollowCounts,
+            "팔로우 수 동기화 작업이 시작되었습니다",
+        )
+        .await;
+    }
+
     match sync_user_follow_counts(&app_state.http_client).await {
         Ok(response) => {
             info!("Successfully triggered follow counts sync");
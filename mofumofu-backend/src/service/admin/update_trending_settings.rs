@@ -0,0 +1,42 @@
+use crate::dto::admin::request::UpdateTrendingSettingsRequest;
+use crate::dto::admin::response::TrendingSettingsResponse;
+use crate::repository::post::trending_settings::repository_update_trending_settings;
+use crate::service::auth::role_check::require_moderator;
+use crate::service::error::errors::ServiceResult;
+use crate::service::post::trending_scores::service_refresh_trending_scores;
+use sea_orm::ConnectionTrait;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+pub async fn service_update_trending_settings<C>(
+    conn: &C,
+    user_id: Uuid,
+    request: UpdateTrendingSettingsRequest,
+) -> ServiceResult<TrendingSettingsResponse>
+where
+    C: ConnectionTrait,
+{
+    require_moderator(conn, user_id).await?;
+
+    let settings = repository_update_trending_settings(
+        conn,
+        request.like_weight,
+        request.comment_weight,
+        request.view_weight,
+        request.gravity,
+        user_id,
+    )
+    .await?;
+
+    info!("Trending settings updated by {}: {:?}", user_id, settings);
+
+    // 다음 주기를 기다리지 않고 바로 반영
+    if let Err(e) = service_refresh_trending_scores(conn).await {
+        warn!(
+            "Failed to refresh trending scores after settings update: {:?}",
+            e
+        );
+    }
+
+    Ok(settings.into())
+}
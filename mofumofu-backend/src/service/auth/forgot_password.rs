@@ -1,8 +1,9 @@
 use crate::dto::auth::request::forgot_password::ForgotPasswordRequest;
-use crate::microservices::email_client::queue_send_reset_password_email;
+use crate::dto::outbox::internal::outbox_message::OutboxMessage;
 use crate::repository::user::find_user_by_email::repository_find_user_by_email;
 use crate::service::auth::jwt::create_password_reset_token;
 use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::outbox::enqueue_outbox::service_enqueue_outbox;
 use crate::state::AppState;
 use tracing::{error, info};
 
@@ -21,16 +22,17 @@ pub async fn service_forgot_password(
                 Errors::SysInternalError("Failed to create reset token".to_string())
             })?;
 
-            // 비동기로 비밀번호 재설정 이메일 발송 요청
-            if let Err(e) = queue_send_reset_password_email(
-                &state.http_client,
-                &user.email,
-                &user.email,
-                &reset_token,
+            // 아웃박스에 기록하면 디스패처가 재시도하며 발송
+            if let Err(e) = service_enqueue_outbox(
+                &state.conn,
+                OutboxMessage::ResetPasswordEmail {
+                    email: user.email.clone(),
+                    token: reset_token,
+                },
             )
             .await
             {
-                error!("Failed to queue password reset email: {}", e);
+                error!("Failed to queue password reset email: {:?}", e);
                 // 이메일 발송 실패는 로그만 남기고 성공으로 처리 (보안상)
             } else {
                 info!("Password reset email queued for user: {}", user.email);
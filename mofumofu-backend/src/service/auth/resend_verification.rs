@@ -1,14 +1,13 @@
 use crate::dto::auth::request::resend_verification::ResendVerificationRequest;
-use crate::microservices::email_client::queue_send_email_verification;
+use crate::dto::outbox::internal::outbox_message::OutboxMessage;
 use crate::repository::user::find_user_by_email::repository_find_user_by_email;
 use crate::service::auth::jwt::create_email_verification_token;
 use crate::service::error::errors::{Errors, ServiceResult};
-use crate::state::AppState;
+use crate::service::outbox::enqueue_outbox::service_enqueue_outbox;
 use sea_orm::ConnectionTrait;
 use tracing::{error, info};
 
 pub async fn service_resend_verification<C>(
-    state: &AppState,
     conn: &C,
     payload: ResendVerificationRequest,
 ) -> ServiceResult<()>
@@ -32,22 +31,18 @@ where
             Errors::SysInternalError("Failed to create verification token".to_string())
         })?;
 
-    // 비동기로 이메일 발송 요청
-    if let Err(e) = queue_send_email_verification(
-        &state.http_client,
-        &user.email,
-        &user.name,
-        &verification_token,
+    // 아웃박스에 기록하면 디스패처가 재시도하며 발송
+    service_enqueue_outbox(
+        conn,
+        OutboxMessage::VerificationEmail {
+            email: user.email.clone(),
+            username: user.name,
+            token: verification_token,
+        },
     )
-    .await
-    {
-        error!("Failed to queue verification email: {}", e);
-        return Err(Errors::SysInternalError(
-            "Failed to send verification email".to_string(),
-        ));
-    } else {
-        info!("Verification email queued for user: {}", user.email);
-    }
+    .await?;
+
+    info!("Verification email queued for user: {}", user.email);
 
     Ok(())
 }
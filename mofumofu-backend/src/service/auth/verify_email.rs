@@ -1,14 +1,21 @@
+use crate::connection::redis_connection::RedisConnection;
 use crate::dto::auth::request::verify_email::VerifyEmailRequest;
+use crate::dto::cache::internal::cache_event::CacheEvent;
 use crate::dto::user::internal::update_user::UpdateUserFields;
 use crate::repository::user::find_user_by_uuid::repository_find_user_by_uuid;
 use crate::repository::user::update_user::repository_update_user;
 use crate::service::auth::jwt::decode_email_verification_token;
+use crate::service::cache::invalidate_cache::service_publish_cache_event;
 use crate::service::error::errors::{Errors, ServiceResult};
 use chrono::Utc;
 use sea_orm::{ConnectionTrait, TransactionTrait};
 use tracing::{error, info};
 
-pub async fn service_verify_email<C>(conn: &C, payload: VerifyEmailRequest) -> ServiceResult<()>
+pub async fn service_verify_email<C>(
+    conn: &C,
+    redis: &RedisConnection,
+    payload: VerifyEmailRequest,
+) -> ServiceResult<()>
 where
     C: ConnectionTrait + TransactionTrait,
 {
@@ -61,6 +68,14 @@ where
 
     txn.commit().await?;
 
+    service_publish_cache_event(
+        redis,
+        CacheEvent::UserChanged {
+            user_id: claims.sub,
+        },
+    )
+    .await;
+
     info!("Email verified successfully for user: {}", claims.sub);
 
     Ok(())
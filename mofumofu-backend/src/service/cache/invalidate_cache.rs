@@ -0,0 +1,49 @@
+use crate::connection::redis_connection::RedisConnection;
+use crate::dto::cache::internal::cache_event::CacheEvent;
+use crate::dto::cache::internal::cache_tag::CACHE_GENERATION_KEY;
+use redis::Script;
+use std::sync::LazyLock;
+use tracing::warn;
+
+/// 태그별 세대 키 유지 시간 (초, 캐시를 채우는 요청보다 오래 남아 있으면 충분함)
+const TAG_GENERATION_TTL_SECONDS: i64 = 86400;
+
+/// 태그의 세대 번호를 올리고, 태그 set에 모인 캐시 키를 모두 지운 뒤 set도 삭제
+///
+/// 세대 번호는 이 무효화 전에 조회를 시작한 요청이 이전 데이터를 다시 저장하지 않도록 막음
+static INVALIDATE_TAG_SCRIPT: LazyLock<Script> = LazyLock::new(|| {
+    Script::new(
+        r#"
+local generation = redis.call('INCR', KEYS[3])
+redis.call('SET', KEYS[2], generation, 'EX', ARGV[1])
+local keys = redis.call('SMEMBERS', KEYS[1])
+for _, key in ipairs(keys) do
+    redis.call('DEL', key)
+end
+redis.call('DEL', KEYS[1])
+return #keys
+"#,
+    )
+});
+
+/// 변경 사항에 해당하는 캐시 항목 무효화
+///
+/// 커밋 이후에 호출하며, 실패해도 요청은 성공으로 처리하고 TTL이 지나면 만료됨
+pub async fn service_publish_cache_event(redis: &RedisConnection, event: CacheEvent) {
+    let mut redis_conn = redis.clone();
+
+    for tag in event.tags() {
+        let tag_key = tag.key();
+        let result: redis::RedisResult<i64> = INVALIDATE_TAG_SCRIPT
+            .key(&tag_key)
+            .key(tag.generation_key())
+            .key(CACHE_GENERATION_KEY)
+            .arg(TAG_GENERATION_TTL_SECONDS)
+            .invoke_async(&mut redis_conn)
+            .await;
+
+        if let Err(e) = result {
+            warn!("Failed to invalidate response cache tag {}: {}", tag_key, e);
+        }
+    }
+}
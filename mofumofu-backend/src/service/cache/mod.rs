@@ -0,0 +1,2 @@
+pub mod invalidate_cache;
+pub mod response_cache;
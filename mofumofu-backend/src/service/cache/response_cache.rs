@@ -0,0 +1,216 @@
+use crate::connection::redis_connection::RedisConnection;
+use crate::dto::cache::internal::cache_endpoint::CacheEndpoint;
+use crate::dto::cache::internal::cache_tag::{CACHE_GENERATION_KEY, CacheTag};
+use crate::service::error::errors::ServiceResult;
+use crate::utils::metrics::RESPONSE_CACHE_REQUESTS_TOTAL;
+use metrics::counter;
+use redis::{AsyncCommands, Script};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::future::Future;
+use std::sync::LazyLock;
+use std::time::Duration;
+use tracing::warn;
+use uuid::Uuid;
+
+/// 캐시를 채우는 동안 다른 요청이 DB를 조회하지 않도록 잡는 락의 유지 시간 (밀리초)
+const FILL_LOCK_TTL_MS: u64 = 5000;
+/// 다른 요청이 캐시를 채우기를 기다리는 간격과 횟수 (최대 1초)
+const FILL_WAIT_INTERVAL: Duration = Duration::from_millis(50);
+const FILL_WAIT_ATTEMPTS: u32 = 20;
+/// 태그 set 유지 시간 (초, 무효화 없이 쌓이는 것 방지)
+const TAG_TTL_SECONDS: i64 = 86400;
+
+/// 내가 잡은 락일 때만 해제
+static RELEASE_LOCK_SCRIPT: LazyLock<Script> = LazyLock::new(|| {
+    Script::new(
+        r#"
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+    return redis.call('DEL', KEYS[1])
+end
+return 0
+"#,
+    )
+});
+
+/// 조회를 시작한 뒤 태그 중 하나라도 무효화되었으면 저장하지 않음
+///
+/// KEYS: 캐시 키, (태그 set, 태그 세대 키) 쌍
+/// ARGV: {JSON, TTL(초), 조회 시작 시점의 세대 번호, 태그 set TTL(초)}
+static STORE_SCRIPT: LazyLock<Script> = LazyLock::new(|| {
+    Script::new(
+        r#"
+local started = tonumber(ARGV[3])
+for i = 2, #KEYS, 2 do
+    local generation = tonumber(redis.call('GET', KEYS[i + 1]) or '0')
+    if generation > started then
+        return 0
+    end
+end
+redis.call('SET', KEYS[1], ARGV[1], 'EX', ARGV[2])
+for i = 2, #KEYS, 2 do
+    redis.call('SADD', KEYS[i], KEYS[1])
+    redis.call('EXPIRE', KEYS[i], ARGV[4])
+end
+return 1
+"#,
+    )
+});
+
+fn cache_key(endpoint: CacheEndpoint, key: &str) -> String {
+    format!("cache:{}:{}", endpoint.name(), key)
+}
+
+fn lock_key(cache_key: &str) -> String {
+    format!("cache_lock:{}", cache_key)
+}
+
+/// 캐시에 있으면 그대로 반환하고, 없으면 load로 조회한 뒤 태그와 함께 저장
+///
+/// 같은 키를 동시에 조회하면 한 요청만 load를 실행하고 나머지는 캐시가 채워지기를 기다림.
+/// load 도중 태그가 무효화되면 이전 데이터일 수 있으므로 저장하지 않음.
+/// Redis 오류 시에는 캐시 없이 바로 조회함
+pub async fn service_cached<T, F, Fut>(
+    redis: &RedisConnection,
+    endpoint: CacheEndpoint,
+    key: &str,
+    load: F,
+) -> ServiceResult<T>
+where
+    T: Serialize + DeserializeOwned,
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = ServiceResult<(T, Vec<CacheTag>)>>,
+{
+    let ttl = endpoint.ttl_seconds();
+    if ttl == 0 {
+        return load().await.map(|(value, _)| value);
+    }
+
+    let cache_key = cache_key(endpoint, key);
+    let mut redis_conn = redis.clone();
+
+    match read_cached(&mut redis_conn, &cache_key).await {
+        Ok(Some(value)) => {
+            record(endpoint, "hit");
+            return Ok(value);
+        }
+        Ok(None) => {}
+        Err(e) => {
+            warn!("Failed to read response cache {}: {}", cache_key, e);
+            record(endpoint, "error");
+            return load().await.map(|(value, _)| value);
+        }
+    }
+
+    // 캐시를 채울 권한(락) 획득 시도
+    let lock_key = lock_key(&cache_key);
+    let lock_token = Uuid::new_v4().to_string();
+    let acquired: bool = redis::cmd("SET")
+        .arg(&lock_key)
+        .arg(&lock_token)
+        .arg("NX")
+        .arg("PX")
+        .arg(FILL_LOCK_TTL_MS)
+        .query_async::<Option<String>>(&mut redis_conn)
+        .await
+        .map(|reply| reply.is_some())
+        .unwrap_or(true);
+
+    if !acquired {
+        // 다른 요청이 채우는 중이면 잠시 기다렸다가 캐시에서 읽음
+        for _ in 0..FILL_WAIT_ATTEMPTS {
+            tokio::time::sleep(FILL_WAIT_INTERVAL).await;
+            if let Ok(Some(value)) = read_cached(&mut redis_conn, &cache_key).await {
+                record(endpoint, "hit");
+                return Ok(value);
+            }
+        }
+    }
+
+    record(endpoint, "miss");
+    // 조회 도중 무효화되었는지 판단할 기준 (읽지 못하면 저장하지 않음)
+    let started_generation: Option<i64> = redis_conn
+        .get::<_, Option<i64>>(CACHE_GENERATION_KEY)
+        .await
+        .map(|generation| generation.unwrap_or(0))
+        .ok();
+    let result = load().await;
+
+    if let (Ok((value, tags)), Some(started_generation)) = (&result, started_generation)
+        && let Err(e) = store(
+            &mut redis_conn,
+            &cache_key,
+            value,
+            tags,
+            ttl,
+            started_generation,
+        )
+        .await
+    {
+        warn!("Failed to store response cache {}: {}", cache_key, e);
+    }
+
+    if acquired {
+        let released: redis::RedisResult<i32> = RELEASE_LOCK_SCRIPT
+            .key(&lock_key)
+            .arg(&lock_token)
+            .invoke_async(&mut redis_conn)
+            .await;
+        if let Err(e) = released {
+            warn!("Failed to release response cache lock {}: {}", lock_key, e);
+        }
+    }
+
+    result.map(|(value, _)| value)
+}
+
+async fn read_cached<T>(
+    redis_conn: &mut RedisConnection,
+    cache_key: &str,
+) -> redis::RedisResult<Option<T>>
+where
+    T: DeserializeOwned,
+{
+    let cached: Option<String> = redis_conn.get(cache_key).await?;
+
+    // 구조가 바뀌어 읽을 수 없는 항목은 없는 것으로 취급
+    Ok(cached.and_then(|json| serde_json::from_str(&json).ok()))
+}
+
+async fn store<T>(
+    redis_conn: &mut RedisConnection,
+    cache_key: &str,
+    value: &T,
+    tags: &[CacheTag],
+    ttl: u64,
+    started_generation: i64,
+) -> redis::RedisResult<()>
+where
+    T: Serialize,
+{
+    let Ok(json) = serde_json::to_string(value) else {
+        return Ok(());
+    };
+
+    let mut invocation = STORE_SCRIPT.key(cache_key);
+    for tag in tags {
+        invocation.key(tag.key()).key(tag.generation_key());
+    }
+
+    invocation
+        .arg(json)
+        .arg(ttl)
+        .arg(started_generation)
+        .arg(TAG_TTL_SECONDS)
+        .invoke_async(redis_conn)
+        .await
+}
+
+fn record(endpoint: CacheEndpoint, result: &'static str) {
+    counter!(
+        RESPONSE_CACHE_REQUESTS_TOTAL,
+        "endpoint" => endpoint.name(),
+        "result" => result
+    )
+    .increment(1);
+}
@@ -0,0 +1,101 @@
+use crate::dto::comment::response::CommentInfo;
+use crate::entity::comments::Model as CommentModel;
+use crate::entity::common::LikeTargetType;
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::markdown::comment_renderer::comment_render_html;
+use crate::service::reaction::get_reactions::service_get_reactions;
+use crate::service::user::load_users::service_load_users;
+use sea_orm::ConnectionTrait;
+use uuid::Uuid;
+
+/// 댓글 목록을 CommentInfo로 변환
+///
+/// 좋아요/답글 수는 댓글에 저장된 값을 사용하고,
+/// 작성자와 반응은 한 번에 묶어서 조회함
+pub async fn service_build_comment_infos<C>(
+    conn: &C,
+    comments: Vec<CommentModel>,
+    viewer_id: Option<Uuid>,
+) -> ServiceResult<Vec<CommentInfo>>
+where
+    C: ConnectionTrait,
+{
+    if comments.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    // 작성자 일괄 조회 (삭제된 댓글은 작성자를 숨기므로 제외)
+    let users = service_load_users(
+        conn,
+        comments
+            .iter()
+            .filter(|comment| !comment.is_deleted)
+            .map(|comment| comment.user_id),
+    )
+    .await?;
+
+    // 반응 집계 및 조회한 사용자의 반응
+    let comment_ids: Vec<Uuid> = comments.iter().map(|comment| comment.id).collect();
+    let mut reaction_summaries =
+        service_get_reactions(conn, viewer_id, LikeTargetType::Comment, &comment_ids).await?;
+
+    comments
+        .into_iter()
+        .map(|comment| {
+            let reaction_summary = reaction_summaries.remove(&comment.id).unwrap_or_default();
+
+            // 삭제된 댓글은 내용과 사용자 정보를 숨김
+            if comment.is_deleted {
+                return Ok(CommentInfo {
+                    id: comment.id,
+                    content: None,
+                    render: None,
+                    post_id: comment.post_id,
+                    user_id: None,
+                    user_handle: None,
+                    user_name: None,
+                    user_profile_image: None,
+                    parent_id: comment.parent_id,
+                    like_count: comment.like_count,
+                    reply_count: comment.reply_count,
+                    is_deleted: comment.is_deleted,
+                    created_at: comment.created_at,
+                    updated_at: comment.updated_at,
+                    reactions: reaction_summary.reactions,
+                    my_reactions: reaction_summary.my_reactions,
+                    is_pinned: comment.is_pinned,
+                    is_hidden: comment.is_hidden,
+                    is_locked: comment.is_locked,
+                    edit_count: comment.edit_count,
+                });
+            }
+
+            let user = users.get(&comment.user_id).ok_or(Errors::UserNotFound)?;
+
+            let render = comment_render_html(&comment);
+
+            Ok(CommentInfo {
+                id: comment.id,
+                content: Some(comment.content),
+                render: Some(render),
+                post_id: comment.post_id,
+                user_id: Some(comment.user_id),
+                user_handle: Some(user.handle.clone()),
+                user_name: Some(user.name.clone()),
+                user_profile_image: user.profile_image.clone(),
+                parent_id: comment.parent_id,
+                like_count: comment.like_count,
+                reply_count: comment.reply_count,
+                is_deleted: comment.is_deleted,
+                created_at: comment.created_at,
+                updated_at: comment.updated_at,
+                reactions: reaction_summary.reactions,
+                my_reactions: reaction_summary.my_reactions,
+                is_pinned: comment.is_pinned,
+                is_hidden: comment.is_hidden,
+                is_locked: comment.is_locked,
+                edit_count: comment.edit_count,
+            })
+        })
+        .collect()
+}
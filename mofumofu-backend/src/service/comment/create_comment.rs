@@ -1,15 +1,32 @@
+use crate::connection::redis_connection::RedisConnection;
+use crate::dto::cache::internal::cache_event::CacheEvent;
 use crate::dto::comment::request::CreateCommentRequest;
 use crate::dto::comment::response::CreateCommentResponse;
 use crate::repository::comment::create_comment::repository_create_comment;
+use crate::repository::comment::get_comment_by_id::repository_get_comment_with_ancestors;
 use crate::repository::comment::update_reply_count::repository_increment_reply_count;
 use crate::repository::post::get_post_by_uuid::repository_get_post_by_uuid;
+use crate::repository::post::post_analytics::repository_increment_post_daily_comments;
 use crate::repository::post::update_comment_count::repository_increment_comment_count;
+use crate::service::cache::invalidate_cache::service_publish_cache_event;
 use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::markdown::comment_renderer::render_comment_markdown;
+use crate::service::mention::log_mention_events::service_log_mention_events;
+use crate::service::mention::mention_parser::{
+    extract_mentions_from_markdown, link_mentions_in_markdown,
+};
+use crate::service::mention::resolve_mentions::service_resolve_mentions;
+use crate::service::mention::save_mentions::service_save_mentions;
+use crate::utils::metrics::COMMENTS_CREATED_TOTAL;
+use metrics::counter;
 use sea_orm::{ConnectionTrait, TransactionTrait};
+use std::collections::HashSet;
+use tracing::warn;
 use uuid::Uuid;
 
 pub async fn service_create_comment<C>(
     conn: &C,
+    redis: &RedisConnection,
     user_id: &Uuid,
     request: CreateCommentRequest,
 ) -> ServiceResult<CreateCommentResponse>
@@ -19,16 +36,18 @@ where
     let txn = conn.begin().await?;
 
     // 포스트 존재 확인
-    let _post = repository_get_post_by_uuid(&txn, &request.post_id).await?;
+    let post = repository_get_post_by_uuid(&txn, &request.post_id).await?;
+
+    // 게시글 작성자가 댓글을 막은 경우
+    if post.comments_disabled {
+        return Err(Errors::CommentsDisabled);
+    }
 
     // 부모 댓글이 있다면 존재 확인
     if let Some(parent_id) = request.parent_id {
-        let parent_comment =
-            crate::repository::comment::get_comment_by_id::repository_get_comment_by_id(
-                &txn, parent_id,
-            )
-            .await?
-            .ok_or(Errors::CommentNotFound)?;
+        // 부모 댓글부터 최상위 댓글까지 한 번에 조회
+        let ancestors = repository_get_comment_with_ancestors(&txn, parent_id).await?;
+        let parent_comment = ancestors.first().ok_or(Errors::CommentNotFound)?;
 
         // 부모 댓글이 같은 포스트에 속하는지 확인
         if parent_comment.post_id != request.post_id {
@@ -39,18 +58,54 @@ where
         if parent_comment.is_deleted {
             return Err(Errors::CannotReplyToDeletedComment);
         }
+
+        // 숨긴 댓글은 작성자 본인과 게시글 작성자만 답글을 달 수 있음
+        if parent_comment.is_hidden
+            && parent_comment.user_id != *user_id
+            && post.user_id != *user_id
+        {
+            return Err(Errors::CommentNotFound);
+        }
+
+        // 잠긴 스레드(상위 댓글 중 하나라도 잠김)에는 답글을 달 수 없음
+        if ancestors.iter().any(|comment| comment.is_locked) {
+            return Err(Errors::CommentThreadLocked);
+        }
     }
 
+    // 멘션 파싱 - 존재하는 사용자만 프로필 링크로 렌더링
+    let mentioned_users =
+        service_resolve_mentions(&txn, extract_mentions_from_markdown(&request.content)).await?;
+    let mentioned_handles: HashSet<String> = mentioned_users
+        .iter()
+        .map(|user| user.handle.clone())
+        .collect();
+    let render = render_comment_markdown(&link_mentions_in_markdown(
+        &request.content,
+        &mentioned_handles,
+    ));
+
     // 댓글 생성
     let created_comment = repository_create_comment(
         &txn,
         *user_id,
         request.post_id,
         &request.content,
+        &render,
         request.parent_id,
     )
     .await?;
 
+    // 멘션 저장
+    let new_mentions = service_save_mentions(
+        &txn,
+        *user_id,
+        request.post_id,
+        Some(created_comment.id),
+        &mentioned_users,
+    )
+    .await?;
+
     // 포스트 댓글 수 증가
     repository_increment_comment_count(&txn, &request.post_id).await?;
 
@@ -60,6 +115,36 @@ where
     }
 
     txn.commit().await?;
+
+    counter!(COMMENTS_CREATED_TOTAL).increment(1);
+
+    // 댓글 수가 바뀌므로 포스트 캐시 무효화
+    service_publish_cache_event(
+        redis,
+        CacheEvent::PostChanged {
+            post_id: request.post_id,
+        },
+    )
+    .await;
+
+    // 작성자 통계용 일간 집계
+    if let Err(e) = repository_increment_post_daily_comments(conn, request.post_id).await {
+        warn!(
+            "Failed to record post analytics for {}: {}",
+            request.post_id, e
+        );
+    }
+
+    // 멘션된 사용자별 이벤트 로깅
+    service_log_mention_events(
+        conn,
+        *user_id,
+        request.post_id,
+        Some(created_comment.id),
+        &new_mentions,
+    )
+    .await;
+
     Ok(CreateCommentResponse {
         comment_id: created_comment.id,
     })
@@ -1,14 +1,20 @@
+use crate::connection::redis_connection::RedisConnection;
+use crate::dto::cache::internal::cache_event::CacheEvent;
 use crate::dto::comment::request::DeleteCommentRequest;
 use crate::repository::comment::delete_comment::repository_delete_comment;
 use crate::repository::comment::get_comment_by_id::repository_get_comment_by_id;
 use crate::repository::comment::update_reply_count::repository_decrement_reply_count;
+use crate::repository::post::post_analytics::repository_decrement_post_daily_comments;
 use crate::repository::post::update_comment_count::repository_decrement_comment_count;
+use crate::service::cache::invalidate_cache::service_publish_cache_event;
 use crate::service::error::errors::{Errors, ServiceResult};
 use sea_orm::{ConnectionTrait, TransactionTrait};
+use tracing::warn;
 use uuid::Uuid;
 
 pub async fn service_delete_comment<C>(
     conn: &C,
+    redis: &RedisConnection,
     user_id: &Uuid,
     request: DeleteCommentRequest,
 ) -> ServiceResult<()>
@@ -44,5 +50,29 @@ where
     }
 
     txn.commit().await?;
+
+    // 댓글이 달린 날의 일간 집계에서 제외
+    if let Err(e) = repository_decrement_post_daily_comments(
+        conn,
+        comment.post_id,
+        comment.created_at.date_naive(),
+    )
+    .await
+    {
+        warn!(
+            "Failed to record post analytics for {}: {}",
+            comment.post_id, e
+        );
+    }
+
+    // 댓글 수가 바뀌므로 포스트 캐시 무효화
+    service_publish_cache_event(
+        redis,
+        CacheEvent::PostChanged {
+            post_id: comment.post_id,
+        },
+    )
+    .await;
+
     Ok(())
 }
@@ -1,14 +1,23 @@
+use crate::dto::comment::internal::hidden_comment_visibility::HiddenCommentVisibility;
 use crate::dto::comment::response::CommentInfo;
+use crate::entity::common::LikeTargetType;
 use crate::repository::comment::get_comment_by_id::repository_get_comment_by_id;
 use crate::repository::comment::get_reply_count::repository_get_reply_count;
 use crate::repository::like::check_like_status::repository_check_like_status_by_comment_id;
 use crate::repository::like::get_like_count::repository_get_like_count_by_comment_id;
+use crate::repository::post::get_post_by_uuid::repository_get_post_by_uuid;
 use crate::repository::user::find_user_by_uuid::repository_find_user_by_uuid;
 use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::markdown::comment_renderer::comment_render_html;
+use crate::service::reaction::get_reactions::service_get_reactions;
 use sea_orm::{ConnectionTrait, TransactionTrait};
 use uuid::Uuid;
 
-pub async fn service_get_comment_by_id<C>(conn: &C, comment_id: Uuid) -> ServiceResult<CommentInfo>
+pub async fn service_get_comment_by_id<C>(
+    conn: &C,
+    comment_id: Uuid,
+    viewer_id: Option<Uuid>,
+) -> ServiceResult<CommentInfo>
 where
     C: ConnectionTrait + TransactionTrait,
 {
@@ -17,14 +26,31 @@ where
         .await?
         .ok_or(Errors::CommentNotFound)?;
 
+    // 숨긴 댓글은 게시글 작성자와 댓글 작성자 본인에게만 노출
+    if comment.is_hidden {
+        let post = repository_get_post_by_uuid(conn, &comment.post_id).await?;
+        let visibility = HiddenCommentVisibility::for_viewer(viewer_id, post.user_id);
+        if !visibility.can_see(comment.user_id) {
+            return Err(Errors::CommentNotFound);
+        }
+    }
+
     let like_count = repository_get_like_count_by_comment_id(conn, comment.id).await? as i32;
     let reply_count = repository_get_reply_count(conn, comment.id).await? as i32;
 
+    // 반응 집계 및 조회한 사용자의 반응
+    let reaction_summary =
+        service_get_reactions(conn, viewer_id, LikeTargetType::Comment, &[comment.id])
+            .await?
+            .remove(&comment.id)
+            .unwrap_or_default();
+
     // 삭제된 댓글은 내용과 사용자 정보를 숨김
     let comment_info = if comment.is_deleted {
         CommentInfo {
             id: comment.id,
             content: None,
+            render: None,
             post_id: comment.post_id,
             user_id: None,
             user_handle: None,
@@ -36,15 +62,24 @@ where
             is_deleted: comment.is_deleted,
             created_at: comment.created_at,
             updated_at: comment.updated_at,
+            reactions: reaction_summary.reactions,
+            my_reactions: reaction_summary.my_reactions,
+            is_pinned: comment.is_pinned,
+            is_hidden: comment.is_hidden,
+            is_locked: comment.is_locked,
+            edit_count: comment.edit_count,
         }
     } else {
         let user = repository_find_user_by_uuid(conn, &comment.user_id)
             .await?
             .ok_or(Errors::UserNotFound)?;
 
+        let render = comment_render_html(&comment);
+
         CommentInfo {
             id: comment.id,
             content: Some(comment.content),
+            render: Some(render),
             post_id: comment.post_id,
             user_id: Some(comment.user_id),
             user_handle: Some(user.handle),
@@ -56,6 +91,12 @@ where
             is_deleted: comment.is_deleted,
             created_at: comment.created_at,
             updated_at: comment.updated_at,
+            reactions: reaction_summary.reactions,
+            my_reactions: reaction_summary.my_reactions,
+            is_pinned: comment.is_pinned,
+            is_hidden: comment.is_hidden,
+            is_locked: comment.is_locked,
+            edit_count: comment.edit_count,
         }
     };
 
@@ -0,0 +1,56 @@
+use crate::dto::comment::request::GetCommentHistoryRequest;
+use crate::dto::comment::response::{CommentEditInfo, GetCommentHistoryResponse};
+use crate::entity::common::UserRole;
+use crate::repository::comment::get_comment_by_id::repository_get_comment_by_id;
+use crate::repository::comment::repository_get_comment_edits;
+use crate::repository::post::get_post_by_uuid::repository_get_post_by_uuid;
+use crate::service::auth::role_check::get_user_role;
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::markdown::comment_renderer::render_comment_markdown;
+use sea_orm::{ConnectionTrait, TransactionTrait};
+use uuid::Uuid;
+
+pub async fn service_get_comment_history<C>(
+    conn: &C,
+    user_id: &Uuid,
+    request: GetCommentHistoryRequest,
+) -> ServiceResult<GetCommentHistoryResponse>
+where
+    C: ConnectionTrait + TransactionTrait,
+{
+    let comment = repository_get_comment_by_id(conn, request.comment_id)
+        .await?
+        .ok_or(Errors::CommentNotFound)?;
+
+    // 게시글 작성자와 모더레이터만 수정 기록을 볼 수 있음
+    let post = repository_get_post_by_uuid(conn, &comment.post_id).await?;
+    if post.user_id != *user_id {
+        match get_user_role(conn, *user_id).await? {
+            UserRole::Moderator | UserRole::Admin => {}
+            _ => return Err(Errors::UserUnauthorized),
+        }
+    }
+
+    let edits = repository_get_comment_edits(conn, comment.id)
+        .await?
+        .into_iter()
+        .map(|edit| CommentEditInfo {
+            render: render_comment_markdown(&edit.content),
+            content: edit.content,
+            written_at: edit.written_at,
+            edited_at: edit.edited_at,
+        })
+        .collect();
+
+    let render = comment
+        .render
+        .unwrap_or_else(|| render_comment_markdown(&comment.content));
+
+    Ok(GetCommentHistoryResponse {
+        comment_id: comment.id,
+        content: comment.content,
+        render,
+        edit_count: comment.edit_count,
+        edits,
+    })
+}
@@ -0,0 +1,160 @@
+use crate::config::db_config::DbConfig;
+use crate::dto::comment::internal::comment_tree_query::CommentTreeQuery;
+use crate::dto::comment::internal::comment_tree_row::CommentTreeRow;
+use crate::dto::comment::internal::hidden_comment_visibility::HiddenCommentVisibility;
+use crate::dto::comment::request::GetCommentTreeRequest;
+use crate::dto::comment::response::{
+    CommentInfo, CommentTreeCursor, CommentTreeNode, GetCommentTreeResponse,
+};
+use crate::entity::comments::Model as CommentModel;
+use crate::repository::comment::get_comment_by_id::repository_get_comment_by_id;
+use crate::repository::comment::{
+    repository_count_comments, repository_count_replies, repository_get_comment_tree,
+};
+use crate::repository::post::get_post_by_uuid::repository_get_post_by_uuid;
+use crate::service::comment::build_comment_infos::service_build_comment_infos;
+use crate::service::error::errors::{Errors, ServiceResult};
+use sea_orm::{ConnectionTrait, TransactionTrait};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// 한 포스트의 댓글을 답글까지 트리로 조회
+///
+/// 댓글 수와 관계없이 쿼리 수가 일정하도록 트리는 재귀 CTE 한 번으로 조회하고,
+/// 작성자와 반응은 목록 조회와 같이 service_build_comment_infos에서 묶어서 조회함
+pub async fn service_get_comment_tree<C>(
+    conn: &C,
+    request: GetCommentTreeRequest,
+    viewer_id: Option<Uuid>,
+) -> ServiceResult<GetCommentTreeResponse>
+where
+    C: ConnectionTrait + TransactionTrait,
+{
+    let config = DbConfig::get();
+
+    // 숨김 댓글은 게시글 작성자와 댓글 작성자 본인에게만 노출
+    let post = repository_get_post_by_uuid(conn, &request.post_id).await?;
+    let visibility = HiddenCommentVisibility::for_viewer(viewer_id, post.user_id);
+
+    // 부모 댓글 아래를 이어서 불러오는 경우 부모 확인
+    if let Some(parent_id) = request.parent_id {
+        let parent_comment = repository_get_comment_by_id(conn, parent_id)
+            .await?
+            .ok_or(Errors::CommentNotFound)?;
+
+        if parent_comment.post_id != post.id {
+            return Err(Errors::InvalidParentComment);
+        }
+
+        if parent_comment.is_hidden && !visibility.can_see(parent_comment.user_id) {
+            return Err(Errors::CommentNotFound);
+        }
+    }
+
+    let depth = request
+        .depth
+        .unwrap_or(config.comment_tree_max_depth)
+        .min(config.comment_tree_max_depth);
+
+    let rows = repository_get_comment_tree(
+        conn,
+        CommentTreeQuery {
+            post_id: post.id,
+            parent_id: request.parent_id,
+            offset: request.offset,
+            limit: request.limit,
+            replies_per_level: config.comment_tree_replies_per_level,
+            depth,
+            sort: &request.sort,
+            visibility,
+        },
+    )
+    .await?;
+
+    let total_count = match request.parent_id {
+        Some(parent_id) => repository_count_replies(conn, parent_id, visibility).await?,
+        None => repository_count_comments(conn, post.id, visibility).await?,
+    };
+
+    // 답글 수는 저장된 값 대신 조회자에게 보이는 답글 수를 사용
+    let comments = rows
+        .iter()
+        .map(|row| CommentModel {
+            reply_count: row.child_count as i32,
+            ..row.comment.clone()
+        })
+        .collect();
+    let mut comment_infos: HashMap<Uuid, CommentInfo> =
+        service_build_comment_infos(conn, comments, viewer_id)
+            .await?
+            .into_iter()
+            .map(|info| (info.id, info))
+            .collect();
+
+    // 부모별로 묶고 형제 순서대로 정렬
+    let mut anchors = Vec::new();
+    let mut children: HashMap<Uuid, Vec<CommentTreeRow>> = HashMap::new();
+    for row in rows {
+        match (row.depth, row.comment.parent_id) {
+            (0, _) => anchors.push(row),
+            (_, Some(parent_id)) => children.entry(parent_id).or_default().push(row),
+            (_, None) => {}
+        }
+    }
+    anchors.sort_by_key(|row| row.position);
+    for siblings in children.values_mut() {
+        siblings.sort_by_key(|row| row.position);
+    }
+
+    let loaded_count = anchors.len() as u64;
+    let mut comments = Vec::with_capacity(anchors.len());
+    for row in anchors {
+        comments.push(build_comment_tree_node(
+            row,
+            &mut children,
+            &mut comment_infos,
+        )?);
+    }
+
+    let next_offset = request.offset + loaded_count;
+    let next_cursor = (total_count > next_offset).then_some(CommentTreeCursor {
+        parent_id: request.parent_id,
+        offset: next_offset,
+    });
+
+    Ok(GetCommentTreeResponse {
+        comments,
+        total_count,
+        next_cursor,
+    })
+}
+
+fn build_comment_tree_node(
+    row: CommentTreeRow,
+    children: &mut HashMap<Uuid, Vec<CommentTreeRow>>,
+    comment_infos: &mut HashMap<Uuid, CommentInfo>,
+) -> ServiceResult<CommentTreeNode> {
+    let comment_id = row.comment.id;
+
+    let mut replies = Vec::new();
+    for child in children.remove(&comment_id).unwrap_or_default() {
+        replies.push(build_comment_tree_node(child, children, comment_infos)?);
+    }
+
+    // 불러오지 않은 답글이 남아 있으면 이어서 불러올 커서
+    let next_replies_cursor =
+        (row.child_count > replies.len() as i64).then_some(CommentTreeCursor {
+            parent_id: Some(comment_id),
+            offset: replies.len() as u64,
+        });
+
+    let comment = comment_infos
+        .remove(&comment_id)
+        .ok_or(Errors::CommentNotFound)?;
+
+    Ok(CommentTreeNode {
+        comment,
+        replies,
+        next_replies_cursor,
+    })
+}
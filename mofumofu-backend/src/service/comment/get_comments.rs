@@ -1,12 +1,11 @@
+use crate::dto::comment::internal::hidden_comment_visibility::HiddenCommentVisibility;
 use crate::dto::comment::request::GetCommentsRequest;
-use crate::dto::comment::response::{CommentInfo, GetCommentsResponse};
+use crate::dto::comment::response::GetCommentsResponse;
 use crate::repository::comment::get_comments::{
     repository_count_comments, repository_get_comments,
 };
-use crate::repository::comment::get_reply_count::repository_get_reply_count;
-use crate::repository::like::check_like_status::repository_check_like_status_by_comment_id;
-use crate::repository::like::get_like_count::repository_get_like_count_by_comment_id;
-use crate::repository::user::find_user_by_uuid::repository_find_user_by_uuid;
+use crate::repository::post::get_post_by_uuid::repository_get_post_by_uuid;
+use crate::service::comment::build_comment_infos::service_build_comment_infos;
 use crate::service::error::errors::ServiceResult;
 use sea_orm::{ConnectionTrait, TransactionTrait};
 use uuid::Uuid;
@@ -14,6 +13,7 @@ use uuid::Uuid;
 pub async fn service_get_comments<C>(
     conn: &C,
     request: GetCommentsRequest,
+    viewer_id: Option<Uuid>,
 ) -> ServiceResult<GetCommentsResponse>
 where
     C: ConnectionTrait + TransactionTrait,
@@ -21,59 +21,24 @@ where
     let page = request.page;
     let per_page = request.per_page;
 
-    // 댓글 조회
-    let comments =
-        repository_get_comments(conn, request.post_id, page, per_page, request.sort.clone())
-            .await?;
-    let total_count = repository_count_comments(conn, request.post_id).await?;
+    // 숨김 댓글은 게시글 작성자와 댓글 작성자 본인에게만 노출
+    let post = repository_get_post_by_uuid(conn, &request.post_id).await?;
+    let visibility = HiddenCommentVisibility::for_viewer(viewer_id, post.user_id);
 
-    let mut comment_infos = Vec::new();
-
-    for comment in comments {
-        let like_count = repository_get_like_count_by_comment_id(conn, comment.id).await? as i32;
-        let reply_count = repository_get_reply_count(conn, comment.id).await? as i32;
-
-        // 삭제된 댓글은 내용과 사용자 정보를 숨김
-        let comment_info = if comment.is_deleted {
-            CommentInfo {
-                id: comment.id,
-                content: None,
-                post_id: comment.post_id,
-                user_id: None,
-                user_handle: None,
-                user_name: None,
-                user_profile_image: None,
-                parent_id: comment.parent_id,
-                like_count,
-                reply_count,
-                is_deleted: comment.is_deleted,
-                created_at: comment.created_at,
-                updated_at: comment.updated_at,
-            }
-        } else {
-            let user = repository_find_user_by_uuid(conn, &comment.user_id)
-                .await?
-                .ok_or(crate::service::error::errors::Errors::UserNotFound)?;
-
-            CommentInfo {
-                id: comment.id,
-                content: Some(comment.content),
-                post_id: comment.post_id,
-                user_id: Some(comment.user_id),
-                user_handle: Some(user.handle),
-                user_name: Some(user.name),
-                user_profile_image: user.profile_image,
-                parent_id: comment.parent_id,
-                like_count,
-                reply_count,
-                is_deleted: comment.is_deleted,
-                created_at: comment.created_at,
-                updated_at: comment.updated_at,
-            }
-        };
+    // 댓글 조회 (고정 댓글 우선)
+    let comments = repository_get_comments(
+        conn,
+        request.post_id,
+        page,
+        per_page,
+        request.sort.clone(),
+        visibility,
+    )
+    .await?;
+    let total_count = repository_count_comments(conn, request.post_id, visibility).await?;
 
-        comment_infos.push(comment_info);
-    }
+    // 작성자와 반응은 한 번에, 좋아요/답글 수는 저장된 값으로 채움
+    let comment_infos = service_build_comment_infos(conn, comments, viewer_id).await?;
 
     let has_next = comment_infos.len() == per_page as usize;
 
@@ -85,3 +50,47 @@ where
         has_next,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dto::comment::request::CommentSortOrder;
+    use crate::utils::query_counter::count_queries;
+    use crate::utils::test_database::{
+        insert_comment, insert_comment_like, insert_post, insert_user, test_transaction,
+    };
+
+    // 댓글 수만큼 쿼리가 늘지 않는지 (N+1) 확인
+    #[tokio::test]
+    async fn query_count_does_not_grow_with_comments() {
+        let Some(txn) = test_transaction().await else {
+            return;
+        };
+        let author = insert_user(&txn).await;
+        let viewer = insert_user(&txn).await;
+        let post = insert_post(&txn, author.id, "ko").await;
+        for _ in 0..5 {
+            let user = insert_user(&txn).await;
+            let comment = insert_comment(&txn, post.id, user.id, None).await;
+            insert_comment_like(&txn, viewer.id, comment.id).await;
+        }
+
+        let request = |per_page| GetCommentsRequest {
+            post_id: post.id,
+            page: 1,
+            per_page,
+            sort: CommentSortOrder::Latest,
+        };
+        let (one, one_queries) =
+            count_queries(service_get_comments(&txn, request(1), Some(viewer.id))).await;
+        let (many, many_queries) =
+            count_queries(service_get_comments(&txn, request(5), Some(viewer.id))).await;
+
+        assert_eq!(one.unwrap().comments.len(), 1);
+        assert_eq!(many.unwrap().comments.len(), 5);
+        assert!(one_queries > 0);
+        assert_eq!(one_queries, many_queries);
+
+        txn.rollback().await.unwrap();
+    }
+}
@@ -1,17 +1,18 @@
+use crate::dto::comment::internal::hidden_comment_visibility::HiddenCommentVisibility;
 use crate::dto::comment::request::GetRepliesRequest;
-use crate::dto::comment::response::{CommentInfo, GetRepliesResponse};
+use crate::dto::comment::response::GetRepliesResponse;
+use crate::repository::comment::get_comment_by_id::repository_get_comment_by_id;
 use crate::repository::comment::get_comments::{repository_count_replies, repository_get_replies};
-use crate::repository::comment::get_reply_count::repository_get_reply_count;
-use crate::repository::like::check_like_status::repository_check_like_status_by_comment_id;
-use crate::repository::like::get_like_count::repository_get_like_count_by_comment_id;
-use crate::repository::user::find_user_by_uuid::repository_find_user_by_uuid;
-use crate::service::error::errors::ServiceResult;
+use crate::repository::post::get_post_by_uuid::repository_get_post_by_uuid;
+use crate::service::comment::build_comment_infos::service_build_comment_infos;
+use crate::service::error::errors::{Errors, ServiceResult};
 use sea_orm::{ConnectionTrait, TransactionTrait};
 use uuid::Uuid;
 
 pub async fn service_get_replies<C>(
     conn: &C,
     request: GetRepliesRequest,
+    viewer_id: Option<Uuid>,
 ) -> ServiceResult<GetRepliesResponse>
 where
     C: ConnectionTrait + TransactionTrait,
@@ -19,6 +20,17 @@ where
     let page = request.page;
     let per_page = request.per_page;
 
+    // 숨김 댓글은 게시글 작성자와 댓글 작성자 본인에게만 노출
+    let parent_comment = repository_get_comment_by_id(conn, request.parent_comment_id)
+        .await?
+        .ok_or(Errors::CommentNotFound)?;
+    let post = repository_get_post_by_uuid(conn, &parent_comment.post_id).await?;
+    let visibility = HiddenCommentVisibility::for_viewer(viewer_id, post.user_id);
+
+    if parent_comment.is_hidden && !visibility.can_see(parent_comment.user_id) {
+        return Err(Errors::CommentNotFound);
+    }
+
     // 답글 조회
     let replies = repository_get_replies(
         conn,
@@ -26,57 +38,13 @@ where
         page,
         per_page,
         request.sort.clone(),
+        visibility,
     )
     .await?;
-    let total_count = repository_count_replies(conn, request.parent_comment_id).await?;
-
-    let mut reply_infos = Vec::new();
-
-    for reply in replies {
-        let like_count = repository_get_like_count_by_comment_id(conn, reply.id).await? as i32;
-        let reply_count = repository_get_reply_count(conn, reply.id).await? as i32;
+    let total_count = repository_count_replies(conn, request.parent_comment_id, visibility).await?;
 
-        // 삭제된 댓글은 내용과 사용자 정보를 숨김
-        let reply_info = if reply.is_deleted {
-            CommentInfo {
-                id: reply.id,
-                content: None,
-                post_id: reply.post_id,
-                user_id: None,
-                user_handle: None,
-                user_name: None,
-                user_profile_image: None,
-                parent_id: reply.parent_id,
-                like_count,
-                reply_count,
-                is_deleted: reply.is_deleted,
-                created_at: reply.created_at,
-                updated_at: reply.updated_at,
-            }
-        } else {
-            let user = repository_find_user_by_uuid(conn, &reply.user_id)
-                .await?
-                .ok_or(crate::service::error::errors::Errors::UserNotFound)?;
-
-            CommentInfo {
-                id: reply.id,
-                content: Some(reply.content),
-                post_id: reply.post_id,
-                user_id: Some(reply.user_id),
-                user_handle: Some(user.handle),
-                user_name: Some(user.name),
-                user_profile_image: user.profile_image,
-                parent_id: reply.parent_id,
-                like_count,
-                reply_count,
-                is_deleted: reply.is_deleted,
-                created_at: reply.created_at,
-                updated_at: reply.updated_at,
-            }
-        };
-
-        reply_infos.push(reply_info);
-    }
+    // 작성자와 반응은 한 번에, 좋아요/답글 수는 저장된 값으로 채움
+    let reply_infos = service_build_comment_infos(conn, replies, viewer_id).await?;
 
     let has_next = reply_infos.len() == per_page as usize;
 
@@ -88,3 +56,48 @@ where
         has_next,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dto::comment::request::CommentSortOrder;
+    use crate::utils::query_counter::count_queries;
+    use crate::utils::test_database::{
+        insert_comment, insert_comment_like, insert_post, insert_user, test_transaction,
+    };
+
+    // 답글 수만큼 쿼리가 늘지 않는지 (N+1) 확인
+    #[tokio::test]
+    async fn query_count_does_not_grow_with_replies() {
+        let Some(txn) = test_transaction().await else {
+            return;
+        };
+        let author = insert_user(&txn).await;
+        let viewer = insert_user(&txn).await;
+        let post = insert_post(&txn, author.id, "ko").await;
+        let parent = insert_comment(&txn, post.id, author.id, None).await;
+        for _ in 0..5 {
+            let user = insert_user(&txn).await;
+            let reply = insert_comment(&txn, post.id, user.id, Some(parent.id)).await;
+            insert_comment_like(&txn, viewer.id, reply.id).await;
+        }
+
+        let request = |per_page| GetRepliesRequest {
+            parent_comment_id: parent.id,
+            page: 1,
+            per_page,
+            sort: CommentSortOrder::Latest,
+        };
+        let (one, one_queries) =
+            count_queries(service_get_replies(&txn, request(1), Some(viewer.id))).await;
+        let (many, many_queries) =
+            count_queries(service_get_replies(&txn, request(5), Some(viewer.id))).await;
+
+        assert_eq!(one.unwrap().replies.len(), 1);
+        assert_eq!(many.unwrap().replies.len(), 5);
+        assert!(one_queries > 0);
+        assert_eq!(one_queries, many_queries);
+
+        txn.rollback().await.unwrap();
+    }
+}
@@ -0,0 +1,34 @@
+use crate::dto::comment::request::LockCommentRequest;
+use crate::repository::comment::get_comment_by_id::repository_get_comment_by_id;
+use crate::repository::comment::repository_set_comment_locked;
+use crate::repository::post::get_post_by_uuid::repository_get_post_by_uuid;
+use crate::service::error::errors::{Errors, ServiceResult};
+use sea_orm::{ConnectionTrait, TransactionTrait};
+use uuid::Uuid;
+
+pub async fn service_lock_comment<C>(
+    conn: &C,
+    user_id: &Uuid,
+    request: LockCommentRequest,
+) -> ServiceResult<()>
+where
+    C: ConnectionTrait + TransactionTrait,
+{
+    let comment = repository_get_comment_by_id(conn, request.comment_id)
+        .await?
+        .ok_or(Errors::CommentNotFound)?;
+
+    if comment.is_deleted {
+        return Err(Errors::CommentNotFound);
+    }
+
+    // 게시글 작성자만 잠금 가능
+    let post = repository_get_post_by_uuid(conn, &comment.post_id).await?;
+    if post.user_id != *user_id {
+        return Err(Errors::UserUnauthorized);
+    }
+
+    repository_set_comment_locked(conn, &comment.id, request.locked).await?;
+
+    Ok(())
+}
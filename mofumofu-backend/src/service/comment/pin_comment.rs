@@ -0,0 +1,55 @@
+use crate::config::db_config::DbConfig;
+use crate::dto::comment::request::PinCommentRequest;
+use crate::repository::comment::get_comment_by_id::repository_get_comment_by_id;
+use crate::repository::comment::{repository_count_pinned_comments, repository_set_comment_pinned};
+use crate::repository::post::get_post_by_uuid::repository_get_post_by_uuid_for_update;
+use crate::service::error::errors::{Errors, ServiceResult};
+use sea_orm::{ConnectionTrait, TransactionTrait};
+use uuid::Uuid;
+
+pub async fn service_pin_comment<C>(
+    conn: &C,
+    user_id: &Uuid,
+    request: PinCommentRequest,
+) -> ServiceResult<()>
+where
+    C: ConnectionTrait + TransactionTrait,
+{
+    let txn = conn.begin().await?;
+
+    let comment = repository_get_comment_by_id(&txn, request.comment_id)
+        .await?
+        .ok_or(Errors::CommentNotFound)?;
+
+    if comment.is_deleted {
+        return Err(Errors::CommentNotFound);
+    }
+
+    // 게시글 작성자만 고정 가능
+    // 포스트 행을 잠가 같은 포스트의 고정 요청이 동시에 개수 제한을 통과하지 않도록 함
+    let post = repository_get_post_by_uuid_for_update(&txn, &comment.post_id).await?;
+    if post.user_id != *user_id {
+        return Err(Errors::UserUnauthorized);
+    }
+
+    if request.pinned && !comment.is_pinned {
+        // 최상위 댓글만 고정 가능
+        if comment.parent_id.is_some() {
+            return Err(Errors::CannotPinReply);
+        }
+
+        let pinned_count =
+            repository_count_pinned_comments(&txn, &comment.post_id, &comment.id).await?;
+        if pinned_count >= DbConfig::get().comment_max_pinned_per_post {
+            return Err(Errors::CommentPinLimitExceeded);
+        }
+    }
+
+    if request.pinned != comment.is_pinned {
+        repository_set_comment_pinned(&txn, &comment.id, request.pinned).await?;
+    }
+
+    txn.commit().await?;
+
+    Ok(())
+}
@@ -1,8 +1,17 @@
 use crate::dto::comment::request::UpdateCommentRequest;
 use crate::repository::comment::get_comment_by_id::repository_get_comment_by_id;
+use crate::repository::comment::repository_create_comment_edit;
 use crate::repository::comment::update_comment::repository_update_comment;
 use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::markdown::comment_renderer::render_comment_markdown;
+use crate::service::mention::log_mention_events::service_log_mention_events;
+use crate::service::mention::mention_parser::{
+    extract_mentions_from_markdown, link_mentions_in_markdown,
+};
+use crate::service::mention::resolve_mentions::service_resolve_mentions;
+use crate::service::mention::save_mentions::service_save_mentions;
 use sea_orm::{ConnectionTrait, TransactionTrait};
+use std::collections::HashSet;
 use uuid::Uuid;
 
 pub async fn service_update_comment<C>(
@@ -30,9 +39,56 @@ where
         return Err(Errors::CommentNotFound);
     }
 
+    // 내용이 같으면 수정 기록을 남기지 않음
+    if comment.content == request.content {
+        return Ok(());
+    }
+
+    // 수정 전 내용을 기록
+    repository_create_comment_edit(
+        &txn,
+        comment.id,
+        &comment.content,
+        comment.updated_at.unwrap_or(comment.created_at),
+    )
+    .await?;
+
+    // 멘션 파싱 - 존재하는 사용자만 프로필 링크로 렌더링
+    let mentioned_users =
+        service_resolve_mentions(&txn, extract_mentions_from_markdown(&request.content)).await?;
+    let mentioned_handles: HashSet<String> = mentioned_users
+        .iter()
+        .map(|user| user.handle.clone())
+        .collect();
+    let render = render_comment_markdown(&link_mentions_in_markdown(
+        &request.content,
+        &mentioned_handles,
+    ));
+
     // 댓글 업데이트
-    repository_update_comment(&txn, request.comment_id, &request.content).await?;
+    repository_update_comment(&txn, request.comment_id, &request.content, &render).await?;
+
+    // 멘션 갱신
+    let new_mentions = service_save_mentions(
+        &txn,
+        *user_id,
+        comment.post_id,
+        Some(comment.id),
+        &mentioned_users,
+    )
+    .await?;
 
     txn.commit().await?;
+
+    // 새로 멘션된 사용자별 이벤트 로깅
+    service_log_mention_events(
+        conn,
+        *user_id,
+        comment.post_id,
+        Some(comment.id),
+        &new_mentions,
+    )
+    .await;
+
     Ok(())
 }
@@ -1,3 +1,4 @@
+use crate::config::db_config::DbConfig;
 use crate::dto::draft::request::create_draft::CreateDraftRequest;
 use crate::dto::draft::response::create_draft::CreateDraftResponse;
 use crate::repository::draft::create_draft::repository_create_draft;
@@ -15,9 +16,9 @@ pub async fn service_create_draft<C>(
 where
     C: ConnectionTrait + TransactionTrait,
 {
-    // 드래프트 개수 체크 (10개 제한)
+    // 드래프트 개수 체크 (DRAFT_MAX_PER_USER 제한)
     let current_count = repository_get_draft_count(conn, user_uuid).await?;
-    if current_count >= 10 {
+    if current_count >= DbConfig::get().draft_max_per_user {
         return Err(Errors::DraftLimitExceeded);
     }
 
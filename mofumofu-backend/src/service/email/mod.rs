@@ -0,0 +1,2 @@
+pub mod smtp_mailer;
+pub mod templates;
@@ -0,0 +1,53 @@
+use crate::config::db_config::DbConfig;
+use lettre::message::Mailbox;
+use lettre::message::header::ContentType;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use tracing::info;
+
+/// SMTP로 HTML 이메일 발송 (태스크 서버의 send_email과 같은 설정 사용)
+pub async fn send_email(
+    email_to: &str,
+    subject: &str,
+    html_content: &str,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let config = DbConfig::get();
+
+    let (Some(host), Some(from_email)) = (&config.smtp_host, &config.emails_from_email) else {
+        return Err("SMTP_HOST and EMAILS_FROM_EMAIL must be set to send emails".into());
+    };
+
+    let from = Mailbox::new(config.emails_from_name.clone(), from_email.parse()?);
+
+    let message = Message::builder()
+        .from(from)
+        .to(email_to.parse()?)
+        .subject(subject)
+        .header(ContentType::TEXT_HTML)
+        .body(html_content.to_string())?;
+
+    let builder = if config.smtp_ssl {
+        AsyncSmtpTransport::<Tokio1Executor>::relay(host)?
+    } else if config.smtp_tls {
+        AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host)?
+    } else {
+        AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(host)
+    };
+
+    let mut builder = builder.port(config.smtp_port);
+    if let Some(user) = &config.smtp_user {
+        builder = builder.credentials(Credentials::new(
+            user.clone(),
+            config
+                .smtp_password
+                .as_ref()
+                .map(|password| password.expose().to_string())
+                .unwrap_or_default(),
+        ));
+    }
+
+    let response = builder.build().send(message).await?;
+    info!("Email sent to {}: {:?}", email_to, response.code());
+
+    Ok(())
+}
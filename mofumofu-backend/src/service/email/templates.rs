@@ -0,0 +1,102 @@
+use crate::config::db_config::DbConfig;
+
+/// 제목과 HTML 본문
+pub struct EmailData {
+    pub subject: String,
+    pub html_content: String,
+}
+
+const TEXT_STYLE: &str =
+    "font-family:Arial,Helvetica,sans-serif;font-size:16px;color:#555;padding:0 25px 16px;";
+const BUTTON_STYLE: &str = "display:inline-block;font-size:18px;background-color:#009688;border-radius:8px;color:#fff;padding:15px 30px;text-decoration:none;";
+
+/// 태스크 서버의 MJML 템플릿과 같은 구성의 단순 HTML 레이아웃
+fn render_layout(
+    title: &str,
+    greeting: &str,
+    intro: &str,
+    button: &str,
+    link: &str,
+    expiry: &str,
+    footer: &str,
+) -> String {
+    let link = ammonia::clean_text(link);
+
+    format!(
+        r#"<!doctype html>
+<html>
+<body style="margin:0;background-color:#fafbfc;">
+<table role="presentation" width="100%" cellpadding="0" cellspacing="0" style="background-color:#fafbfc;">
+<tr><td align="center">
+<table role="presentation" width="600" cellpadding="0" cellspacing="0" style="background-color:#fff;padding:40px 20px;text-align:center;">
+<tr><td style="font-family:Arial,Helvetica,sans-serif;font-size:20px;color:#333;padding:35px;">{title}</td></tr>
+<tr><td style="{TEXT_STYLE}">{greeting}</td></tr>
+<tr><td style="{TEXT_STYLE}">{intro}</td></tr>
+<tr><td style="padding:10px 25px 25px;"><a href="{link}" style="{BUTTON_STYLE}">{button}</a></td></tr>
+<tr><td style="{TEXT_STYLE}">Or copy and paste the following link into your browser:</td></tr>
+<tr><td style="{TEXT_STYLE}"><a href="{link}">{link}</a></td></tr>
+<tr><td style="{TEXT_STYLE}">{expiry}</td></tr>
+<tr><td style="border-top:2px solid #ccc;"></td></tr>
+<tr><td style="{TEXT_STYLE}font-size:14px;padding-top:16px;">{footer}</td></tr>
+</table>
+</td></tr>
+</table>
+</body>
+</html>"#
+    )
+}
+
+/// 이메일 인증 메일
+pub fn generate_email_verification_email(username: &str, verification_token: &str) -> EmailData {
+    let config = DbConfig::get();
+    let project_name = ammonia::clean_text(&config.project_name);
+    let link = format!(
+        "{}/account/verify-email?token={}",
+        config.frontend_host, verification_token
+    );
+
+    EmailData {
+        subject: format!("{} - Verify your email address", config.project_name),
+        html_content: render_layout(
+            &format!("{} - Email Verification", project_name),
+            &format!("Hello {}", ammonia::clean_text(username)),
+            "Thank you for signing up! Please verify your email address by clicking the button below:",
+            "Verify Email",
+            &link,
+            &format!(
+                "This verification link will expire in {} hours.",
+                config.auth_email_verification_token_expire_time
+            ),
+            "If you didn't create an account, you can safely ignore this email.",
+        ),
+    }
+}
+
+/// 비밀번호 재설정 메일
+pub fn generate_reset_password_email(email: &str, token: &str) -> EmailData {
+    let config = DbConfig::get();
+    let project_name = ammonia::clean_text(&config.project_name);
+    let link = format!(
+        "{}/account/reset-password?token={}",
+        config.frontend_host, token
+    );
+
+    EmailData {
+        subject: format!(
+            "{} - Password recovery for user {}",
+            config.project_name, email
+        ),
+        html_content: render_layout(
+            &format!("{} - Password Recovery", project_name),
+            &format!("Hello {}", ammonia::clean_text(email)),
+            "We've received a request to reset your password. You can do it by clicking the button below:",
+            "Reset password",
+            &link,
+            &format!(
+                "This password will expire in {} hours.",
+                config.auth_password_reset_token_expire_time
+            ),
+            "If you didn't request a password recovery you can disregard this email.",
+        ),
+    }
+}
@@ -5,6 +5,10 @@ use crate::service::error::protocol::follow::{
     FOLLOW_ALREADY_FOLLOWING, FOLLOW_CANNOT_FOLLOW_SELF, FOLLOW_NOT_EXIST,
 };
 use crate::service::error::protocol::general::{BAD_REQUEST, VALIDATION_ERROR};
+use crate::service::error::protocol::hashtag::{
+    HASHTAG_ALIAS_CONFLICT, HASHTAG_ALIAS_NOT_FOUND, HASHTAG_ALREADY_FOLLOWING,
+    HASHTAG_CANNOT_MERGE_SELF, HASHTAG_NOT_FOLLOWING, HASHTAG_NOT_FOUND,
+};
 use crate::service::error::protocol::like::{LIKE_ALREADY_EXISTS, LIKE_NOT_FOUND};
 use crate::service::error::protocol::markdown::MARKDOWN_RENDER_FAILED;
 use crate::service::error::protocol::oauth::{
@@ -18,6 +22,8 @@ use crate::service::error::protocol::password::{
     PASSWORD_NEW_PASSWORD_MISSING, PASSWORD_REQUIRED_FOR_UPDATE,
 };
 use crate::service::error::protocol::post::POST_NOT_FOUND;
+use crate::service::error::protocol::rate_limit::RATE_LIMIT_EXCEEDED;
+use crate::service::error::protocol::reaction::REACTION_INVALID_EMOJI;
 use crate::service::error::protocol::report::REPORT_NOT_FOUND;
 use crate::service::error::protocol::system::{
     SYS_DATABASE_ERROR, SYS_HASHING_ERROR, SYS_INTERNAL_ERROR, SYS_NOT_FOUND,
@@ -32,6 +38,7 @@ use crate::service::error::protocol::user::{
     USER_INVALID_TOKEN, USER_NO_REFRESH_TOKEN, USER_NOT_FOUND, USER_NOT_VERIFIED,
     USER_TOKEN_EXPIRED, USER_UNAUTHORIZED,
 };
+use crate::utils::trace_context::current_request_id;
 use axum::Json;
 use axum::extract::Request;
 use axum::http::StatusCode;
@@ -54,12 +61,15 @@ pub type ApiResult<T> = Result<T, Errors>;
 // status: HTTP 상태 코드
 // code: 오류 코드 문자열
 // details: 개발 환경에서만 표시되는 상세 오류 메시지 (선택적)
+// request_id: 로그와 대조할 수 있는 요청 ID (X-Request-Id)
 #[derive(Debug, Serialize, ToSchema)]
 pub struct ErrorResponse {
     pub status: u16,
     pub code: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub details: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
 }
 
 impl IntoResponse for ErrorResponse {
@@ -113,6 +123,14 @@ pub enum Errors {
     FollowAlreadyFollowing,
     FollowNotExist,
 
+    // hashtag 관련 오류
+    HashtagNotFound,
+    HashtagAlreadyFollowing,
+    HashtagNotFollowing,
+    HashtagAliasConflict,
+    HashtagAliasNotFound,
+    HashtagCannotMergeSelf,
+
     // oauth
     OauthInvalidAuthUrl,
     OauthInvalidTokenUrl,
@@ -151,6 +169,9 @@ pub enum Errors {
     LikeAlreadyExists,
     LikeNotFound,
 
+    // Reaction errors
+    ReactionInvalidEmoji,
+
     // Markdown errors
     MarkdownRenderFailed(String),
 
@@ -158,12 +179,19 @@ pub enum Errors {
     CommentNotFound,
     InvalidParentComment,
     CannotReplyToDeletedComment,
+    CommentsDisabled,
+    CommentThreadLocked,
+    CommentPinLimitExceeded,
+    CannotPinReply,
 
     // Draft errors
     DraftNotFound,
     DraftLimitExceeded,
     DraftSlugAlreadyExists,
 
+    // Rate limit errors
+    RateLimitExceeded,
+
     // 일반 오류
     BadRequestError(String),   // 잘못된 요청 (추가 정보 포함)
     ValidationError(String),   // 유효성 검사 오류 (추가 정보 포함)
@@ -200,7 +228,10 @@ impl IntoResponse for Errors {
             | Errors::PostNotFound
             | Errors::DraftNotFound
             | Errors::NotFound(_)
-            | Errors::FollowNotExist => {
+            | Errors::FollowNotExist
+            | Errors::HashtagNotFound
+            | Errors::HashtagNotFollowing
+            | Errors::HashtagAliasNotFound => {
                 warn!("Resource not found: {:?}", self);
             }
 
@@ -215,6 +246,9 @@ impl IntoResponse for Errors {
             | Errors::ForbiddenError(_)
             | Errors::FollowCannotFollowSelf
             | Errors::FollowAlreadyFollowing
+            | Errors::HashtagAlreadyFollowing
+            | Errors::HashtagAliasConflict
+            | Errors::HashtagCannotMergeSelf
             | Errors::PasswordRequiredForUpdate
             | Errors::PasswordIncorrect
             | Errors::PasswordCannotUpdateOauthOnly
@@ -228,6 +262,7 @@ impl IntoResponse for Errors {
             | Errors::EmailAlreadyVerified
             | Errors::LikeAlreadyExists
             | Errors::LikeNotFound
+            | Errors::ReactionInvalidEmoji
             | Errors::OauthAccountAlreadyLinked
             | Errors::OauthConnectionNotFound
             | Errors::OauthCannotUnlinkLastConnection
@@ -260,7 +295,11 @@ impl IntoResponse for Errors {
             // Comment 에러 - debug! 레벨 (일반적인 사용자 요청 오류)
             Errors::CommentNotFound
             | Errors::InvalidParentComment
-            | Errors::CannotReplyToDeletedComment => {
+            | Errors::CannotReplyToDeletedComment
+            | Errors::CommentsDisabled
+            | Errors::CommentThreadLocked
+            | Errors::CommentPinLimitExceeded
+            | Errors::CannotPinReply => {
                 debug!("Comment error: {:?}", self);
             }
 
@@ -268,6 +307,11 @@ impl IntoResponse for Errors {
             Errors::ReportNotFound => {
                 debug!("Report error: {:?}", self);
             }
+
+            // 요청 제한 초과 - debug! 레벨 (미들웨어에서 메트릭으로 집계)
+            Errors::RateLimitExceeded => {
+                debug!("Rate limit exceeded: {:?}", self);
+            }
         }
 
         // 오류 유형에 따라 상태 코드, 오류 코드, 상세 정보를 결정
@@ -300,6 +344,18 @@ impl IntoResponse for Errors {
             }
             Errors::FollowNotExist => (StatusCode::NOT_FOUND, FOLLOW_NOT_EXIST, None),
 
+            // Hashtag
+            Errors::HashtagNotFound => (StatusCode::NOT_FOUND, HASHTAG_NOT_FOUND, None),
+            Errors::HashtagAlreadyFollowing => {
+                (StatusCode::CONFLICT, HASHTAG_ALREADY_FOLLOWING, None)
+            }
+            Errors::HashtagNotFollowing => (StatusCode::NOT_FOUND, HASHTAG_NOT_FOLLOWING, None),
+            Errors::HashtagAliasConflict => (StatusCode::CONFLICT, HASHTAG_ALIAS_CONFLICT, None),
+            Errors::HashtagAliasNotFound => (StatusCode::NOT_FOUND, HASHTAG_ALIAS_NOT_FOUND, None),
+            Errors::HashtagCannotMergeSelf => {
+                (StatusCode::BAD_REQUEST, HASHTAG_CANNOT_MERGE_SELF, None)
+            }
+
             // Oauth
             Errors::OauthInvalidAuthUrl => (StatusCode::BAD_REQUEST, OAUTH_INVALID_AUTH_URL, None),
             Errors::OauthInvalidTokenUrl => {
@@ -372,6 +428,9 @@ impl IntoResponse for Errors {
             Errors::LikeAlreadyExists => (StatusCode::CONFLICT, LIKE_ALREADY_EXISTS, None),
             Errors::LikeNotFound => (StatusCode::NOT_FOUND, LIKE_NOT_FOUND, None),
 
+            // Reaction errors
+            Errors::ReactionInvalidEmoji => (StatusCode::BAD_REQUEST, REACTION_INVALID_EMOJI, None),
+
             // Markdown errors
             Errors::MarkdownRenderFailed(msg) => {
                 (StatusCode::BAD_REQUEST, MARKDOWN_RENDER_FAILED, Some(msg))
@@ -386,6 +445,12 @@ impl IntoResponse for Errors {
                 "comment:cannot_reply_to_deleted",
                 None,
             ),
+            Errors::CommentsDisabled => (StatusCode::FORBIDDEN, "comment:disabled", None),
+            Errors::CommentThreadLocked => (StatusCode::FORBIDDEN, "comment:thread_locked", None),
+            Errors::CommentPinLimitExceeded => {
+                (StatusCode::BAD_REQUEST, "comment:pin_limit_exceeded", None)
+            }
+            Errors::CannotPinReply => (StatusCode::BAD_REQUEST, "comment:cannot_pin_reply", None),
 
             // Draft errors
             Errors::DraftNotFound => (StatusCode::NOT_FOUND, "draft:not_found", None),
@@ -394,6 +459,9 @@ impl IntoResponse for Errors {
                 (StatusCode::CONFLICT, "draft:slug_already_exists", None)
             }
 
+            // Rate limit errors
+            Errors::RateLimitExceeded => (StatusCode::TOO_MANY_REQUESTS, RATE_LIMIT_EXCEEDED, None),
+
             // 일반 오류 - 400 Bad Request
             Errors::BadRequestError(msg) => (StatusCode::BAD_REQUEST, BAD_REQUEST, Some(msg)),
             Errors::ValidationError(msg) => (StatusCode::BAD_REQUEST, VALIDATION_ERROR, Some(msg)),
@@ -436,6 +504,7 @@ impl IntoResponse for Errors {
             status: status.as_u16(),
             code: code.to_string(),
             details: if is_dev { details } else { None }, // 개발 환경에서만 상세 정보 표시
+            request_id: current_request_id(),
         };
 
         // HTTP 응답으로 변환하여 반환
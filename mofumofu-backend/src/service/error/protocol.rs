@@ -14,6 +14,15 @@ pub mod post {
     pub const POST_NOT_FOUND: &str = "post:not_found";
 }
 
+pub mod hashtag {
+    pub const HASHTAG_NOT_FOUND: &str = "hashtag:not_found";
+    pub const HASHTAG_ALREADY_FOLLOWING: &str = "hashtag:already_following";
+    pub const HASHTAG_NOT_FOLLOWING: &str = "hashtag:not_following";
+    pub const HASHTAG_ALIAS_CONFLICT: &str = "hashtag:alias_conflict";
+    pub const HASHTAG_ALIAS_NOT_FOUND: &str = "hashtag:alias_not_found";
+    pub const HASHTAG_CANNOT_MERGE_SELF: &str = "hashtag:cannot_merge_self";
+}
+
 pub mod follow {
     pub const FOLLOW_CANNOT_FOLLOW_SELF: &str = "follow:cannot_follow_self";
     pub const FOLLOW_ALREADY_FOLLOWING: &str = "follow:already_following";
@@ -48,6 +57,14 @@ pub mod like {
     pub const LIKE_NOT_FOUND: &str = "like:not_found";
 }
 
+pub mod reaction {
+    pub const REACTION_INVALID_EMOJI: &str = "reaction:invalid_emoji";
+}
+
+pub mod rate_limit {
+    pub const RATE_LIMIT_EXCEEDED: &str = "rate_limit:exceeded";
+}
+
 pub mod report {
     pub const REPORT_NOT_FOUND: &str = "report:not_found";
 }
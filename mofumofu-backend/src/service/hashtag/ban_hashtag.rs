@@ -0,0 +1,46 @@
+use crate::connection::redis_connection::RedisConnection;
+use crate::dto::cache::internal::cache_event::CacheEvent;
+use crate::dto::hashtag::request::ban_hashtag::BanHashtagRequest;
+use crate::repository::hashtag::find_hashtag_by_name::repository_find_hashtag_by_name;
+use crate::repository::hashtag::update_hashtag::repository_set_hashtag_banned;
+use crate::service::auth::role_check::require_moderator;
+use crate::service::cache::invalidate_cache::service_publish_cache_event;
+use crate::service::error::errors::{Errors, ServiceResult};
+use sea_orm::ConnectionTrait;
+use tracing::info;
+use uuid::Uuid;
+
+/// 금지된 해시태그는 새 포스트에 붙지 않고, 목록/상세/팔로우에서 제외된다.
+pub async fn service_ban_hashtag<C>(
+    conn: &C,
+    redis: &RedisConnection,
+    user_id: Uuid,
+    request: BanHashtagRequest,
+) -> ServiceResult<()>
+where
+    C: ConnectionTrait,
+{
+    // 관리자/모더레이터 권한 체크
+    require_moderator(conn, user_id).await?;
+
+    let hashtag = repository_find_hashtag_by_name(conn, &request.hashtag)
+        .await?
+        .ok_or(Errors::HashtagNotFound)?;
+
+    repository_set_hashtag_banned(conn, hashtag.id, request.banned).await?;
+
+    service_publish_cache_event(
+        redis,
+        CacheEvent::HashtagsChanged {
+            post_ids: Vec::new(),
+        },
+    )
+    .await;
+
+    info!(
+        "Hashtag {} banned={} by {}",
+        hashtag.name, request.banned, user_id
+    );
+
+    Ok(())
+}
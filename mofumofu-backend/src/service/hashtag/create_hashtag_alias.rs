@@ -0,0 +1,53 @@
+use crate::dto::hashtag::request::hashtag_alias::CreateHashtagAliasRequest;
+use crate::repository::hashtag::find_hashtag_by_name::{
+    repository_find_hashtag_by_name, repository_find_hashtag_by_normalized_name,
+};
+use crate::repository::hashtag::hashtag_alias::repository_create_hashtag_alias;
+use crate::service::auth::role_check::require_moderator;
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::utils::hashtag_normalizer::normalize_hashtag;
+use sea_orm::{ConnectionTrait, TransactionTrait};
+use tracing::info;
+use uuid::Uuid;
+
+pub async fn service_create_hashtag_alias<C>(
+    conn: &C,
+    user_id: Uuid,
+    request: CreateHashtagAliasRequest,
+) -> ServiceResult<()>
+where
+    C: ConnectionTrait + TransactionTrait,
+{
+    // 관리자/모더레이터 권한 체크
+    require_moderator(conn, user_id).await?;
+
+    let alias = normalize_hashtag(&request.alias);
+    if alias.is_empty() {
+        return Err(Errors::BadRequestError("Invalid alias".to_string()));
+    }
+
+    let txn = conn.begin().await?;
+
+    let hashtag = repository_find_hashtag_by_name(&txn, &request.hashtag)
+        .await?
+        .ok_or(Errors::HashtagNotFound)?;
+
+    // 이미 해시태그나 별칭으로 쓰이는 이름 (해시태그끼리는 병합을 사용)
+    if repository_find_hashtag_by_normalized_name(&txn, &alias)
+        .await?
+        .is_some()
+    {
+        return Err(Errors::HashtagAliasConflict);
+    }
+
+    repository_create_hashtag_alias(&txn, &alias, hashtag.id, Some(user_id)).await?;
+
+    txn.commit().await?;
+
+    info!(
+        "Hashtag alias created: {} -> {} by {}",
+        alias, hashtag.name, user_id
+    );
+
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use crate::dto::hashtag::request::hashtag_alias::DeleteHashtagAliasRequest;
+use crate::repository::hashtag::hashtag_alias::repository_delete_hashtag_alias;
+use crate::service::auth::role_check::require_moderator;
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::utils::hashtag_normalizer::normalize_hashtag;
+use sea_orm::ConnectionTrait;
+use uuid::Uuid;
+
+pub async fn service_delete_hashtag_alias<C>(
+    conn: &C,
+    user_id: Uuid,
+    request: DeleteHashtagAliasRequest,
+) -> ServiceResult<()>
+where
+    C: ConnectionTrait,
+{
+    // 관리자/모더레이터 권한 체크
+    require_moderator(conn, user_id).await?;
+
+    let alias = normalize_hashtag(&request.alias);
+
+    if !repository_delete_hashtag_alias(conn, &alias).await? {
+        return Err(Errors::HashtagAliasNotFound);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use crate::repository::hashtag::find_hashtag_by_name::repository_find_hashtag_by_name;
+use crate::repository::hashtag::hashtag_follow::{
+    repository_check_hashtag_follow_exists, repository_create_hashtag_follow,
+    repository_increment_hashtag_follower_count,
+};
+use crate::service::error::errors::{Errors, ServiceResult};
+use sea_orm::{ConnectionTrait, TransactionTrait};
+use uuid::Uuid;
+
+pub async fn service_follow_hashtag<C>(
+    conn: &C,
+    user_id: Uuid,
+    hashtag_name: &str,
+) -> ServiceResult<()>
+where
+    C: ConnectionTrait + TransactionTrait,
+{
+    let txn = conn.begin().await?;
+
+    // 금지된 해시태그는 팔로우할 수 없음
+    let hashtag = repository_find_hashtag_by_name(&txn, hashtag_name)
+        .await?
+        .filter(|hashtag| !hashtag.is_banned)
+        .ok_or(Errors::HashtagNotFound)?;
+
+    // 이미 팔로우 중인지 체크
+    if repository_check_hashtag_follow_exists(&txn, user_id, hashtag.id).await? {
+        return Err(Errors::HashtagAlreadyFollowing);
+    }
+
+    repository_create_hashtag_follow(&txn, user_id, hashtag.id).await?;
+    repository_increment_hashtag_follower_count(&txn, hashtag.id).await?;
+
+    txn.commit().await?;
+
+    Ok(())
+}
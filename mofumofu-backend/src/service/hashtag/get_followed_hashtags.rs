@@ -0,0 +1,26 @@
+use crate::dto::hashtag::response::followed_hashtags::{FollowedHashtag, FollowedHashtagsResponse};
+use crate::repository::hashtag::hashtag_follow::repository_get_followed_hashtags;
+use crate::service::error::errors::ServiceResult;
+use sea_orm::ConnectionTrait;
+use uuid::Uuid;
+
+pub async fn service_get_followed_hashtags<C>(
+    conn: &C,
+    user_id: Uuid,
+) -> ServiceResult<FollowedHashtagsResponse>
+where
+    C: ConnectionTrait,
+{
+    let hashtags = repository_get_followed_hashtags(conn, user_id)
+        .await?
+        .into_iter()
+        .map(|(follow, hashtag)| FollowedHashtag {
+            name: hashtag.name,
+            usage_count: hashtag.usage_count,
+            follower_count: hashtag.follower_count,
+            followed_at: follow.created_at,
+        })
+        .collect();
+
+    Ok(FollowedHashtagsResponse { hashtags })
+}
@@ -0,0 +1,115 @@
+use crate::dto::hashtag::internal::hashtag_post_cursor::HashtagPostCursor;
+use crate::dto::hashtag::request::hashtag_detail::HashtagDetailRequest;
+use crate::dto::hashtag::response::hashtag_detail::{HashtagDetailResponse, HashtagTopAuthor};
+use crate::repository::hashtag::find_hashtag_by_name::repository_find_hashtag_by_name;
+use crate::repository::hashtag::get_hashtag_posts::{
+    repository_count_posts_by_hashtag, repository_get_hashtag_top_authors,
+    repository_get_posts_by_hashtags,
+};
+use crate::repository::hashtag::hashtag_alias::repository_get_hashtag_aliases;
+use crate::repository::hashtag::hashtag_follow::repository_check_hashtag_follow_exists;
+use crate::repository::user::find_users_by_uuids::repository_find_users_by_uuids;
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::post::build_post_list_items::service_build_post_list_items;
+use sea_orm::ConnectionTrait;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// 해시태그 상세에 보여줄 상위 작성자 수
+const HASHTAG_TOP_AUTHOR_LIMIT: u64 = 5;
+
+/// 해시태그 랜딩 페이지 (통계, 상위 작성자, 최신/인기 포스트)
+pub async fn service_get_hashtag_detail<C>(
+    conn: &C,
+    viewer_id: Option<Uuid>,
+    request: HashtagDetailRequest,
+) -> ServiceResult<HashtagDetailResponse>
+where
+    C: ConnectionTrait,
+{
+    let limit = request.limit.unwrap_or(20);
+    let cursor = request
+        .cursor
+        .as_deref()
+        .map(|cursor| {
+            HashtagPostCursor::decode(cursor)
+                .ok_or_else(|| Errors::BadRequestError("Invalid cursor".to_string()))
+        })
+        .transpose()?;
+
+    let hashtag = repository_find_hashtag_by_name(conn, &request.hashtag)
+        .await?
+        .filter(|hashtag| !hashtag.is_banned)
+        .ok_or(Errors::HashtagNotFound)?;
+
+    let aliases = repository_get_hashtag_aliases(conn, hashtag.id)
+        .await?
+        .into_iter()
+        .map(|alias| alias.alias)
+        .collect();
+
+    let post_count = repository_count_posts_by_hashtag(conn, hashtag.id).await?;
+
+    let is_following = match viewer_id {
+        Some(viewer_id) => {
+            repository_check_hashtag_follow_exists(conn, viewer_id, hashtag.id).await?
+        }
+        None => false,
+    };
+
+    // 상위 작성자
+    let author_counts =
+        repository_get_hashtag_top_authors(conn, hashtag.id, HASHTAG_TOP_AUTHOR_LIMIT).await?;
+    let author_ids: Vec<Uuid> = author_counts.iter().map(|(user_id, _)| *user_id).collect();
+    let mut authors: HashMap<Uuid, _> = repository_find_users_by_uuids(conn, &author_ids)
+        .await?
+        .into_iter()
+        .map(|user| (user.id, user))
+        .collect();
+    let top_authors = author_counts
+        .into_iter()
+        .filter_map(|(user_id, post_count)| {
+            authors.remove(&user_id).map(|user| HashtagTopAuthor {
+                handle: user.handle,
+                name: user.name,
+                profile_image: user.profile_image,
+                post_count,
+            })
+        })
+        .collect();
+
+    // 다음 페이지 여부 확인을 위해 하나 더 조회
+    let mut posts = repository_get_posts_by_hashtags(
+        conn,
+        &[hashtag.id],
+        &request.sort,
+        cursor.as_ref(),
+        limit + 1,
+    )
+    .await?;
+
+    let next_cursor = if posts.len() as u64 > limit {
+        posts.truncate(limit as usize);
+        posts
+            .last()
+            .map(|post| HashtagPostCursor::after(post).encode())
+    } else {
+        None
+    };
+
+    Ok(HashtagDetailResponse {
+        name: hashtag.name,
+        description: hashtag.description,
+        cover_image: hashtag.cover_image,
+        aliases,
+        usage_count: hashtag.usage_count,
+        post_count,
+        follower_count: hashtag.follower_count,
+        created_at: hashtag.created_at,
+        last_used_at: hashtag.last_used_at,
+        is_following,
+        top_authors,
+        posts: service_build_post_list_items(conn, posts).await?,
+        next_cursor,
+    })
+}
@@ -0,0 +1,56 @@
+use crate::dto::hashtag::internal::hashtag_post_cursor::HashtagPostCursor;
+use crate::dto::hashtag::request::hashtag_detail::HashtagPostSort;
+use crate::dto::hashtag::request::hashtag_timeline::HashtagTimelineRequest;
+use crate::dto::hashtag::response::hashtag_timeline::HashtagTimelineResponse;
+use crate::repository::hashtag::get_hashtag_posts::repository_get_posts_by_hashtags;
+use crate::repository::hashtag::hashtag_follow::repository_get_followed_hashtag_ids;
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::post::build_post_list_items::service_build_post_list_items;
+use sea_orm::ConnectionTrait;
+use uuid::Uuid;
+
+/// 팔로우하는 해시태그가 달린 최신 포스트 타임라인
+pub async fn service_get_hashtag_timeline<C>(
+    conn: &C,
+    user_id: Uuid,
+    request: HashtagTimelineRequest,
+) -> ServiceResult<HashtagTimelineResponse>
+where
+    C: ConnectionTrait,
+{
+    let limit = request.limit.unwrap_or(20);
+    let cursor = request
+        .cursor
+        .as_deref()
+        .map(|cursor| {
+            HashtagPostCursor::decode(cursor)
+                .ok_or_else(|| Errors::BadRequestError("Invalid cursor".to_string()))
+        })
+        .transpose()?;
+
+    let hashtag_ids = repository_get_followed_hashtag_ids(conn, user_id).await?;
+
+    // 다음 페이지 여부 확인을 위해 하나 더 조회
+    let mut posts = repository_get_posts_by_hashtags(
+        conn,
+        &hashtag_ids,
+        &HashtagPostSort::Latest,
+        cursor.as_ref(),
+        limit + 1,
+    )
+    .await?;
+
+    let next_cursor = if posts.len() as u64 > limit {
+        posts.truncate(limit as usize);
+        posts
+            .last()
+            .map(|post| HashtagPostCursor::after(post).encode())
+    } else {
+        None
+    };
+
+    Ok(HashtagTimelineResponse {
+        posts: service_build_post_list_items(conn, posts).await?,
+        next_cursor,
+    })
+}
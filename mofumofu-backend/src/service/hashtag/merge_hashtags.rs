@@ -0,0 +1,93 @@
+use crate::connection::redis_connection::RedisConnection;
+use crate::dto::cache::internal::cache_event::CacheEvent;
+use crate::dto::hashtag::request::merge_hashtags::MergeHashtagsRequest;
+use crate::dto::hashtag::response::merge_hashtags::MergeHashtagsResponse;
+use crate::dto::outbox::internal::outbox_message::OutboxMessage;
+use crate::repository::hashtag::find_hashtag_by_name::{
+    repository_find_hashtag_by_name, repository_find_hashtag_by_normalized_name,
+};
+use crate::repository::hashtag::hashtag_alias::{
+    repository_create_hashtag_alias, repository_move_hashtag_aliases,
+};
+use crate::repository::hashtag::merge_hashtags::{
+    repository_delete_hashtag, repository_move_hashtag_follows, repository_move_post_hashtags,
+    repository_recount_hashtag,
+};
+use crate::service::auth::role_check::require_moderator;
+use crate::service::cache::invalidate_cache::service_publish_cache_event;
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::outbox::enqueue_outbox::service_enqueue_outbox;
+use sea_orm::{ConnectionTrait, TransactionTrait};
+use tracing::info;
+use uuid::Uuid;
+
+/// 원본 해시태그를 대상 해시태그로 병합
+/// 포스트 연결과 팔로우를 옮기고, 원본 이름은 대상의 별칭으로 남긴다.
+pub async fn service_merge_hashtags<C>(
+    conn: &C,
+    redis: &RedisConnection,
+    user_id: Uuid,
+    request: MergeHashtagsRequest,
+) -> ServiceResult<MergeHashtagsResponse>
+where
+    C: ConnectionTrait + TransactionTrait,
+{
+    // 관리자/모더레이터 권한 체크
+    require_moderator(conn, user_id).await?;
+
+    let txn = conn.begin().await?;
+
+    let source = repository_find_hashtag_by_name(&txn, &request.source)
+        .await?
+        .ok_or(Errors::HashtagNotFound)?;
+    let target = repository_find_hashtag_by_name(&txn, &request.target)
+        .await?
+        .ok_or(Errors::HashtagNotFound)?;
+
+    if source.id == target.id {
+        return Err(Errors::HashtagCannotMergeSelf);
+    }
+
+    let post_ids = repository_move_post_hashtags(&txn, source.id, target.id).await?;
+    repository_move_hashtag_follows(&txn, source.id, target.id).await?;
+    repository_move_hashtag_aliases(&txn, source.id, target.id).await?;
+
+    repository_delete_hashtag(&txn, source.id).await?;
+    repository_create_hashtag_alias(&txn, &source.name, target.id, Some(user_id)).await?;
+
+    repository_recount_hashtag(&txn, target.id).await?;
+
+    let merged = repository_find_hashtag_by_normalized_name(&txn, &target.name)
+        .await?
+        .ok_or(Errors::HashtagNotFound)?;
+
+    // 해시태그가 바뀐 포스트 재색인 요청
+    for post_id in &post_ids {
+        service_enqueue_outbox(&txn, OutboxMessage::SearchUpdatePost { post_id: *post_id }).await?;
+    }
+
+    txn.commit().await?;
+
+    service_publish_cache_event(
+        redis,
+        CacheEvent::HashtagsChanged {
+            post_ids: post_ids.clone(),
+        },
+    )
+    .await;
+
+    info!(
+        "Hashtag merged: {} -> {} ({} posts) by {}",
+        source.name,
+        merged.name,
+        post_ids.len(),
+        user_id
+    );
+
+    Ok(MergeHashtagsResponse {
+        name: merged.name,
+        usage_count: merged.usage_count,
+        follower_count: merged.follower_count,
+        moved_post_count: post_ids.len(),
+    })
+}
@@ -1 +1,13 @@
+pub mod ban_hashtag;
+pub mod create_hashtag_alias;
+pub mod delete_hashtag_alias;
+pub mod follow_hashtag;
+pub mod get_followed_hashtags;
+pub mod get_hashtag_detail;
+pub mod get_hashtag_timeline;
 pub mod get_trending_hashtags;
+pub mod merge_hashtags;
+pub mod suggest_hashtags;
+pub mod unfollow_hashtag;
+pub mod update_hashtag;
+pub mod update_hashtag_cover;
@@ -0,0 +1,36 @@
+use crate::dto::hashtag::request::suggest_hashtags::SuggestHashtagsRequest;
+use crate::dto::hashtag::response::suggest_hashtags::{HashtagSuggestion, SuggestHashtagsResponse};
+use crate::repository::hashtag::suggest_hashtags::repository_suggest_hashtags;
+use crate::service::error::errors::ServiceResult;
+use crate::utils::hashtag_normalizer::normalize_hashtag;
+use sea_orm::ConnectionTrait;
+
+/// 해시태그 입력 자동완성
+pub async fn service_suggest_hashtags<C>(
+    conn: &C,
+    request: SuggestHashtagsRequest,
+) -> ServiceResult<SuggestHashtagsResponse>
+where
+    C: ConnectionTrait,
+{
+    let limit = request.limit.unwrap_or(8);
+    let prefix = normalize_hashtag(&request.q);
+
+    // '#'만 입력한 경우
+    if prefix.is_empty() {
+        return Ok(SuggestHashtagsResponse {
+            hashtags: Vec::new(),
+        });
+    }
+
+    let hashtags = repository_suggest_hashtags(conn, &prefix, limit)
+        .await?
+        .into_iter()
+        .map(|row| HashtagSuggestion {
+            name: row.name,
+            usage_count: row.usage_count,
+        })
+        .collect();
+
+    Ok(SuggestHashtagsResponse { hashtags })
+}
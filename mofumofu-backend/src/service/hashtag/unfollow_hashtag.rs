@@ -0,0 +1,32 @@
+use crate::repository::hashtag::find_hashtag_by_name::repository_find_hashtag_by_name;
+use crate::repository::hashtag::hashtag_follow::{
+    repository_decrement_hashtag_follower_count, repository_delete_hashtag_follow,
+};
+use crate::service::error::errors::{Errors, ServiceResult};
+use sea_orm::{ConnectionTrait, TransactionTrait};
+use uuid::Uuid;
+
+pub async fn service_unfollow_hashtag<C>(
+    conn: &C,
+    user_id: Uuid,
+    hashtag_name: &str,
+) -> ServiceResult<()>
+where
+    C: ConnectionTrait + TransactionTrait,
+{
+    let txn = conn.begin().await?;
+
+    let hashtag = repository_find_hashtag_by_name(&txn, hashtag_name)
+        .await?
+        .ok_or(Errors::HashtagNotFound)?;
+
+    if !repository_delete_hashtag_follow(&txn, user_id, hashtag.id).await? {
+        return Err(Errors::HashtagNotFollowing);
+    }
+
+    repository_decrement_hashtag_follower_count(&txn, hashtag.id).await?;
+
+    txn.commit().await?;
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use crate::dto::hashtag::request::update_hashtag::UpdateHashtagRequest;
+use crate::repository::hashtag::find_hashtag_by_name::repository_find_hashtag_by_name;
+use crate::repository::hashtag::update_hashtag::repository_update_hashtag_description;
+use crate::service::auth::role_check::require_moderator;
+use crate::service::error::errors::{Errors, ServiceResult};
+use sea_orm::ConnectionTrait;
+use uuid::Uuid;
+
+pub async fn service_update_hashtag<C>(
+    conn: &C,
+    user_id: Uuid,
+    request: UpdateHashtagRequest,
+) -> ServiceResult<()>
+where
+    C: ConnectionTrait,
+{
+    // 관리자/모더레이터 권한 체크
+    require_moderator(conn, user_id).await?;
+
+    let hashtag = repository_find_hashtag_by_name(conn, &request.hashtag)
+        .await?
+        .ok_or(Errors::HashtagNotFound)?;
+
+    // 빈 설명은 삭제로 처리
+    let description = request
+        .description
+        .map(|description| description.trim().to_string())
+        .filter(|description| !description.is_empty());
+
+    repository_update_hashtag_description(conn, hashtag.id, description).await?;
+
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use crate::connection::cloudflare_r2::R2Client;
+use crate::repository::hashtag::find_hashtag_by_name::repository_find_hashtag_by_name;
+use crate::repository::hashtag::update_hashtag::repository_update_hashtag_cover_image;
+use crate::service::auth::role_check::require_moderator;
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::utils::image_validator::{generate_image_hash, process_image_for_upload};
+use axum::extract::Multipart;
+use sea_orm::ConnectionTrait;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+pub async fn service_update_hashtag_cover<C>(
+    conn: &C,
+    r2_client: &R2Client,
+    user_id: Uuid,
+    mut multipart: Multipart,
+) -> ServiceResult<String>
+where
+    C: ConnectionTrait,
+{
+    // 관리자/모더레이터 권한 체크
+    require_moderator(conn, user_id).await?;
+
+    let mut file_data: Option<Vec<u8>> = None;
+    let mut hashtag_name: Option<String> = None;
+
+    // multipart 데이터 파싱
+    while let Some(field) = multipart.next_field().await.map_err(|e| {
+        error!("Failed to read multipart field: {}", e);
+        Errors::FileReadError("Invalid multipart data".to_string())
+    })? {
+        let field_name = field.name().unwrap_or("").to_string();
+
+        match field_name.as_str() {
+            "file" => {
+                let data = field.bytes().await.map_err(|e| {
+                    error!("Failed to read image data: {}", e);
+                    Errors::FileReadError("Failed to read image data".to_string())
+                })?;
+
+                file_data = Some(data.to_vec());
+            }
+            "hashtag" => {
+                hashtag_name = Some(field.text().await.map_err(|e| {
+                    error!("Failed to read hashtag field: {}", e);
+                    Errors::BadRequestError("Failed to read hashtag".to_string())
+                })?);
+            }
+            _ => {
+                warn!("Unknown field in multipart: {}", field_name);
+            }
+        }
+    }
+
+    let hashtag_name =
+        hashtag_name.ok_or_else(|| Errors::BadRequestError("Hashtag is required".to_string()))?;
+    let file_data = file_data.ok_or(Errors::FileNotFound)?;
+
+    let hashtag = repository_find_hashtag_by_name(conn, &hashtag_name)
+        .await?
+        .ok_or(Errors::HashtagNotFound)?;
+
+    // Process and compress image (8MB limit for cover)
+    const MAX_COVER_SIZE: usize = 8 * 1024 * 1024;
+    let max_dimensions = Some((1600, 400)); // Max dimensions for cover
+    let (processed_data, content_type, extension) = process_image_for_upload(
+        &file_data,
+        MAX_COVER_SIZE,
+        true, // Convert to WebP for better compression
+        max_dimensions,
+    )?;
+
+    // Generate hash-based filename using processed data
+    let hash = generate_image_hash(&processed_data);
+    let filename = format!("cover_{}.{}", hash, extension);
+
+    info!(
+        "Processing hashtag cover upload: hashtag={}, filename={}, content_type={}, original_size={} bytes, processed_size={} bytes",
+        hashtag.name,
+        filename,
+        content_type,
+        file_data.len(),
+        processed_data.len()
+    );
+
+    // Delete existing cover if exists
+    if let Some(existing_image_url) = &hashtag.cover_image {
+        // Extract key from URL and delete from R2
+        let url_parts: Vec<&str> = existing_image_url.split('/').collect();
+        if url_parts.len() >= 4 {
+            let key = url_parts[url_parts.len() - 4..].join("/");
+            if let Err(e) = r2_client.delete(&key).await {
+                warn!("Failed to delete existing hashtag cover from R2: {}", e);
+            }
+        }
+    }
+
+    // Upload to R2
+    let r2_key = format!("hashtags/{}/cover/{}", hashtag.id, filename);
+    r2_client
+        .upload_with_content_type(&r2_key, processed_data, &content_type)
+        .await
+        .map_err(|e| {
+            error!("Failed to upload hashtag cover to R2: {}", e);
+            Errors::SysInternalError("Failed to upload hashtag cover image".to_string())
+        })?;
+
+    let public_url = r2_client.get_r2_public_url(&r2_key);
+
+    repository_update_hashtag_cover_image(conn, hashtag.id, Some(public_url.clone())).await?;
+
+    info!("Hashtag cover uploaded successfully: {}", public_url);
+    Ok(public_url)
+}
@@ -0,0 +1,153 @@
+use crate::config::db_config::DbConfig;
+use crate::dto::health::response::{DependencyCheck, DependencyStatus, ReadinessResponse};
+use crate::microservices::admin_tasks_client::check_task_server_health;
+use crate::microservices::markdown_client::check_markdown_service_health;
+use crate::state::AppState;
+use std::future::Future;
+use std::sync::LazyLock;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use tracing::warn;
+
+/// 준비 상태 확인 결과를 재사용하는 시간
+///
+/// 프로브가 여러 곳에서 자주 와도 의존성에는 이 간격으로만 요청을 보냄
+const READINESS_CACHE_TTL: Duration = Duration::from_secs(5);
+
+/// 마지막 확인 시각과 결과 (동시에 온 요청은 락을 기다렸다가 같은 결과를 받음)
+static LAST_READINESS: LazyLock<Mutex<Option<(Instant, ReadinessResponse)>>> =
+    LazyLock::new(|| Mutex::new(None));
+
+/// 모든 의존성을 동시에 확인하고 의존성별 상태와 지연 시간을 반환 (오류 내용은 응답에 넣지 않음)
+///
+/// 최근 READINESS_CACHE_TTL 안에 확인한 결과가 있으면 그대로 반환
+pub async fn service_check_readiness(state: &AppState) -> ReadinessResponse {
+    let mut last = LAST_READINESS.lock().await;
+    if let Some((checked_at, response)) = last.as_ref()
+        && checked_at.elapsed() < READINESS_CACHE_TTL
+    {
+        return response.clone();
+    }
+
+    let response = check_dependencies(state).await;
+    *last = Some((Instant::now(), response.clone()));
+    response
+}
+
+async fn check_dependencies(state: &AppState) -> ReadinessResponse {
+    let config = DbConfig::get();
+    let timeout = Duration::from_millis(config.health_check_timeout_ms);
+
+    let (postgres, redis, meilisearch, r2, markdown, task_server) = tokio::join!(
+        check_dependency("postgres", timeout, async {
+            state.conn.ping().await.map_err(|e| e.to_string())
+        }),
+        check_dependency("redis", timeout, async {
+            let mut redis_conn = state.redis.clone();
+            redis::cmd("PING")
+                .query_async::<String>(&mut redis_conn)
+                .await
+                .map(|_| ())
+                .map_err(|e| e.to_string())
+        }),
+        check_dependency("meilisearch", timeout, async {
+            state
+                .meilisearch
+                .get_client()
+                .health()
+                .await
+                .map(|_| ())
+                .map_err(|e| e.to_string())
+        }),
+        check_dependency("r2", timeout, async {
+            state
+                .cloudflare_r2
+                .head_bucket()
+                .await
+                .map_err(|e| e.to_string())
+        }),
+        // 네이티브 렌더러 사용 시 마크다운 서비스는 필요 없음
+        check_optional_dependency(
+            "markdown_service",
+            !config.markdown_native_renderer,
+            timeout,
+            async {
+                check_markdown_service_health(&state.http_client)
+                    .await
+                    .map_err(|e| e.to_string())
+            }
+        ),
+        // 네이티브 작업 큐와 렌더러를 모두 쓰면 태스크 서버는 필요 없음
+        check_optional_dependency(
+            "task_server",
+            !(config.native_job_runner && config.markdown_native_renderer),
+            timeout,
+            async {
+                check_task_server_health(&state.http_client)
+                    .await
+                    .map_err(|e| e.to_string())
+            }
+        ),
+    );
+
+    let dependencies = vec![postgres, redis, meilisearch, r2, markdown, task_server];
+    let ready = dependencies
+        .iter()
+        .all(|dependency| dependency.status != DependencyStatus::Down);
+
+    ReadinessResponse {
+        ready,
+        dependencies,
+    }
+}
+
+async fn check_optional_dependency<F>(
+    name: &str,
+    enabled: bool,
+    timeout: Duration,
+    check: F,
+) -> DependencyCheck
+where
+    F: Future<Output = Result<(), String>>,
+{
+    if !enabled {
+        return DependencyCheck {
+            name: name.to_string(),
+            status: DependencyStatus::Skipped,
+            latency_ms: 0,
+        };
+    }
+
+    check_dependency(name, timeout, check).await
+}
+
+async fn check_dependency<F>(name: &str, timeout: Duration, check: F) -> DependencyCheck
+where
+    F: Future<Output = Result<(), String>>,
+{
+    let started = Instant::now();
+
+    let result = match tokio::time::timeout(timeout, check).await {
+        Ok(result) => result,
+        Err(_) => Err(format!("Timed out after {}ms", timeout.as_millis())),
+    };
+
+    let latency_ms = started.elapsed().as_millis() as u64;
+
+    match result {
+        Ok(()) => DependencyCheck {
+            name: name.to_string(),
+            status: DependencyStatus::Up,
+            latency_ms,
+        },
+        // 내부 주소 등이 노출되지 않도록 오류 내용은 로그에만 남김
+        Err(e) => {
+            warn!("Readiness check failed for {}: {}", name, e);
+            DependencyCheck {
+                name: name.to_string(),
+                status: DependencyStatus::Down,
+                latency_ms,
+            }
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod check_readiness;
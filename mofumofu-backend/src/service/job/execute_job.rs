@@ -0,0 +1,262 @@
+use crate::dto::job::internal::job::Job;
+use crate::repository::auth::delete_expired_refresh_tokens::repository_delete_expired_refresh_tokens;
+use crate::repository::post::get_post_by_uuid::repository_get_post_by_uuid;
+use crate::repository::post::get_posts_for_indexing::{
+    repository_get_existing_post_ids, repository_get_posts_for_indexing,
+};
+use crate::repository::post::sync_like_counts::repository_sync_post_like_counts;
+use crate::repository::system_events::delete_old_events::repository_delete_old_events;
+use crate::repository::user::sync_follow_counts::repository_sync_user_follow_counts;
+use crate::service::email::smtp_mailer::send_email;
+use crate::service::email::templates::{
+    generate_email_verification_email, generate_reset_password_email,
+};
+use crate::service::error::errors::Errors;
+use crate::service::meilisearch::post_documents::build_post_documents;
+use crate::service::meilisearch::post_indexer::{
+    add_or_replace_posts, delete_post_document, delete_post_documents, get_post_document_ids,
+};
+use crate::state::AppState;
+use serde_json::{Value, json};
+use std::collections::HashSet;
+use tracing::info;
+use uuid::Uuid;
+
+/// 재색인 시 한 번에 불러와 색인할 포스트 수
+const REINDEX_BATCH_SIZE: u64 = 500;
+/// 시스템 이벤트 보관 기간 (태스크 서버와 동일)
+const SYSTEM_EVENT_RETENTION_DAYS: i64 = 30;
+
+/// 작업 실행 (성공 시 태스크 서버의 결과와 같은 형태의 JSON, 실패 시 재시도에 쓰일 오류 메시지)
+pub async fn execute_job(state: &AppState, job: &Job) -> Result<Value, String> {
+    match job {
+        Job::SendEmailVerification {
+            email_to,
+            username,
+            verification_token,
+        } => {
+            let email = generate_email_verification_email(username, verification_token);
+            send_email(email_to, &email.subject, &email.html_content)
+                .await
+                .map_err(|e| e.to_string())?;
+
+            Ok(json!({
+                "status": "SUCCESS",
+                "message": format!("이메일 인증 메일이 성공적으로 전송되었습니다: {}", email_to),
+                "email_to": email_to,
+                "subject": email.subject,
+            }))
+        }
+        Job::SendResetPasswordEmail {
+            email_to,
+            email,
+            token,
+        } => {
+            let data = generate_reset_password_email(email, token);
+            send_email(email_to, &data.subject, &data.html_content)
+                .await
+                .map_err(|e| e.to_string())?;
+
+            Ok(json!({
+                "status": "SUCCESS",
+                "message": format!("비밀번호 재설정 이메일이 성공적으로 전송되었습니다: {}", email_to),
+                "email_to": email_to,
+                "subject": data.subject,
+            }))
+        }
+        Job::IndexPost { post_id } | Job::UpdatePost { post_id } => {
+            index_single_post(state, post_id).await
+        }
+        Job::DeletePost { post_id } => {
+            delete_post_document(&state.meilisearch, &post_id.to_string())
+                .await
+                .map_err(|e| e.to_string())?;
+
+            Ok(json!({
+                "status": "SUCCESS",
+                "message": "포스트 색인 삭제 완료",
+                "post_id": post_id,
+            }))
+        }
+        Job::ReindexAllPosts => reindex_all_posts(state).await,
+        Job::SyncLikeCounts => {
+            let updated_count = sync_like_counts(state).await?;
+
+            Ok(json!({
+                "status": "SUCCESS",
+                "updated_count": updated_count,
+                "message": format!("Successfully synchronized like counts for {} posts", updated_count),
+            }))
+        }
+        Job::SyncFollowCounts => {
+            let (follower_updated_count, following_updated_count) =
+                sync_follow_counts(state).await?;
+
+            Ok(json!({
+                "status": "SUCCESS",
+                "follower_updated_count": follower_updated_count,
+                "following_updated_count": following_updated_count,
+                "total_updated": follower_updated_count + following_updated_count,
+                "message": "Successfully synchronized follow counts",
+            }))
+        }
+        Job::SyncAllCounts => {
+            let like_updated_count = sync_like_counts(state).await?;
+            let (follower_updated_count, following_updated_count) =
+                sync_follow_counts(state).await?;
+
+            Ok(json!({
+                "status": "SUCCESS",
+                "updated_count": like_updated_count,
+                "follower_updated_count": follower_updated_count,
+                "following_updated_count": following_updated_count,
+                "total_updated": like_updated_count + follower_updated_count + following_updated_count,
+                "message": "Successfully synchronized all counts",
+            }))
+        }
+        Job::CleanupExpiredTokens => {
+            let (expired, revoked) = repository_delete_expired_refresh_tokens(&state.conn)
+                .await
+                .map_err(|e| e.to_string())?;
+
+            Ok(json!({
+                "status": "SUCCESS",
+                "expired_tokens_deleted": expired,
+                "revoked_tokens_deleted": revoked,
+                "total_deleted": expired + revoked,
+                "message": format!("토큰 정리 완료: 만료된 토큰 {}개, 폐기된 토큰 {}개 삭제", expired, revoked),
+            }))
+        }
+        Job::CleanupOldEvents => {
+            let deleted_count =
+                repository_delete_old_events(&state.conn, SYSTEM_EVENT_RETENTION_DAYS)
+                    .await
+                    .map_err(|e| e.to_string())?;
+
+            Ok(json!({
+                "status": "SUCCESS",
+                "deleted_count": deleted_count,
+                "message": format!("시스템 이벤트 정리 완료: {}개 삭제", deleted_count),
+            }))
+        }
+    }
+}
+
+async fn index_single_post(state: &AppState, post_id: &Uuid) -> Result<Value, String> {
+    let post = match repository_get_post_by_uuid(&state.conn, post_id).await {
+        Ok(post) => post,
+        // 색인 전에 삭제된 포스트는 재시도해도 소용이 없으므로 성공으로 처리
+        Err(Errors::PostNotFound) => {
+            return Ok(json!({
+                "status": "SUCCESS",
+                "message": "포스트가 존재하지 않아 색인하지 않았습니다",
+                "post_id": post_id,
+            }));
+        }
+        Err(e) => return Err(format!("{:?}", e)),
+    };
+
+    let documents = build_post_documents(&state.conn, std::slice::from_ref(&post))
+        .await
+        .map_err(|e| format!("{:?}", e))?;
+    add_or_replace_posts(&state.meilisearch, &documents)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(json!({
+        "status": "SUCCESS",
+        "message": "포스트 색인 완료",
+        "post_id": post_id,
+    }))
+}
+
+/// 전체 재색인
+///
+/// 먼저 지우고 다시 넣으면 시간 초과나 재시도 때 검색 결과가 비므로,
+/// 모든 포스트를 추가/교체한 뒤 DB에 없는 문서만 지움
+async fn reindex_all_posts(state: &AppState) -> Result<Value, String> {
+    let mut indexed_count = 0;
+    let mut after = None;
+
+    loop {
+        let posts = repository_get_posts_for_indexing(&state.conn, after, REINDEX_BATCH_SIZE)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let Some(last) = posts.last() else {
+            break;
+        };
+        after = Some(last.id);
+
+        let documents = build_post_documents(&state.conn, &posts)
+            .await
+            .map_err(|e| format!("{:?}", e))?;
+        add_or_replace_posts(&state.meilisearch, &documents)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        indexed_count += documents.len();
+        info!("Reindexed {} posts so far", indexed_count);
+    }
+
+    let deleted_count = delete_stale_post_documents(state).await?;
+
+    Ok(json!({
+        "status": "SUCCESS",
+        "indexed_count": indexed_count,
+        "deleted_count": deleted_count,
+        "message": format!("전체 재색인 완료: {}개 포스트", indexed_count),
+    }))
+}
+
+/// 색인에는 있지만 DB에서 삭제된 포스트 문서 삭제
+async fn delete_stale_post_documents(state: &AppState) -> Result<usize, String> {
+    let mut stale_ids = Vec::new();
+    let mut offset = 0;
+
+    loop {
+        let document_ids =
+            get_post_document_ids(&state.meilisearch, offset, REINDEX_BATCH_SIZE as usize)
+                .await
+                .map_err(|e| e.to_string())?;
+        if document_ids.is_empty() {
+            break;
+        }
+        offset += document_ids.len();
+
+        let post_ids: Vec<Uuid> = document_ids
+            .iter()
+            .filter_map(|id| Uuid::parse_str(id).ok())
+            .collect();
+        let existing: HashSet<Uuid> = repository_get_existing_post_ids(&state.conn, &post_ids)
+            .await
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .collect();
+
+        stale_ids.extend(
+            document_ids.into_iter().filter(|id| {
+                Uuid::parse_str(id).map_or(true, |post_id| !existing.contains(&post_id))
+            }),
+        );
+    }
+
+    // 목록을 다 훑은 뒤 지워야 offset이 어긋나지 않음
+    delete_post_documents(&state.meilisearch, &stale_ids)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(stale_ids.len())
+}
+
+async fn sync_like_counts(state: &AppState) -> Result<u64, String> {
+    repository_sync_post_like_counts(&state.conn)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+async fn sync_follow_counts(state: &AppState) -> Result<(u64, u64), String> {
+    repository_sync_user_follow_counts(&state.conn)
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,207 @@
+use crate::config::db_config::DbConfig;
+use crate::connection::redis_connection::RedisConnection;
+use crate::dto::job::internal::job::Job;
+use crate::dto::job::internal::job_record::JobRecord;
+use chrono::{DateTime, Utc};
+use redis::{AsyncCommands, Script};
+use uuid::Uuid;
+
+/// 실행 대기 작업 (score = 실행 예정 시각 ms)
+const QUEUE_KEY: &str = "jobs:queue";
+/// 실행 중인 작업 (score = lease 만료 시각 ms)
+const RUNNING_KEY: &str = "jobs:running";
+/// 끝나지 않은 작업 정보 보관 시간 (큐에서 사라진 작업의 키가 남지 않도록)
+const UNFINISHED_RECORD_TTL: u64 = 7 * 24 * 3600;
+
+/// 같은 ID의 작업이 없을 때만 작업 정보를 저장하고 대기열에 추가
+const ENQUEUE_SCRIPT: &str = r#"
+if redis.call('SET', KEYS[1], ARGV[1], 'NX', 'EX', ARGV[2]) then
+    redis.call('ZADD', KEYS[2], ARGV[3], ARGV[4])
+    return 1
+end
+return 0
+"#;
+
+/// 실행 시각이 된 작업을 최대 ARGV[2]개 꺼내 실행 중 목록으로 옮김
+const CLAIM_SCRIPT: &str = r#"
+local ids = redis.call('ZRANGEBYSCORE', KEYS[1], '-inf', ARGV[1], 'LIMIT', 0, ARGV[2])
+for _, id in ipairs(ids) do
+    redis.call('ZREM', KEYS[1], id)
+    redis.call('ZADD', KEYS[2], ARGV[3], id)
+end
+return ids
+"#;
+
+/// lease가 만료된 실행 중 작업을 대기열로 되돌림 (작업 도중 프로세스가 죽은 경우)
+const REQUEUE_EXPIRED_SCRIPT: &str = r#"
+local ids = redis.call('ZRANGEBYSCORE', KEYS[2], '-inf', ARGV[1])
+for _, id in ipairs(ids) do
+    redis.call('ZREM', KEYS[2], id)
+    redis.call('ZADD', KEYS[1], ARGV[1], id)
+end
+return #ids
+"#;
+
+fn job_key(id: &str) -> String {
+    format!("job:{}", id)
+}
+
+fn encode_record(record: &JobRecord) -> redis::RedisResult<String> {
+    serde_json::to_string(record).map_err(|e| {
+        redis::RedisError::from((
+            redis::ErrorKind::TypeError,
+            "Failed to encode job record",
+            e.to_string(),
+        ))
+    })
+}
+
+/// 작업을 바로 실행하도록 등록하고 작업 ID를 반환
+pub async fn service_enqueue_job(redis: &RedisConnection, job: Job) -> redis::RedisResult<String> {
+    service_schedule_job(redis, job, Utc::now()).await
+}
+
+/// 작업을 run_at에 실행하도록 등록하고 작업 ID를 반환
+pub async fn service_schedule_job(
+    redis: &RedisConnection,
+    job: Job,
+    run_at: DateTime<Utc>,
+) -> redis::RedisResult<String> {
+    let id = Uuid::new_v4().to_string();
+    service_enqueue_job_with_id(redis, &id, job, run_at).await?;
+    Ok(id)
+}
+
+/// 지정한 ID로 작업 등록 (같은 ID의 작업이 이미 있으면 무시하고 false 반환)
+///
+/// 아웃박스의 멱등성 키나 cron 실행 시각을 ID로 쓰면 중복 실행을 막을 수 있음
+pub async fn service_enqueue_job_with_id(
+    redis: &RedisConnection,
+    id: &str,
+    job: Job,
+    run_at: DateTime<Utc>,
+) -> redis::RedisResult<bool> {
+    let mut redis_conn = redis.clone();
+    let record = JobRecord::new(id.to_string(), job);
+
+    let created: i32 = Script::new(ENQUEUE_SCRIPT)
+        .key(job_key(id))
+        .key(QUEUE_KEY)
+        .arg(encode_record(&record)?)
+        .arg(UNFINISHED_RECORD_TTL)
+        .arg(run_at.timestamp_millis())
+        .arg(id)
+        .invoke_async(&mut redis_conn)
+        .await?;
+
+    Ok(created == 1)
+}
+
+/// 실행 시각이 된 작업을 최대 limit개 선점하고 ID 목록을 반환
+pub async fn service_claim_jobs(
+    redis: &RedisConnection,
+    limit: usize,
+    lease_seconds: u64,
+) -> redis::RedisResult<Vec<String>> {
+    let mut redis_conn = redis.clone();
+    let now = Utc::now().timestamp_millis();
+
+    Script::new(CLAIM_SCRIPT)
+        .key(QUEUE_KEY)
+        .key(RUNNING_KEY)
+        .arg(now)
+        .arg(limit)
+        .arg(now + (lease_seconds * 1000) as i64)
+        .invoke_async(&mut redis_conn)
+        .await
+}
+
+/// lease가 만료된 작업을 대기열로 되돌리고 되돌린 수를 반환
+pub async fn service_requeue_expired_jobs(redis: &RedisConnection) -> redis::RedisResult<usize> {
+    let mut redis_conn = redis.clone();
+
+    Script::new(REQUEUE_EXPIRED_SCRIPT)
+        .key(QUEUE_KEY)
+        .key(RUNNING_KEY)
+        .arg(Utc::now().timestamp_millis())
+        .invoke_async(&mut redis_conn)
+        .await
+}
+
+/// 작업 정보 조회 (없거나 만료되었으면 None)
+pub async fn service_get_job_record(
+    redis: &RedisConnection,
+    id: &str,
+) -> redis::RedisResult<Option<JobRecord>> {
+    let mut redis_conn = redis.clone();
+    let value: Option<String> = redis_conn.get(job_key(id)).await?;
+
+    Ok(value.and_then(|value| serde_json::from_str(&value).ok()))
+}
+
+/// 실행을 시작한 작업의 상태 저장 (lease는 유지)
+pub async fn service_save_job_record(
+    redis: &RedisConnection,
+    record: &JobRecord,
+) -> redis::RedisResult<()> {
+    let mut redis_conn = redis.clone();
+
+    redis_conn
+        .set_ex(
+            job_key(&record.id),
+            encode_record(record)?,
+            UNFINISHED_RECORD_TTL,
+        )
+        .await
+}
+
+/// 끝난 작업(성공/최종 실패)의 결과를 저장하고 실행 중 목록에서 제거
+pub async fn service_finish_job(
+    redis: &RedisConnection,
+    record: &JobRecord,
+) -> redis::RedisResult<()> {
+    let mut redis_conn = redis.clone();
+
+    redis::pipe()
+        .atomic()
+        .set_ex(
+            job_key(&record.id),
+            encode_record(record)?,
+            DbConfig::get().job_result_ttl,
+        )
+        .ignore()
+        .zrem(RUNNING_KEY, &record.id)
+        .ignore()
+        .query_async(&mut redis_conn)
+        .await
+}
+
+/// 실패한 작업을 run_at에 다시 실행하도록 대기열로 되돌림
+pub async fn service_retry_job(
+    redis: &RedisConnection,
+    record: &JobRecord,
+    run_at: DateTime<Utc>,
+) -> redis::RedisResult<()> {
+    let mut redis_conn = redis.clone();
+
+    redis::pipe()
+        .atomic()
+        .set_ex(
+            job_key(&record.id),
+            encode_record(record)?,
+            UNFINISHED_RECORD_TTL,
+        )
+        .ignore()
+        .zrem(RUNNING_KEY, &record.id)
+        .ignore()
+        .zadd(QUEUE_KEY, &record.id, run_at.timestamp_millis())
+        .ignore()
+        .query_async(&mut redis_conn)
+        .await
+}
+
+/// 작업 정보가 사라진 작업을 실행 중 목록에서 제거
+pub async fn service_drop_job(redis: &RedisConnection, id: &str) -> redis::RedisResult<()> {
+    let mut redis_conn = redis.clone();
+    redis_conn.zrem(RUNNING_KEY, id).await
+}
@@ -0,0 +1,109 @@
+use crate::config::db_config::DbConfig;
+use crate::connection::redis_connection::RedisConnection;
+use crate::dto::job::internal::job::Job;
+use crate::service::job::job_queue::service_enqueue_job_with_id;
+use crate::utils::shutdown::BackgroundTasks;
+use chrono::{DateTime, Utc};
+use cron::Schedule;
+use std::str::FromStr;
+use std::time::Duration;
+use tracing::{error, info, warn};
+
+/// 주기 작업 확인 주기
+const SCHEDULER_TICK: Duration = Duration::from_secs(1);
+
+struct ScheduledJob {
+    name: &'static str,
+    schedule: Schedule,
+    job: Job,
+    next_run: Option<DateTime<Utc>>,
+}
+
+/// cron 설정에 따라 주기 작업을 등록하는 스케줄러 시작
+///
+/// 실행 시각을 작업 ID에 넣어 여러 인스턴스가 떠 있어도 한 번만 등록되도록 함
+pub fn spawn_job_scheduler(tasks: &BackgroundTasks, redis: RedisConnection) {
+    let config = DbConfig::get();
+
+    let mut jobs: Vec<ScheduledJob> = [
+        (
+            "cleanup_expired_refresh_tokens",
+            &config.job_schedule_cleanup_tokens,
+            Job::CleanupExpiredTokens,
+        ),
+        (
+            "cleanup_old_system_events",
+            &config.job_schedule_cleanup_events,
+            Job::CleanupOldEvents,
+        ),
+        (
+            "reindex_all_posts",
+            &config.job_schedule_reindex_posts,
+            Job::ReindexAllPosts,
+        ),
+        (
+            "sync_all_counts",
+            &config.job_schedule_sync_counts,
+            Job::SyncAllCounts,
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(name, expression, job)| {
+        let expression = expression.as_deref()?;
+        match Schedule::from_str(expression) {
+            Ok(schedule) => {
+                let next_run = schedule.upcoming(Utc).next();
+                info!("Scheduled job {} with '{}'", name, expression);
+                Some(ScheduledJob {
+                    name,
+                    schedule,
+                    job,
+                    next_run,
+                })
+            }
+            Err(e) => {
+                error!(
+                    "Invalid cron expression for {}: '{}' ({})",
+                    name, expression, e
+                );
+                None
+            }
+        }
+    })
+    .collect();
+
+    if jobs.is_empty() {
+        return;
+    }
+
+    let shutdown = tasks.clone();
+
+    tasks.spawn(async move {
+        loop {
+            tokio::select! {
+                _ = shutdown.cancelled() => break,
+                _ = tokio::time::sleep(SCHEDULER_TICK) => {}
+            }
+            let now = Utc::now();
+
+            for scheduled in jobs.iter_mut() {
+                let Some(next_run) = scheduled.next_run else {
+                    continue;
+                };
+                if next_run > now {
+                    continue;
+                }
+
+                let job_id = format!("cron:{}:{}", scheduled.name, next_run.timestamp());
+                match service_enqueue_job_with_id(&redis, &job_id, scheduled.job.clone(), now).await
+                {
+                    Ok(true) => info!("Enqueued scheduled job {}", job_id),
+                    Ok(false) => {}
+                    Err(e) => warn!("Failed to enqueue scheduled job {}: {}", job_id, e),
+                }
+
+                scheduled.next_run = scheduled.schedule.after(&now).next();
+            }
+        }
+    });
+}
@@ -0,0 +1,156 @@
+use crate::config::db_config::DbConfig;
+use crate::dto::job::internal::job_record::JobState;
+use crate::service::job::execute_job::execute_job;
+use crate::service::job::job_queue::{
+    service_claim_jobs, service_drop_job, service_finish_job, service_get_job_record,
+    service_requeue_expired_jobs, service_retry_job, service_save_job_record,
+};
+use crate::state::AppState;
+use crate::utils::shutdown::BackgroundTasks;
+use chrono::{Duration as ChronoDuration, Utc};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+use tracing::{error, info, warn};
+
+/// 제한 시간을 넘긴 작업이 먼저 실패 처리되도록 lease에 더하는 여유 시간 (초)
+const LEASE_MARGIN_SECONDS: u64 = 60;
+
+/// 내장 작업 큐 워커 시작 (JOB_WORKER_CONCURRENCY개까지 동시에 실행)
+///
+/// 종료 시 새 작업은 가져오지 않고 실행 중인 작업이 끝나기를 기다린다.
+/// 제한 시간 안에 끝나지 않은 작업은 lease가 만료되면 다시 대기열로 돌아간다.
+pub fn spawn_job_worker(tasks: &BackgroundTasks, state: AppState) {
+    let config = DbConfig::get();
+    let period = Duration::from_secs(config.job_poll_interval.max(1));
+    let lease_seconds = config.job_timeout + LEASE_MARGIN_SECONDS;
+    let semaphore = Arc::new(Semaphore::new(config.job_worker_concurrency.max(1)));
+    let shutdown = tasks.clone();
+
+    tasks.spawn(async move {
+        let mut interval = tokio::time::interval(period);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            tokio::select! {
+                _ = shutdown.cancelled() => break,
+                _ = interval.tick() => {}
+            }
+
+            match service_requeue_expired_jobs(&state.redis).await {
+                Ok(0) => {}
+                Ok(requeued) => warn!("Requeued {} jobs with expired leases", requeued),
+                Err(e) => warn!("Failed to requeue expired jobs: {}", e),
+            }
+
+            let available = semaphore.available_permits();
+            if available == 0 {
+                continue;
+            }
+
+            let job_ids = match service_claim_jobs(&state.redis, available, lease_seconds).await {
+                Ok(job_ids) => job_ids,
+                Err(e) => {
+                    warn!("Failed to claim jobs: {}", e);
+                    continue;
+                }
+            };
+
+            for job_id in job_ids {
+                let Ok(permit) = semaphore.clone().acquire_owned().await else {
+                    return;
+                };
+                let state = state.clone();
+
+                shutdown.spawn(async move {
+                    run_job(&state, &job_id).await;
+                    drop(permit);
+                });
+            }
+        }
+    });
+}
+
+async fn run_job(state: &AppState, job_id: &str) {
+    let config = DbConfig::get();
+
+    let mut record = match service_get_job_record(&state.redis, job_id).await {
+        Ok(Some(record)) => record,
+        Ok(None) => {
+            warn!("Job {} has no record, dropping it", job_id);
+            if let Err(e) = service_drop_job(&state.redis, job_id).await {
+                warn!("Failed to drop job {}: {}", job_id, e);
+            }
+            return;
+        }
+        Err(e) => {
+            // lease가 끝나면 다시 대기열로 돌아감
+            warn!("Failed to load job {}: {}", job_id, e);
+            return;
+        }
+    };
+
+    record.state = JobState::Started;
+    record.attempts += 1;
+    record.updated_at = Utc::now();
+    if let Err(e) = service_save_job_record(&state.redis, &record).await {
+        warn!("Failed to mark job {} as started: {}", job_id, e);
+    }
+
+    info!(
+        "Running job {} ({}), attempt {}",
+        job_id,
+        record.job.name(),
+        record.attempts
+    );
+
+    let timeout = Duration::from_secs(config.job_timeout);
+    let result = tokio::time::timeout(timeout, execute_job(state, &record.job))
+        .await
+        .unwrap_or_else(|_| Err(format!("Job timed out after {}s", config.job_timeout)));
+
+    record.updated_at = Utc::now();
+
+    let saved = match result {
+        Ok(value) => {
+            info!("Job {} ({}) succeeded", job_id, record.job.name());
+            record.state = JobState::Success;
+            record.result = Some(value);
+            record.error = None;
+            service_finish_job(&state.redis, &record).await
+        }
+        Err(e) if record.attempts < config.job_max_attempts => {
+            let exponent = (record.attempts - 1).min(20);
+            let delay = config.job_retry_backoff.saturating_mul(2u64.pow(exponent));
+
+            warn!(
+                "Job {} ({}) failed on attempt {}, retrying in {}s: {}",
+                job_id,
+                record.job.name(),
+                record.attempts,
+                delay,
+                e
+            );
+            record.state = JobState::Retry;
+            record.error = Some(e);
+            let run_at = Utc::now() + ChronoDuration::seconds(delay as i64);
+            service_retry_job(&state.redis, &record, run_at).await
+        }
+        Err(e) => {
+            error!(
+                "Job {} ({}) failed after {} attempts: {}",
+                job_id,
+                record.job.name(),
+                record.attempts,
+                e
+            );
+            record.state = JobState::Failure;
+            record.error = Some(e);
+            service_finish_job(&state.redis, &record).await
+        }
+    };
+
+    if let Err(e) = saved {
+        error!("Failed to save result of job {}: {}", job_id, e);
+    }
+}
@@ -0,0 +1,4 @@
+pub mod execute_job;
+pub mod job_queue;
+pub mod job_scheduler;
+pub mod job_worker;
@@ -1,10 +1,11 @@
 use crate::entity::common::{ActionType, TargetType};
 use crate::repository::comment::get_comment_by_id::repository_get_comment_by_id;
 use crate::repository::comment::update_like_count::repository_increment_comment_like_count;
-use crate::repository::like::check_like_status::repository_check_like_status_by_comment_id;
 use crate::repository::like::create_like::repository_create_like_by_comment_id;
 use crate::repository::system_events::log_event::repository_log_event;
 use crate::service::error::errors::{Errors, ServiceResult};
+use crate::utils::metrics::LIKES_TOTAL;
+use metrics::counter;
 use sea_orm::{ConnectionTrait, TransactionTrait};
 use uuid::Uuid;
 
@@ -28,21 +29,19 @@ where
         return Err(Errors::CommentNotFound);
     }
 
-    // 이미 좋아요가 있는지 확인
-    let already_liked =
-        repository_check_like_status_by_comment_id(&txn, user_id, comment_id).await?;
-    if already_liked {
+    // 좋아요 생성 (❤️ 반응으로 저장, 이미 있으면 아무것도 생성되지 않음)
+    let created = repository_create_like_by_comment_id(&txn, *user_id, *comment_id).await?;
+    if !created {
         return Err(Errors::LikeAlreadyExists);
     }
 
-    // 좋아요 생성
-    let _created_like = repository_create_like_by_comment_id(&txn, *user_id, *comment_id).await?;
-
     // 댓글 좋아요 수 증가
     repository_increment_comment_like_count(&txn, comment_id).await?;
 
     txn.commit().await?;
 
+    counter!(LIKES_TOTAL, "target" => "comment").increment(1);
+
     // 좋아요 생성 이벤트 로깅
     repository_log_event(
         conn,
@@ -1,15 +1,23 @@
+use crate::connection::redis_connection::RedisConnection;
+use crate::dto::cache::internal::cache_event::CacheEvent;
 use crate::entity::common::{ActionType, TargetType};
-use crate::repository::like::check_like_status::repository_check_like_status_by_post_id;
 use crate::repository::like::create_like::repository_create_like_by_post_id;
 use crate::repository::post::get_post_by_uuid::repository_get_post_by_uuid;
+use crate::repository::post::post_analytics::repository_increment_post_daily_likes;
 use crate::repository::post::update_like_count::repository_increment_post_like_count;
 use crate::repository::system_events::log_event::repository_log_event;
+use crate::service::cache::invalidate_cache::service_publish_cache_event;
 use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::post::related_posts_cache::service_invalidate_related_posts_cache;
+use crate::utils::metrics::LIKES_TOTAL;
+use metrics::counter;
 use sea_orm::{ConnectionTrait, TransactionTrait};
+use tracing::warn;
 use uuid::Uuid;
 
 pub async fn service_create_post_like<C>(
     conn: &C,
+    redis: &RedisConnection,
     user_id: &Uuid,
     post_id: &Uuid,
 ) -> ServiceResult<()>
@@ -23,20 +31,28 @@ where
 
     // 자신의 포스트에도 좋아요를 누를 수 있음 (제거된 제약)
 
-    // 이미 좋아요가 있는지 확인
-    let already_liked = repository_check_like_status_by_post_id(&txn, user_id, post_id).await?;
-    if already_liked {
+    // 좋아요 생성 (❤️ 반응으로 저장, 이미 있으면 아무것도 생성되지 않음)
+    let created = repository_create_like_by_post_id(&txn, *user_id, *post_id).await?;
+    if !created {
         return Err(Errors::LikeAlreadyExists);
     }
 
-    // 좋아요 생성
-    let _created_like = repository_create_like_by_post_id(&txn, *user_id, *post_id).await?;
-
     // 포스트 좋아요 개수 증가
     repository_increment_post_like_count(&txn, *post_id).await?;
 
     txn.commit().await?;
 
+    counter!(LIKES_TOTAL, "target" => "post").increment(1);
+
+    // 작성자 통계용 일간 집계
+    if let Err(e) = repository_increment_post_daily_likes(conn, *post_id).await {
+        warn!("Failed to record post analytics for {}: {}", *post_id, e);
+    }
+
+    // 함께 좋아요 점수가 바뀌므로 이 포스트의 관련 포스트 후보 무효화
+    service_invalidate_related_posts_cache(redis, post_id).await;
+    service_publish_cache_event(redis, CacheEvent::PostChanged { post_id: *post_id }).await;
+
     // 좋아요 생성 이벤트 로깅
     repository_log_event(
         conn,
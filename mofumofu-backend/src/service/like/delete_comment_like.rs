@@ -22,12 +22,10 @@ where
         .await?
         .ok_or(Errors::CommentNotFound)?;
 
-    // 좋아요 삭제
-    let deleted = repository_delete_like_by_comment_id(&txn, *user_id, *comment_id).await?;
-
-    if !deleted {
-        return Err(Errors::LikeNotFound);
-    }
+    // 좋아요 삭제 (❤️ 반응)
+    repository_delete_like_by_comment_id(&txn, *user_id, *comment_id)
+        .await?
+        .ok_or(Errors::LikeNotFound)?;
 
     // 댓글 좋아요 수 감소
     repository_decrement_comment_like_count(&txn, comment_id).await?;
@@ -1,14 +1,21 @@
+use crate::connection::redis_connection::RedisConnection;
+use crate::dto::cache::internal::cache_event::CacheEvent;
 use crate::entity::common::{ActionType, TargetType};
 use crate::repository::like::delete_like::repository_delete_like_by_post_id;
 use crate::repository::post::get_post_by_uuid::repository_get_post_by_uuid;
+use crate::repository::post::post_analytics::repository_decrement_post_daily_likes;
 use crate::repository::post::update_like_count::repository_decrement_post_like_count;
 use crate::repository::system_events::log_event::repository_log_event;
+use crate::service::cache::invalidate_cache::service_publish_cache_event;
 use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::post::related_posts_cache::service_invalidate_related_posts_cache;
 use sea_orm::{ConnectionTrait, TransactionTrait};
+use tracing::warn;
 use uuid::Uuid;
 
 pub async fn service_delete_post_like<C>(
     conn: &C,
+    redis: &RedisConnection,
     user_id: &Uuid,
     post_id: &Uuid,
 ) -> ServiceResult<()>
@@ -20,18 +27,28 @@ where
     // 포스트 존재 확인
     let _post = repository_get_post_by_uuid(&txn, post_id).await?;
 
-    // 좋아요 삭제
-    let deleted = repository_delete_like_by_post_id(&txn, *user_id, *post_id).await?;
-
-    if !deleted {
-        return Err(Errors::LikeNotFound);
-    }
+    // 좋아요 삭제 (❤️ 반응)
+    let deleted_like = repository_delete_like_by_post_id(&txn, *user_id, *post_id)
+        .await?
+        .ok_or(Errors::LikeNotFound)?;
 
     // 포스트 좋아요 개수 감소
     repository_decrement_post_like_count(&txn, *post_id).await?;
 
     txn.commit().await?;
 
+    // 좋아요를 받은 날의 일간 집계에서 제외
+    if let Err(e) =
+        repository_decrement_post_daily_likes(conn, *post_id, deleted_like.created_at.date_naive())
+            .await
+    {
+        warn!("Failed to record post analytics for {}: {}", *post_id, e);
+    }
+
+    // 함께 좋아요 점수가 바뀌므로 이 포스트의 관련 포스트 후보 무효화
+    service_invalidate_related_posts_cache(redis, post_id).await;
+    service_publish_cache_event(redis, CacheEvent::PostChanged { post_id: *post_id }).await;
+
     // 좋아요 삭제 이벤트 로깅
     repository_log_event(
         conn,
@@ -0,0 +1,82 @@
+use crate::entity::comments::Model as CommentModel;
+use crate::service::markdown::sanitizer::{
+    CLEAN_CONTENT_TAGS, EXTERNAL_LINK_REL, filter_attribute,
+};
+use ammonia::Builder;
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd, html};
+use std::collections::{HashMap, HashSet};
+use std::sync::LazyLock;
+
+/// 댓글에서 허용하는 태그 (인라인 코드, 링크, 강조, 코드 블록)
+const COMMENT_ALLOWED_TAGS: &[&str] = &["p", "br", "em", "strong", "code", "pre", "a"];
+
+/// 댓글 링크에 허용하는 URL 스킴
+const COMMENT_URL_SCHEMES: &[&str] = &["http", "https", "mailto"];
+
+/// 댓글 전용 sanitizer - 포스트 설정과 관계없이 항상 고정된 허용 목록을 사용
+///
+/// rel은 포스트와 같이 외부 링크에만 붙이므로 멘션 같은 내부 링크에는 붙지 않음
+static COMMENT_SANITIZER: LazyLock<Builder<'static>> = LazyLock::new(|| {
+    let mut builder = Builder::default();
+    builder
+        .tags(COMMENT_ALLOWED_TAGS.iter().copied().collect())
+        .clean_content_tags(CLEAN_CONTENT_TAGS.iter().copied().collect())
+        .generic_attributes(HashSet::new())
+        .tag_attributes(HashMap::from([("a", HashSet::from(["href"]))]))
+        .url_schemes(COMMENT_URL_SCHEMES.iter().copied().collect())
+        .link_rel(Some(EXTERNAL_LINK_REL))
+        .attribute_filter(filter_attribute)
+        .strip_comments(true);
+    builder
+});
+
+/// 허용하지 않는 마크다운 요소를 평범한 텍스트/문단으로 바꿈
+fn restrict_comment_events(events: Vec<Event<'_>>) -> Vec<Event<'_>> {
+    events
+        .into_iter()
+        .filter_map(|event| match event {
+            // HTML은 렌더링하지 않고 그대로 보여줌
+            Event::Html(raw) | Event::InlineHtml(raw) => Some(Event::Text(raw)),
+            // 헤딩은 일반 문단으로
+            Event::Start(Tag::Heading { .. }) => Some(Event::Start(Tag::Paragraph)),
+            Event::End(TagEnd::Heading(_)) => Some(Event::End(TagEnd::Paragraph)),
+            // 이미지는 대체 텍스트만 남김
+            Event::Start(Tag::Image { .. }) | Event::End(TagEnd::Image) => None,
+            other => Some(other),
+        })
+        .collect()
+}
+
+/// 댓글 마크다운을 제한된 HTML로 렌더링
+///
+/// 인라인 코드, 링크, 강조, 코드 블록만 허용하고 나머지는 sanitizer가 태그를 제거함
+pub fn render_comment_markdown(markdown: &str) -> String {
+    let events = restrict_comment_events(Parser::new_ext(markdown, Options::empty()).collect());
+
+    let mut html_content = String::with_capacity(markdown.len() * 3 / 2);
+    html::push_html(&mut html_content, events.into_iter());
+
+    COMMENT_SANITIZER.clean(&html_content).to_string()
+}
+
+/// 저장된 댓글의 HTML (렌더링 결과가 없는 기존 댓글은 조회 시 렌더링)
+pub fn comment_render_html(comment: &CommentModel) -> String {
+    comment
+        .render
+        .clone()
+        .unwrap_or_else(|| render_comment_markdown(&comment.content))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adds_rel_to_external_links_only() {
+        assert_eq!(
+            render_comment_markdown("[@alice](/@alice/profile) [site](https://example.com)"),
+            "<p><a href=\"/@alice/profile\">@alice</a> \
+             <a href=\"https://example.com\" rel=\"nofollow ugc noopener\">site</a></p>\n"
+        );
+    }
+}
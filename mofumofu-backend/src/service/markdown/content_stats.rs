@@ -0,0 +1,93 @@
+use crate::dto::post::internal::content_stats::ContentStats;
+use pulldown_cmark::{Event, Options, Parser};
+
+/// 띄어쓰기 기반 언어(영어, 한국어 등)의 분당 읽기 단어 수
+const WORDS_PER_MINUTE: f64 = 200.0;
+
+/// 띄어쓰기가 없는 언어(중국어, 일본어)의 분당 읽기 글자 수
+const CJK_CHARS_PER_MINUTE: f64 = 500.0;
+
+/// 언어 감지에 사용하는 최대 글자 수
+const LANGUAGE_DETECTION_SAMPLE_CHARS: usize = 10_000;
+
+/// 한자, 히라가나, 가타카나 여부 (한글은 띄어쓰기를 하므로 제외)
+fn is_cjk_ideograph_or_kana(c: char) -> bool {
+    matches!(
+        c,
+        '\u{3040}'..='\u{309F}' // Hiragana
+            | '\u{30A0}'..='\u{30FF}' // Katakana
+            | '\u{31F0}'..='\u{31FF}' // Katakana Phonetic Extensions
+            | '\u{3400}'..='\u{4DBF}' // CJK Unified Ideographs Extension A
+            | '\u{4E00}'..='\u{9FFF}' // CJK Unified Ideographs
+            | '\u{F900}'..='\u{FAFF}' // CJK Compatibility Ideographs
+            | '\u{FF66}'..='\u{FF9F}' // Halfwidth Katakana
+            | '\u{20000}'..='\u{2A6DF}' // CJK Unified Ideographs Extension B
+    )
+}
+
+/// 마크다운 문법을 제외한 본문 텍스트 추출
+fn extract_plain_text(markdown: &str) -> String {
+    let options = Options::ENABLE_TABLES
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS;
+
+    let mut text = String::with_capacity(markdown.len());
+    for event in Parser::new_ext(markdown, options) {
+        match event {
+            Event::Text(t) | Event::Code(t) => {
+                text.push_str(&t);
+                text.push(' ');
+            }
+            Event::SoftBreak | Event::HardBreak | Event::End(_) => text.push(' '),
+            _ => {}
+        }
+    }
+
+    text
+}
+
+fn detect_language(text: &str) -> Option<String> {
+    let sample: String = text.chars().take(LANGUAGE_DETECTION_SAMPLE_CHARS).collect();
+
+    whatlang::detect(&sample)
+        .filter(|info| info.is_reliable())
+        .map(|info| info.lang().code().to_string())
+}
+
+/// 단어 수, 예상 읽기 시간, 언어를 계산
+///
+/// 한자/가나는 띄어쓰기 없이 쓰이므로 글자 하나를 한 단어로 센다.
+pub fn compute_content_stats(markdown: &str) -> ContentStats {
+    let text = extract_plain_text(markdown);
+
+    let mut words = 0usize;
+    let mut cjk_chars = 0usize;
+
+    for token in text.split_whitespace() {
+        cjk_chars += token
+            .chars()
+            .filter(|c| is_cjk_ideograph_or_kana(*c))
+            .count();
+
+        if token
+            .chars()
+            .any(|c| c.is_alphanumeric() && !is_cjk_ideograph_or_kana(c))
+        {
+            words += 1;
+        }
+    }
+
+    let minutes = words as f64 / WORDS_PER_MINUTE + cjk_chars as f64 / CJK_CHARS_PER_MINUTE;
+    let reading_time = if words + cjk_chars == 0 {
+        0
+    } else {
+        minutes.ceil().max(1.0) as i32
+    };
+
+    ContentStats {
+        word_count: (words + cjk_chars) as i32,
+        reading_time,
+        language: detect_language(&text),
+    }
+}
@@ -0,0 +1,5 @@
+pub mod comment_renderer;
+pub mod content_stats;
+pub mod native_renderer;
+pub mod render;
+pub mod sanitizer;
@@ -0,0 +1,603 @@
+use crate::microservices::markdown_client::{RenderedMarkdown, TableOfContentsItem};
+use pulldown_cmark::{
+    BlockQuoteKind, CodeBlockKind, CowStr, Event, HeadingLevel, Options, Parser, Tag, TagEnd, html,
+};
+use std::collections::{HashMap, HashSet};
+use unicode_normalization::char::is_combining_mark;
+
+/// 헤딩 ID 접두사 (markdown-service의 rehype-slug 설정과 동일)
+const HEADING_ID_PREFIX: &str = "h-";
+
+/// remark-rehype가 각주 ID 앞에 붙이는 접두사
+const FOOTNOTE_ID_PREFIX: &str = "user-content-";
+/// 각주 목록 제목의 ID (접두사 없이 고정)
+const FOOTNOTE_LABEL_ID: &str = "footnote-label";
+const FOOTNOTE_LABEL: &str = "Footnotes";
+
+fn parser_options() -> Options {
+    Options::ENABLE_TABLES
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_MATH
+        | Options::ENABLE_GFM
+}
+
+/// github-slugger와 같은 규칙으로 헤딩 텍스트를 slug로 변환
+///
+/// 소문자로 바꾼 뒤 문자, 숫자, 결합 문자, -, _ 만 남기고 공백은 하나씩 -로 바꾼다.
+/// (앞뒤 공백을 자르거나 연속된 -를 합치지 않음)
+fn slugify(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .filter_map(|c| {
+            if c.is_alphanumeric() || is_combining_mark(c) || c == '-' || c == '_' {
+                Some(c)
+            } else if c == ' ' {
+                Some('-')
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// 중복 slug에는 -1, -2 ... 를 붙여 고유한 ID를 만든다
+///
+/// github-slugger처럼 번호는 원래 slug의 등장 횟수로 센다.
+fn unique_slug(text: &str, occurrences: &mut HashMap<String, usize>) -> String {
+    let base = slugify(text);
+    let mut slug = base.clone();
+
+    while occurrences.contains_key(&slug) {
+        let count = occurrences.entry(base.clone()).or_insert(0);
+        *count += 1;
+        slug = format!("{}-{}", base, count);
+    }
+    occurrences.insert(slug.clone(), 0);
+
+    slug
+}
+
+/// 각주 라벨을 mdast-util-to-hast와 같은 방식으로 ID에 쓸 수 있게 변환
+fn footnote_id(label: &str) -> String {
+    let mut id = String::new();
+    for c in label.to_lowercase().chars() {
+        if c.is_ascii_alphanumeric() || "!#$&'()*+,-./:;=?@_~".contains(c) {
+            id.push(c);
+        } else {
+            let mut buffer = [0; 4];
+            for byte in c.encode_utf8(&mut buffer).bytes() {
+                id.push_str(&format!("%{:02X}", byte));
+            }
+        }
+    }
+    id
+}
+
+fn heading_level_number(level: HeadingLevel) -> i32 {
+    match level {
+        HeadingLevel::H1 => 1,
+        HeadingLevel::H2 => 2,
+        HeadingLevel::H3 => 3,
+        HeadingLevel::H4 => 4,
+        HeadingLevel::H5 => 5,
+        HeadingLevel::H6 => 6,
+    }
+}
+
+fn alert_kind_name(kind: BlockQuoteKind) -> (&'static str, &'static str) {
+    match kind {
+        BlockQuoteKind::Note => ("note", "Note"),
+        BlockQuoteKind::Tip => ("tip", "Tip"),
+        BlockQuoteKind::Important => ("important", "Important"),
+        BlockQuoteKind::Warning => ("warning", "Warning"),
+        BlockQuoteKind::Caution => ("caution", "Caution"),
+    }
+}
+
+/// 헤딩에 ID를 부여하고 TOC 항목을 수집
+fn assign_heading_ids(events: &mut [Event<'_>]) -> Vec<TableOfContentsItem> {
+    let mut toc_items = Vec::new();
+    let mut occurrences = HashMap::new();
+
+    let mut index = 0;
+    while index < events.len() {
+        let Event::Start(Tag::Heading { level, .. }) = &events[index] else {
+            index += 1;
+            continue;
+        };
+        let level = *level;
+
+        let mut text = String::new();
+        let mut end = index + 1;
+        while end < events.len() {
+            match &events[end] {
+                Event::End(TagEnd::Heading(_)) => break,
+                Event::Text(t) | Event::Code(t) | Event::InlineMath(t) => text.push_str(t),
+                _ => {}
+            }
+            end += 1;
+        }
+
+        let text = text.trim().to_string();
+        let id = format!(
+            "{}{}",
+            HEADING_ID_PREFIX,
+            unique_slug(&text, &mut occurrences)
+        );
+
+        if let Event::Start(Tag::Heading { id: heading_id, .. }) = &mut events[index] {
+            *heading_id = Some(CowStr::from(id.clone()));
+        }
+
+        if !text.is_empty() {
+            toc_items.push(TableOfContentsItem {
+                level: heading_level_number(level),
+                text,
+                id,
+            });
+        }
+
+        index = end + 1;
+    }
+
+    toc_items
+}
+
+/// 할 일 항목이 들어 있는 목록과 그 항목의 위치 (remark-gfm처럼 클래스를 붙이기 위함)
+fn find_task_lists(events: &[Event<'_>]) -> (HashSet<usize>, HashSet<usize>) {
+    let mut lists = HashSet::new();
+    let mut items = HashSet::new();
+    let mut open_lists = Vec::new();
+    let mut open_items = Vec::new();
+
+    for (index, event) in events.iter().enumerate() {
+        match event {
+            Event::Start(Tag::List(_)) => open_lists.push(index),
+            Event::End(TagEnd::List(_)) => {
+                open_lists.pop();
+            }
+            Event::Start(Tag::Item) => open_items.push(index),
+            Event::End(TagEnd::Item) => {
+                open_items.pop();
+            }
+            Event::TaskListMarker(_) => {
+                if let (Some(&list), Some(&item)) = (open_lists.last(), open_items.last()) {
+                    lists.insert(list);
+                    items.insert(item);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    (lists, items)
+}
+
+/// 본문에서 각주 정의를 떼어 냄 (remark처럼 문서 끝의 각주 목록에 모아서 출력)
+fn split_footnote_definitions(
+    events: Vec<Event<'_>>,
+) -> (Vec<Event<'_>>, HashMap<String, Vec<Event<'_>>>) {
+    let mut body = Vec::with_capacity(events.len());
+    let mut definitions = HashMap::new();
+    let mut current: Option<(String, Vec<Event<'_>>)> = None;
+
+    for event in events {
+        match event {
+            Event::Start(Tag::FootnoteDefinition(label)) => {
+                current = Some((label.to_lowercase(), Vec::new()));
+            }
+            Event::End(TagEnd::FootnoteDefinition) => {
+                if let Some((label, content)) = current.take() {
+                    // 같은 라벨이 여러 번 정의되면 처음 것만 사용
+                    definitions.entry(label).or_insert(content);
+                }
+            }
+            event => match &mut current {
+                Some((_, content)) => content.push(event),
+                None => body.push(event),
+            },
+        }
+    }
+
+    (body, definitions)
+}
+
+/// 각주 참조 순서와 횟수
+struct Footnotes<'a> {
+    definitions: HashMap<String, Vec<Event<'a>>>,
+    order: Vec<String>,
+    counts: HashMap<String, usize>,
+}
+
+impl<'a> Footnotes<'a> {
+    fn new(definitions: HashMap<String, Vec<Event<'a>>>) -> Self {
+        Footnotes {
+            definitions,
+            order: Vec::new(),
+            counts: HashMap::new(),
+        }
+    }
+
+    /// 참조 위치의 <sup> 링크 (정의가 없으면 원문 그대로)
+    fn reference(&mut self, label: &str) -> Event<'a> {
+        let original = label;
+        let label = label.to_lowercase();
+        if !self.definitions.contains_key(&label) {
+            return Event::Text(format!("[^{}]", original).into());
+        }
+
+        let number = match self.order.iter().position(|l| *l == label) {
+            Some(position) => position + 1,
+            None => {
+                self.order.push(label.clone());
+                self.order.len()
+            }
+        };
+        let count = self.counts.entry(label.clone()).or_insert(0);
+        *count += 1;
+
+        let id = footnote_id(&label);
+        let suffix = if *count > 1 {
+            format!("-{}", count)
+        } else {
+            String::new()
+        };
+        Event::InlineHtml(
+            format!(
+                "<sup><a href=\"#{prefix}fn-{id}\" id=\"{prefix}fnref-{id}{suffix}\">{number}</a></sup>",
+                prefix = FOOTNOTE_ID_PREFIX,
+            )
+            .into(),
+        )
+    }
+
+    /// 문서 끝에 붙는 각주 목록 (참조된 각주가 없으면 None)
+    fn into_section(mut self) -> Option<Vec<Event<'a>>> {
+        if self.order.is_empty() {
+            return None;
+        }
+
+        let mut section = vec![Event::Html(
+            format!(
+                "<section class=\"footnotes\"><h2 class=\"sr-only\" id=\"{}\">{}</h2>\n<ol>\n",
+                FOOTNOTE_LABEL_ID, FOOTNOTE_LABEL
+            )
+            .into(),
+        )];
+
+        // 각주 안에서 새 각주를 참조할 수 있으므로 순서 목록이 늘어나는 동안 계속 처리
+        let mut index = 0;
+        while index < self.order.len() {
+            let label = self.order[index].clone();
+            index += 1;
+
+            let content = self.definitions.remove(&label).unwrap_or_default();
+            let mut content = rewrite_events(content, &mut self);
+
+            let id = footnote_id(&label);
+            let count = self.counts.get(&label).copied().unwrap_or(0);
+            let backrefs = (1..=count)
+                .map(|n| {
+                    let (suffix, sup) = if n > 1 {
+                        (format!("-{}", n), format!("<sup>{}</sup>", n))
+                    } else {
+                        (String::new(), String::new())
+                    };
+                    format!(
+                        "<a href=\"#{}fnref-{}{}\" class=\"data-footnote-backref\">↩{}</a>",
+                        FOOTNOTE_ID_PREFIX, id, suffix, sup
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(" ");
+            let backrefs = Event::InlineHtml(format!(" {}", backrefs).into());
+
+            // 마지막 문단 안에 되돌아가기 링크를 붙이고, 문단이 아니면 뒤에 붙임
+            match content.last() {
+                Some(Event::End(TagEnd::Paragraph)) => {
+                    content.insert(content.len() - 1, backrefs);
+                }
+                _ => content.push(backrefs),
+            }
+
+            section.push(Event::Html(
+                format!("<li id=\"{}fn-{}\">\n", FOOTNOTE_ID_PREFIX, id).into(),
+            ));
+            section.extend(content);
+            section.push(Event::Html("</li>\n".into()));
+        }
+
+        section.push(Event::Html("</ol>\n</section>\n".into()));
+        Some(section)
+    }
+}
+
+/// markdown-service와 같은 형태의 HTML이 나오도록 이벤트를 변환
+fn rewrite_events<'a>(events: Vec<Event<'a>>, footnotes: &mut Footnotes<'a>) -> Vec<Event<'a>> {
+    let (task_lists, task_items) = find_task_lists(&events);
+    let mut rewritten = Vec::with_capacity(events.len());
+    let mut events = events.into_iter().enumerate().peekable();
+
+    while let Some((index, event)) = events.next() {
+        match event {
+            // remark-breaks: 줄바꿈을 <br>로 변환
+            Event::SoftBreak => rewritten.push(Event::HardBreak),
+
+            // rehype-highlight와 같은 클래스만 부여하고 토큰 하이라이팅은 하지 않음
+            // (언어가 있는 코드 블록은 needs_markdown_service로 markdown-service에 맡김)
+            Event::Start(Tag::CodeBlock(kind)) => {
+                let lang = match kind {
+                    CodeBlockKind::Fenced(ref info) => info.split_whitespace().next().unwrap_or(""),
+                    CodeBlockKind::Indented => "",
+                };
+                // 언어가 없으면 rehype-highlight가 클래스를 붙이지 않음
+                let html = if lang.is_empty() {
+                    "<pre><code>".to_string()
+                } else {
+                    format!(
+                        "<pre><code class=\"hljs language-{}\">",
+                        lang.replace('"', "")
+                    )
+                };
+                rewritten.push(Event::Html(html.into()));
+            }
+            Event::End(TagEnd::CodeBlock) => {
+                rewritten.push(Event::Html("</code></pre>\n".into()));
+            }
+
+            // remark-math가 KaTeX에 넘기는 형태 그대로 출력 (KaTeX 렌더링은 하지 않으므로
+            // 수식이 있는 문서는 needs_markdown_service로 markdown-service에 맡김)
+            // 줄을 바꿔 감싼 $$ 블록만 display 수식이고, 문장 안의 $$...$$는 inline 수식
+            Event::Start(Tag::Paragraph) if matches!(events.peek(), Some((_, Event::DisplayMath(math))) if math.starts_with('\n')) =>
+            {
+                let Some((_, Event::DisplayMath(math))) = events.next() else {
+                    unreachable!()
+                };
+                let math = math.strip_prefix('\n').unwrap_or(&math);
+                let math = math.strip_suffix('\n').unwrap_or(math).to_string();
+
+                rewritten.push(Event::Html(
+                    "<pre><code class=\"language-math math-display\">".into(),
+                ));
+                rewritten.push(Event::Text(math.into()));
+                rewritten.push(Event::Html("</code></pre>\n".into()));
+
+                // 수식만 있는 문단이면 문단 태그는 출력하지 않음
+                if matches!(events.peek(), Some((_, Event::End(TagEnd::Paragraph)))) {
+                    events.next();
+                } else {
+                    rewritten.push(Event::Start(Tag::Paragraph));
+                }
+            }
+            Event::InlineMath(math) | Event::DisplayMath(math) => {
+                rewritten.push(Event::InlineHtml(
+                    "<code class=\"language-math math-inline\">".into(),
+                ));
+                rewritten.push(Event::Text(math));
+                rewritten.push(Event::InlineHtml("</code>".into()));
+            }
+
+            // remark-gfm: 할 일 목록에 클래스와 비활성 체크박스를 붙임
+            Event::Start(Tag::List(start)) if task_lists.contains(&index) => {
+                let html = match start {
+                    None => "<ul class=\"contains-task-list\">\n".to_string(),
+                    Some(1) => "<ol class=\"contains-task-list\">\n".to_string(),
+                    Some(start) => {
+                        format!("<ol start=\"{}\" class=\"contains-task-list\">\n", start)
+                    }
+                };
+                rewritten.push(Event::Html(html.into()));
+            }
+            Event::Start(Tag::Item) if task_items.contains(&index) => {
+                rewritten.push(Event::Html("<li class=\"task-list-item\">".into()));
+            }
+            Event::TaskListMarker(checked) => {
+                let html = if checked {
+                    "<input type=\"checkbox\" checked disabled> "
+                } else {
+                    "<input type=\"checkbox\" disabled> "
+                };
+                rewritten.push(Event::InlineHtml(html.into()));
+            }
+
+            // remark-gfm: 각주 참조 번호는 처음 참조된 순서로 매김
+            Event::FootnoteReference(label) => rewritten.push(footnotes.reference(&label)),
+
+            // remark-github-blockquote-alert: > [!NOTE] 형식의 알림 블록
+            Event::Start(Tag::BlockQuote(Some(kind))) => {
+                let (class, title) = alert_kind_name(kind);
+                rewritten.push(Event::Html(
+                    format!(
+                        "<div class=\"markdown-alert markdown-alert-{}\"><p class=\"markdown-alert-title\">{}</p>\n",
+                        class, title
+                    )
+                    .into(),
+                ));
+            }
+            Event::End(TagEnd::BlockQuote(Some(_))) => {
+                rewritten.push(Event::Html("</div>\n".into()));
+            }
+
+            other => rewritten.push(other),
+        }
+    }
+
+    rewritten
+}
+
+/// 내장 렌더러가 같은 결과를 낼 수 없어 markdown-service로 렌더링해야 하는지 확인
+///
+/// 언어를 지정한 코드 블록(rehype-highlight 토큰)과 수식(rehype-katex)은 내장 렌더러가 처리하지 않는다.
+pub fn needs_markdown_service(markdown: &str) -> bool {
+    Parser::new_ext(markdown, parser_options()).any(|event| match event {
+        Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(info))) => {
+            info.split_whitespace().next().is_some()
+        }
+        Event::InlineMath(_) | Event::DisplayMath(_) => true,
+        _ => false,
+    })
+}
+
+/// 마크다운을 프로세스 내에서 HTML로 렌더링
+pub fn render_markdown_native(markdown: &str) -> RenderedMarkdown {
+    let events: Vec<Event<'_>> = Parser::new_ext(markdown, parser_options()).collect();
+    let (mut events, definitions) = split_footnote_definitions(events);
+
+    let mut toc_items = assign_heading_ids(&mut events);
+
+    let mut footnotes = Footnotes::new(definitions);
+    let mut events = rewrite_events(events, &mut footnotes);
+    if let Some(section) = footnotes.into_section() {
+        events.extend(section);
+        toc_items.push(TableOfContentsItem {
+            level: 2,
+            text: FOOTNOTE_LABEL.to_string(),
+            id: FOOTNOTE_LABEL_ID.to_string(),
+        });
+    }
+
+    let mut html_content = String::with_capacity(markdown.len() * 3 / 2);
+    html::push_html(&mut html_content, events.into_iter());
+
+    RenderedMarkdown {
+        html_content,
+        toc_items,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::markdown::sanitizer::DEFAULT_SANITIZER;
+    use serde::Deserialize;
+    use std::path::PathBuf;
+
+    /// tests/fixtures/markdown 아래의 <이름>.md / .html / .toc.json
+    const FIXTURES: &[&str] = &[
+        "footnotes",
+        "heading_ids",
+        "plain_code",
+        "tables",
+        "task_lists",
+    ];
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct ExpectedTocItem {
+        level: i32,
+        text: String,
+        id: String,
+    }
+
+    fn read_fixture(name: &str, extension: &str) -> String {
+        let path: PathBuf = [
+            env!("CARGO_MANIFEST_DIR"),
+            "tests",
+            "fixtures",
+            "markdown",
+            &format!("{}.{}", name, extension),
+        ]
+        .iter()
+        .collect();
+        std::fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("failed to read {}: {}", path.display(), e))
+    }
+
+    /// 정화 후 직렬화 차이와 태그 사이 공백을 없앰
+    fn normalize(html: &str) -> String {
+        let cleaned = DEFAULT_SANITIZER.clean(html).to_string();
+
+        let mut normalized = String::with_capacity(cleaned.len());
+        let mut pending_whitespace = String::new();
+        for c in cleaned.trim().chars() {
+            if c.is_whitespace() {
+                pending_whitespace.push(c);
+                continue;
+            }
+            if !(c == '<' && normalized.ends_with('>')) {
+                normalized.push_str(&pending_whitespace);
+            }
+            pending_whitespace.clear();
+            normalized.push(c);
+        }
+        normalized
+    }
+
+    #[test]
+    fn matches_markdown_service_fixtures() {
+        for name in FIXTURES {
+            let rendered = render_markdown_native(&read_fixture(name, "md"));
+
+            assert_eq!(
+                normalize(&rendered.html_content),
+                normalize(&read_fixture(name, "html")),
+                "html_content of fixture {}",
+                name
+            );
+
+            let expected: Vec<ExpectedTocItem> =
+                serde_json::from_str(&read_fixture(name, "toc.json")).unwrap();
+            let actual: Vec<ExpectedTocItem> = rendered
+                .toc_items
+                .into_iter()
+                .map(|item| ExpectedTocItem {
+                    level: item.level,
+                    text: item.text,
+                    id: item.id,
+                })
+                .collect();
+            assert_eq!(actual, expected, "toc_items of fixture {}", name);
+        }
+    }
+
+    #[test]
+    fn defers_highlighting_and_math_to_markdown_service() {
+        let cases = [
+            ("```rust\nfn main() {}\n```", true),
+            ("Inline $x^2$ here.", true),
+            ("$$\nE = mc^2\n$$", true),
+            ("```\nplain text\n```", false),
+            ("    indented code", false),
+            ("Inline `$x$` code.", false),
+            ("# Title\n\n| a |\n| - |\n| b |", false),
+        ];
+
+        for (markdown, expected) in cases {
+            assert_eq!(
+                needs_markdown_service(markdown),
+                expected,
+                "markdown: {:?}",
+                markdown
+            );
+        }
+    }
+
+    #[test]
+    fn slugify_follows_github_slugger() {
+        let cases = [
+            ("Hello World", "hello-world"),
+            ("  padded  ", "--padded--"),
+            ("What's new?", "whats-new"),
+            ("snake_case-and-kebab", "snake_case-and-kebab"),
+            ("한글 제목", "한글-제목"),
+            ("Emoji 🎉 here", "emoji--here"),
+            ("Cafe\u{301}", "cafe\u{301}"),
+        ];
+
+        for (text, expected) in cases {
+            assert_eq!(slugify(text), expected, "text: {:?}", text);
+        }
+    }
+
+    #[test]
+    fn unique_slug_counts_by_original_slug() {
+        let mut occurrences = HashMap::new();
+        let slugs: Vec<String> = ["a", "a-1", "a", "a-1", "a"]
+            .iter()
+            .map(|text| unique_slug(text, &mut occurrences))
+            .collect();
+
+        assert_eq!(slugs, ["a", "a-1", "a-2", "a-1-1", "a-3"]);
+    }
+}
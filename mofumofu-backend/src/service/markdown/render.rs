@@ -0,0 +1,44 @@
+use crate::config::db_config::DbConfig;
+use crate::microservices::markdown_client::{RenderedMarkdown, render_markdown};
+use crate::service::markdown::native_renderer::{needs_markdown_service, render_markdown_native};
+use crate::service::markdown::sanitizer::sanitize_html;
+use reqwest::Client;
+use tracing::{info, warn};
+
+/// 설정에 따라 내장 렌더러 또는 markdown-service로 마크다운을 렌더링
+///
+/// 내장 렌더러를 설정해도 코드 하이라이팅이나 수식이 필요한 문서는 markdown-service로 렌더링하고,
+/// markdown-service가 실패하면 하이라이팅/수식 없이 내장 렌더러 결과를 사용한다.
+/// 어느 렌더러를 사용하든 결과 HTML은 저장/반환 전에 항상 sanitize된다.
+pub async fn service_render_markdown(
+    http_client: &Client,
+    markdown: &str,
+) -> Result<RenderedMarkdown, Box<dyn std::error::Error + Send + Sync>> {
+    let rendered = if DbConfig::get().markdown_native_renderer {
+        if needs_markdown_service(markdown) {
+            match render_markdown(http_client, markdown).await {
+                Ok(rendered) => rendered,
+                Err(e) => {
+                    warn!(
+                        "Markdown service failed, rendering natively without highlighting and math: {}",
+                        e
+                    );
+                    render_markdown_native(markdown)
+                }
+            }
+        } else {
+            info!(
+                "Rendering markdown natively, length: {} chars",
+                markdown.len()
+            );
+            render_markdown_native(markdown)
+        }
+    } else {
+        render_markdown(http_client, markdown).await?
+    };
+
+    Ok(RenderedMarkdown {
+        html_content: sanitize_html(&rendered.html_content),
+        toc_items: rendered.toc_items,
+    })
+}
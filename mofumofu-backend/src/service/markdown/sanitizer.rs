@@ -0,0 +1,723 @@
+use crate::config::db_config::DbConfig;
+use ammonia::Builder;
+use std::borrow::Cow;
+use std::cell::Cell;
+use std::collections::{HashMap, HashSet};
+use std::sync::LazyLock;
+
+/// 외부 링크에 붙는 rel 속성
+pub(crate) const EXTERNAL_LINK_REL: &str = "nofollow ugc noopener";
+
+/// 기본 허용 태그 (markdown-service의 sanitize 스키마 기준)
+const DEFAULT_ALLOWED_TAGS: &[&str] = &[
+    // 기본 HTML
+    "div",
+    "span",
+    "p",
+    "br",
+    "hr",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "strong",
+    "em",
+    "b",
+    "i",
+    "u",
+    "s",
+    "mark",
+    "small",
+    "sub",
+    "sup",
+    "blockquote",
+    "pre",
+    "code",
+    "kbd",
+    "ul",
+    "ol",
+    "li",
+    "dl",
+    "dt",
+    "dd",
+    "table",
+    "thead",
+    "tbody",
+    "tfoot",
+    "tr",
+    "th",
+    "td",
+    "a",
+    "img",
+    // GFM 확장
+    "input",
+    "details",
+    "summary",
+    "del",
+    "ins",
+    "section",
+    "aside",
+    // KaTeX 수학 요소
+    "math",
+    "semantics",
+    "mrow",
+    "mi",
+    "mo",
+    "mn",
+    "msup",
+    "msub",
+    "msubsup",
+    "mfrac",
+    "msqrt",
+    "mroot",
+    "munder",
+    "mover",
+    "munderover",
+    "mtable",
+    "mtr",
+    "mtd",
+    "mspace",
+    "mtext",
+    "annotation",
+    "mstyle",
+    "merror",
+    "mpadded",
+    "mphantom",
+    "menclose",
+    // SVG
+    "svg",
+    "g",
+    "path",
+    "rect",
+    "circle",
+    "ellipse",
+    "line",
+    "polyline",
+    "polygon",
+    "text",
+    "tspan",
+    "defs",
+    "marker",
+];
+
+/// 모든 태그에 허용하는 속성 (style은 화면을 덮는 CSS를 넣을 수 있어 KaTeX가 쓰는 span/svg에만 허용)
+const DEFAULT_GENERIC_ATTRIBUTES: &[&str] = &["id", "class", "title", "dir", "lang"];
+
+/// 모든 태그에 허용하는 속성 접두사
+const DEFAULT_GENERIC_ATTRIBUTE_PREFIXES: &[&str] = &["data-", "aria-"];
+
+/// 태그별 허용 속성
+const DEFAULT_TAG_ATTRIBUTES: &[(&str, &[&str])] = &[
+    ("a", &["href", "target"]),
+    (
+        "img",
+        &[
+            "src", "alt", "title", "width", "height", "loading", "decoding",
+        ],
+    ),
+    ("input", &["type", "disabled", "checked", "value"]),
+    ("th", &["scope", "colspan", "rowspan", "headers", "align"]),
+    ("td", &["colspan", "rowspan", "headers", "align"]),
+    ("ol", &["start"]),
+    ("details", &["open"]),
+    // KaTeX 레이아웃 (값은 filter_katex_style로 제한)
+    ("span", &["style"]),
+    (
+        "svg",
+        &[
+            "width",
+            "height",
+            "viewBox",
+            "xmlns",
+            "fill",
+            "stroke",
+            "preserveAspectRatio",
+            "style",
+        ],
+    ),
+    ("g", &["transform", "fill", "stroke"]),
+    ("path", &["d", "fill", "stroke", "stroke-width"]),
+    ("rect", &["x", "y", "width", "height", "fill", "stroke"]),
+    ("circle", &["cx", "cy", "r", "fill", "stroke"]),
+    ("ellipse", &["cx", "cy", "rx", "ry", "fill", "stroke"]),
+    ("line", &["x1", "y1", "x2", "y2", "stroke"]),
+    ("polyline", &["points", "fill", "stroke"]),
+    ("polygon", &["points", "fill", "stroke"]),
+    ("text", &["x", "y", "fill", "font-size", "text-anchor"]),
+    ("tspan", &["x", "y", "dx", "dy", "rotate"]),
+    (
+        "marker",
+        &[
+            "viewBox",
+            "refX",
+            "refY",
+            "markerUnits",
+            "markerWidth",
+            "markerHeight",
+            "orient",
+        ],
+    ),
+    ("math", &["xmlns", "display"]),
+    ("annotation", &["encoding"]),
+    ("mrow", &["mathcolor", "mathbackground"]),
+    ("mi", &["mathvariant", "mathcolor"]),
+    (
+        "mo",
+        &[
+            "form",
+            "fence",
+            "separator",
+            "lspace",
+            "rspace",
+            "stretchy",
+            "symmetric",
+            "maxsize",
+            "minsize",
+        ],
+    ),
+    ("mn", &["mathvariant", "mathcolor"]),
+    ("mfrac", &["linethickness", "numalign", "denomalign"]),
+    ("mspace", &["width", "height", "depth"]),
+    ("menclose", &["notation"]),
+    ("mpadded", &["width", "height", "depth", "lspace", "rspace"]),
+];
+
+/// style에 허용하는 CSS 속성 (rehype-katex가 위치와 크기를 잡는 데 쓰는 것만)
+const KATEX_STYLE_PROPERTIES: &[&str] = &[
+    "height",
+    "top",
+    "vertical-align",
+    "width",
+    "min-width",
+    "padding-left",
+    "border-bottom-width",
+];
+
+/// 기본 허용 URL 스킴 (javascript:, vbscript:, data: 등은 제거됨)
+const DEFAULT_URL_SCHEMES: &[&str] = &["http", "https", "mailto", "tel"];
+
+/// 내용까지 통째로 제거하는 태그 (허용 태그에 함께 넣으면 ammonia가 panic하므로 설정 검증에서 막음)
+pub(crate) const CLEAN_CONTENT_TAGS: &[&str] = &["script", "style"];
+
+thread_local! {
+    /// 현재 검사 중인 <a> 태그의 href가 외부 링크인지 여부
+    ///
+    /// ammonia는 link_rel로 추가한 rel 속성을 항상 마지막에 붙이므로,
+    /// href를 먼저 보고 기록한 값을 rel 처리 시점에 읽고 초기화한다.
+    static CURRENT_LINK_IS_EXTERNAL: Cell<bool> = const { Cell::new(false) };
+}
+
+/// 스킴이 있거나 프로토콜 상대 경로(//)인 링크를 외부 링크로 판단
+fn is_external_link(href: &str) -> bool {
+    let href = href.trim();
+
+    if href.starts_with("//") {
+        return true;
+    }
+
+    match href.split_once(':') {
+        Some((scheme, _)) => {
+            !scheme.is_empty()
+                && !scheme.contains(['/', '?', '#'])
+                && matches!(scheme.to_ascii_lowercase().as_str(), "http" | "https")
+        }
+        None => false,
+    }
+}
+
+/// em/px 길이 값인지 확인 (예: "0.8141em", "-0.05em", "0")
+fn is_length_value(value: &str) -> bool {
+    let number = match value
+        .strip_suffix("em")
+        .or_else(|| value.strip_suffix("px"))
+    {
+        Some(number) => number,
+        None => return value == "0",
+    };
+    let number = number.strip_prefix('-').unwrap_or(number);
+    let (integer, fraction) = number.split_once('.').unwrap_or((number, ""));
+
+    !(integer.is_empty() && fraction.is_empty())
+        && integer.bytes().all(|b| b.is_ascii_digit())
+        && fraction.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// KaTeX 레이아웃 속성(height, top, margin-* 등)에 em/px 값인 선언만 남김
+///
+/// 남는 선언이 없으면 style 속성을 제거하고, 모두 허용되면 원래 값을 그대로 둔다.
+fn filter_katex_style(value: &str) -> Option<Cow<'_, str>> {
+    let mut allowed = Vec::new();
+    let mut dropped = false;
+
+    for declaration in value.split(';') {
+        let declaration = declaration.trim();
+        if declaration.is_empty() {
+            continue;
+        }
+        let accepted = declaration
+            .split_once(':')
+            .is_some_and(|(property, value)| {
+                let property = property.trim();
+                (KATEX_STYLE_PROPERTIES.contains(&property) || property.starts_with("margin-"))
+                    && property
+                        .bytes()
+                        .all(|b| b.is_ascii_lowercase() || b == b'-')
+                    && is_length_value(value.trim())
+            });
+        if accepted {
+            allowed.push(declaration);
+        } else {
+            dropped = true;
+        }
+    }
+
+    match (allowed.is_empty(), dropped) {
+        (true, _) => None,
+        (false, false) => Some(Cow::Borrowed(value)),
+        (false, true) => Some(Cow::Owned(format!("{};", allowed.join(";")))),
+    }
+}
+
+/// 외부 링크에만 rel을 남기는 속성 필터 (댓글 sanitizer와 공유)
+pub(super) fn filter_attribute<'u>(
+    element: &str,
+    attribute: &str,
+    value: &'u str,
+) -> Option<Cow<'u, str>> {
+    // 설정으로 style을 허용한 경우에도 KaTeX 레이아웃 값만 통과
+    if attribute == "style" {
+        return filter_katex_style(value);
+    }
+
+    if element != "a" {
+        return Some(Cow::Borrowed(value));
+    }
+
+    match attribute {
+        "href" => {
+            CURRENT_LINK_IS_EXTERNAL.with(|external| external.set(is_external_link(value)));
+            Some(Cow::Borrowed(value))
+        }
+        // 내부 링크(앵커, 상대 경로)에는 rel을 붙이지 않음
+        "rel" => {
+            let external = CURRENT_LINK_IS_EXTERNAL.with(|external| external.replace(false));
+            external.then_some(Cow::Borrowed(value))
+        }
+        _ => Some(Cow::Borrowed(value)),
+    }
+}
+
+/// 설정의 허용 목록으로 sanitizer를 만듦 (None이면 기본 목록 사용)
+fn build_sanitizer<'a>(
+    allowed_tags: Option<&'a [String]>,
+    allowed_attributes: Option<&'a [String]>,
+    url_schemes: Option<&'a [String]>,
+) -> Builder<'a> {
+    let tags: HashSet<&'a str> = match allowed_tags {
+        Some(tags) => tags.iter().map(String::as_str).collect(),
+        None => DEFAULT_ALLOWED_TAGS.iter().copied().collect(),
+    };
+
+    let mut generic_attributes: HashSet<&'a str> = HashSet::new();
+    let mut generic_attribute_prefixes: HashSet<&'a str> = HashSet::new();
+    let mut tag_attributes: HashMap<&'a str, HashSet<&'a str>> = HashMap::new();
+
+    match allowed_attributes {
+        // "tag:attr" 또는 "*:attr" 형식, "*:data-*"처럼 끝이 *이면 접두사로 취급
+        Some(entries) => {
+            for entry in entries {
+                let (tag, attribute) = entry.split_once(':').unwrap_or(("*", entry.as_str()));
+                match (tag, attribute.strip_suffix('*')) {
+                    ("*", Some(prefix)) => {
+                        generic_attribute_prefixes.insert(prefix);
+                    }
+                    ("*", None) => {
+                        generic_attributes.insert(attribute);
+                    }
+                    (tag, _) => {
+                        tag_attributes.entry(tag).or_default().insert(attribute);
+                    }
+                }
+            }
+        }
+        None => {
+            generic_attributes.extend(DEFAULT_GENERIC_ATTRIBUTES);
+            generic_attribute_prefixes.extend(DEFAULT_GENERIC_ATTRIBUTE_PREFIXES);
+            for (tag, attributes) in DEFAULT_TAG_ATTRIBUTES {
+                tag_attributes
+                    .entry(tag)
+                    .or_default()
+                    .extend(attributes.iter().copied());
+            }
+        }
+    }
+
+    // rel은 link_rel로만 설정
+    if let Some(attributes) = tag_attributes.get_mut("a") {
+        attributes.remove("rel");
+    }
+    generic_attributes.remove("rel");
+
+    let url_schemes: HashSet<&'a str> = match url_schemes {
+        Some(schemes) => schemes.iter().map(String::as_str).collect(),
+        None => DEFAULT_URL_SCHEMES.iter().copied().collect(),
+    };
+
+    let mut builder = Builder::default();
+    builder
+        .tags(tags)
+        .clean_content_tags(CLEAN_CONTENT_TAGS.iter().copied().collect())
+        .generic_attributes(generic_attributes)
+        .generic_attribute_prefixes(generic_attribute_prefixes)
+        .tag_attributes(tag_attributes)
+        .url_schemes(url_schemes)
+        .link_rel(Some(EXTERNAL_LINK_REL))
+        .attribute_filter(filter_attribute)
+        .strip_comments(true);
+
+    builder
+}
+
+/// 기본 허용 목록 sanitizer (설정으로 목록을 바꾸지 않은 경우)
+pub(super) static DEFAULT_SANITIZER: LazyLock<Builder<'static>> =
+    LazyLock::new(|| build_sanitizer(None, None, None));
+
+/// 렌더링된 HTML을 허용 목록 기준으로 정화
+///
+/// 허용되지 않은 태그/속성, 이벤트 핸들러(on*), javascript: 등의 URL을 제거하고
+/// 외부 링크에는 rel="nofollow ugc noopener"를 추가한다.
+/// 허용 목록을 설정한 경우 재로딩된 값이 바로 반영되도록 호출마다 현재 설정으로 만든다.
+pub fn sanitize_html(html: &str) -> String {
+    let config = DbConfig::get();
+
+    if config.html_sanitizer_allowed_tags.is_none()
+        && config.html_sanitizer_allowed_attributes.is_none()
+        && config.html_sanitizer_url_schemes.is_none()
+    {
+        return DEFAULT_SANITIZER.clean(html).to_string();
+    }
+
+    build_sanitizer(
+        config.html_sanitizer_allowed_tags.as_deref(),
+        config.html_sanitizer_allowed_attributes.as_deref(),
+        config.html_sanitizer_url_schemes.as_deref(),
+    )
+    .clean(html)
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clean(html: &str) -> String {
+        DEFAULT_SANITIZER.clean(html).to_string()
+    }
+
+    /// (설명, 입력, 결과에 남으면 안 되는 문자열)
+    const XSS_PAYLOADS: &[(&str, &str, &[&str])] = &[
+        (
+            "script tag",
+            "<p>hi</p><script>alert(1)</script>",
+            &["<script", "alert"],
+        ),
+        (
+            "script with uppercase and attributes",
+            "<SCRIPT type=\"text/javascript\" src=\"https://evil.example/x.js\"></SCRIPT>",
+            &["<script", "evil.example"],
+        ),
+        (
+            "style tag",
+            "<style>body { display: none }</style><p>x</p>",
+            &["<style", "display"],
+        ),
+        (
+            "onerror handler",
+            "<img src=\"x\" onerror=\"alert(1)\">",
+            &["onerror", "alert"],
+        ),
+        (
+            "unquoted onerror handler",
+            "<img src=x onerror=alert(1)//>",
+            &["onerror", "alert"],
+        ),
+        (
+            "onclick on allowed tag",
+            "<p onclick=\"alert(1)\">x</p>",
+            &["onclick", "alert"],
+        ),
+        (
+            "inline style",
+            "<div style=\"position:fixed;inset:0\">x</div>",
+            &["style", "position"],
+        ),
+        (
+            "overlay style on span",
+            "<span class=\"katex\" style=\"position:fixed;inset:0;background:url(https://evil.example/x.png)\">x</span>",
+            &["style", "position", "evil.example"],
+        ),
+        (
+            "non-length value on allowed property",
+            "<span class=\"strut\" style=\"height:expression(alert(1));width:calc(100vw)\"></span>",
+            &["style", "expression", "calc"],
+        ),
+        (
+            "javascript url",
+            "<a href=\"javascript:alert(1)\">x</a>",
+            &["javascript", "alert"],
+        ),
+        (
+            "javascript url with mixed case and spaces",
+            "<a href=\"  JaVaScRiPt:alert(1)\">x</a>",
+            &["javascript", "JaVaScRiPt", "alert"],
+        ),
+        (
+            "vbscript url",
+            "<a href=\"vbscript:msgbox(1)\">x</a>",
+            &["vbscript", "msgbox"],
+        ),
+        (
+            "data url in link",
+            "<a href=\"data:text/html;base64,PHNjcmlwdD5hbGVydCgxKTwvc2NyaXB0Pg==\">x</a>",
+            &["data:", "base64"],
+        ),
+        (
+            "data url in image",
+            "<img src=\"data:image/svg+xml;base64,PHN2ZyBvbmxvYWQ9YWxlcnQoMSk+\">",
+            &["data:", "base64"],
+        ),
+        (
+            "decimal entity encoded scheme",
+            "<a href=\"&#106;&#97;&#118;&#97;&#115;&#99;&#114;&#105;&#112;&#116;&#58;alert(1)\">x</a>",
+            &["javascript", "alert", "&#"],
+        ),
+        (
+            "hex entity encoded scheme",
+            "<a href=\"&#x6A;&#x61;&#x76;&#x61;script&#x3A;alert(1)\">x</a>",
+            &["javascript", "script:", "alert"],
+        ),
+        (
+            "scheme split by encoded tab",
+            "<a href=\"jav&#x09;ascript:alert(1)\">x</a>",
+            &["ascript", "alert"],
+        ),
+        (
+            "scheme split by encoded newline",
+            "<a href=\"java&#10;script:alert(1)\">x</a>",
+            &["script", "alert"],
+        ),
+        (
+            "svg onload",
+            "<svg onload=\"alert(1)\"><circle r=\"1\"></circle></svg>",
+            &["onload", "alert"],
+        ),
+        (
+            "script inside svg",
+            "<svg><script>alert(1)</script></svg>",
+            &["<script", "alert"],
+        ),
+        (
+            "svg use with external reference",
+            "<svg><use href=\"https://evil.example/x.svg#a\"></use></svg>",
+            &["<use", "evil.example"],
+        ),
+        (
+            "svg animate setting href",
+            "<svg><a><animate attributeName=\"href\" values=\"javascript:alert(1)\"></animate><text x=\"0\" y=\"10\">x</text></a></svg>",
+            &["<animate", "javascript", "alert"],
+        ),
+        (
+            "svg foreignObject",
+            "<svg><foreignObject><iframe src=\"javascript:alert(1)\"></iframe></foreignObject></svg>",
+            &["foreignobject", "<iframe", "javascript"],
+        ),
+        (
+            "math with href",
+            "<math href=\"javascript:alert(1)\"><mi>x</mi></math>",
+            &["href", "javascript"],
+        ),
+        (
+            "math mtext namespace confusion",
+            "<math><mtext><table><mglyph><style><img src=x onerror=alert(1)></style></mglyph></table></mtext></math>",
+            &["onerror", "alert"],
+        ),
+        (
+            "math annotation-xml html",
+            "<math><annotation-xml encoding=\"text/html\"><img src=x onerror=alert(1)></annotation-xml></math>",
+            &["onerror", "alert"],
+        ),
+        (
+            "nested anchors",
+            "<a href=\"https://example.com\"><a href=\"javascript:alert(1)\">x</a></a>",
+            &["javascript", "alert"],
+        ),
+        (
+            "anchor inside anchor via table",
+            "<a href=\"https://example.com\"><table><a href=\"javascript:alert(1)\">x</a></table></a>",
+            &["javascript", "alert"],
+        ),
+        (
+            "iframe",
+            "<iframe src=\"https://evil.example\"></iframe>",
+            &["<iframe", "evil.example"],
+        ),
+        (
+            "form action",
+            "<form action=\"https://evil.example\"><input type=\"submit\"></form>",
+            &["<form", "evil.example"],
+        ),
+        (
+            "meta refresh",
+            "<meta http-equiv=\"refresh\" content=\"0;url=javascript:alert(1)\">",
+            &["<meta", "javascript"],
+        ),
+        (
+            "html comment hiding markup",
+            "<!--<img src=x onerror=alert(1)>-->",
+            &["onerror", "<!--"],
+        ),
+        (
+            "user supplied rel and target blank",
+            "<a href=\"/posts\" rel=\"opener\" target=\"_blank\">x</a>",
+            &["opener\""],
+        ),
+    ];
+
+    #[test]
+    fn strips_xss_payloads() {
+        for (name, payload, forbidden) in XSS_PAYLOADS {
+            let cleaned = clean(payload);
+            let lowered = cleaned.to_ascii_lowercase();
+            for needle in *forbidden {
+                assert!(
+                    !lowered.contains(&needle.to_ascii_lowercase()),
+                    "{}: {:?} survived in {:?}",
+                    name,
+                    needle,
+                    cleaned
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn keeps_safe_markup() {
+        assert_eq!(
+            clean("<p>Hello <strong>world</strong></p>"),
+            "<p>Hello <strong>world</strong></p>"
+        );
+        assert_eq!(
+            clean("<h2 id=\"h-intro\" class=\"title\">Intro</h2>"),
+            "<h2 id=\"h-intro\" class=\"title\">Intro</h2>"
+        );
+        assert_eq!(
+            clean("<img src=\"https://cdn.example.com/a.png\" alt=\"a\">"),
+            "<img src=\"https://cdn.example.com/a.png\" alt=\"a\">"
+        );
+    }
+
+    #[test]
+    fn keeps_rehype_katex_output() {
+        let path: std::path::PathBuf = [
+            env!("CARGO_MANIFEST_DIR"),
+            "tests",
+            "fixtures",
+            "sanitizer",
+            "katex.html",
+        ]
+        .iter()
+        .collect();
+        let katex = std::fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("failed to read {}: {}", path.display(), e));
+
+        assert_eq!(clean(&katex), katex);
+    }
+
+    #[test]
+    fn keeps_only_katex_layout_style() {
+        let cases = [
+            (
+                "<span class=\"vlist\" style=\"top:-3.063em;margin-right:0.05em;\"></span>",
+                "<span class=\"vlist\" style=\"top:-3.063em;margin-right:0.05em;\"></span>",
+            ),
+            (
+                "<span style=\"height:2.7em;color:red;min-width:12px\"></span>",
+                "<span style=\"height:2.7em;min-width:12px;\"></span>",
+            ),
+            ("<span style=\"margin-top:1e9em\"></span>", "<span></span>"),
+            ("<div style=\"height:1em\">x</div>", "<div>x</div>"),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(clean(input), expected, "input: {}", input);
+        }
+    }
+
+    #[test]
+    fn adds_rel_to_external_links_only() {
+        let cases = [
+            (
+                "<a href=\"https://example.com\">x</a>",
+                "<a href=\"https://example.com\" rel=\"nofollow ugc noopener\">x</a>",
+            ),
+            (
+                "<a href=\"HTTP://EXAMPLE.COM/a\">x</a>",
+                "<a href=\"HTTP://EXAMPLE.COM/a\" rel=\"nofollow ugc noopener\">x</a>",
+            ),
+            (
+                "<a href=\"//example.com/a\">x</a>",
+                "<a href=\"//example.com/a\" rel=\"nofollow ugc noopener\">x</a>",
+            ),
+            (
+                "<a href=\"/@mofu\">@mofu</a>",
+                "<a href=\"/@mofu\">@mofu</a>",
+            ),
+            (
+                "<a href=\"#h-intro\">Intro</a>",
+                "<a href=\"#h-intro\">Intro</a>",
+            ),
+            (
+                "<a href=\"posts/1?ref=a:b\">x</a>",
+                "<a href=\"posts/1?ref=a:b\">x</a>",
+            ),
+            (
+                "<a href=\"mailto:mofu@example.com\">x</a>",
+                "<a href=\"mailto:mofu@example.com\">x</a>",
+            ),
+            // 사용자가 넣은 rel은 버리고 외부 링크에만 다시 붙임
+            (
+                "<a href=\"https://example.com\" rel=\"opener\">x</a>",
+                "<a href=\"https://example.com\" rel=\"nofollow ugc noopener\">x</a>",
+            ),
+            (
+                "<a href=\"/posts\" rel=\"opener\">x</a>",
+                "<a href=\"/posts\">x</a>",
+            ),
+            // 허용되지 않은 스킴은 href가 제거되고 rel도 붙지 않음
+            ("<a href=\"javascript:alert(1)\">x</a>", "<a>x</a>"),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(clean(input), expected, "input: {}", input);
+        }
+    }
+
+    #[test]
+    fn external_flag_does_not_leak_between_links() {
+        assert_eq!(
+            clean("<a href=\"https://example.com\">a</a><a href=\"/b\">b</a><a>c</a>"),
+            "<a href=\"https://example.com\" rel=\"nofollow ugc noopener\">a</a><a href=\"/b\">b</a><a>c</a>"
+        );
+    }
+
+    #[test]
+    fn custom_tags_cannot_overlap_clean_content_tags() {
+        let tags = vec!["p".to_string(), "a".to_string()];
+        let cleaned = build_sanitizer(Some(&tags), None, None)
+            .clean("<p>x</p><script>alert(1)</script><div>y</div>")
+            .to_string();
+        assert_eq!(cleaned, "<p>x</p>y");
+    }
+}
@@ -1 +1,2 @@
+pub mod post_documents;
 pub mod post_indexer;
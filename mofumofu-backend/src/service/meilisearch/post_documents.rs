@@ -0,0 +1,46 @@
+use crate::connection::meilisearch::MeilisearchPost;
+use crate::entity::posts::Model as PostModel;
+use crate::repository::hashtag::get_hashtags_by_post::repository_get_hashtags_by_posts;
+use crate::repository::user::find_users_by_uuids::repository_find_users_by_uuids;
+use crate::service::error::errors::Errors;
+use sea_orm::ConnectionTrait;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// 포스트 목록을 작성자/해시태그와 함께 검색 문서로 변환 (작성자가 없는 포스트는 제외)
+pub async fn build_post_documents<C>(
+    conn: &C,
+    posts: &[PostModel],
+) -> Result<Vec<MeilisearchPost>, Errors>
+where
+    C: ConnectionTrait,
+{
+    let post_ids: Vec<Uuid> = posts.iter().map(|post| post.id).collect();
+    let mut user_ids: Vec<Uuid> = posts.iter().map(|post| post.user_id).collect();
+    user_ids.sort();
+    user_ids.dedup();
+
+    let users: HashMap<Uuid, _> = repository_find_users_by_uuids(conn, &user_ids)
+        .await?
+        .into_iter()
+        .map(|user| (user.id, user))
+        .collect();
+
+    let mut hashtags: HashMap<Uuid, Vec<String>> =
+        repository_get_hashtags_by_posts(conn, &post_ids)
+            .await?
+            .into_iter()
+            .map(|(post_id, tags)| (post_id, tags.into_iter().map(|tag| tag.name).collect()))
+            .collect();
+
+    Ok(posts
+        .iter()
+        .filter_map(|post| {
+            let user = users.get(&post.user_id)?;
+            let tags = hashtags.remove(&post.id).unwrap_or_default();
+            Some(MeilisearchPost::from_post_with_user_and_hashtags(
+                post, user, tags,
+            ))
+        })
+        .collect())
+}
@@ -1,4 +1,5 @@
 use crate::connection::meilisearch::{MeilisearchClient, MeilisearchPost, MeilisearchPostId};
+use meilisearch_sdk::documents::DocumentsQuery;
 use tracing::warn;
 
 pub async fn setup_posts_index(
@@ -27,6 +28,7 @@ pub async fn setup_posts_index(
             "created_at",
             "like_count",
             "view_count",
+            "language",
         ])
         .await?;
 
@@ -59,6 +61,79 @@ pub async fn delete_post(meilisearch: &MeilisearchClient, post_id: &str) {
     }
 }
 
+/// 여러 포스트를 추가하거나 교체 (작업 큐에서 재시도할 수 있도록 오류를 반환)
+pub async fn add_or_replace_posts(
+    meilisearch: &MeilisearchClient,
+    posts: &[MeilisearchPost],
+) -> Result<(), meilisearch_sdk::errors::Error> {
+    if posts.is_empty() {
+        return Ok(());
+    }
+
+    let posts_index = meilisearch.get_client().index("posts");
+    posts_index.add_or_replace(posts, Some("id")).await?;
+
+    Ok(())
+}
+
+/// 포스트 문서 삭제 (작업 큐에서 재시도할 수 있도록 오류를 반환)
+pub async fn delete_post_document(
+    meilisearch: &MeilisearchClient,
+    post_id: &str,
+) -> Result<(), meilisearch_sdk::errors::Error> {
+    let posts_index = meilisearch.get_client().index("posts");
+    posts_index.delete_document(post_id).await?;
+
+    Ok(())
+}
+
+/// 색인된 포스트 문서 ID를 offset부터 limit개 조회
+pub async fn get_post_document_ids(
+    meilisearch: &MeilisearchClient,
+    offset: usize,
+    limit: usize,
+) -> Result<Vec<String>, meilisearch_sdk::errors::Error> {
+    let posts_index = meilisearch.get_client().index("posts");
+    let documents = posts_index
+        .get_documents_with::<MeilisearchPostId>(
+            DocumentsQuery::new(&posts_index)
+                .with_offset(offset)
+                .with_limit(limit)
+                .with_fields(["id"]),
+        )
+        .await?;
+
+    Ok(documents.results.into_iter().map(|doc| doc.id).collect())
+}
+
+/// 여러 포스트 문서 삭제 (작업 큐에서 재시도할 수 있도록 오류를 반환)
+pub async fn delete_post_documents(
+    meilisearch: &MeilisearchClient,
+    post_ids: &[String],
+) -> Result<(), meilisearch_sdk::errors::Error> {
+    if post_ids.is_empty() {
+        return Ok(());
+    }
+
+    let posts_index = meilisearch.get_client().index("posts");
+    posts_index.delete_documents(post_ids).await?;
+
+    Ok(())
+}
+
+/// posts 색인 통계 (태스크 서버의 get_index_stats와 같은 형태)
+pub async fn get_posts_index_stats(
+    meilisearch: &MeilisearchClient,
+) -> Result<serde_json::Value, meilisearch_sdk::errors::Error> {
+    let stats = meilisearch.get_client().index("posts").get_stats().await?;
+
+    Ok(serde_json::json!({
+        "number_of_documents": stats.number_of_documents,
+        "is_indexing": stats.is_indexing,
+        "field_distribution": stats.field_distribution,
+    }))
+}
+
 pub async fn search_posts(
     meilisearch: &MeilisearchClient,
     query: Option<&str>,
@@ -67,6 +142,7 @@ pub async fn search_posts(
     date_from: Option<i64>,
     date_to: Option<i64>,
     min_likes: Option<i32>,
+    language: Option<&str>,
     sort: &str,
     page: u32,
     page_size: u32,
@@ -103,6 +179,10 @@ pub async fn search_posts(
         filters.push(format!("like_count >= {}", min_likes_count));
     }
 
+    if let Some(language) = language {
+        filters.push(format!("language = '{}'", language));
+    }
+
     // 검색 쿼리 빌드
     let query_str = query.unwrap_or("");
     let filter_str = if filters.is_empty() {
@@ -0,0 +1,162 @@
+use crate::dto::comment::internal::hidden_comment_visibility::HiddenCommentVisibility;
+use crate::dto::mention::request::get_mentions::GetMentionsRequest;
+use crate::dto::mention::response::get_mentions::GetMentionsResponse;
+use crate::dto::mention::response::mention_info::MentionInfo;
+use crate::entity::comments::Model as CommentModel;
+use crate::repository::comment::get_comment_by_id::repository_get_comments_by_ids;
+use crate::repository::mention::get_mentions::{
+    repository_count_mentions_by_user, repository_get_mentions_by_user,
+};
+use crate::repository::post::get_post_by_uuid::repository_get_posts_by_uuids;
+use crate::repository::user::find_users_by_uuids::repository_find_users_by_uuids;
+use crate::service::error::errors::ServiceResult;
+use sea_orm::ConnectionTrait;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// 사용자가 멘션된 포스트/댓글 목록 (최신순)
+pub async fn service_get_mentions<C>(
+    conn: &C,
+    user_id: &Uuid,
+    request: GetMentionsRequest,
+) -> ServiceResult<GetMentionsResponse>
+where
+    C: ConnectionTrait,
+{
+    let page = request.page;
+    let per_page = request.per_page;
+
+    let mentions = repository_get_mentions_by_user(conn, *user_id, page, per_page).await?;
+    let total_count = repository_count_mentions_by_user(conn, *user_id).await?;
+    let has_next = mentions.len() == per_page as usize;
+
+    // 포스트, 댓글, 사용자 정보를 한 번에 조회
+    let post_ids: Vec<Uuid> = mentions.iter().map(|mention| mention.post_id).collect();
+    let comment_ids: Vec<Uuid> = mentions.iter().filter_map(|m| m.comment_id).collect();
+
+    let posts: HashMap<Uuid, _> = repository_get_posts_by_uuids(conn, &post_ids)
+        .await?
+        .into_iter()
+        .map(|post| (post.id, post))
+        .collect();
+    let comments: HashMap<Uuid, _> = repository_get_comments_by_ids(conn, &comment_ids)
+        .await?
+        .into_iter()
+        .map(|comment| (comment.id, comment))
+        .collect();
+
+    let user_ids: Vec<Uuid> = mentions
+        .iter()
+        .map(|mention| mention.mentioner_id)
+        .chain(posts.values().map(|post| post.user_id))
+        .collect();
+    let users: HashMap<Uuid, _> = repository_find_users_by_uuids(conn, &user_ids)
+        .await?
+        .into_iter()
+        .map(|user| (user.id, user))
+        .collect();
+
+    let mentions = mentions
+        .into_iter()
+        .filter_map(|mention| {
+            let post = posts.get(&mention.post_id)?;
+            let post_author = users.get(&post.user_id)?;
+            let mentioner = users.get(&mention.mentioner_id)?;
+
+            let comment_content = mention
+                .comment_id
+                .and_then(|comment_id| comments.get(&comment_id))
+                .and_then(|comment| visible_comment_content(comment, *user_id, post.user_id));
+
+            Some(MentionInfo {
+                id: mention.id,
+                post_id: post.id,
+                post_title: post.title.clone(),
+                post_slug: post.slug.clone(),
+                post_author_handle: post_author.handle.clone(),
+                comment_id: mention.comment_id,
+                comment_content,
+                mentioner_handle: mentioner.handle.clone(),
+                mentioner_name: mentioner.name.clone(),
+                mentioner_profile_image: mentioner.profile_image.clone(),
+                created_at: mention.created_at,
+            })
+        })
+        .collect();
+
+    Ok(GetMentionsResponse {
+        mentions,
+        total_count,
+        page,
+        per_page,
+        has_next,
+    })
+}
+
+/// 멘션된 사용자에게 보여줄 댓글 내용 (삭제된 댓글과 볼 수 없는 숨긴 댓글은 제외)
+fn visible_comment_content(
+    comment: &CommentModel,
+    viewer_id: Uuid,
+    post_author_id: Uuid,
+) -> Option<String> {
+    if comment.is_deleted {
+        return None;
+    }
+    if comment.is_hidden
+        && !HiddenCommentVisibility::for_viewer(Some(viewer_id), post_author_id)
+            .can_see(comment.user_id)
+    {
+        return None;
+    }
+    Some(comment.content.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn comment(user_id: Uuid, is_hidden: bool, is_deleted: bool) -> CommentModel {
+        CommentModel {
+            id: Uuid::new_v4(),
+            content: "hello @bob".to_string(),
+            render: None,
+            post_id: Uuid::new_v4(),
+            user_id,
+            parent_id: None,
+            created_at: Utc::now(),
+            updated_at: None,
+            is_deleted,
+            like_count: 0,
+            reply_count: 0,
+            is_pinned: false,
+            pinned_at: None,
+            is_hidden,
+            is_locked: false,
+            edit_count: 0,
+        }
+    }
+
+    #[test]
+    fn hides_hidden_comment_content_from_mentioned_users() {
+        let (author, mentioned, post_author) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+
+        let visible = comment(author, false, false);
+        assert_eq!(
+            visible_comment_content(&visible, mentioned, post_author).as_deref(),
+            Some("hello @bob")
+        );
+
+        let hidden = comment(author, true, false);
+        assert_eq!(
+            visible_comment_content(&hidden, mentioned, post_author),
+            None
+        );
+        // 댓글 작성자와 포스트 작성자는 숨긴 댓글도 볼 수 있음
+        assert!(visible_comment_content(&hidden, author, post_author).is_some());
+        assert!(visible_comment_content(&hidden, post_author, post_author).is_some());
+
+        let deleted = comment(author, false, true);
+        assert_eq!(visible_comment_content(&deleted, author, post_author), None);
+    }
+}
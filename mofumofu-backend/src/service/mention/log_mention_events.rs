@@ -0,0 +1,38 @@
+use crate::entity::common::{ActionType, TargetType};
+use crate::repository::system_events::log_event::repository_log_event;
+use sea_orm::ConnectionTrait;
+use serde_json::json;
+use uuid::Uuid;
+
+/// 새로 멘션된 사용자마다 멘션 이벤트를 기록
+///
+/// 이벤트의 user_id는 멘션된 사용자이며, 멘션한 사용자는 metadata에 담긴다.
+pub async fn service_log_mention_events<C>(
+    conn: &C,
+    mentioner_id: Uuid,
+    post_id: Uuid,
+    comment_id: Option<Uuid>,
+    mentioned_user_ids: &[Uuid],
+) where
+    C: ConnectionTrait,
+{
+    let (target_id, target_type) = match comment_id {
+        Some(comment_id) => (comment_id, TargetType::Comment),
+        None => (post_id, TargetType::Post),
+    };
+
+    for mentioned_user_id in mentioned_user_ids {
+        repository_log_event(
+            conn,
+            Some(*mentioned_user_id),
+            ActionType::MentionCreated,
+            Some(target_id),
+            Some(target_type.clone()),
+            Some(json!({
+                "mentioner_id": mentioner_id,
+                "post_id": post_id,
+            })),
+        )
+        .await;
+    }
+}
@@ -0,0 +1,114 @@
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+use std::collections::HashSet;
+use std::ops::Range;
+
+/// 핸들 길이 제한 (회원가입 검증과 동일)
+const HANDLE_MIN_LEN: usize = 3;
+const HANDLE_MAX_LEN: usize = 20;
+
+fn is_handle_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// 텍스트에서 @handle 위치를 찾는다
+///
+/// 이메일 주소(foo@bar)처럼 앞에 핸들 문자가 붙어 있거나,
+/// 핸들 규칙(영문/숫자/_, 3~20자)에 맞지 않으면 멘션으로 보지 않는다.
+/// 반환되는 범위는 '@'를 포함한다.
+fn find_mentions(text: &str) -> Vec<(Range<usize>, &str)> {
+    let mut mentions = Vec::new();
+    let mut prev: Option<char> = None;
+
+    for (index, c) in text.char_indices() {
+        let preceded_by_word = prev.is_some_and(|p| is_handle_char(p) || p == '@');
+        prev = Some(c);
+
+        if c != '@' || preceded_by_word {
+            continue;
+        }
+
+        let start = index + 1;
+        let end = text[start..]
+            .find(|c: char| !is_handle_char(c))
+            .map_or(text.len(), |offset| start + offset);
+        let len = end - start;
+
+        if (HANDLE_MIN_LEN..=HANDLE_MAX_LEN).contains(&len) {
+            mentions.push((index..end, &text[start..end]));
+        }
+    }
+
+    mentions
+}
+
+fn push_unique(handles: &mut Vec<String>, handle: &str) {
+    if !handles.iter().any(|h| h == handle) {
+        handles.push(handle.to_string());
+    }
+}
+
+/// 마크다운 본문 중 멘션을 찾을 텍스트 구간의 원문 범위
+///
+/// 코드, 링크, 이미지, HTML 안의 @는 멘션으로 취급하지 않는다.
+fn mention_text_ranges(markdown: &str) -> Vec<Range<usize>> {
+    let options = Options::ENABLE_TABLES
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS;
+
+    let mut ranges = Vec::new();
+    let mut skip_depth = 0usize;
+
+    for (event, range) in Parser::new_ext(markdown, options).into_offset_iter() {
+        match event {
+            Event::Start(Tag::CodeBlock(_) | Tag::Link { .. } | Tag::Image { .. }) => {
+                skip_depth += 1
+            }
+            Event::End(TagEnd::CodeBlock | TagEnd::Link | TagEnd::Image) => {
+                skip_depth = skip_depth.saturating_sub(1)
+            }
+            Event::Text(_) if skip_depth == 0 => ranges.push(range),
+            _ => {}
+        }
+    }
+
+    ranges
+}
+
+/// 마크다운(포스트/댓글)에서 멘션된 핸들을 등장 순서대로 중복 없이 추출
+pub fn extract_mentions_from_markdown(markdown: &str) -> Vec<String> {
+    let mut handles = Vec::new();
+    for range in mention_text_ranges(markdown) {
+        for (_, handle) in find_mentions(&markdown[range]) {
+            push_unique(&mut handles, handle);
+        }
+    }
+    handles
+}
+
+/// 존재하는 사용자에 대한 멘션을 프로필 링크로 바꾼 마크다운을 반환
+pub fn link_mentions_in_markdown(markdown: &str, handles: &HashSet<String>) -> String {
+    if handles.is_empty() {
+        return markdown.to_string();
+    }
+
+    let mut linked = String::with_capacity(markdown.len());
+    let mut last = 0;
+
+    for range in mention_text_ranges(markdown) {
+        for (mention, handle) in find_mentions(&markdown[range.clone()]) {
+            if !handles.contains(handle) {
+                continue;
+            }
+            let start = range.start + mention.start;
+            let end = range.start + mention.end;
+
+            linked.push_str(&markdown[last..start]);
+            linked.push_str(&format!("[@{}](/@{}/profile)", handle, handle));
+            last = end;
+        }
+    }
+    linked.push_str(&markdown[last..]);
+
+    linked
+}
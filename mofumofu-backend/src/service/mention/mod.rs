@@ -0,0 +1,5 @@
+pub mod get_mentions;
+pub mod log_mention_events;
+pub mod mention_parser;
+pub mod resolve_mentions;
+pub mod save_mentions;
@@ -0,0 +1,23 @@
+use crate::config::db_config::DbConfig;
+use crate::entity::users::Model as UserModel;
+use crate::repository::user::find_users_by_handles::repository_find_users_by_handles;
+use crate::service::error::errors::ServiceResult;
+use sea_orm::ConnectionTrait;
+
+/// 멘션된 핸들을 실제 사용자로 변환
+///
+/// 스팸 방지를 위해 앞에서부터 MENTION_MAX_PER_ITEM개의 핸들만 처리하고
+/// 존재하지 않는 핸들은 무시한다.
+pub async fn service_resolve_mentions<C>(
+    conn: &C,
+    mut handles: Vec<String>,
+) -> ServiceResult<Vec<UserModel>>
+where
+    C: ConnectionTrait,
+{
+    handles.truncate(DbConfig::get().mention_max_per_item);
+
+    let users = repository_find_users_by_handles(conn, &handles).await?;
+
+    Ok(users)
+}
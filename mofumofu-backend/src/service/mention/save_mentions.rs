@@ -0,0 +1,44 @@
+use crate::entity::users::Model as UserModel;
+use crate::repository::mention::create_mentions::repository_create_mentions;
+use crate::repository::mention::delete_mentions::repository_delete_mentions;
+use crate::repository::mention::get_mentions::repository_get_mentioned_user_ids_by_target;
+use crate::service::error::errors::ServiceResult;
+use sea_orm::ConnectionTrait;
+use std::collections::HashSet;
+use uuid::Uuid;
+
+/// 포스트 본문(comment_id = None) 또는 댓글의 멘션을 새 목록과 같게 맞춘다
+///
+/// 작성자 본인은 저장하지 않으며, 이번에 새로 멘션된 사용자 ID를 반환한다.
+pub async fn service_save_mentions<C>(
+    conn: &C,
+    mentioner_id: Uuid,
+    post_id: Uuid,
+    comment_id: Option<Uuid>,
+    mentioned_users: &[UserModel],
+) -> ServiceResult<Vec<Uuid>>
+where
+    C: ConnectionTrait,
+{
+    let mentioned: HashSet<Uuid> = mentioned_users
+        .iter()
+        .map(|user| user.id)
+        .filter(|user_id| *user_id != mentioner_id)
+        .collect();
+
+    let previous: HashSet<Uuid> =
+        repository_get_mentioned_user_ids_by_target(conn, post_id, comment_id)
+            .await?
+            .into_iter()
+            .collect();
+
+    // 더 이상 멘션하지 않는 사용자 삭제
+    let removed: Vec<Uuid> = previous.difference(&mentioned).copied().collect();
+    repository_delete_mentions(conn, post_id, comment_id, &removed).await?;
+
+    // 새로 멘션된 사용자만 추가 (기존 멘션은 생성 시각 유지)
+    let added: Vec<Uuid> = mentioned.difference(&previous).copied().collect();
+    repository_create_mentions(conn, mentioner_id, post_id, comment_id, &added).await?;
+
+    Ok(added)
+}
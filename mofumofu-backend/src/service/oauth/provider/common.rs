@@ -1,14 +1,29 @@
 use crate::service::error::errors::{Errors, ServiceResult};
+use crate::utils::trace_context::inject_trace_context;
 use oauth2::basic::{
     BasicClient, BasicErrorResponse, BasicRevocationErrorResponse, BasicTokenIntrospectionResponse,
     BasicTokenResponse,
 };
 use oauth2::{
-    AccessToken, AuthUrl, AuthorizationCode, Client, ClientId, ClientSecret, EndpointNotSet,
-    EndpointSet, RedirectUrl, StandardRevocableToken, TokenResponse, TokenUrl,
+    AccessToken, AsyncHttpClient, AuthUrl, AuthorizationCode, Client, ClientId, ClientSecret,
+    EndpointNotSet, EndpointSet, HttpRequest, RedirectUrl, StandardRevocableToken, TokenResponse,
+    TokenUrl,
 };
 use tracing::{error, info, warn};
 
+/// 토큰 교환 요청에도 trace context를 전달하는 HTTP 클라이언트
+struct TracedHttpClient(reqwest::Client);
+
+impl<'c> AsyncHttpClient<'c> for TracedHttpClient {
+    type Error = <reqwest::Client as AsyncHttpClient<'c>>::Error;
+    type Future = <reqwest::Client as AsyncHttpClient<'c>>::Future;
+
+    fn call(&'c self, mut request: HttpRequest) -> Self::Future {
+        inject_trace_context(request.headers_mut());
+        self.0.call(request)
+    }
+}
+
 pub fn build_oauth_client(
     client_id: &str,
     client_secret: &str,
@@ -84,7 +99,7 @@ pub async fn exchange_oauth_code(
     );
     let token_result = oauth_client
         .exchange_code(AuthorizationCode::new(code.to_string()))
-        .request_async(&http_client)
+        .request_async(&TracedHttpClient(http_client))
         .await
         .map_err(|e| {
             error!("OAuth token exchange failed for {}: {:?}", provider, e);
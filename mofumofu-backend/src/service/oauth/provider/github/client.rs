@@ -2,6 +2,7 @@ use crate::config::db_config::DbConfig;
 use crate::dto::oauth::internal::github::{GithubEmail, GithubUserInfo};
 use crate::service::error::errors::{Errors, ServiceResult};
 use crate::service::oauth::provider::common::{build_oauth_client, exchange_oauth_code};
+use crate::utils::trace_context::TracedRequest;
 use oauth2::basic::{
     BasicErrorResponse, BasicRevocationErrorResponse, BasicTokenIntrospectionResponse,
     BasicTokenResponse,
@@ -53,6 +54,7 @@ pub async fn get_github_user_info(
         .get(GITHUB_USERINFO_URL)
         .bearer_auth(access_token.secret())
         .header("User-Agent", GITHUB_USER_AGENT)
+        .with_trace_context()
         .send()
         .await
         .map_err(|_e| Errors::OauthUserInfoFetchFailed)?;
@@ -67,6 +69,7 @@ pub async fn get_github_user_info(
             .get(GITHUB_EMAILS_URL)
             .bearer_auth(access_token.secret())
             .header("User-Agent", "Mozilla/5.0")
+            .with_trace_context()
             .send()
             .await;
 
@@ -2,6 +2,7 @@ use crate::config::db_config::DbConfig;
 use crate::dto::oauth::internal::google::GoogleUserInfo;
 use crate::service::error::errors::{Errors, ServiceResult};
 use crate::service::oauth::provider::common::{build_oauth_client, exchange_oauth_code};
+use crate::utils::trace_context::TracedRequest;
 use oauth2::basic::{
     BasicErrorResponse, BasicRevocationErrorResponse, BasicTokenIntrospectionResponse,
     BasicTokenResponse,
@@ -82,6 +83,7 @@ pub async fn get_google_user_info(
     let response = http_client
         .get(GOOGLE_USERINFO_URL)
         .bearer_auth(access_token.secret())
+        .with_trace_context()
         .send()
         .await
         .map_err(|_e| Errors::OauthUserInfoFetchFailed)?;
@@ -0,0 +1,90 @@
+use crate::config::db_config::DbConfig;
+use crate::connection::redis_connection::RedisConnection;
+use crate::dto::job::internal::job::Job;
+use crate::dto::outbox::internal::outbox_message::OutboxMessage;
+use crate::microservices::{email_client, search_client};
+use crate::service::job::job_queue::service_enqueue_job_with_id;
+use chrono::Utc;
+use reqwest::Client;
+
+/// 메시지를 작업 큐(또는 태스크 서버)로 전달 (실패 시 오류 메시지 반환)
+pub async fn deliver_outbox_message(
+    redis: &RedisConnection,
+    http_client: &Client,
+    message: &OutboxMessage,
+    idempotency_key: &str,
+) -> Result<(), String> {
+    if DbConfig::get().native_job_runner {
+        // 멱등성 키를 작업 ID로 사용해 중복 전달 시에도 한 번만 큐에 등록
+        return service_enqueue_job_with_id(
+            redis,
+            idempotency_key,
+            outbox_message_to_job(message),
+            Utc::now(),
+        )
+        .await
+        .map(|_| ())
+        .map_err(|e| e.to_string());
+    }
+
+    // 태스크 서버는 Idempotency-Key를 Celery 작업 ID로 사용해 같은 키는 한 번만 큐에 넣음
+    let result = match message {
+        OutboxMessage::SearchIndexPost { post_id } => {
+            search_client::queue_index_post(http_client, post_id, idempotency_key).await
+        }
+        OutboxMessage::SearchUpdatePost { post_id } => {
+            search_client::queue_update_post(http_client, post_id, idempotency_key).await
+        }
+        OutboxMessage::SearchDeletePost { post_id } => {
+            search_client::queue_delete_post(http_client, post_id, idempotency_key).await
+        }
+        OutboxMessage::VerificationEmail {
+            email,
+            username,
+            token,
+        } => {
+            email_client::queue_send_email_verification(
+                http_client,
+                email,
+                username,
+                token,
+                idempotency_key,
+            )
+            .await
+        }
+        OutboxMessage::ResetPasswordEmail { email, token } => {
+            email_client::queue_send_reset_password_email(
+                http_client,
+                email,
+                email,
+                token,
+                idempotency_key,
+            )
+            .await
+        }
+    };
+
+    result.map(|_| ()).map_err(|e| e.to_string())
+}
+
+fn outbox_message_to_job(message: &OutboxMessage) -> Job {
+    match message.clone() {
+        OutboxMessage::SearchIndexPost { post_id } => Job::IndexPost { post_id },
+        OutboxMessage::SearchUpdatePost { post_id } => Job::UpdatePost { post_id },
+        OutboxMessage::SearchDeletePost { post_id } => Job::DeletePost { post_id },
+        OutboxMessage::VerificationEmail {
+            email,
+            username,
+            token,
+        } => Job::SendEmailVerification {
+            email_to: email,
+            username,
+            verification_token: token,
+        },
+        OutboxMessage::ResetPasswordEmail { email, token } => Job::SendResetPasswordEmail {
+            email_to: email.clone(),
+            email,
+            token,
+        },
+    }
+}
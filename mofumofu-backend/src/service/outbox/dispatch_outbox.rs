@@ -0,0 +1,131 @@
+use crate::config::db_config::DbConfig;
+use crate::connection::redis_connection::RedisConnection;
+use crate::dto::outbox::internal::outbox_message::OutboxMessage;
+use crate::entity::outbox::Model as OutboxModel;
+use crate::repository::outbox::claim_outbox_entries::repository_claim_outbox_entries;
+use crate::repository::outbox::delete_delivered_outbox_entries::repository_delete_delivered_outbox_entries;
+use crate::repository::outbox::update_outbox_entry::{
+    repository_mark_outbox_delivered, repository_mark_outbox_failed,
+};
+use crate::service::error::errors::ServiceResult;
+use crate::service::outbox::deliver_outbox_message::deliver_outbox_message;
+use crate::utils::shutdown::BackgroundTasks;
+use chrono::{Duration as ChronoDuration, Utc};
+use reqwest::Client;
+use sea_orm::{ConnectionTrait, DatabaseConnection};
+use std::time::Duration;
+use tracing::{error, info, warn};
+
+/// 선점한 항목을 다른 디스패처가 가져가지 못하는 시간 (초)
+const OUTBOX_LEASE_SECONDS: i64 = 300;
+
+/// 전달 시점이 된 아웃박스 항목을 한 배치 전달
+///
+/// 전달에 성공한 항목 수를 반환
+pub async fn service_dispatch_outbox<C>(
+    conn: &C,
+    redis: &RedisConnection,
+    http_client: &Client,
+) -> ServiceResult<usize>
+where
+    C: ConnectionTrait,
+{
+    let config = DbConfig::get();
+
+    let entries =
+        repository_claim_outbox_entries(conn, config.outbox_batch_size, OUTBOX_LEASE_SECONDS)
+            .await?;
+
+    let mut delivered = 0;
+
+    for entry in entries {
+        let result = match serde_json::from_value::<OutboxMessage>(entry.payload.clone()) {
+            Ok(message) => {
+                deliver_outbox_message(redis, http_client, &message, &entry.idempotency_key).await
+            }
+            Err(e) => Err(format!("Invalid outbox payload: {}", e)),
+        };
+
+        match result {
+            Ok(()) => {
+                repository_mark_outbox_delivered(conn, entry.id).await?;
+                delivered += 1;
+            }
+            Err(e) => record_failure(conn, &entry, &e).await?,
+        }
+    }
+
+    Ok(delivered)
+}
+
+/// 실패 기록 후 지수 백오프로 다음 시도 시점을 정하고, 최대 시도 횟수를 넘으면 dead 처리
+async fn record_failure<C>(conn: &C, entry: &OutboxModel, error_message: &str) -> ServiceResult<()>
+where
+    C: ConnectionTrait,
+{
+    let config = DbConfig::get();
+
+    // attempts는 선점 시 이미 증가된 값
+    let next_attempt_at = if entry.attempts >= config.outbox_max_attempts {
+        error!(
+            "Outbox entry {} ({}) moved to dead letter after {} attempts: {}",
+            entry.id, entry.event_type, entry.attempts, error_message
+        );
+        None
+    } else {
+        let exponent = (entry.attempts - 1).clamp(0, 20) as u32;
+        let delay = config
+            .outbox_backoff_base
+            .saturating_mul(2u64.pow(exponent))
+            .min(config.outbox_backoff_max);
+
+        warn!(
+            "Outbox entry {} ({}) failed on attempt {}, retrying in {}s: {}",
+            entry.id, entry.event_type, entry.attempts, delay, error_message
+        );
+        Some(Utc::now() + ChronoDuration::seconds(delay as i64))
+    };
+
+    repository_mark_outbox_failed(conn, entry.id, error_message, next_attempt_at).await?;
+
+    Ok(())
+}
+
+/// 아웃박스 디스패처 백그라운드 작업 시작
+///
+/// 진행 중인 전달은 마치고 종료한다. 남은 항목은 DB에 있으므로 다음 실행 때 전달된다.
+pub fn spawn_outbox_dispatcher(
+    tasks: &BackgroundTasks,
+    conn: DatabaseConnection,
+    redis: RedisConnection,
+    http_client: Client,
+) {
+    let config = DbConfig::get();
+    let period = Duration::from_secs(config.outbox_poll_interval.max(1));
+    let retention_days = config.outbox_retention_days;
+    let shutdown = tasks.clone();
+
+    tasks.spawn(async move {
+        let mut interval = tokio::time::interval(period);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            tokio::select! {
+                _ = shutdown.cancelled() => break,
+                _ = interval.tick() => {}
+            }
+
+            match service_dispatch_outbox(&conn, &redis, &http_client).await {
+                Ok(0) => {}
+                Ok(delivered) => info!("Delivered {} outbox entries", delivered),
+                Err(e) => warn!("Failed to dispatch outbox entries: {:?}", e),
+            }
+
+            match repository_delete_delivered_outbox_entries(&conn, retention_days).await {
+                Ok(0) => {}
+                Ok(deleted) => info!("Deleted {} delivered outbox entries", deleted),
+                Err(e) => warn!("Failed to delete delivered outbox entries: {}", e),
+            }
+        }
+    });
+}
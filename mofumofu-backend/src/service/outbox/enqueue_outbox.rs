@@ -0,0 +1,26 @@
+use crate::dto::outbox::internal::outbox_message::OutboxMessage;
+use crate::repository::outbox::create_outbox_entry::repository_create_outbox_entry;
+use crate::service::error::errors::{Errors, ServiceResult};
+use sea_orm::ConnectionTrait;
+use tracing::error;
+use uuid::Uuid;
+
+/// 아웃박스에 메시지 기록
+///
+/// 호출한 쪽의 트랜잭션 안에서 실행하면 DB 변경과 함께 커밋되거나 함께 롤백됨
+pub async fn service_enqueue_outbox<C>(conn: &C, message: OutboxMessage) -> ServiceResult<()>
+where
+    C: ConnectionTrait,
+{
+    let payload = serde_json::to_value(&message).map_err(|e| {
+        error!("Failed to serialize outbox message: {}", e);
+        Errors::SysInternalError("Failed to serialize outbox message".to_string())
+    })?;
+
+    // 태스크 서버가 중복 전달을 걸러낼 수 있도록 항목마다 고유한 멱등성 키 부여
+    let idempotency_key = Uuid::new_v4().to_string();
+
+    repository_create_outbox_entry(conn, message.event_type(), payload, &idempotency_key).await?;
+
+    Ok(())
+}
@@ -0,0 +1,3 @@
+pub mod deliver_outbox_message;
+pub mod dispatch_outbox;
+pub mod enqueue_outbox;
@@ -0,0 +1,58 @@
+use crate::repository::post::content_stats::{
+    repository_get_posts_missing_content_stats, repository_update_post_content_stats,
+};
+use crate::service::error::errors::ServiceResult;
+use crate::service::markdown::content_stats::compute_content_stats;
+use crate::utils::shutdown::BackgroundTasks;
+use sea_orm::{DatabaseConnection, TransactionTrait};
+use tracing::{info, warn};
+
+/// 한 번에 불러와 계산할 포스트 수
+const BACKFILL_BATCH_SIZE: u64 = 200;
+
+/// 통계 컬럼 추가 전에 작성된 포스트의 단어 수, 읽기 시간, 언어를 계산
+///
+/// 이미 계산된 포스트는 건너뛰므로 여러 번 실행해도 결과가 같다.
+/// shutdown이 취소되면 현재 배치까지만 반영하고 멈춘다.
+pub async fn service_backfill_content_stats(
+    conn: &DatabaseConnection,
+    shutdown: &BackgroundTasks,
+) -> ServiceResult<u64> {
+    let mut updated = 0;
+    let mut after = None;
+
+    while !shutdown.is_cancelled() {
+        let posts =
+            repository_get_posts_missing_content_stats(conn, after, BACKFILL_BATCH_SIZE).await?;
+        let Some((last_id, _)) = posts.last() else {
+            break;
+        };
+        after = Some(*last_id);
+
+        let txn = conn.begin().await?;
+        for (post_id, content) in &posts {
+            repository_update_post_content_stats(&txn, post_id, compute_content_stats(content))
+                .await?;
+        }
+        txn.commit().await?;
+
+        updated += posts.len() as u64;
+    }
+
+    Ok(updated)
+}
+
+/// 시작 시 한 번 본문 통계 backfill 실행
+///
+/// 태스크 서버 사용 여부와 관계없이 통계가 채워지도록 작업 큐 대신 백그라운드 작업으로 실행한다.
+pub fn spawn_content_stats_backfill(tasks: &BackgroundTasks, conn: DatabaseConnection) {
+    let shutdown = tasks.clone();
+
+    tasks.spawn(async move {
+        match service_backfill_content_stats(&conn, &shutdown).await {
+            Ok(0) => {}
+            Ok(updated) => info!("Backfilled content stats for {} posts", updated),
+            Err(e) => warn!("Failed to backfill content stats: {:?}", e),
+        }
+    });
+}
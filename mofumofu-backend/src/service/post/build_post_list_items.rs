@@ -0,0 +1,57 @@
+use crate::dto::post::response::PostListItem;
+use crate::entity::posts::Model as PostModel;
+use crate::repository::hashtag::get_hashtags_by_post::repository_get_hashtags_by_posts;
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::user::load_users::service_load_users;
+use sea_orm::ConnectionTrait;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// 포스트 목록을 PostListItem으로 변환 (작성자와 해시태그는 한 번에 조회)
+pub async fn service_build_post_list_items<C>(
+    conn: &C,
+    posts: Vec<PostModel>,
+) -> ServiceResult<Vec<PostListItem>>
+where
+    C: ConnectionTrait,
+{
+    if posts.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let post_ids: Vec<Uuid> = posts.iter().map(|post| post.id).collect();
+    let mut post_hashtags_map: HashMap<Uuid, Vec<String>> =
+        repository_get_hashtags_by_posts(conn, &post_ids)
+            .await?
+            .into_iter()
+            .map(|(post_id, tags)| (post_id, tags.into_iter().map(|tag| tag.name).collect()))
+            .collect();
+
+    let users = service_load_users(conn, posts.iter().map(|post| post.user_id)).await?;
+
+    posts
+        .into_iter()
+        .map(|post| {
+            let user = users.get(&post.user_id).ok_or(Errors::UserNotFound)?;
+
+            Ok(PostListItem {
+                id: post.id,
+                title: post.title,
+                summary: post.summary,
+                thumbnail_image: post.thumbnail_image,
+                user_handle: user.handle.clone(),
+                user_name: user.name.clone(),
+                user_avatar: user.profile_image.clone(),
+                created_at: post.created_at,
+                like_count: post.like_count,
+                comment_count: post.comment_count,
+                view_count: post.view_count,
+                slug: post.slug,
+                hashtags: post_hashtags_map.remove(&post.id).unwrap_or_default(),
+                word_count: post.word_count,
+                reading_time: post.reading_time,
+                language: post.language,
+            })
+        })
+        .collect()
+}
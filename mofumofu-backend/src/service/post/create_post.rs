@@ -1,19 +1,34 @@
+use crate::connection::redis_connection::RedisConnection;
+use crate::dto::cache::internal::cache_event::CacheEvent;
+use crate::dto::outbox::internal::outbox_message::OutboxMessage;
 use crate::dto::post::request::create_post::CreatePostRequest;
 use crate::dto::post::response::create_post::CreatePostResponse;
 use crate::entity::common::{ActionType, TargetType};
-use crate::microservices::markdown_client::render_markdown;
-use crate::microservices::search_client;
 use crate::repository::hashtag::associate_post_hashtags::repository_associate_post_hashtags;
 use crate::repository::post::create_post::repository_create_post;
 use crate::repository::system_events::log_event::repository_log_event;
+use crate::service::cache::invalidate_cache::service_publish_cache_event;
 use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::markdown::content_stats::compute_content_stats;
+use crate::service::markdown::render::service_render_markdown;
+use crate::service::mention::log_mention_events::service_log_mention_events;
+use crate::service::mention::mention_parser::{
+    extract_mentions_from_markdown, link_mentions_in_markdown,
+};
+use crate::service::mention::resolve_mentions::service_resolve_mentions;
+use crate::service::mention::save_mentions::service_save_mentions;
+use crate::service::outbox::enqueue_outbox::service_enqueue_outbox;
+use crate::utils::metrics::POSTS_CREATED_TOTAL;
+use metrics::counter;
 use sea_orm::{ConnectionTrait, TransactionTrait};
 use serde_json::json;
-use tracing::{info, warn};
+use std::collections::HashSet;
+use tracing::info;
 use uuid::Uuid;
 
 pub async fn service_create_post<C>(
     conn: &C,
+    redis: &RedisConnection,
     http_client: &reqwest::Client,
     payload: CreatePostRequest,
     user_uuid: &Uuid,
@@ -24,9 +39,18 @@ where
     let hashtags = payload.hashtags.clone();
     let content = payload.content.clone();
 
+    // 멘션 파싱 - 존재하는 사용자만 프로필 링크로 렌더링
+    let mentioned_users =
+        service_resolve_mentions(conn, extract_mentions_from_markdown(&content)).await?;
+    let mentioned_handles: HashSet<String> = mentioned_users
+        .iter()
+        .map(|user| user.handle.clone())
+        .collect();
+    let linked_content = link_mentions_in_markdown(&content, &mentioned_handles);
+
     // 마크다운 렌더링 (필수)
     info!("마크다운 렌더링 시작 (content length: {})", content.len());
-    let rendered = render_markdown(http_client, &content)
+    let rendered = service_render_markdown(http_client, &linked_content)
         .await
         .map_err(|e| Errors::MarkdownRenderFailed(format!("마크다운 렌더링 실패: {}", e)))?;
 
@@ -50,6 +74,9 @@ where
 
     let (render_html, toc_json) = (Some(rendered.html_content), Some(json!(toc_items)));
 
+    // 단어 수, 읽기 시간, 언어 계산
+    let content_stats = compute_content_stats(&content);
+
     let txn = conn.begin().await?;
 
     let post = CreatePostRequest {
@@ -60,7 +87,8 @@ where
         hashtags: payload.hashtags,
     };
 
-    let created_post = repository_create_post(&txn, post, user_uuid, render_html, toc_json).await?;
+    let created_post =
+        repository_create_post(&txn, post, user_uuid, render_html, toc_json, content_stats).await?;
 
     let hashtag_ids = if let Some(ref tags) = hashtags {
         if !tags.is_empty() {
@@ -72,13 +100,25 @@ where
         Vec::new()
     };
 
+    let new_mentions =
+        service_save_mentions(&txn, *user_uuid, created_post.id, None, &mentioned_users).await?;
+
+    // 색인 요청은 아웃박스에 기록해 커밋과 함께 보장
+    service_enqueue_outbox(
+        &txn,
+        OutboxMessage::SearchIndexPost {
+            post_id: created_post.id,
+        },
+    )
+    .await?;
+
     // Commit the transaction
     txn.commit().await?;
 
-    // Python 태스크로 색인 요청 (DB 트랜잭션 외부에서 실행)
-    if let Err(e) = search_client::queue_index_post(http_client, &created_post.id).await {
-        warn!("Failed to queue post indexing task: {}", e);
-    }
+    counter!(POSTS_CREATED_TOTAL).increment(1);
+
+    // 목록과 인기 해시태그 캐시 무효화
+    service_publish_cache_event(redis, CacheEvent::PostCreated).await;
 
     info!("글 생성 완료 (post_id: {})", created_post.id);
 
@@ -106,6 +146,9 @@ where
         .await;
     }
 
+    // 멘션된 사용자별 이벤트 로깅
+    service_log_mention_events(conn, *user_uuid, created_post.id, None, &new_mentions).await;
+
     Ok(CreatePostResponse {
         post_id: created_post.id,
     })
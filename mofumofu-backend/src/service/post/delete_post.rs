@@ -1,17 +1,20 @@
+use crate::connection::redis_connection::RedisConnection;
+use crate::dto::cache::internal::cache_event::CacheEvent;
+use crate::dto::outbox::internal::outbox_message::OutboxMessage;
 use crate::dto::post::request::delete_post::DeletePostRequest;
 use crate::entity::common::{ActionType, TargetType};
-use crate::microservices::search_client;
 use crate::repository::post::delete_post::repository_delete_post;
 use crate::repository::post::get_post_by_user_and_slug::repository_get_post_by_user_and_slug;
 use crate::repository::system_events::log_event::repository_log_event;
+use crate::service::cache::invalidate_cache::service_publish_cache_event;
 use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::outbox::enqueue_outbox::service_enqueue_outbox;
 use sea_orm::{ConnectionTrait, TransactionTrait};
-use tracing::warn;
 use uuid::Uuid;
 
 pub async fn service_delete_post<C>(
     conn: &C,
-    http_client: &reqwest::Client,
+    redis: &RedisConnection,
     payload: DeletePostRequest,
     user_uuid: &Uuid,
 ) -> ServiceResult<()>
@@ -24,11 +27,11 @@ where
 
     repository_delete_post(&txn, &payload.slug, user_uuid).await?;
 
+    service_enqueue_outbox(&txn, OutboxMessage::SearchDeletePost { post_id: post.id }).await?;
+
     txn.commit().await?;
 
-    if let Err(e) = search_client::queue_delete_post(http_client, &post.id).await {
-        warn!("Failed to queue post search deletion task: {}", e);
-    }
+    service_publish_cache_event(redis, CacheEvent::PostDeleted { post_id: post.id }).await;
 
     repository_log_event(
         conn,
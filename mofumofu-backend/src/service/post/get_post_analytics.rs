@@ -0,0 +1,103 @@
+use crate::dto::post::request::GetPostAnalyticsRequest;
+use crate::dto::post::response::{
+    PostAnalyticsResponse, PostDailyAnalytics, PostReferrerAnalytics,
+};
+use crate::repository::post::get_post_by_uuid::repository_get_post_by_uuid;
+use crate::repository::post::post_analytics::{
+    repository_count_post_unique_viewers, repository_get_post_daily_stats,
+    repository_get_post_referrers,
+};
+use crate::service::error::errors::{Errors, ServiceResult};
+use chrono::{Duration, Utc};
+use sea_orm::ConnectionTrait;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// 기본 조회 기간 (일)
+const DEFAULT_RANGE_DAYS: i64 = 30;
+/// 한 번에 조회할 수 있는 최대 기간 (일)
+const MAX_RANGE_DAYS: i64 = 366;
+/// 응답에 포함할 유입 도메인 수
+const MAX_REFERRERS: u64 = 20;
+
+pub async fn service_get_post_analytics<C>(
+    conn: &C,
+    user_id: &Uuid,
+    request: GetPostAnalyticsRequest,
+) -> ServiceResult<PostAnalyticsResponse>
+where
+    C: ConnectionTrait,
+{
+    let post = repository_get_post_by_uuid(conn, &request.post_id).await?;
+
+    // 게시글 작성자만 조회 가능
+    if post.user_id != *user_id {
+        return Err(Errors::UserUnauthorized);
+    }
+
+    let to = request.to.unwrap_or_else(|| Utc::now().date_naive());
+    let from = request
+        .from
+        .unwrap_or_else(|| to - Duration::days(DEFAULT_RANGE_DAYS - 1));
+
+    if from > to {
+        return Err(Errors::BadRequestError(
+            "from must not be after to".to_string(),
+        ));
+    }
+    if (to - from).num_days() >= MAX_RANGE_DAYS {
+        return Err(Errors::BadRequestError(format!(
+            "Date range must be at most {} days",
+            MAX_RANGE_DAYS
+        )));
+    }
+
+    let mut stats_by_day: HashMap<_, _> = repository_get_post_daily_stats(conn, post.id, from, to)
+        .await?
+        .into_iter()
+        .map(|row| (row.day, row))
+        .collect();
+    let unique_viewers = repository_count_post_unique_viewers(conn, post.id, from, to).await?;
+    let referrers = repository_get_post_referrers(conn, post.id, from, to, MAX_REFERRERS)
+        .await?
+        .into_iter()
+        .map(|row| PostReferrerAnalytics {
+            domain: (!row.domain.is_empty()).then_some(row.domain),
+            views: row.views,
+        })
+        .collect();
+
+    // 기록이 없는 날도 0으로 채워 그래프를 그리기 쉽게 함
+    let daily: Vec<PostDailyAnalytics> = from
+        .iter_days()
+        .take_while(|date| *date <= to)
+        .map(|date| match stats_by_day.remove(&date) {
+            Some(row) => PostDailyAnalytics {
+                date,
+                views: row.views,
+                unique_viewers: row.unique_viewers,
+                likes: row.likes,
+                comments: row.comments,
+            },
+            None => PostDailyAnalytics {
+                date,
+                views: 0,
+                unique_viewers: 0,
+                likes: 0,
+                comments: 0,
+            },
+        })
+        .collect();
+
+    Ok(PostAnalyticsResponse {
+        post_id: post.id,
+        from,
+        to,
+        total_views: daily.iter().map(|day| day.views as i64).sum(),
+        unique_viewers,
+        total_likes: daily.iter().map(|day| day.likes as i64).sum(),
+        total_comments: daily.iter().map(|day| day.comments as i64).sum(),
+        daily,
+        referrers,
+    })
+}
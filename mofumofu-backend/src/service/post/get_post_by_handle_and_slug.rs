@@ -1,24 +1,69 @@
+use crate::connection::redis_connection::RedisConnection;
+use crate::dto::cache::internal::cache_endpoint::CacheEndpoint;
+use crate::dto::cache::internal::cache_tag::CacheTag;
 use crate::dto::post::response::post_info::{PostAuthor, PostInfoResponse, TocItem};
-use crate::microservices::markdown_client::render_markdown;
+use crate::entity::common::LikeTargetType;
 use crate::repository::hashtag::get_hashtags_by_post::repository_get_hashtags_by_post;
 use crate::repository::post::get_post_by_handle_and_slug::repository_get_post_by_handle_and_slug;
 use crate::repository::user::find_user_by_uuid::repository_find_user_by_uuid;
+use crate::service::cache::response_cache::service_cached;
 use crate::service::error::errors::{Errors, ServiceResult};
-use redis::aio::ConnectionManager;
+use crate::service::markdown::render::service_render_markdown;
+use crate::service::mention::mention_parser::{
+    extract_mentions_from_markdown, link_mentions_in_markdown,
+};
+use crate::service::mention::resolve_mentions::service_resolve_mentions;
+use crate::service::reaction::get_reactions::service_get_reactions;
 use reqwest::Client;
 use sea_orm::{ConnectionTrait, TransactionTrait};
+use std::collections::HashSet;
 use tracing::{info, warn};
+use uuid::Uuid;
 
 pub async fn service_get_post_by_handle_and_slug<C>(
     conn: &C,
+    redis: &RedisConnection,
     http_client: &Client,
     handle: &str,
     slug: &str,
+    viewer_id: Option<Uuid>,
 ) -> ServiceResult<PostInfoResponse>
 where
     C: ConnectionTrait + TransactionTrait,
+{
+    // 조회한 사용자와 무관한 내용만 캐시
+    let cache_key = format!("{}:{}", handle, slug);
+    let mut post = service_cached(redis, CacheEndpoint::PostDetail, &cache_key, || {
+        load_post(conn, http_client, handle, slug)
+    })
+    .await?;
+
+    // 로그인한 경우 본인의 반응은 캐시와 별도로 조회
+    if viewer_id.is_some() {
+        let reaction_summary =
+            service_get_reactions(conn, viewer_id, LikeTargetType::Post, &[post.id])
+                .await?
+                .remove(&post.id)
+                .unwrap_or_default();
+        post.reactions = reaction_summary.reactions;
+        post.my_reactions = reaction_summary.my_reactions;
+    }
+
+    Ok(post)
+}
+
+/// 포스트 상세와 캐시 태그 (포스트와 작성자)
+async fn load_post<C>(
+    conn: &C,
+    http_client: &Client,
+    handle: &str,
+    slug: &str,
+) -> ServiceResult<(PostInfoResponse, Vec<CacheTag>)>
+where
+    C: ConnectionTrait,
 {
     let post = repository_get_post_by_handle_and_slug(conn, handle, slug).await?;
+    let cache_tags = vec![CacheTag::Post(post.id), CacheTag::User(post.user_id)];
 
     // Get author information
     let user = repository_find_user_by_uuid(conn, &post.user_id)
@@ -29,6 +74,12 @@ where
     let hashtags = repository_get_hashtags_by_post(conn, post.id).await?;
     let tags: Vec<String> = hashtags.into_iter().map(|tag| tag.name).collect();
 
+    // 반응 집계
+    let reaction_summary = service_get_reactions(conn, None, LikeTargetType::Post, &[post.id])
+        .await?
+        .remove(&post.id)
+        .unwrap_or_default();
+
     // 렌더링된 HTML 또는 마크다운 직접 렌더링 사용
     let (rendered_html, toc_items) = match (&post.render, &post.toc) {
         (Some(rendered_html), Some(toc_json)) if !rendered_html.is_empty() => {
@@ -52,8 +103,17 @@ where
                 post.id, handle, slug
             );
 
+            // 작성/수정 시와 같이 존재하는 사용자의 멘션만 링크로 렌더링
+            let mentioned_handles: HashSet<String> =
+                service_resolve_mentions(conn, extract_mentions_from_markdown(&post.content))
+                    .await?
+                    .into_iter()
+                    .map(|user| user.handle)
+                    .collect();
+            let linked_content = link_mentions_in_markdown(&post.content, &mentioned_handles);
+
             // 마크다운 직접 렌더링
-            match render_markdown(http_client, &post.content).await {
+            match service_render_markdown(http_client, &linked_content).await {
                 Ok(rendered_result) => {
                     let toc_items: Vec<TocItem> = rendered_result
                         .toc_items
@@ -74,7 +134,7 @@ where
         }
     };
 
-    Ok(PostInfoResponse {
+    let response = PostInfoResponse {
         id: post.id,
         title: post.title,
         summary: post.summary,
@@ -93,5 +153,13 @@ where
         view_count: post.view_count,
         slug: post.slug,
         tags,
-    })
+        word_count: post.word_count,
+        reading_time: post.reading_time,
+        language: post.language,
+        reactions: reaction_summary.reactions,
+        my_reactions: reaction_summary.my_reactions,
+        comments_disabled: post.comments_disabled,
+    };
+
+    Ok((response, cache_tags))
 }
@@ -1,8 +1,10 @@
 use crate::dto::post::response::post_info::{PostAuthor, PostInfoResponse};
+use crate::entity::common::LikeTargetType;
 use crate::repository::hashtag::get_hashtags_by_post::repository_get_hashtags_by_post;
 use crate::repository::post::get_post_by_uuid::repository_get_post_by_uuid;
 use crate::repository::user::find_user_by_uuid::repository_find_user_by_uuid;
 use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::reaction::get_reactions::service_get_reactions;
 use reqwest::Client;
 use sea_orm::{ConnectionTrait, TransactionTrait};
 use tracing::info;
@@ -12,6 +14,7 @@ pub async fn service_get_post_by_uuid<C>(
     conn: &C,
     _http_client: &Client,
     post_id: &Uuid,
+    viewer_id: Option<Uuid>,
 ) -> ServiceResult<PostInfoResponse>
 where
     C: ConnectionTrait + TransactionTrait,
@@ -33,6 +36,13 @@ where
         .map(|hashtag| hashtag.name)
         .collect();
 
+    // 반응 집계 및 조회한 사용자의 반응
+    let reaction_summary =
+        service_get_reactions(conn, viewer_id, LikeTargetType::Post, &[*post_id])
+            .await?
+            .remove(post_id)
+            .unwrap_or_default();
+
     Ok(PostInfoResponse {
         id: post.id,
         title: post.title,
@@ -52,5 +62,11 @@ where
         view_count: post.view_count,
         slug: post.slug,
         tags: hashtags,
+        word_count: post.word_count,
+        reading_time: post.reading_time,
+        language: post.language,
+        reactions: reaction_summary.reactions,
+        my_reactions: reaction_summary.my_reactions,
+        comments_disabled: post.comments_disabled,
     })
 }
@@ -1,13 +1,19 @@
+use crate::config::db_config::DbConfig;
+use crate::connection::redis_connection::RedisConnection;
+use crate::dto::cache::internal::cache_endpoint::CacheEndpoint;
+use crate::dto::cache::internal::cache_tag::CacheTag;
 use crate::dto::post::request::{GetPostsRequest, PostSortOrder};
-use crate::dto::post::response::{GetPostsResponse, PostListItem};
-use crate::repository::hashtag::get_hashtags_by_post::repository_get_hashtags_by_posts;
+use crate::dto::post::response::GetPostsResponse;
 use crate::repository::post::get_posts::{repository_get_posts, repository_get_posts_count};
-use crate::repository::user::find_user_by_uuid::repository_find_user_by_uuid;
-use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::cache::response_cache::service_cached;
+use crate::service::error::errors::ServiceResult;
+use crate::service::post::build_post_list_items::service_build_post_list_items;
 use sea_orm::{ConnectionTrait, TransactionTrait};
+use uuid::Uuid;
 
 pub async fn service_get_posts<C>(
     conn: &C,
+    redis: &RedisConnection,
     request: GetPostsRequest,
 ) -> ServiceResult<GetPostsResponse>
 where
@@ -16,63 +22,127 @@ where
     let page = request.page.unwrap_or(1);
     let page_size = request.page_size.unwrap_or(8);
     let sort_order = request.sort.unwrap_or(PostSortOrder::Latest);
+    let language = request.language.as_deref();
 
+    // 조회가 몰리는 앞쪽 페이지만 캐시
+    if page > DbConfig::get().cache_feed_max_page {
+        return load_posts(conn, page, page_size, &sort_order, language)
+            .await
+            .map(|(response, _)| response);
+    }
+
+    let sort_key = match sort_order {
+        PostSortOrder::Latest => "latest",
+        PostSortOrder::Popular => "popular",
+        PostSortOrder::Trending => "trending",
+        PostSortOrder::Oldest => "oldest",
+    };
+    // 언어 코드와 겹치지 않도록 전체 피드는 "lang:*"로 구분
+    let language_key = match language {
+        Some(language) => format!("lang:{}", language),
+        None => "lang:*".to_string(),
+    };
+    let cache_key = format!("{}:{}:{}:{}", sort_key, language_key, page, page_size);
+
+    service_cached(redis, CacheEndpoint::Feed, &cache_key, || {
+        load_posts(conn, page, page_size, &sort_order, language)
+    })
+    .await
+}
+
+/// 포스트 목록과 캐시 태그 (목록 구성, 포함된 포스트와 작성자)
+async fn load_posts<C>(
+    conn: &C,
+    page: u32,
+    page_size: u32,
+    sort_order: &PostSortOrder,
+    language: Option<&str>,
+) -> ServiceResult<(GetPostsResponse, Vec<CacheTag>)>
+where
+    C: ConnectionTrait,
+{
     // DB에서 직접 포스트 조회
-    let posts = repository_get_posts(conn, page, page_size, &sort_order).await?;
+    let posts = repository_get_posts(conn, page, page_size, sort_order, language).await?;
 
     if posts.is_empty() {
-        return Ok(GetPostsResponse {
-            posts: Vec::new(),
-            current_page: page,
-            page_size,
-            has_more: false,
-            total_count: 0,
-        });
+        return Ok((
+            GetPostsResponse {
+                posts: Vec::new(),
+                current_page: page,
+                page_size,
+                has_more: false,
+                total_count: 0,
+            },
+            vec![CacheTag::Feed],
+        ));
     }
 
-    // DB 결과를 PostListItem으로 변환
-    let post_ids: Vec<uuid::Uuid> = posts.iter().map(|p| p.id).collect();
-    let post_hashtags_map = repository_get_hashtags_by_posts(conn, &post_ids)
-        .await?
-        .into_iter()
-        .collect::<std::collections::HashMap<_, _>>();
+    let mut tags = vec![CacheTag::Feed];
+    tags.extend(posts.iter().map(|post| CacheTag::Post(post.id)));
+    let mut author_ids: Vec<Uuid> = posts.iter().map(|post| post.user_id).collect();
+    author_ids.sort_unstable();
+    author_ids.dedup();
+    tags.extend(author_ids.into_iter().map(CacheTag::User));
 
-    let mut post_items = Vec::new();
-    for post in posts {
-        let user = repository_find_user_by_uuid(conn, &post.user_id)
-            .await?
-            .ok_or(Errors::UserNotFound)?;
+    // 작성자와 해시태그를 한 번에 조회해 PostListItem으로 변환
+    let post_items = service_build_post_list_items(conn, posts).await?;
 
-        let hashtags = post_hashtags_map
-            .get(&post.id)
-            .map(|tags| tags.iter().map(|tag| tag.name.clone()).collect())
-            .unwrap_or_else(Vec::new);
+    let has_more = post_items.len() == page_size as usize;
+    let total_count = repository_get_posts_count(conn, language).await?;
 
-        post_items.push(PostListItem {
-            id: post.id,
-            title: post.title,
-            summary: post.summary,
-            thumbnail_image: post.thumbnail_image,
-            user_handle: user.handle,
-            user_name: user.name,
-            user_avatar: user.profile_image,
-            created_at: post.created_at,
-            like_count: post.like_count,
-            comment_count: post.comment_count,
-            view_count: post.view_count,
-            slug: post.slug,
-            hashtags,
-        });
-    }
+    Ok((
+        GetPostsResponse {
+            posts: post_items,
+            current_page: page,
+            page_size,
+            has_more,
+            total_count,
+        },
+        tags,
+    ))
+}
 
-    let has_more = post_items.len() == page_size as usize;
-    let total_count = repository_get_posts_count(conn).await?;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::query_counter::count_queries;
+    use crate::utils::test_database::{insert_hashtag, insert_post, insert_user, test_transaction};
 
-    Ok(GetPostsResponse {
-        posts: post_items,
-        current_page: page,
-        page_size,
-        has_more,
-        total_count,
-    })
+    // 캐시를 거치지 않는 조회 경로에서 포스트 수만큼 쿼리가 늘지 않는지 (N+1) 확인
+    #[tokio::test]
+    async fn query_count_does_not_grow_with_posts() {
+        let Some(txn) = test_transaction().await else {
+            return;
+        };
+        let language = &Uuid::new_v4().simple().to_string()[..8];
+        for _ in 0..5 {
+            let user = insert_user(&txn).await;
+            let post = insert_post(&txn, user.id, language).await;
+            insert_hashtag(&txn, post.id).await;
+        }
+
+        let (one, one_queries) = count_queries(load_posts(
+            &txn,
+            1,
+            1,
+            &PostSortOrder::Latest,
+            Some(language),
+        ))
+        .await;
+        let (many, many_queries) = count_queries(load_posts(
+            &txn,
+            1,
+            5,
+            &PostSortOrder::Latest,
+            Some(language),
+        ))
+        .await;
+
+        assert_eq!(one.unwrap().0.posts.len(), 1);
+        assert_eq!(many.unwrap().0.posts.len(), 5);
+        assert!(one_queries > 0);
+        assert_eq!(one_queries, many_queries);
+
+        txn.rollback().await.unwrap();
+    }
 }
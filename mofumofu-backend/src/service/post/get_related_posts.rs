@@ -0,0 +1,77 @@
+use crate::connection::redis_connection::RedisConnection;
+use crate::dto::post::request::GetRelatedPostsRequest;
+use crate::dto::post::response::RelatedPostsResponse;
+use crate::repository::post::get_post_by_uuid::repository_get_post_by_uuid;
+use crate::repository::post::get_posts::repository_get_posts_by_uuids;
+use crate::repository::post::get_related_post_ids::repository_get_related_post_ids;
+use crate::service::error::errors::ServiceResult;
+use crate::service::post::build_post_list_items::service_build_post_list_items;
+use crate::service::post::related_posts_cache::{
+    service_cache_related_post_ids, service_get_cached_related_post_ids,
+};
+use crate::service::post::viewed_posts::service_get_viewed_post_ids;
+use sea_orm::ConnectionTrait;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// 캐시에 저장할 후보 수 (조회자별로 본 포스트를 뺀 뒤 limit만큼 자름)
+const RELATED_POST_CANDIDATES: u64 = 50;
+
+pub async fn service_get_related_posts<C>(
+    conn: &C,
+    redis: &RedisConnection,
+    request: GetRelatedPostsRequest,
+    anonymous_user_id: Option<&str>,
+) -> ServiceResult<RelatedPostsResponse>
+where
+    C: ConnectionTrait,
+{
+    let limit = request.limit.unwrap_or(6) as usize;
+    let post = repository_get_post_by_uuid(conn, &request.post_id).await?;
+
+    // 후보는 포스트마다 캐시 (이 포스트의 좋아요가 바뀌면 무효화, 다른 포스트의 좋아요는 TTL 후 반영)
+    let candidate_ids = match service_get_cached_related_post_ids(redis, &post.id).await {
+        Some(candidate_ids) => candidate_ids,
+        None => {
+            let candidate_ids: Vec<Uuid> = repository_get_related_post_ids(
+                conn,
+                post.id,
+                post.user_id,
+                RELATED_POST_CANDIDATES,
+            )
+            .await?
+            .into_iter()
+            .map(|row| row.post_id)
+            .collect();
+
+            service_cache_related_post_ids(redis, &post.id, &candidate_ids).await;
+            candidate_ids
+        }
+    };
+
+    // 이미 본 포스트 제외
+    let viewed_post_ids = match anonymous_user_id {
+        Some(anonymous_user_id) => service_get_viewed_post_ids(redis, anonymous_user_id).await,
+        None => Default::default(),
+    };
+    let selected_ids: Vec<Uuid> = candidate_ids
+        .into_iter()
+        .filter(|post_id| !viewed_post_ids.contains(post_id))
+        .take(limit)
+        .collect();
+
+    // 점수 순서 유지 (캐시 이후 삭제된 포스트는 건너뜀)
+    let mut posts_by_id: HashMap<Uuid, _> = repository_get_posts_by_uuids(conn, &selected_ids)
+        .await?
+        .into_iter()
+        .map(|post| (post.id, post))
+        .collect();
+    let posts = selected_ids
+        .iter()
+        .filter_map(|post_id| posts_by_id.remove(post_id))
+        .collect();
+
+    Ok(RelatedPostsResponse {
+        posts: service_build_post_list_items(conn, posts).await?,
+    })
+}
@@ -1,9 +1,8 @@
 use crate::dto::post::response::{PostListItem, UserPostsResponse};
 use crate::repository::hashtag::get_hashtags_by_post::repository_get_hashtags_by_posts;
 use crate::repository::post::get_user_posts::repository_get_user_posts;
-use crate::repository::user::find_user_by_uuid::repository_find_user_by_uuid;
 use crate::repository::user::get_user_by_handle::repository_get_user_by_handle;
-use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::error::errors::ServiceResult;
 use sea_orm::{ConnectionTrait, TransactionTrait};
 
 pub async fn service_get_user_posts<C>(
@@ -31,11 +30,8 @@ where
         .collect::<std::collections::HashMap<_, _>>();
 
     let mut post_items = Vec::new();
+    // 모든 글의 작성자가 같으므로 이미 조회한 사용자 정보를 재사용
     for post in &posts {
-        let post_user = repository_find_user_by_uuid(conn, &post.user_id)
-            .await?
-            .ok_or(Errors::UserNotFound)?;
-
         let hashtags = post_hashtags_map
             .get(&post.id)
             .map(|tags| tags.iter().map(|tag| tag.name.clone()).collect())
@@ -46,15 +42,18 @@ where
             title: post.title.clone(),
             summary: post.summary.clone(),
             thumbnail_image: post.thumbnail_image.clone(),
-            user_handle: post_user.handle,
-            user_name: post_user.name,
-            user_avatar: post_user.profile_image,
+            user_handle: user.handle.clone(),
+            user_name: user.name.clone(),
+            user_avatar: user.profile_image.clone(),
             created_at: post.created_at,
             like_count: post.like_count,
             comment_count: post.comment_count,
             view_count: post.view_count,
             slug: post.slug.clone(),
             hashtags,
+            word_count: post.word_count,
+            reading_time: post.reading_time,
+            language: post.language.clone(),
         });
     }
 
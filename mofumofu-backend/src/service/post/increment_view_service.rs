@@ -1,12 +1,19 @@
+use crate::config::db_config::DbConfig;
 use crate::entity::common::{ActionType, TargetType};
 use crate::repository::post::get_post_by_uuid::repository_get_post_by_uuid;
 use crate::repository::post::increment_view_count::repository_increment_view_count;
+use crate::repository::post::post_analytics::repository_record_post_view;
 use crate::repository::system_events::log_event::repository_log_event;
 use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::post::view_buffer::service_buffer_view;
+use crate::service::post::viewed_posts::service_record_viewed_post;
 use crate::state::AppState;
+use crate::utils::bot_user_agent::is_bot_user_agent;
+use crate::utils::crypto::hash_viewer_id;
+use crate::utils::referrer_domain::extract_referrer_domain;
 use redis::AsyncCommands;
 use sea_orm::ConnectionTrait;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 use uuid::Uuid;
 
 const VIEW_COUNT_TTL: i64 = 3600; // 1시간
@@ -16,6 +23,8 @@ pub async fn service_increment_view<C>(
     conn: &C,
     post_id: &Uuid,
     anonymous_user_id: Option<&str>,
+    referrer: Option<&str>,
+    user_agent: &str,
 ) -> ServiceResult<()>
 where
     C: ConnectionTrait,
@@ -23,8 +32,15 @@ where
     // 포스트 존재 여부 확인
     let _post = repository_get_post_by_uuid(conn, post_id).await?;
 
+    // 크롤러/봇은 조회수에 포함하지 않음
+    if is_bot_user_agent(user_agent) {
+        info!("Skipping view from bot user agent for post: {}", post_id);
+        return Ok(());
+    }
+
     // 조회수 증가
-    increment_view_count_with_redis_check(app_state, conn, post_id, anonymous_user_id).await?;
+    increment_view_count_with_redis_check(app_state, conn, post_id, anonymous_user_id, referrer)
+        .await?;
 
     Ok(())
 }
@@ -34,6 +50,7 @@ pub async fn increment_view_count_with_redis_check<C>(
     conn: &C,
     post_id: &Uuid,
     anonymous_user_id: Option<&str>,
+    referrer: Option<&str>,
 ) -> ServiceResult<()>
 where
     C: ConnectionTrait,
@@ -41,13 +58,14 @@ where
     let mut redis_conn = app_state.redis.clone();
 
     // Redis 키 생성 (anonymous_user_id만 사용)
-    let redis_key = match anonymous_user_id {
-        Some(anon_id) => format!("view:{}:anon:{}", post_id, anon_id),
+    let anon_id = match anonymous_user_id {
+        Some(anon_id) => anon_id,
         None => {
             error!("anonymous_user_id is None");
             return Err(Errors::SysInternalError("".to_string()));
         }
     };
+    let redis_key = format!("view:{}:anon:{}", post_id, anon_id);
 
     // Redis에서 키 존재 여부 확인
     let exists: bool = redis_conn.exists(&redis_key).await.map_err(|e| {
@@ -56,18 +74,34 @@ where
     })?;
 
     if !exists {
-        // DB에서 조회수 증가
-        repository_increment_view_count(conn, post_id).await?;
-
-        repository_log_event(
-            conn,
-            None, // 익명 사용자이므로 user_id는 None
-            ActionType::PostViewed,
-            Some(*post_id),
-            Some(TargetType::Post),
-            None,
-        )
-        .await;
+        let referrer_domain = referrer.and_then(extract_referrer_domain);
+
+        // 집계 테이블에는 익명 ID 대신 해시만 남김
+        let viewer_hash = hash_viewer_id(&DbConfig::get().view_count_viewer_salt, anon_id);
+
+        // 버퍼링 모드에서는 Redis에 모았다가 주기적으로 일괄 반영
+        let buffered = if DbConfig::get().view_count_buffered {
+            match service_buffer_view(
+                &app_state.redis,
+                post_id,
+                &viewer_hash,
+                referrer_domain.as_deref(),
+            )
+            .await
+            {
+                Ok(()) => true,
+                Err(e) => {
+                    warn!("Failed to buffer view, writing directly: {}", e);
+                    false
+                }
+            }
+        } else {
+            false
+        };
+
+        if !buffered {
+            record_view_directly(conn, post_id, &viewer_hash, referrer_domain.as_deref()).await?;
+        }
 
         // 중복 방지를 위한 키 저장 (TTL 설정)
         let _: () = redis_conn
@@ -78,13 +112,47 @@ where
                 Errors::SysInternalError("".to_string())
             })?;
 
-        info!(
-            "View count incremented for post: {} and logged system event",
-            post_id
-        );
+        info!("View counted for post: {}", post_id);
     } else {
         info!("View already counted for post: {} within TTL", post_id);
     }
 
+    // 관련 포스트에서 제외할 수 있도록 조회 기록 저장
+    service_record_viewed_post(&app_state.redis, anon_id, post_id).await;
+
+    Ok(())
+}
+
+/// 조회수 증가, 이벤트 로깅, 일간 집계를 바로 DB에 기록 (버퍼링하지 않을 때의 기존 동작)
+async fn record_view_directly<C>(
+    conn: &C,
+    post_id: &Uuid,
+    viewer_hash: &str,
+    referrer_domain: Option<&str>,
+) -> ServiceResult<()>
+where
+    C: ConnectionTrait,
+{
+    repository_increment_view_count(conn, post_id).await?;
+
+    repository_log_event(
+        conn,
+        None, // 익명 사용자이므로 user_id는 None
+        ActionType::PostViewed,
+        Some(*post_id),
+        Some(TargetType::Post),
+        None,
+    )
+    .await;
+
+    // 작성자 통계용 일간 집계 (실패해도 조회수 증가는 유지)
+    if let Err(e) = repository_record_post_view(conn, *post_id, viewer_hash, referrer_domain).await
+    {
+        warn!(
+            "Failed to record post view analytics for {}: {}",
+            post_id, e
+        );
+    }
+
     Ok(())
 }
@@ -1,12 +1,21 @@
+pub mod backfill_content_stats;
+pub mod build_post_list_items;
 pub mod create_post;
 pub mod delete_post;
+pub mod get_post_analytics;
 pub mod get_post_by_handle_and_slug;
 pub mod get_post_by_uuid;
 pub mod get_post_for_edit;
 pub mod get_posts;
+pub mod get_related_posts;
 pub mod get_user_posts;
 pub mod increment_view_service;
+pub mod related_posts_cache;
 pub mod search_posts;
+pub mod trending_scores;
+pub mod update_comment_settings;
 pub mod update_post;
 pub mod update_post_thumbnail;
 pub mod upload_image;
+pub mod view_buffer;
+pub mod viewed_posts;
@@ -0,0 +1,73 @@
+use crate::config::db_config::DbConfig;
+use crate::connection::redis_connection::RedisConnection;
+use redis::AsyncCommands;
+use tracing::warn;
+use uuid::Uuid;
+
+fn related_posts_key(post_id: &Uuid) -> String {
+    format!("related_posts:{}", post_id)
+}
+
+/// 캐시된 관련 포스트 후보 (없거나 Redis 오류 시 None)
+pub async fn service_get_cached_related_post_ids(
+    redis: &RedisConnection,
+    post_id: &Uuid,
+) -> Option<Vec<Uuid>> {
+    let mut redis_conn = redis.clone();
+
+    let cached: Option<String> = match redis_conn.get(related_posts_key(post_id)).await {
+        Ok(cached) => cached,
+        Err(e) => {
+            warn!("Failed to read related posts cache for {}: {}", post_id, e);
+            return None;
+        }
+    };
+
+    cached.and_then(|json| serde_json::from_str(&json).ok())
+}
+
+/// 관련 포스트 후보를 캐시
+///
+/// 좋아요가 바뀐 포스트의 후보는 바로 지우고, 같은 사용자가 좋아요한 다른 포스트의 후보는
+/// 목록을 역으로 찾을 수 없으므로 짧은 TTL(RELATED_POSTS_CACHE_TTL)이 지나면 다시 계산함
+pub async fn service_cache_related_post_ids(
+    redis: &RedisConnection,
+    post_id: &Uuid,
+    related_post_ids: &[Uuid],
+) {
+    let mut redis_conn = redis.clone();
+
+    let json = match serde_json::to_string(related_post_ids) {
+        Ok(json) => json,
+        Err(e) => {
+            warn!("Failed to serialize related posts for {}: {}", post_id, e);
+            return;
+        }
+    };
+
+    let result: redis::RedisResult<()> = redis_conn
+        .set_ex(
+            related_posts_key(post_id),
+            json,
+            DbConfig::get().related_posts_cache_ttl,
+        )
+        .await;
+
+    if let Err(e) = result {
+        warn!("Failed to cache related posts for {}: {}", post_id, e);
+    }
+}
+
+/// 좋아요가 바뀐 포스트의 관련 포스트 후보를 비움
+pub async fn service_invalidate_related_posts_cache(redis: &RedisConnection, post_id: &Uuid) {
+    let mut redis_conn = redis.clone();
+
+    let result: redis::RedisResult<()> = redis_conn.del(related_posts_key(post_id)).await;
+
+    if let Err(e) = result {
+        warn!(
+            "Failed to invalidate related posts cache for {}: {}",
+            post_id, e
+        );
+    }
+}
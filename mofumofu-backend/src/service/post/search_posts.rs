@@ -1,11 +1,10 @@
 use crate::connection::meilisearch::MeilisearchClient;
 use crate::dto::post::request::{PostSortOrder, SearchPostsRequest};
-use crate::dto::post::response::{GetPostsResponse, PostListItem};
-use crate::repository::hashtag::get_hashtags_by_post::repository_get_hashtags_by_posts;
+use crate::dto::post::response::GetPostsResponse;
 use crate::repository::post::get_posts::repository_get_posts_by_ids;
-use crate::repository::user::find_user_by_uuid::repository_find_user_by_uuid;
 use crate::service::error::errors::{Errors, ServiceResult};
 use crate::service::meilisearch::post_indexer;
+use crate::service::post::build_post_list_items::service_build_post_list_items;
 use chrono::{DateTime, Utc};
 use sea_orm::{ConnectionTrait, TransactionTrait};
 
@@ -28,6 +27,8 @@ where
     // 정렬 문자열 변환
     let sort_str = match sort_order {
         PostSortOrder::Popular => "popular",
+        // 검색 인덱스에는 트렌딩 점수가 없으므로 인기순으로 대체
+        PostSortOrder::Trending => "popular",
         PostSortOrder::Oldest => "oldest",
         PostSortOrder::Latest => "latest",
     };
@@ -41,6 +42,7 @@ where
         date_from,
         date_to,
         request.min_likes,
+        request.language.as_deref(),
         sort_str,
         page,
         page_size,
@@ -64,40 +66,8 @@ where
     // DB에서 실제 post 데이터 조회
     let posts = repository_get_posts_by_ids(conn, &post_ids).await?;
 
-    // DB 결과를 PostListItem으로 변환 (get_posts.rs와 동일한 로직)
-    let post_ids_uuid: Vec<uuid::Uuid> = posts.iter().map(|p| p.id).collect();
-    let post_hashtags_map = repository_get_hashtags_by_posts(conn, &post_ids_uuid)
-        .await?
-        .into_iter()
-        .collect::<std::collections::HashMap<_, _>>();
-
-    let mut post_items = Vec::new();
-    for post in posts {
-        let user = repository_find_user_by_uuid(conn, &post.user_id)
-            .await?
-            .ok_or(Errors::UserNotFound)?;
-
-        let hashtags = post_hashtags_map
-            .get(&post.id)
-            .map(|tags| tags.iter().map(|tag| tag.name.clone()).collect())
-            .unwrap_or_else(Vec::new);
-
-        post_items.push(PostListItem {
-            id: post.id,
-            title: post.title,
-            summary: post.summary,
-            thumbnail_image: post.thumbnail_image,
-            user_handle: user.handle,
-            user_name: user.name,
-            user_avatar: user.profile_image,
-            created_at: post.created_at,
-            like_count: post.like_count,
-            comment_count: post.comment_count,
-            view_count: post.view_count,
-            slug: post.slug,
-            hashtags,
-        });
-    }
+    // 작성자와 해시태그를 한 번에 조회해 PostListItem으로 변환
+    let post_items = service_build_post_list_items(conn, posts).await?;
 
     let has_more = post_items.len() == page_size as usize;
 
@@ -0,0 +1,40 @@
+use crate::config::db_config::DbConfig;
+use crate::repository::post::refresh_trending_scores::repository_refresh_trending_scores;
+use crate::service::error::errors::ServiceResult;
+use crate::utils::shutdown::BackgroundTasks;
+use sea_orm::{ConnectionTrait, DatabaseConnection};
+use std::time::Duration;
+use tracing::{info, warn};
+
+pub async fn service_refresh_trending_scores<C>(conn: &C) -> ServiceResult<u64>
+where
+    C: ConnectionTrait,
+{
+    let refreshed =
+        repository_refresh_trending_scores(conn, DbConfig::get().trending_window_days).await?;
+
+    Ok(refreshed)
+}
+
+/// 트렌딩 점수를 주기적으로 갱신하는 백그라운드 작업 시작
+pub fn spawn_trending_score_refresher(tasks: &BackgroundTasks, conn: DatabaseConnection) {
+    let period = Duration::from_secs(DbConfig::get().trending_refresh_interval.max(1));
+    let shutdown = tasks.clone();
+
+    tasks.spawn(async move {
+        let mut interval = tokio::time::interval(period);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            tokio::select! {
+                _ = shutdown.cancelled() => break,
+                _ = interval.tick() => {}
+            }
+
+            match service_refresh_trending_scores(&conn).await {
+                Ok(refreshed) => info!("Refreshed trending scores for {} posts", refreshed),
+                Err(e) => warn!("Failed to refresh trending scores: {:?}", e),
+            }
+        }
+    });
+}
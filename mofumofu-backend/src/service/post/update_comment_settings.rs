@@ -0,0 +1,32 @@
+use crate::connection::redis_connection::RedisConnection;
+use crate::dto::cache::internal::cache_event::CacheEvent;
+use crate::dto::post::request::UpdatePostCommentSettingsRequest;
+use crate::repository::post::get_post_by_uuid::repository_get_post_by_uuid;
+use crate::repository::post::update_comments_disabled::repository_set_post_comments_disabled;
+use crate::service::cache::invalidate_cache::service_publish_cache_event;
+use crate::service::error::errors::{Errors, ServiceResult};
+use sea_orm::{ConnectionTrait, TransactionTrait};
+use uuid::Uuid;
+
+pub async fn service_update_post_comment_settings<C>(
+    conn: &C,
+    redis: &RedisConnection,
+    user_id: &Uuid,
+    request: UpdatePostCommentSettingsRequest,
+) -> ServiceResult<()>
+where
+    C: ConnectionTrait + TransactionTrait,
+{
+    let post = repository_get_post_by_uuid(conn, &request.post_id).await?;
+
+    // 게시글 작성자만 변경 가능
+    if post.user_id != *user_id {
+        return Err(Errors::UserUnauthorized);
+    }
+
+    repository_set_post_comments_disabled(conn, &post.id, request.comments_disabled).await?;
+
+    service_publish_cache_event(redis, CacheEvent::PostChanged { post_id: post.id }).await;
+
+    Ok(())
+}
@@ -1,19 +1,32 @@
+use crate::connection::redis_connection::RedisConnection;
+use crate::dto::cache::internal::cache_event::CacheEvent;
+use crate::dto::outbox::internal::outbox_message::OutboxMessage;
 use crate::dto::post::request::update_post::UpdatePostRequest;
 use crate::entity::common::{ActionType, TargetType};
-use crate::microservices::markdown_client::render_markdown;
-use crate::microservices::search_client;
 use crate::repository::hashtag::associate_post_hashtags::repository_associate_post_hashtags;
 use crate::repository::hashtag::remove_post_hashtags::repository_remove_post_hashtags;
 use crate::repository::post::update_post::repository_update_post;
 use crate::repository::system_events::log_event::repository_log_event;
+use crate::service::cache::invalidate_cache::service_publish_cache_event;
 use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::markdown::content_stats::compute_content_stats;
+use crate::service::markdown::render::service_render_markdown;
+use crate::service::mention::log_mention_events::service_log_mention_events;
+use crate::service::mention::mention_parser::{
+    extract_mentions_from_markdown, link_mentions_in_markdown,
+};
+use crate::service::mention::resolve_mentions::service_resolve_mentions;
+use crate::service::mention::save_mentions::service_save_mentions;
+use crate::service::outbox::enqueue_outbox::service_enqueue_outbox;
 use sea_orm::{ConnectionTrait, TransactionTrait};
 use serde_json::json;
-use tracing::{info, warn};
+use std::collections::HashSet;
+use tracing::info;
 use uuid::Uuid;
 
 pub async fn service_update_post<C>(
     conn: &C,
+    redis: &RedisConnection,
     http_client: &reqwest::Client,
     payload: UpdatePostRequest,
     user_uuid: &Uuid,
@@ -22,9 +35,20 @@ where
     C: ConnectionTrait + TransactionTrait,
 {
     // 마크다운 렌더링 (content가 변경되는 경우에만)
-    let (render_html, toc_json) = if let Some(ref content) = payload.content {
+    let (render_html, toc_json, content_stats, mentioned_users) = if let Some(ref content) =
+        payload.content
+    {
+        // 멘션 파싱 - 존재하는 사용자만 프로필 링크로 렌더링
+        let mentioned_users =
+            service_resolve_mentions(conn, extract_mentions_from_markdown(content)).await?;
+        let mentioned_handles: HashSet<String> = mentioned_users
+            .iter()
+            .map(|user| user.handle.clone())
+            .collect();
+        let linked_content = link_mentions_in_markdown(content, &mentioned_handles);
+
         info!("마크다운 렌더링 시작 (content length: {})", content.len());
-        let rendered = render_markdown(http_client, content)
+        let rendered = service_render_markdown(http_client, &linked_content)
             .await
             .map_err(|e| Errors::MarkdownRenderFailed(format!("마크다운 렌더링 실패: {}", e)))?;
 
@@ -46,15 +70,27 @@ where
             })
             .collect();
 
-        (Some(rendered.html_content), Some(json!(toc_items)))
+        (
+            Some(rendered.html_content),
+            Some(json!(toc_items)),
+            Some(compute_content_stats(content)),
+            Some(mentioned_users),
+        )
     } else {
-        (None, None)
+        (None, None, None, None)
     };
 
     let txn = conn.begin().await?;
 
-    let updated_post =
-        repository_update_post(&txn, payload.clone(), user_uuid, render_html, toc_json).await?;
+    let updated_post = repository_update_post(
+        &txn,
+        payload.clone(),
+        user_uuid,
+        render_html,
+        toc_json,
+        content_stats,
+    )
+    .await?;
 
     if let Some(ref hashtags) = payload.hashtags {
         repository_remove_post_hashtags(&txn, updated_post.id).await?;
@@ -64,14 +100,33 @@ where
         }
     }
 
-    txn.commit().await?;
+    let new_mentions = match mentioned_users {
+        Some(ref users) => {
+            service_save_mentions(&txn, *user_uuid, updated_post.id, None, users).await?
+        }
+        None => Vec::new(),
+    };
 
-    if let Err(e) = search_client::queue_update_post(http_client, &updated_post.id).await {
-        warn!("Failed to queue post search update task: {}", e);
-    }
+    service_enqueue_outbox(
+        &txn,
+        OutboxMessage::SearchUpdatePost {
+            post_id: updated_post.id,
+        },
+    )
+    .await?;
+
+    txn.commit().await?;
 
     info!("글 수정 완료 (post_id: {})", updated_post.id);
 
+    service_publish_cache_event(
+        redis,
+        CacheEvent::PostContentChanged {
+            post_id: updated_post.id,
+        },
+    )
+    .await;
+
     repository_log_event(
         conn,
         Some(*user_uuid),
@@ -82,5 +137,8 @@ where
     )
     .await;
 
+    // 새로 멘션된 사용자별 이벤트 로깅
+    service_log_mention_events(conn, *user_uuid, updated_post.id, None, &new_mentions).await;
+
     Ok(())
 }
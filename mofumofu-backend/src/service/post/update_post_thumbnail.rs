@@ -1,6 +1,9 @@
 use crate::connection::cloudflare_r2::R2Client;
+use crate::connection::redis_connection::RedisConnection;
+use crate::dto::cache::internal::cache_event::CacheEvent;
 use crate::repository::post::get_post_by_uuid::repository_get_post_by_uuid;
 use crate::repository::post::update_post_thumbnail::repository_update_post_thumbnail;
+use crate::service::cache::invalidate_cache::service_publish_cache_event;
 use crate::service::error::errors::{Errors, ServiceResult};
 use crate::utils::image_validator::{generate_image_hash, process_image_for_upload};
 use axum::extract::Multipart;
@@ -10,6 +13,7 @@ use uuid::Uuid;
 
 pub async fn service_update_post_thumbnail<C>(
     conn: &C,
+    redis: &RedisConnection,
     r2_client: &R2Client,
     user_uuid: &Uuid,
     mut multipart: Multipart,
@@ -139,6 +143,8 @@ where
             Errors::SysInternalError("Failed to update post thumbnail".to_string())
         })?;
 
+    service_publish_cache_event(redis, CacheEvent::PostChanged { post_id: post.id }).await;
+
     info!("Thumbnail image uploaded successfully: {}", public_url);
     Ok(public_url)
 }
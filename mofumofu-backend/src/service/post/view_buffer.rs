@@ -0,0 +1,196 @@
+use crate::config::db_config::DbConfig;
+use crate::connection::redis_connection::RedisConnection;
+use crate::dto::post::internal::buffered_view::{
+    BufferedReferrer, BufferedViewCount, BufferedViewer, buffered_view_field,
+    parse_buffered_view_field,
+};
+use crate::repository::post::apply_buffered_views::{
+    repository_apply_buffered_referrers, repository_apply_buffered_view_counts,
+    repository_apply_buffered_viewers,
+};
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::utils::shutdown::BackgroundTasks;
+use sea_orm::{ConnectionTrait, DatabaseConnection, TransactionTrait};
+use std::collections::HashMap;
+use std::time::Duration;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+const VIEW_COUNTS_KEY: &str = "view_buffer:counts";
+const VIEWERS_KEY: &str = "view_buffer:viewers";
+const REFERRERS_KEY: &str = "view_buffer:referrers";
+
+/// 조회 한 건을 Redis에 모아둠 (DB 반영은 주기적으로 일괄 처리)
+pub async fn service_buffer_view(
+    redis: &RedisConnection,
+    post_id: &Uuid,
+    viewer_id: &str,
+    referrer_domain: Option<&str>,
+) -> redis::RedisResult<()> {
+    let mut redis_conn = redis.clone();
+    let day = chrono::Utc::now().date_naive();
+
+    redis::pipe()
+        .hincr(VIEW_COUNTS_KEY, buffered_view_field(post_id, day, None), 1)
+        .ignore()
+        .hset_nx(
+            VIEWERS_KEY,
+            buffered_view_field(post_id, day, Some(viewer_id)),
+            1,
+        )
+        .ignore()
+        .hincr(
+            REFERRERS_KEY,
+            buffered_view_field(post_id, day, Some(referrer_domain.unwrap_or_default())),
+            1,
+        )
+        .ignore()
+        .query_async(&mut redis_conn)
+        .await
+}
+
+/// Redis에 모인 조회를 한 번에 DB에 반영하고 반영된 조회수를 반환
+/// DB 반영에 실패하면 꺼낸 값을 Redis에 되돌려 다음 주기에 다시 시도
+pub async fn service_flush_buffered_views<C>(
+    conn: &C,
+    redis: &RedisConnection,
+) -> ServiceResult<i64>
+where
+    C: ConnectionTrait + TransactionTrait,
+{
+    let mut redis_conn = redis.clone();
+
+    // 읽기와 삭제를 원자적으로 처리해 그 사이 들어온 조회가 사라지지 않도록 함
+    let (counts, viewers, referrers): (
+        HashMap<String, i64>,
+        HashMap<String, i64>,
+        HashMap<String, i64>,
+    ) = redis::pipe()
+        .atomic()
+        .hgetall(VIEW_COUNTS_KEY)
+        .hgetall(VIEWERS_KEY)
+        .hgetall(REFERRERS_KEY)
+        .del(&[VIEW_COUNTS_KEY, VIEWERS_KEY, REFERRERS_KEY])
+        .ignore()
+        .query_async(&mut redis_conn)
+        .await
+        .map_err(|e| {
+            error!("Failed to drain buffered views: {}", e);
+            Errors::SysInternalError("".to_string())
+        })?;
+
+    if counts.is_empty() && viewers.is_empty() && referrers.is_empty() {
+        return Ok(0);
+    }
+
+    let view_counts: Vec<BufferedViewCount> = counts
+        .iter()
+        .filter_map(|(field, views)| {
+            let (post_id, day, _) = parse_buffered_view_field(field)?;
+            Some(BufferedViewCount {
+                post_id,
+                day,
+                views: *views as i32,
+            })
+        })
+        .collect();
+    let buffered_viewers: Vec<BufferedViewer> = viewers
+        .keys()
+        .filter_map(|field| {
+            let (post_id, day, viewer_id) = parse_buffered_view_field(field)?;
+            Some(BufferedViewer {
+                post_id,
+                day,
+                viewer_id: viewer_id?,
+            })
+        })
+        .collect();
+    let buffered_referrers: Vec<BufferedReferrer> = referrers
+        .iter()
+        .filter_map(|(field, views)| {
+            let (post_id, day, domain) = parse_buffered_view_field(field)?;
+            Some(BufferedReferrer {
+                post_id,
+                day,
+                domain: domain.unwrap_or_default(),
+                views: *views as i32,
+            })
+        })
+        .collect();
+
+    let result = async {
+        let txn = conn.begin().await?;
+        repository_apply_buffered_view_counts(&txn, &view_counts).await?;
+        repository_apply_buffered_viewers(&txn, &buffered_viewers).await?;
+        repository_apply_buffered_referrers(&txn, &buffered_referrers).await?;
+        txn.commit().await
+    }
+    .await;
+
+    if let Err(e) = result {
+        error!("Failed to flush buffered views, restoring to Redis: {}", e);
+        restore_buffered_views(redis, &counts, &viewers, &referrers).await;
+        return Err(e.into());
+    }
+
+    Ok(view_counts.iter().map(|count| count.views as i64).sum())
+}
+
+async fn restore_buffered_views(
+    redis: &RedisConnection,
+    counts: &HashMap<String, i64>,
+    viewers: &HashMap<String, i64>,
+    referrers: &HashMap<String, i64>,
+) {
+    let mut redis_conn = redis.clone();
+    let mut pipe = redis::pipe();
+
+    for (field, views) in counts {
+        pipe.hincr(VIEW_COUNTS_KEY, field, *views).ignore();
+    }
+    for field in viewers.keys() {
+        pipe.hset_nx(VIEWERS_KEY, field, 1).ignore();
+    }
+    for (field, views) in referrers {
+        pipe.hincr(REFERRERS_KEY, field, *views).ignore();
+    }
+
+    let result: redis::RedisResult<()> = pipe.query_async(&mut redis_conn).await;
+    if let Err(e) = result {
+        error!("Failed to restore buffered views, views are lost: {}", e);
+    }
+}
+
+/// Redis에 모인 조회수를 주기적으로 DB에 반영하는 백그라운드 작업 시작
+///
+/// 종료 시에는 남은 조회수를 한 번 더 반영한 뒤 끝낸다.
+pub fn spawn_view_buffer_flusher(
+    tasks: &BackgroundTasks,
+    conn: DatabaseConnection,
+    redis: RedisConnection,
+) {
+    let period = Duration::from_secs(DbConfig::get().view_count_flush_interval.max(1));
+    let shutdown = tasks.clone();
+
+    tasks.spawn(async move {
+        let mut interval = tokio::time::interval(period);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            let stopping = tokio::select! {
+                _ = shutdown.cancelled() => true,
+                _ = interval.tick() => false,
+            };
+
+            match service_flush_buffered_views(&conn, &redis).await {
+                Ok(0) => {}
+                Ok(flushed) => info!("Flushed {} buffered post views", flushed),
+                Err(e) => warn!("Failed to flush buffered post views: {:?}", e),
+            }
+
+            if stopping {
+                break;
+            }
+        }
+    });
+}
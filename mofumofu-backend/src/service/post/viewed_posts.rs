@@ -0,0 +1,61 @@
+use crate::config::db_config::DbConfig;
+use crate::connection::redis_connection::RedisConnection;
+use redis::AsyncCommands;
+use std::collections::HashSet;
+use tracing::warn;
+use uuid::Uuid;
+
+const VIEWED_POSTS_TTL: i64 = 60 * 60 * 24 * 30; // 30일
+
+fn viewed_posts_key(anonymous_user_id: &str) -> String {
+    format!("viewed_posts:anon:{}", anonymous_user_id)
+}
+
+/// 익명 사용자가 본 포스트를 최근 순으로 기록 (오래된 기록은 잘라냄)
+pub async fn service_record_viewed_post(
+    redis: &RedisConnection,
+    anonymous_user_id: &str,
+    post_id: &Uuid,
+) {
+    let mut redis_conn = redis.clone();
+    let key = viewed_posts_key(anonymous_user_id);
+    let history_size = DbConfig::get().viewed_posts_history_size;
+
+    let result: redis::RedisResult<()> = redis::pipe()
+        .zadd(&key, post_id.to_string(), chrono::Utc::now().timestamp())
+        .ignore()
+        .zremrangebyrank(&key, 0, -(history_size + 1))
+        .ignore()
+        .expire(&key, VIEWED_POSTS_TTL)
+        .ignore()
+        .query_async(&mut redis_conn)
+        .await;
+
+    if let Err(e) = result {
+        warn!("Failed to record viewed post {}: {}", post_id, e);
+    }
+}
+
+/// 익명 사용자가 최근에 본 포스트 ID 목록 (Redis 오류 시 빈 목록)
+pub async fn service_get_viewed_post_ids(
+    redis: &RedisConnection,
+    anonymous_user_id: &str,
+) -> HashSet<Uuid> {
+    let mut redis_conn = redis.clone();
+
+    let post_ids: Vec<String> = match redis_conn
+        .zrange(viewed_posts_key(anonymous_user_id), 0, -1)
+        .await
+    {
+        Ok(post_ids) => post_ids,
+        Err(e) => {
+            warn!("Failed to load viewed posts: {}", e);
+            return HashSet::new();
+        }
+    };
+
+    post_ids
+        .iter()
+        .filter_map(|post_id| Uuid::parse_str(post_id).ok())
+        .collect()
+}
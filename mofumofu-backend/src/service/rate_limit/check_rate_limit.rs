@@ -0,0 +1,115 @@
+use crate::connection::redis_connection::RedisConnection;
+use crate::dto::rate_limit::internal::rate_limit_decision::RateLimitDecision;
+use crate::dto::rate_limit::internal::rate_limit_policy::{RateLimitAlgorithm, RateLimitPolicy};
+use chrono::Utc;
+use redis::Script;
+use std::sync::LazyLock;
+use uuid::Uuid;
+
+/// 최근 window(ms) 동안의 요청 시각을 sorted set에 기록하고 limit개까지 허용
+///
+/// 반환값: {허용 여부, 남은 요청 수, 가장 오래된 요청이 빠지기까지 남은 시간(ms)}
+static SLIDING_WINDOW_SCRIPT: LazyLock<Script> = LazyLock::new(|| {
+    Script::new(
+        r#"
+local now = tonumber(ARGV[1])
+local window = tonumber(ARGV[2])
+local limit = tonumber(ARGV[3])
+redis.call('ZREMRANGEBYSCORE', KEYS[1], '-inf', now - window)
+local count = redis.call('ZCARD', KEYS[1])
+local allowed = 0
+if count < limit then
+    redis.call('ZADD', KEYS[1], now, ARGV[4])
+    count = count + 1
+    allowed = 1
+end
+redis.call('PEXPIRE', KEYS[1], window)
+local reset = window
+local oldest = redis.call('ZRANGE', KEYS[1], 0, 0, 'WITHSCORES')
+if oldest[2] then
+    reset = tonumber(oldest[2]) + window - now
+end
+return {allowed, limit - count, reset}
+"#,
+    )
+});
+
+/// window(ms) 동안 limit개가 채워지는 토큰 버킷에서 토큰 하나를 사용
+///
+/// 반환값: {허용 여부, 남은 토큰 수, 다음 토큰(거부 시) 또는 버킷이 가득 차기(허용 시)까지 남은 시간(ms)}
+static TOKEN_BUCKET_SCRIPT: LazyLock<Script> = LazyLock::new(|| {
+    Script::new(
+        r#"
+local now = tonumber(ARGV[1])
+local window = tonumber(ARGV[2])
+local capacity = tonumber(ARGV[3])
+local rate = capacity / window
+local state = redis.call('HMGET', KEYS[1], 'tokens', 'ts')
+local tokens = tonumber(state[1]) or capacity
+local ts = tonumber(state[2]) or now
+tokens = math.min(capacity, tokens + math.max(0, now - ts) * rate)
+local allowed = 0
+if tokens >= 1 then
+    tokens = tokens - 1
+    allowed = 1
+end
+redis.call('HSET', KEYS[1], 'tokens', tostring(tokens), 'ts', now)
+redis.call('PEXPIRE', KEYS[1], window)
+local reset
+if allowed == 1 then
+    reset = math.ceil((capacity - tokens) / rate)
+else
+    reset = math.ceil((1 - tokens) / rate)
+end
+return {allowed, math.floor(tokens), reset}
+"#,
+    )
+});
+
+fn rate_limit_key(policy: &RateLimitPolicy, subject: &str) -> String {
+    format!("rate_limit:{}:{}", policy.name, subject)
+}
+
+/// 정책에 따라 subject의 요청 하나를 세고 허용 여부를 반환
+pub async fn service_check_rate_limit(
+    redis: &RedisConnection,
+    policy: &RateLimitPolicy,
+    subject: &str,
+) -> redis::RedisResult<RateLimitDecision> {
+    let mut redis_conn = redis.clone();
+    let (limit, window_seconds) = policy.effective_limits();
+    let window_ms = window_seconds.max(1) * 1000;
+    let now = Utc::now().timestamp_millis();
+
+    let key = rate_limit_key(policy, subject);
+
+    let (allowed, remaining, reset_ms): (i64, i64, i64) = match policy.algorithm {
+        RateLimitAlgorithm::SlidingWindow => {
+            SLIDING_WINDOW_SCRIPT
+                .key(key)
+                .arg(now)
+                .arg(window_ms)
+                .arg(limit)
+                .arg(Uuid::new_v4().to_string())
+                .invoke_async(&mut redis_conn)
+                .await?
+        }
+        RateLimitAlgorithm::TokenBucket => {
+            TOKEN_BUCKET_SCRIPT
+                .key(key)
+                .arg(now)
+                .arg(window_ms)
+                .arg(limit)
+                .invoke_async(&mut redis_conn)
+                .await?
+        }
+    };
+
+    Ok(RateLimitDecision {
+        allowed: allowed == 1,
+        limit,
+        window_seconds,
+        remaining: remaining.clamp(0, limit as i64) as u32,
+        reset_seconds: (reset_ms.max(0) as u64).div_ceil(1000),
+    })
+}
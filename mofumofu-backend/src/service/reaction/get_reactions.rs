@@ -0,0 +1,42 @@
+use crate::dto::reaction::internal::reaction_summary::ReactionSummary;
+use crate::entity::common::LikeTargetType;
+use crate::repository::reaction::get_reaction_counts::repository_get_reaction_counts;
+use crate::repository::reaction::get_user_reactions::repository_get_user_reactions;
+use crate::service::error::errors::ServiceResult;
+use sea_orm::ConnectionTrait;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// 여러 대상의 반응 집계와 조회한 사용자의 반응을 함께 조회
+///
+/// 로그인하지 않은 경우 my_reactions는 항상 비어 있다.
+pub async fn service_get_reactions<C>(
+    conn: &C,
+    viewer_id: Option<Uuid>,
+    target_type: LikeTargetType,
+    target_ids: &[Uuid],
+) -> ServiceResult<HashMap<Uuid, ReactionSummary>>
+where
+    C: ConnectionTrait,
+{
+    let counts = repository_get_reaction_counts(conn, target_type.clone(), target_ids).await?;
+    let mut my_reactions = match viewer_id {
+        Some(viewer_id) => {
+            repository_get_user_reactions(conn, viewer_id, target_type, target_ids).await?
+        }
+        None => HashMap::new(),
+    };
+
+    let summaries = counts
+        .into_iter()
+        .map(|(target_id, reactions)| {
+            let summary = ReactionSummary {
+                reactions,
+                my_reactions: my_reactions.remove(&target_id).unwrap_or_default(),
+            };
+            (target_id, summary)
+        })
+        .collect();
+
+    Ok(summaries)
+}
@@ -0,0 +1,4 @@
+pub mod get_reactions;
+pub mod reaction_emoji;
+pub mod toggle_comment_reaction;
+pub mod toggle_post_reaction;
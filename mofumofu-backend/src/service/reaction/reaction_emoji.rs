@@ -0,0 +1,19 @@
+/// 좋아요와 같은 의미로 취급되는 반응
+pub const LIKE_REACTION_EMOJI: &str = "❤️";
+
+/// 사용할 수 있는 반응 이모지
+pub const ALLOWED_REACTION_EMOJIS: &[&str] = &["👍", "❤️", "🎉", "🤔", "😂", "😮", "😢", "👀"];
+
+/// 입력된 이모지를 허용 목록의 표기로 정규화
+///
+/// 클라이언트에 따라 이모지 변형 선택자(U+FE0F)가 빠진 채로 올 수 있으므로
+/// 선택자를 제외하고 비교한다. 허용되지 않은 이모지면 None.
+pub fn normalize_reaction_emoji(emoji: &str) -> Option<&'static str> {
+    let without_selector = |s: &str| s.chars().filter(|c| *c != '\u{FE0F}').collect::<String>();
+    let input = without_selector(emoji.trim());
+
+    ALLOWED_REACTION_EMOJIS
+        .iter()
+        .copied()
+        .find(|allowed| without_selector(allowed) == input)
+}
@@ -0,0 +1,70 @@
+use crate::dto::reaction::response::toggle_reaction::ToggleReactionResponse;
+use crate::entity::common::LikeTargetType;
+use crate::repository::comment::get_comment_by_id::repository_get_comment_by_id;
+use crate::repository::like::check_like_status::repository_check_like_status_by_comment_id;
+use crate::repository::reaction::create_reaction::repository_create_reaction;
+use crate::repository::reaction::delete_reaction::repository_delete_reaction;
+use crate::repository::reaction::get_reaction_counts::repository_get_reaction_counts;
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::like::create_comment_like::service_create_comment_like;
+use crate::service::like::delete_comment_like::service_delete_comment_like;
+use crate::service::reaction::reaction_emoji::{LIKE_REACTION_EMOJI, normalize_reaction_emoji};
+use sea_orm::{ConnectionTrait, TransactionTrait};
+use uuid::Uuid;
+
+pub async fn service_toggle_comment_reaction<C>(
+    conn: &C,
+    user_id: &Uuid,
+    comment_id: &Uuid,
+    emoji: &str,
+) -> ServiceResult<ToggleReactionResponse>
+where
+    C: ConnectionTrait + TransactionTrait,
+{
+    let emoji = normalize_reaction_emoji(emoji).ok_or(Errors::ReactionInvalidEmoji)?;
+
+    let reacted = if emoji == LIKE_REACTION_EMOJI {
+        // ❤️는 좋아요와 같으므로 좋아요 서비스를 통해 like_count까지 함께 갱신
+        let already_liked =
+            repository_check_like_status_by_comment_id(conn, user_id, comment_id).await?;
+        if already_liked {
+            service_delete_comment_like(conn, user_id, comment_id).await?;
+        } else {
+            service_create_comment_like(conn, user_id, comment_id).await?;
+        }
+        !already_liked
+    } else {
+        let txn = conn.begin().await?;
+
+        // 댓글 존재 확인
+        let comment = repository_get_comment_by_id(&txn, *comment_id)
+            .await?
+            .ok_or(Errors::CommentNotFound)?;
+
+        // 이미 있으면 삭제, 없으면 생성 (삭제된 댓글에는 새 반응을 남길 수 없음)
+        let removed =
+            repository_delete_reaction(&txn, *user_id, LikeTargetType::Comment, *comment_id, emoji)
+                .await?;
+        if !removed {
+            if comment.is_deleted {
+                return Err(Errors::CommentNotFound);
+            }
+            repository_create_reaction(&txn, *user_id, LikeTargetType::Comment, *comment_id, emoji)
+                .await?;
+        }
+
+        txn.commit().await?;
+        !removed
+    };
+
+    let reactions = repository_get_reaction_counts(conn, LikeTargetType::Comment, &[*comment_id])
+        .await?
+        .remove(comment_id)
+        .unwrap_or_default();
+
+    Ok(ToggleReactionResponse {
+        emoji: emoji.to_string(),
+        reacted,
+        reactions,
+    })
+}
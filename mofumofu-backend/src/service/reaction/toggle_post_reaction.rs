@@ -0,0 +1,71 @@
+use crate::connection::redis_connection::RedisConnection;
+use crate::dto::cache::internal::cache_event::CacheEvent;
+use crate::dto::reaction::response::toggle_reaction::ToggleReactionResponse;
+use crate::entity::common::LikeTargetType;
+use crate::repository::like::check_like_status::repository_check_like_status_by_post_id;
+use crate::repository::post::get_post_by_uuid::repository_get_post_by_uuid;
+use crate::repository::reaction::create_reaction::repository_create_reaction;
+use crate::repository::reaction::delete_reaction::repository_delete_reaction;
+use crate::repository::reaction::get_reaction_counts::repository_get_reaction_counts;
+use crate::service::cache::invalidate_cache::service_publish_cache_event;
+use crate::service::error::errors::{Errors, ServiceResult};
+use crate::service::like::create_post_like::service_create_post_like;
+use crate::service::like::delete_post_like::service_delete_post_like;
+use crate::service::reaction::reaction_emoji::{LIKE_REACTION_EMOJI, normalize_reaction_emoji};
+use sea_orm::{ConnectionTrait, TransactionTrait};
+use uuid::Uuid;
+
+pub async fn service_toggle_post_reaction<C>(
+    conn: &C,
+    redis: &RedisConnection,
+    user_id: &Uuid,
+    post_id: &Uuid,
+    emoji: &str,
+) -> ServiceResult<ToggleReactionResponse>
+where
+    C: ConnectionTrait + TransactionTrait,
+{
+    let emoji = normalize_reaction_emoji(emoji).ok_or(Errors::ReactionInvalidEmoji)?;
+
+    let reacted = if emoji == LIKE_REACTION_EMOJI {
+        // ❤️는 좋아요와 같으므로 좋아요 서비스를 통해 like_count까지 함께 갱신
+        let already_liked = repository_check_like_status_by_post_id(conn, user_id, post_id).await?;
+        if already_liked {
+            service_delete_post_like(conn, redis, user_id, post_id).await?;
+        } else {
+            service_create_post_like(conn, redis, user_id, post_id).await?;
+        }
+        !already_liked
+    } else {
+        let txn = conn.begin().await?;
+
+        // 포스트 존재 확인
+        let _post = repository_get_post_by_uuid(&txn, post_id).await?;
+
+        // 이미 있으면 삭제, 없으면 생성
+        let removed =
+            repository_delete_reaction(&txn, *user_id, LikeTargetType::Post, *post_id, emoji)
+                .await?;
+        if !removed {
+            repository_create_reaction(&txn, *user_id, LikeTargetType::Post, *post_id, emoji)
+                .await?;
+        }
+
+        txn.commit().await?;
+
+        // ❤️는 좋아요 서비스에서 무효화하므로 다른 반응만 여기서 처리
+        service_publish_cache_event(redis, CacheEvent::PostChanged { post_id: *post_id }).await;
+        !removed
+    };
+
+    let reactions = repository_get_reaction_counts(conn, LikeTargetType::Post, &[*post_id])
+        .await?
+        .remove(post_id)
+        .unwrap_or_default();
+
+    Ok(ToggleReactionResponse {
+        emoji: emoji.to_string(),
+        reacted,
+        reactions,
+    })
+}
@@ -1,27 +1,41 @@
+use crate::connection::redis_connection::RedisConnection;
+use crate::dto::cache::internal::cache_endpoint::CacheEndpoint;
+use crate::dto::cache::internal::cache_tag::CacheTag;
 use crate::dto::user::response::info::UserInfoResponse;
 use crate::repository::user::get_user_by_handle::repository_get_user_by_handle;
+use crate::service::cache::response_cache::service_cached;
 use crate::service::error::errors::{Errors, ServiceResult};
 use sea_orm::{ConnectionTrait, TransactionTrait};
 
 pub async fn service_get_user_by_handle<C>(
     conn: &C,
+    redis: &RedisConnection,
     handle: &str,
 ) -> ServiceResult<UserInfoResponse>
 where
     C: ConnectionTrait + TransactionTrait,
 {
-    let user = repository_get_user_by_handle(conn, handle).await?;
+    service_cached(redis, CacheEndpoint::Profile, handle, || async {
+        let user = repository_get_user_by_handle(conn, handle).await?;
 
-    Ok(UserInfoResponse {
-        name: user.name,
-        handle: user.handle,
-        email: user.email,
-        bio: user.bio,
-        location: user.location,
-        website: user.website,
-        profile_image: user.profile_image,
-        banner_image: user.banner_image,
-        is_verified: user.is_verified,
-        created_at: user.created_at,
+        // handle이 바뀌어도 이전 handle의 캐시가 지워지도록 사용자 ID로 태그
+        let tags = vec![CacheTag::User(user.id)];
+
+        Ok((
+            UserInfoResponse {
+                name: user.name,
+                handle: user.handle,
+                email: user.email,
+                bio: user.bio,
+                location: user.location,
+                website: user.website,
+                profile_image: user.profile_image,
+                banner_image: user.banner_image,
+                is_verified: user.is_verified,
+                created_at: user.created_at,
+            },
+            tags,
+        ))
     })
+    .await
 }
@@ -0,0 +1,26 @@
+use crate::entity::users::Model as UserModel;
+use crate::repository::user::find_users_by_uuids::repository_find_users_by_uuids;
+use crate::service::error::errors::ServiceResult;
+use sea_orm::ConnectionTrait;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// 목록에 등장하는 사용자들을 한 번의 쿼리로 조회 (중복 ID는 제거)
+pub async fn service_load_users<C, I>(conn: &C, ids: I) -> ServiceResult<HashMap<Uuid, UserModel>>
+where
+    C: ConnectionTrait,
+    I: IntoIterator<Item = Uuid>,
+{
+    let mut user_ids: Vec<Uuid> = ids.into_iter().collect();
+    if user_ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+    user_ids.sort_unstable();
+    user_ids.dedup();
+
+    Ok(repository_find_users_by_uuids(conn, &user_ids)
+        .await?
+        .into_iter()
+        .map(|user| (user.id, user))
+        .collect())
+}
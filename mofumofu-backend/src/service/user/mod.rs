@@ -3,6 +3,8 @@ pub mod delete_user_avatar;
 pub mod delete_user_banner;
 pub mod get_user_by_handle;
 pub mod get_user_by_uuid;
+pub mod load_users;
+pub mod suggest_users;
 pub mod update_user_avatar;
 pub mod update_user_banner;
 pub mod update_user_profile;
@@ -0,0 +1,37 @@
+use crate::dto::user::request::suggest_users::SuggestUsersRequest;
+use crate::dto::user::response::suggest_users::{SuggestUsersResponse, UserSuggestion};
+use crate::repository::user::suggest_users::repository_suggest_users;
+use crate::service::error::errors::ServiceResult;
+use sea_orm::ConnectionTrait;
+use uuid::Uuid;
+
+/// 멘션 입력 자동완성 (로그인한 경우 팔로우 중인 사용자 우선)
+pub async fn service_suggest_users<C>(
+    conn: &C,
+    viewer_id: Option<Uuid>,
+    request: SuggestUsersRequest,
+) -> ServiceResult<SuggestUsersResponse>
+where
+    C: ConnectionTrait,
+{
+    let limit = request.limit.unwrap_or(8);
+    let query = request.q.trim().trim_start_matches('@').to_lowercase();
+
+    // '@'만 입력한 경우
+    if query.is_empty() {
+        return Ok(SuggestUsersResponse { users: Vec::new() });
+    }
+
+    let users = repository_suggest_users(conn, &query, viewer_id, limit)
+        .await?
+        .into_iter()
+        .map(|row| UserSuggestion {
+            handle: row.handle,
+            name: row.name,
+            profile_image: row.profile_image,
+            is_following: row.is_following,
+        })
+        .collect();
+
+    Ok(SuggestUsersResponse { users })
+}
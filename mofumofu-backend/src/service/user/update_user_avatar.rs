@@ -1,7 +1,10 @@
 use crate::connection::cloudflare_r2::R2Client;
+use crate::connection::redis_connection::RedisConnection;
+use crate::dto::cache::internal::cache_event::CacheEvent;
 use crate::dto::user::internal::update_user::UpdateUserFields;
 use crate::repository::user::get_user_by_uuid::repository_get_user_by_uuid;
 use crate::repository::user::update_user::repository_update_user;
+use crate::service::cache::invalidate_cache::service_publish_cache_event;
 use crate::service::error::errors::{Errors, ServiceResult};
 use crate::utils::image_validator::{generate_image_hash, process_image_for_upload};
 use axum::extract::Multipart;
@@ -11,6 +14,7 @@ use uuid::Uuid;
 
 pub async fn service_update_user_avatar<C>(
     conn: &C,
+    redis: &RedisConnection,
     r2_client: &R2Client,
     user_uuid: &Uuid,
     mut multipart: Multipart,
@@ -97,6 +101,14 @@ where
                     Errors::SysInternalError("Failed to update user profile".to_string())
                 })?;
 
+            service_publish_cache_event(
+                redis,
+                CacheEvent::UserChanged {
+                    user_id: *user_uuid,
+                },
+            )
+            .await;
+
             info!("Avatar image uploaded successfully: {}", public_url);
             return Ok(public_url);
         }
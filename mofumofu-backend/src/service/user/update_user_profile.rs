@@ -1,9 +1,12 @@
+use crate::connection::redis_connection::RedisConnection;
+use crate::dto::cache::internal::cache_event::CacheEvent;
 use crate::dto::user::request::update_profile::UpdateProfileRequest;
 use crate::dto::user::response::info::UserInfoResponse;
 
 use crate::dto::user::internal::update_user::UpdateUserFields;
 use crate::repository::user::find_user_by_uuid::repository_find_user_by_uuid;
 use crate::repository::user::update_user::repository_update_user;
+use crate::service::cache::invalidate_cache::service_publish_cache_event;
 use crate::service::error::errors::{Errors, ServiceResult};
 use crate::utils::crypto::{hash_password, verify_password};
 use sea_orm::{ConnectionTrait, TransactionTrait};
@@ -11,6 +14,7 @@ use uuid::Uuid;
 
 pub async fn service_update_user_profile<C>(
     conn: &C,
+    redis: &RedisConnection,
     user_uuid: &Uuid,
     payload: UpdateProfileRequest,
 ) -> ServiceResult<UserInfoResponse>
@@ -68,6 +72,15 @@ where
 
     txn.commit().await?;
 
+    // 프로필 헤더와 작성자 정보가 담긴 캐시 무효화
+    service_publish_cache_event(
+        redis,
+        CacheEvent::UserChanged {
+            user_id: *user_uuid,
+        },
+    )
+    .await;
+
     Ok(UserInfoResponse {
         name: updated_user.name,
         handle: updated_user.handle,
@@ -1,6 +1,6 @@
 use crate::connection::cloudflare_r2::R2Client;
 use crate::connection::meilisearch::MeilisearchClient;
-use redis::aio::ConnectionManager;
+use crate::connection::redis_connection::RedisConnection;
 use reqwest::Client;
 use sea_orm::DatabaseConnection;
 
@@ -8,7 +8,7 @@ use sea_orm::DatabaseConnection;
 pub struct AppState {
     pub conn: DatabaseConnection,
     pub cloudflare_r2: R2Client,
-    pub redis: ConnectionManager,
+    pub redis: RedisConnection,
     pub http_client: Client,
     pub meilisearch: MeilisearchClient,
 }
@@ -2,6 +2,7 @@ use crate::service::error::errors::Errors;
 use argon2::password_hash::SaltString;
 use argon2::password_hash::rand_core::OsRng;
 use argon2::{Algorithm, Argon2, Params, PasswordHash, PasswordHasher, PasswordVerifier, Version};
+use sha2::{Digest, Sha256};
 
 pub fn hash_password(password: &str) -> Result<String, Errors> {
     // OWASP - Password Storage Cheat Sheet
@@ -34,3 +35,12 @@ pub fn verify_password(password: &str, password_hash: &str) -> Result<(), Errors
         .verify_password(password.as_bytes(), &parsed_hash)
         .map_err(|_| Errors::UserInvalidPassword)
 }
+
+/// 익명 방문자 ID를 salt와 함께 해시 (순 방문자 집계에는 원래 ID 대신 이 값을 저장)
+pub fn hash_viewer_id(salt: &str, viewer_id: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(salt.as_bytes());
+    hasher.update(b":");
+    hasher.update(viewer_id.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
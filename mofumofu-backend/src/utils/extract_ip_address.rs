@@ -1,18 +1,110 @@
+use crate::config::db_config::DbConfig;
 use axum::http::HeaderMap;
-use axum_extra::TypedHeader;
-use axum_extra::headers::UserAgent;
-use std::net::SocketAddr;
+use ipnet::IpNet;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Once;
+use tracing::error;
 
+/// TRUSTED_PROXIES 없이 전달 헤더가 온 경우 한 번만 경고
+static UNTRUSTED_FORWARDED_HEADERS: Once = Once::new();
+
+/// 클라이언트 IP 추출
+///
+/// 직접 연결한 주소가 TRUSTED_PROXIES에 있을 때만 CF-Connecting-IP/X-Forwarded-For를 믿는다.
 pub fn extract_ip_address(headers: &HeaderMap, addr: SocketAddr) -> String {
-    headers
+    client_ip(headers, addr.ip(), &DbConfig::get().trusted_proxies).to_string()
+}
+
+fn client_ip(headers: &HeaderMap, peer: IpAddr, trusted_proxies: &[IpNet]) -> IpAddr {
+    let is_trusted = |ip: &IpAddr| trusted_proxies.iter().any(|net| net.contains(ip));
+    if !is_trusted(&peer) {
+        // 프록시 뒤에서 TRUSTED_PROXIES를 빠뜨리면 모든 요청이 프록시 IP 하나로 제한되므로 크게 알림
+        if trusted_proxies.is_empty()
+            && (headers.contains_key("CF-Connecting-IP") || headers.contains_key("X-Forwarded-For"))
+        {
+            UNTRUSTED_FORWARDED_HEADERS.call_once(|| {
+                error!(
+                    "Ignoring CF-Connecting-IP/X-Forwarded-For from {} because TRUSTED_PROXIES is not set. \
+                     Behind a proxy every client shares this IP for rate limits and view counts; \
+                     add the proxy addresses to TRUSTED_PROXIES.",
+                    peer
+                );
+            });
+        }
+        return peer;
+    }
+
+    if let Some(ip) = headers
         .get("CF-Connecting-IP")
         .and_then(|v| v.to_str().ok())
-        .map(ToString::to_string)
-        .or_else(|| {
-            headers
-                .get("X-Forwarded-For")
-                .and_then(|v| v.to_str().ok())
-                .map(|s| s.split(',').next().unwrap_or("").trim().to_string())
-        })
-        .unwrap_or_else(|| addr.ip().to_string())
+        .and_then(|v| v.trim().parse().ok())
+    {
+        return ip;
+    }
+
+    // 클라이언트가 앞쪽 항목을 마음대로 넣을 수 있으므로 뒤에서부터 신뢰하는 프록시를 건너뜀
+    let forwarded = headers
+        .get_all("X-Forwarded-For")
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .map(str::trim)
+        .collect::<Vec<_>>();
+    for entry in forwarded.into_iter().rev() {
+        match entry.parse::<IpAddr>() {
+            Ok(ip) if is_trusted(&ip) => continue,
+            Ok(ip) => return ip,
+            Err(_) => break,
+        }
+    }
+
+    peer
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.append(*name, value.parse().unwrap());
+        }
+        headers
+    }
+
+    #[test]
+    fn ignores_forwarded_headers_from_untrusted_peers() {
+        let trusted = ["10.0.0.0/8".parse().unwrap()];
+        let headers = headers(&[
+            ("CF-Connecting-IP", "1.1.1.1"),
+            ("X-Forwarded-For", "2.2.2.2"),
+        ]);
+
+        let peer: IpAddr = "203.0.113.7".parse().unwrap();
+        assert_eq!(client_ip(&headers, peer, &trusted), peer);
+        assert_eq!(client_ip(&headers, peer, &[]), peer);
+    }
+
+    #[test]
+    fn uses_forwarded_headers_from_trusted_peers() {
+        let trusted = ["10.0.0.0/8".parse().unwrap()];
+        let peer: IpAddr = "10.0.0.2".parse().unwrap();
+
+        let cf = headers(&[("CF-Connecting-IP", "1.1.1.1")]);
+        assert_eq!(
+            client_ip(&cf, peer, &trusted),
+            "1.1.1.1".parse::<IpAddr>().unwrap()
+        );
+
+        // 클라이언트가 넣은 앞쪽 값이 아니라 마지막 신뢰하지 않는 주소를 사용
+        let xff = headers(&[("X-Forwarded-For", "9.9.9.9, 2.2.2.2, 10.0.0.3")]);
+        assert_eq!(
+            client_ip(&xff, peer, &trusted),
+            "2.2.2.2".parse::<IpAddr>().unwrap()
+        );
+
+        let garbage = headers(&[("X-Forwarded-For", "not-an-ip")]);
+        assert_eq!(client_ip(&garbage, peer, &trusted), peer);
+    }
 }
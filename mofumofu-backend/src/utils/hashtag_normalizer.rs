@@ -1,6 +1,9 @@
+use unicode_normalization::UnicodeNormalization;
+
+/// 해시태그 이름 정규화 (NFKC + 소문자, 공백과 '#' 제거, 최대 50자)
 pub fn normalize_hashtag(tag: &str) -> String {
-    tag.trim()
-        .chars()
+    tag.nfkc()
+        .flat_map(char::to_lowercase)
         .filter(|c| !c.is_whitespace() && *c != '#')
         .take(50)
         .collect()
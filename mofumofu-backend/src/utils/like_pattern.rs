@@ -0,0 +1,11 @@
+/// LIKE 패턴에서 와일드카드로 해석되지 않도록 \, %, _ 를 이스케이프
+pub fn escape_like_pattern(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, '\\' | '%' | '_') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
@@ -1,9 +1,21 @@
-use std::sync::LazyLock;
-use tracing::info;
+use crate::config::db_config::DbConfig;
+use opentelemetry::global;
+use opentelemetry::trace::TracerProvider;
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+use opentelemetry_sdk::Resource;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use opentelemetry_sdk::trace::{SdkTracer, SdkTracerProvider};
+use std::sync::{LazyLock, OnceLock};
+use tracing::{Subscriber, info};
+use tracing_opentelemetry::OpenTelemetryLayer;
 use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::registry::LookupSpan;
 use tracing_subscriber::util::SubscriberInitExt;
 use tracing_subscriber::{Layer, fmt};
 
+/// 종료 시 남은 span을 내보내기 위해 보관
+static TRACER_PROVIDER: OnceLock<SdkTracerProvider> = OnceLock::new();
+
 static TRACING_GUARD: LazyLock<tracing_appender::non_blocking::WorkerGuard> = LazyLock::new(|| {
     // logs 디렉토리가 없으면 생성
     std::fs::create_dir_all("logs").expect("Failed to create logs directory");
@@ -11,6 +23,9 @@ static TRACING_GUARD: LazyLock<tracing_appender::non_blocking::WorkerGuard> = La
     let file_appender = tracing_appender::rolling::daily("logs", "app.log");
     let (non_blocking, guard) = tracing_appender::non_blocking(file_appender);
 
+    // 외부 서비스로 trace context(traceparent)를 전달할 때 사용
+    global::set_text_map_propagator(TraceContextPropagator::new());
+
     #[cfg(debug_assertions)]
     {
         tracing_subscriber::registry()
@@ -25,6 +40,7 @@ static TRACING_GUARD: LazyLock<tracing_appender::non_blocking::WorkerGuard> = La
                     .with_ansi(false)
                     .with_filter(tracing_subscriber::filter::LevelFilter::DEBUG),
             )
+            .with(otel_layer())
             .init();
     }
 
@@ -37,6 +53,7 @@ static TRACING_GUARD: LazyLock<tracing_appender::non_blocking::WorkerGuard> = La
                     .with_ansi(false)
                     .with_filter(tracing_subscriber::filter::LevelFilter::DEBUG),
             )
+            .with(otel_layer())
             .init();
     }
 
@@ -45,7 +62,51 @@ static TRACING_GUARD: LazyLock<tracing_appender::non_blocking::WorkerGuard> = La
     guard
 });
 
+/// OTLP 엔드포인트가 설정된 경우에만 OpenTelemetry 레이어 생성
+fn otel_layer<S>() -> Option<impl Layer<S>>
+where
+    S: Subscriber + for<'span> LookupSpan<'span>,
+{
+    let config = DbConfig::get();
+    let endpoint = config.otel_exporter_otlp_endpoint.as_ref()?;
+
+    // 설정 파일에서 지정한 경우도 있으므로 /v1/traces를 붙여 직접 전달
+    let exporter = match SpanExporter::builder()
+        .with_http()
+        .with_endpoint(format!("{}/v1/traces", endpoint.trim_end_matches('/')))
+        .build()
+    {
+        Ok(exporter) => exporter,
+        Err(e) => {
+            eprintln!("Failed to create OTLP span exporter: {}", e);
+            return None;
+        }
+    };
+
+    let provider = SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_resource(
+            Resource::builder()
+                .with_service_name(config.otel_service_name.clone())
+                .build(),
+        )
+        .build();
+    let tracer: SdkTracer = provider.tracer(config.otel_service_name.clone());
+    let _ = TRACER_PROVIDER.set(provider);
+
+    Some(OpenTelemetryLayer::new(tracer).with_filter(tracing_subscriber::filter::LevelFilter::INFO))
+}
+
 pub fn init_tracing() {
     // LazyLock을 강제로 초기화
     LazyLock::force(&TRACING_GUARD);
 }
+
+/// 아직 내보내지 않은 span을 OTLP로 전송하고 종료
+pub fn shutdown_tracing() {
+    if let Some(provider) = TRACER_PROVIDER.get()
+        && let Err(e) = provider.shutdown()
+    {
+        eprintln!("Failed to shut down tracer provider: {}", e);
+    }
+}
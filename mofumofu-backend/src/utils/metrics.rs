@@ -0,0 +1,153 @@
+use metrics::{counter, histogram};
+use metrics_exporter_prometheus::{Matcher, PrometheusBuilder, PrometheusHandle};
+use reqwest::{RequestBuilder, Response};
+use std::future::Future;
+use std::sync::LazyLock;
+use std::time::{Duration, Instant};
+use tracing::info;
+
+// HTTP 요청
+pub const HTTP_REQUESTS_TOTAL: &str = "http_requests_total";
+pub const HTTP_REQUEST_DURATION_SECONDS: &str = "http_request_duration_seconds";
+pub const HTTP_REQUEST_DB_QUERIES: &str = "http_request_db_queries";
+
+// DB 커넥션 풀
+pub const DB_POOL_CONNECTIONS: &str = "db_pool_connections";
+pub const DB_POOL_MAX_CONNECTIONS: &str = "db_pool_max_connections";
+
+// Redis 명령
+pub const REDIS_COMMAND_DURATION_SECONDS: &str = "redis_command_duration_seconds";
+pub const REDIS_COMMAND_ERRORS_TOTAL: &str = "redis_command_errors_total";
+
+// 외부 서비스 호출 (마크다운 서비스, 태스크 서버, Meilisearch)
+pub const EXTERNAL_REQUEST_DURATION_SECONDS: &str = "external_request_duration_seconds";
+pub const EXTERNAL_REQUEST_ERRORS_TOTAL: &str = "external_request_errors_total";
+
+// 비즈니스 지표
+pub const SIGNUPS_TOTAL: &str = "signups_total";
+pub const POSTS_CREATED_TOTAL: &str = "posts_created_total";
+pub const COMMENTS_CREATED_TOTAL: &str = "comments_created_total";
+pub const LIKES_TOTAL: &str = "likes_total";
+
+// 요청 제한
+pub const RATE_LIMITED_REQUESTS_TOTAL: &str = "rate_limited_requests_total";
+
+// 응답 캐시 (endpoint, result = hit | miss | error)
+pub const RESPONSE_CACHE_REQUESTS_TOTAL: &str = "response_cache_requests_total";
+
+/// 지연 시간 히스토그램 버킷 (초)
+const LATENCY_BUCKETS: &[f64] = &[
+    0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// 요청당 DB 쿼리 수 히스토그램 버킷
+const QUERY_COUNT_BUCKETS: &[f64] = &[1.0, 2.0, 3.0, 5.0, 8.0, 13.0, 21.0, 34.0, 55.0, 89.0];
+
+static PROMETHEUS_HANDLE: LazyLock<PrometheusHandle> = LazyLock::new(|| {
+    PrometheusBuilder::new()
+        .set_buckets_for_metric(Matcher::Suffix("_seconds".to_string()), LATENCY_BUCKETS)
+        .expect("Failed to set histogram buckets")
+        .set_buckets_for_metric(
+            Matcher::Full(HTTP_REQUEST_DB_QUERIES.to_string()),
+            QUERY_COUNT_BUCKETS,
+        )
+        .expect("Failed to set histogram buckets")
+        .install_recorder()
+        .expect("Failed to install Prometheus recorder")
+});
+
+/// 전역 메트릭 레코더 설치 및 히스토그램 정리 작업 시작
+pub fn init_metrics() {
+    let handle = PROMETHEUS_HANDLE.clone();
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(5));
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+            handle.run_upkeep();
+        }
+    });
+
+    info!("Prometheus metrics recorder installed");
+}
+
+/// Prometheus 텍스트 형식으로 현재 메트릭 출력
+pub fn render_metrics() -> String {
+    PROMETHEUS_HANDLE.render()
+}
+
+/// 외부 서비스 호출의 지연 시간과 실패 횟수 기록
+pub async fn observe_external_call<T, E, F>(
+    service: &'static str,
+    operation: String,
+    call: F,
+) -> Result<T, E>
+where
+    F: Future<Output = Result<T, E>>,
+{
+    let started = Instant::now();
+    let result = call.await;
+
+    histogram!(
+        EXTERNAL_REQUEST_DURATION_SECONDS,
+        "service" => service,
+        "operation" => operation.clone()
+    )
+    .record(started.elapsed().as_secs_f64());
+
+    if result.is_err() {
+        counter!(
+            EXTERNAL_REQUEST_ERRORS_TOTAL,
+            "service" => service,
+            "operation" => operation
+        )
+        .increment(1);
+    }
+
+    result
+}
+
+/// 마이크로서비스 요청 전송 시 메트릭을 함께 기록하는 확장 트레이트
+pub trait InstrumentedRequest {
+    /// 응답이 2xx가 아니어도 실패로 집계
+    fn send_with_metrics(
+        self,
+        service: &'static str,
+        operation: &'static str,
+    ) -> impl Future<Output = Result<Response, reqwest::Error>> + Send;
+}
+
+impl InstrumentedRequest for RequestBuilder {
+    async fn send_with_metrics(
+        self,
+        service: &'static str,
+        operation: &'static str,
+    ) -> Result<Response, reqwest::Error> {
+        let started = Instant::now();
+        let result = self.send().await;
+
+        histogram!(
+            EXTERNAL_REQUEST_DURATION_SECONDS,
+            "service" => service,
+            "operation" => operation
+        )
+        .record(started.elapsed().as_secs_f64());
+
+        let failed = match &result {
+            Ok(response) => !response.status().is_success(),
+            Err(_) => true,
+        };
+        if failed {
+            counter!(
+                EXTERNAL_REQUEST_ERRORS_TOTAL,
+                "service" => service,
+                "operation" => operation
+            )
+            .increment(1);
+        }
+
+        result
+    }
+}
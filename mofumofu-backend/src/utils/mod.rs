@@ -1,7 +1,16 @@
+pub mod bot_user_agent;
 pub mod crypto;
 pub mod extract_ip_address;
 pub mod extract_user_agent;
 pub mod hashtag_normalizer;
 pub mod image_processor;
 pub mod image_validator;
+pub mod like_pattern;
 pub mod logger;
+pub mod metrics;
+pub mod query_counter;
+pub mod referrer_domain;
+pub mod shutdown;
+#[cfg(test)]
+pub mod test_database;
+pub mod trace_context;
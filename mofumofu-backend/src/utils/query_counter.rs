@@ -0,0 +1,27 @@
+use std::cell::Cell;
+use std::future::Future;
+
+tokio::task_local! {
+    /// 현재 요청에서 실행한 DB 쿼리 수
+    static QUERY_COUNT: Cell<u32>;
+}
+
+/// DB 쿼리 실행 시 호출 (요청 범위 밖에서 실행된 쿼리는 무시)
+pub fn record_query() {
+    let _ = QUERY_COUNT.try_with(|count| count.set(count.get() + 1));
+}
+
+/// future 안에서 실행된 DB 쿼리 수를 함께 반환
+///
+/// 같은 태스크에서 실행된 쿼리만 집계되므로 tokio::spawn으로 넘긴 작업은 포함되지 않음
+pub async fn count_queries<F>(future: F) -> (F::Output, u32)
+where
+    F: Future,
+{
+    QUERY_COUNT
+        .scope(Cell::new(0), async {
+            let output = future.await;
+            (output, QUERY_COUNT.with(Cell::get))
+        })
+        .await
+}
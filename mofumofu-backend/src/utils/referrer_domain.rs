@@ -0,0 +1,18 @@
+use reqwest::Url;
+
+/// 유입 URL에서 도메인만 추출 (소문자, www. 제거, http/https만 허용)
+pub fn extract_referrer_domain(referrer: &str) -> Option<String> {
+    let url = Url::parse(referrer.trim()).ok()?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return None;
+    }
+
+    let host = url.host_str()?.to_lowercase();
+    let domain = host.strip_prefix("www.").unwrap_or(&host);
+
+    if domain.is_empty() {
+        None
+    } else {
+        Some(domain.to_string())
+    }
+}
@@ -0,0 +1,70 @@
+use std::future::Future;
+use std::time::Duration;
+use tokio_util::sync::{CancellationToken, WaitForCancellationFuture};
+use tokio_util::task::TaskTracker;
+use tracing::info;
+
+/// 백그라운드 작업에 종료를 알리고 끝날 때까지 기다리기 위한 핸들
+#[derive(Clone, Default)]
+pub struct BackgroundTasks {
+    token: CancellationToken,
+    tracker: TaskTracker,
+}
+
+impl BackgroundTasks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 종료 시 완료를 기다릴 작업 실행
+    pub fn spawn<F>(&self, task: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.tracker.spawn(task);
+    }
+
+    /// 종료가 요청되면 완료되는 future (작업 루프의 select!에서 사용)
+    pub fn cancelled(&self) -> WaitForCancellationFuture<'_> {
+        self.token.cancelled()
+    }
+
+    /// 종료가 요청되었는지 여부 (배치 사이에서 멈출지 확인할 때 사용)
+    pub fn is_cancelled(&self) -> bool {
+        self.token.is_cancelled()
+    }
+
+    /// 모든 작업에 종료를 알리고 제한 시간 동안 기다림 (시간 내 모두 끝나면 true)
+    pub async fn shutdown(&self, timeout: Duration) -> bool {
+        self.token.cancel();
+        self.tracker.close();
+        tokio::time::timeout(timeout, self.tracker.wait())
+            .await
+            .is_ok()
+    }
+}
+
+/// SIGINT(Ctrl+C) 또는 SIGTERM을 받을 때까지 대기
+pub async fn shutdown_signal() {
+    let ctrl_c = async {
+        tokio::signal::ctrl_c()
+            .await
+            .expect("Failed to install Ctrl+C handler");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("Failed to install SIGTERM handler")
+            .recv()
+            .await;
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => info!("Received SIGINT, shutting down"),
+        _ = terminate => info!("Received SIGTERM, shutting down"),
+    }
+}
@@ -0,0 +1,115 @@
+use crate::entity::comments::{ActiveModel as CommentActiveModel, Model as CommentModel};
+use crate::entity::common::LikeTargetType;
+use crate::entity::hash_tags::{ActiveModel as HashTagActiveModel, Model as HashTagModel};
+use crate::entity::post_hash_tags::ActiveModel as PostHashTagActiveModel;
+use crate::entity::posts::{ActiveModel as PostActiveModel, Model as PostModel};
+use crate::entity::reactions::ActiveModel as ReactionActiveModel;
+use crate::entity::users::{ActiveModel as UserActiveModel, Model as UserModel};
+use crate::service::reaction::reaction_emoji::LIKE_REACTION_EMOJI;
+use crate::utils::query_counter::record_query;
+use sea_orm::{ActiveModelTrait, Database, DatabaseTransaction, Set, TransactionTrait};
+use uuid::Uuid;
+
+/// 테스트용 DB 트랜잭션 (로컬에서 TEST_DATABASE_URL이 없으면 None)
+///
+/// 마이그레이션이 적용된 DB를 가리켜야 하며, 넣은 데이터는 트랜잭션을 롤백해서 지운다.
+/// 운영과 같은 방식으로 쿼리 수를 세도록 메트릭 콜백을 연결함.
+/// CI(CI 환경 변수가 있을 때)에서는 DB 테스트가 조용히 건너뛰어지지 않도록 실패시킴
+pub async fn test_transaction() -> Option<DatabaseTransaction> {
+    let Ok(url) = std::env::var("TEST_DATABASE_URL") else {
+        if std::env::var_os("CI").is_some() {
+            panic!("TEST_DATABASE_URL must be set in CI to run database tests");
+        }
+        eprintln!("TEST_DATABASE_URL is not set, skipping database test");
+        return None;
+    };
+
+    let mut conn = Database::connect(url)
+        .await
+        .expect("failed to connect to TEST_DATABASE_URL");
+    conn.set_metric_callback(|_| record_query());
+    Some(conn.begin().await.expect("failed to begin transaction"))
+}
+
+fn unique_name() -> String {
+    Uuid::new_v4().simple().to_string()[..16].to_string()
+}
+
+pub async fn insert_user(txn: &DatabaseTransaction) -> UserModel {
+    let name = unique_name();
+    UserActiveModel {
+        name: Set(name.clone()),
+        handle: Set(name.clone()),
+        email: Set(format!("{}@example.com", name)),
+        ..Default::default()
+    }
+    .insert(txn)
+    .await
+    .expect("failed to insert user")
+}
+
+pub async fn insert_post(txn: &DatabaseTransaction, user_id: Uuid, language: &str) -> PostModel {
+    PostActiveModel {
+        title: Set("title".to_string()),
+        content: Set("content".to_string()),
+        user_id: Set(user_id),
+        slug: Set(unique_name()),
+        language: Set(Some(language.to_string())),
+        ..Default::default()
+    }
+    .insert(txn)
+    .await
+    .expect("failed to insert post")
+}
+
+pub async fn insert_hashtag(txn: &DatabaseTransaction, post_id: Uuid) -> HashTagModel {
+    let hashtag = HashTagActiveModel {
+        name: Set(unique_name()),
+        ..Default::default()
+    }
+    .insert(txn)
+    .await
+    .expect("failed to insert hashtag");
+
+    PostHashTagActiveModel {
+        post_id: Set(post_id),
+        hash_tag_id: Set(hashtag.id),
+        ..Default::default()
+    }
+    .insert(txn)
+    .await
+    .expect("failed to link hashtag");
+
+    hashtag
+}
+
+pub async fn insert_comment(
+    txn: &DatabaseTransaction,
+    post_id: Uuid,
+    user_id: Uuid,
+    parent_id: Option<Uuid>,
+) -> CommentModel {
+    CommentActiveModel {
+        content: Set("comment".to_string()),
+        post_id: Set(post_id),
+        user_id: Set(user_id),
+        parent_id: Set(parent_id),
+        ..Default::default()
+    }
+    .insert(txn)
+    .await
+    .expect("failed to insert comment")
+}
+
+pub async fn insert_comment_like(txn: &DatabaseTransaction, user_id: Uuid, comment_id: Uuid) {
+    ReactionActiveModel {
+        user_id: Set(user_id),
+        comment_id: Set(Some(comment_id)),
+        target_type: Set(LikeTargetType::Comment),
+        emoji: Set(LIKE_REACTION_EMOJI.to_string()),
+        ..Default::default()
+    }
+    .insert(txn)
+    .await
+    .expect("failed to insert reaction");
+}
@@ -0,0 +1,69 @@
+use opentelemetry::global;
+use opentelemetry::propagation::{Extractor, Injector};
+use reqwest::RequestBuilder;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+tokio::task_local! {
+    /// 현재 처리 중인 요청의 ID (요청 ID 미들웨어에서 설정)
+    pub static REQUEST_ID: String;
+}
+
+/// 현재 요청의 ID (요청 밖에서 실행 중이면 None)
+pub fn current_request_id() -> Option<String> {
+    REQUEST_ID.try_with(|request_id| request_id.clone()).ok()
+}
+
+pub struct HeaderExtractor<'a>(pub &'a HeaderMap);
+
+impl Extractor for HeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|value| value.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(|key| key.as_str()).collect()
+    }
+}
+
+struct HeaderInjector<'a>(&'a mut HeaderMap);
+
+impl Injector for HeaderInjector<'_> {
+    fn set(&mut self, key: &str, value: String) {
+        if let (Ok(name), Ok(value)) = (
+            HeaderName::from_bytes(key.as_bytes()),
+            HeaderValue::from_str(&value),
+        ) {
+            self.0.insert(name, value);
+        }
+    }
+}
+
+/// 요청 ID와 현재 span의 trace context(traceparent)를 헤더에 추가
+pub fn inject_trace_context(headers: &mut HeaderMap) {
+    if let Some(request_id) = current_request_id()
+        && let Ok(value) = HeaderValue::from_str(&request_id)
+    {
+        headers.insert(REQUEST_ID_HEADER, value);
+    }
+
+    let context = tracing::Span::current().context();
+    global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(&context, &mut HeaderInjector(headers))
+    });
+}
+
+/// 외부 서비스 호출 시 trace context를 함께 전달하는 확장 트레이트
+pub trait TracedRequest {
+    fn with_trace_context(self) -> Self;
+}
+
+impl TracedRequest for RequestBuilder {
+    fn with_trace_context(self) -> Self {
+        let mut headers = HeaderMap::new();
+        inject_trace_context(&mut headers);
+        self.headers(headers)
+    }
+}
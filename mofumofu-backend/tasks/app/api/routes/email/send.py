@@ -1,4 +1,6 @@
-from fastapi import APIRouter
+from fastapi import APIRouter, Header
+from typing import Optional
+from app.core.idempotency import enqueue_once
 from app.tasks.email_tasks import (
     send_email_task,
     send_reset_password_email_task,
@@ -60,18 +62,24 @@ async def send_email(request: EmailRequest):
 
 
 @router.post("/send-reset-password")
-async def send_reset_password_email(request: ResetPasswordEmailRequest):
+async def send_reset_password_email(
+    request: ResetPasswordEmailRequest,
+    idempotency_key: Optional[str] = Header(None, alias="Idempotency-Key"),
+):
     """
     비밀번호 재설정 이메일을 보냅니다.
 
     Args:
         request: 비밀번호 재설정 이메일 정보가 포함된 요청 객체
+        idempotency_key: 같은 키로 다시 요청하면 메일을 다시 보내지 않음
 
     Returns:
         dict: 작업 ID와 상태
     """
     try:
-        task = send_reset_password_email_task.delay(
+        task = enqueue_once(
+            send_reset_password_email_task,
+            idempotency_key,
             email_to=request.email_to,
             email=request.email,
             token=request.token,
@@ -88,18 +96,24 @@ async def send_reset_password_email(request: ResetPasswordEmailRequest):
 
 
 @router.post("/send-verification")
-async def send_email_verification(request: EmailVerificationRequest):
+async def send_email_verification(
+    request: EmailVerificationRequest,
+    idempotency_key: Optional[str] = Header(None, alias="Idempotency-Key"),
+):
     """
     이메일 인증 메일을 보냅니다.
 
     Args:
         request: 이메일 인증 정보가 포함된 요청 객체
+        idempotency_key: 같은 키로 다시 요청하면 메일을 다시 보내지 않음
 
     Returns:
         dict: 작업 ID와 상태
     """
     try:
-        task = send_email_verification_task.delay(
+        task = enqueue_once(
+            send_email_verification_task,
+            idempotency_key,
             email_to=request.email_to,
             username=request.username,
             verification_token=request.verification_token,
@@ -1,6 +1,8 @@
-from fastapi import APIRouter
+from fastapi import APIRouter, Header
+from typing import Optional
 from celery.result import AsyncResult
 from app.core.celery_app import celery_app
+from app.core.idempotency import enqueue_once
 from app.tasks.search_tasks import (
     index_single_post_task,
     update_single_post_task,
@@ -19,18 +21,22 @@ class PostIndexRequest(BaseModel):
 
 
 @router.post("/index")
-async def index_post(request: PostIndexRequest):
+async def index_post(
+    request: PostIndexRequest,
+    idempotency_key: Optional[str] = Header(None, alias="Idempotency-Key"),
+):
     """
     단일 포스트를 색인합니다.
 
     Args:
         request: 포스트 ID가 포함된 요청 객체
+        idempotency_key: 같은 키로 다시 요청하면 작업을 다시 넣지 않음
 
     Returns:
         dict: 작업 ID와 상태
     """
     try:
-        task = index_single_post_task.delay(request.post_id)
+        task = enqueue_once(index_single_post_task, idempotency_key, request.post_id)
 
         return {
             "message": "포스트 색인 작업이 큐에 추가되었습니다",
@@ -43,18 +49,22 @@ async def index_post(request: PostIndexRequest):
 
 
 @router.put("/update")
-async def update_post(request: PostIndexRequest):
+async def update_post(
+    request: PostIndexRequest,
+    idempotency_key: Optional[str] = Header(None, alias="Idempotency-Key"),
+):
     """
     단일 포스트 색인을 업데이트합니다.
 
     Args:
         request: 포스트 ID가 포함된 요청 객체
+        idempotency_key: 같은 키로 다시 요청하면 작업을 다시 넣지 않음
 
     Returns:
         dict: 작업 ID와 상태
     """
     try:
-        task = update_single_post_task.delay(request.post_id)
+        task = enqueue_once(update_single_post_task, idempotency_key, request.post_id)
 
         return {
             "message": "포스트 업데이트 작업이 큐에 추가되었습니다",
@@ -67,18 +77,22 @@ async def update_post(request: PostIndexRequest):
 
 
 @router.delete("/delete")
-async def delete_post(request: PostIndexRequest):
+async def delete_post(
+    request: PostIndexRequest,
+    idempotency_key: Optional[str] = Header(None, alias="Idempotency-Key"),
+):
     """
     단일 포스트를 색인에서 삭제합니다.
 
     Args:
         request: 포스트 ID가 포함된 요청 객체
+        idempotency_key: 같은 키로 다시 요청하면 작업을 다시 넣지 않음
 
     Returns:
         dict: 작업 ID와 상태
     """
     try:
-        task = delete_single_post_task.delay(request.post_id)
+        task = enqueue_once(delete_single_post_task, idempotency_key, request.post_id)
 
         return {
             "message": "포스트 삭제 작업이 큐에 추가되었습니다",
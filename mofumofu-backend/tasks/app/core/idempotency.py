@@ -0,0 +1,53 @@
+import logging
+from typing import Any, Optional
+
+import redis
+from celery import Task
+from celery.result import AsyncResult
+
+from app.core.celery_app import celery_app
+from app.core.config import settings
+
+logger = logging.getLogger(__name__)
+
+# 같은 Idempotency-Key로 다시 요청이 와도 작업을 새로 넣지 않는 기간 (백엔드 재시도 기간보다 길게)
+IDEMPOTENCY_TTL_SECONDS = 86400
+
+_redis = redis.Redis.from_url(settings.CELERY_BROKER_URL)
+
+
+def _idempotency_redis_key(idempotency_key: str) -> str:
+    return f"task_idempotency:{idempotency_key}"
+
+
+def enqueue_once(
+    task: Task, idempotency_key: Optional[str], *args: Any, **kwargs: Any
+) -> AsyncResult:
+    """
+    Idempotency-Key가 있으면 같은 키로는 작업을 한 번만 큐에 넣습니다.
+
+    키를 Celery task_id로 사용하고, Redis SETNX로 먼저 자리를 잡은 요청만 작업을 넣습니다.
+    이미 넣은 키로 다시 요청하면 처음 넣은 작업의 결과를 그대로 반환합니다.
+    (백엔드가 요청 후 전달 완료를 기록하기 전에 죽거나 lease가 만료되어 재전송하는 경우)
+
+    Args:
+        task: 큐에 넣을 Celery 작업
+        idempotency_key: 백엔드가 보낸 Idempotency-Key 헤더 값 (없으면 항상 새로 넣음)
+
+    Returns:
+        AsyncResult: 큐에 넣은 (또는 이미 넣은) 작업
+    """
+    if not idempotency_key:
+        return task.apply_async(args=args, kwargs=kwargs)
+
+    redis_key = _idempotency_redis_key(idempotency_key)
+    if not _redis.set(redis_key, idempotency_key, nx=True, ex=IDEMPOTENCY_TTL_SECONDS):
+        logger.info(f"이미 처리한 Idempotency-Key, 작업을 다시 넣지 않음: {idempotency_key}")
+        return AsyncResult(idempotency_key, app=celery_app)
+
+    try:
+        return task.apply_async(args=args, kwargs=kwargs, task_id=idempotency_key)
+    except Exception:
+        # 큐에 넣지 못했으면 재시도할 수 있도록 자리를 풀어 줌
+        _redis.delete(redis_key)
+        raise
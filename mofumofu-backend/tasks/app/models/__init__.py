@@ -7,7 +7,7 @@ from .hashtag import HashTag
 from .comment import Comment
 from .refresh_token import UserRefreshToken
 from .system_event import SystemEvent
-from .like import Like
+from .reaction import Reaction
 from .follow import Follow
 from .report import Report
 
@@ -43,10 +43,10 @@ SystemEvent.user = relationship("User", back_populates="system_events")
 # User에 system_events 관계 추가
 User.system_events = relationship("SystemEvent", back_populates="user", lazy="select")
 
-# Like 관계
-Like.user = relationship("User")
-Like.post = relationship("Post")
-Like.comment = relationship("Comment")
+# Reaction 관계
+Reaction.user = relationship("User")
+Reaction.post = relationship("Post")
+Reaction.comment = relationship("Comment")
 
 # Follow 관계
 Follow.follower = relationship("User", foreign_keys=[Follow.follower_id])
@@ -64,7 +64,7 @@ __all__ = [
     "Comment",
     "UserRefreshToken",
     "SystemEvent",
-    "Like",
+    "Reaction",
     "Follow",
     "Report",
     "post_hash_tags",
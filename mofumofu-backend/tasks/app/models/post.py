@@ -34,5 +34,8 @@ class Post(Base):
     slug = Column(String(80), nullable=False)
     render = Column(Text, nullable=True)
     toc = Column(JSON, nullable=True)
+    word_count = Column(Integer, default=0, nullable=False)
+    reading_time = Column(Integer, default=0, nullable=False)
+    language = Column(String(8), nullable=True)
 
     # 관계 정의는 __init__.py에서 모든 모델 로드 후 설정
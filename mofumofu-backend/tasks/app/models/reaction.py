@@ -1,4 +1,4 @@
-from sqlalchemy import Column, DateTime, ForeignKey, Enum
+from sqlalchemy import Column, DateTime, ForeignKey, Enum, String
 from sqlalchemy.dialects.postgresql import UUID
 from sqlalchemy.sql import func
 from .base import Base
@@ -11,14 +11,19 @@ class LikeTargetType(enum.Enum):
     COMMENT = "comment"
 
 
-class Like(Base):
-    __tablename__ = "likes"
+# 좋아요와 같은 의미로 취급되는 반응 (백엔드의 LIKE_REACTION_EMOJI와 동일)
+LIKE_REACTION_EMOJI = "❤️"
+
+
+class Reaction(Base):
+    __tablename__ = "reactions"
 
     id = Column(UUID(as_uuid=True), primary_key=True, default=uuid.uuid4)
     user_id = Column(UUID(as_uuid=True), ForeignKey("users.id"), nullable=False)
     post_id = Column(UUID(as_uuid=True), ForeignKey("posts.id"), nullable=True)
     comment_id = Column(UUID(as_uuid=True), ForeignKey("comments.id"), nullable=True)
     target_type = Column(Enum(LikeTargetType), nullable=False)
+    emoji = Column(String(32), nullable=False)
     created_at = Column(
         DateTime(timezone=True), server_default=func.now(), nullable=False
     )
@@ -26,4 +31,4 @@ class Like(Base):
     # 관계 정의는 __init__.py에서 모든 모델 로드 후 설정
 
     def __repr__(self):
-        return f"<Like(user_id='{self.user_id}', target_type='{self.target_type}')>"
+        return f"<Reaction(user_id='{self.user_id}', emoji='{self.emoji}')>"
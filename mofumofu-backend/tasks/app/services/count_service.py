@@ -1,5 +1,6 @@
 from sqlalchemy import func
-from app.models import User, Post, Like, Follow
+from app.models import User, Post, Reaction, Follow
+from app.models.reaction import LIKE_REACTION_EMOJI
 from app.services.base_db_service import base_db_service
 import logging
 
@@ -14,7 +15,7 @@ class CountService:
 
     def sync_post_like_counts(self) -> dict:
         """
-        모든 포스트의 like_count를 실제 likes 테이블의 개수로 동기화
+        모든 포스트의 like_count를 실제 ❤️ 반응 개수로 동기화
         """
         try:
             logger.info("Starting post like count synchronization")
@@ -23,10 +24,11 @@ class CountService:
                 # 실제 like 개수 계산하고 업데이트
                 subquery = (
                     session.query(
-                        Like.post_id,
-                        func.count(Like.id).label('actual_count')
+                        Reaction.post_id,
+                        func.count(Reaction.id).label('actual_count')
                     )
-                    .group_by(Like.post_id)
+                    .filter(Reaction.emoji == LIKE_REACTION_EMOJI)
+                    .group_by(Reaction.post_id)
                     .subquery()
                 )
                 
@@ -42,8 +44,9 @@ class CountService:
                 for post in updated_posts:
                     # 해당 포스트의 실제 like 개수 조회
                     actual_count = (
-                        session.query(func.count(Like.id))
-                        .filter(Like.post_id == post.id)
+                        session.query(func.count(Reaction.id))
+                        .filter(Reaction.post_id == post.id)
+                        .filter(Reaction.emoji == LIKE_REACTION_EMOJI)
                         .scalar() or 0
                     )
                     
@@ -53,8 +56,11 @@ class CountService:
                 # like가 0인 포스트들도 처리 (subquery에서 누락된 것들)
                 posts_without_likes = (
                     session.query(Post)
-                    .outerjoin(Like, Post.id == Like.post_id)
-                    .filter(Like.post_id.is_(None))
+                    .outerjoin(
+                        Reaction,
+                        (Post.id == Reaction.post_id) & (Reaction.emoji == LIKE_REACTION_EMOJI),
+                    )
+                    .filter(Reaction.post_id.is_(None))
                     .filter(Post.like_count != 0)
                     .all()
                 )
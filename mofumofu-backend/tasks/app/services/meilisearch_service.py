@@ -113,6 +113,7 @@ class MeilisearchService:
                     "like_count": post.get("like_count", 0),
                     "comment_count": post.get("comment_count", 0),
                     "view_count": post.get("view_count", 0),
+                    "language": post.get("language"),
                 }
                 documents.append(document)
 
@@ -169,6 +170,7 @@ class MeilisearchService:
                 "created_at",
                 "like_count",
                 "view_count",
+                "language",
             ]
         )
 
@@ -202,6 +204,7 @@ class MeilisearchService:
                 "like_count": post.get("like_count", 0),
                 "comment_count": post.get("comment_count", 0),
                 "view_count": post.get("view_count", 0),
+                "language": post.get("language"),
             }
 
             index = self.client.index(self.index_name)
@@ -242,6 +245,7 @@ class MeilisearchService:
                 "like_count": post.get("like_count", 0),
                 "comment_count": post.get("comment_count", 0),
                 "view_count": post.get("view_count", 0),
+                "language": post.get("language"),
             }
 
             index = self.client.index(self.index_name)
@@ -175,6 +175,7 @@ class PostService:
                         "like_count": post.like_count or 0,
                         "view_count": post.view_count or 0,
                         "comment_count": post.comment_count or 0,
+                        "language": post.language,
                     })
                 
                 logger.info(f"색인용 포스트 데이터 조회 완료: {len(result_posts)}개")
@@ -232,6 +233,7 @@ class PostService:
                         "like_count": post.like_count or 0,
                         "view_count": post.view_count or 0,
                         "comment_count": post.comment_count or 0,
+                        "language": post.language,
                     })
                 
                 return result_posts
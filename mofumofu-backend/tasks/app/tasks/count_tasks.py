@@ -8,7 +8,7 @@ logger = logging.getLogger(__name__)
 @celery_app.task(name="sync_post_like_counts")
 def sync_post_like_counts():
     """
-    모든 포스트의 like_count를 실제 likes 테이블의 개수로 동기화하는 태스크
+    모든 포스트의 like_count를 실제 ❤️ 반응 개수로 동기화하는 태스크
     
     Returns:
         dict: 동기화 결과 및 업데이트된 포스트 수